@@ -1,7 +1,9 @@
 mod demonstrator;
+mod simulation;
 mod types;
 
 use crate::demonstrator::{Demonstrator, ProtocolDemonstrator};
+use crate::simulation::SimulateParametersCommand;
 use clap::{CommandFactory, Parser, Subcommand};
 use mithril_doc::GenerateDocCommands;
 use rand_chacha::ChaCha20Rng;
@@ -46,6 +48,9 @@ impl Clone for Config {
 
 #[derive(Subcommand, Debug, PartialEq)]
 enum DemoCommands {
+    /// Simulate the protocol parameters against a stake distribution
+    SimulateParameters(SimulateParametersCommand),
+
     #[clap(alias("doc"), hide(true))]
     GenerateDoc(GenerateDocCommands),
 }
@@ -53,9 +58,19 @@ enum DemoCommands {
 fn main() {
     let config = Config::parse();
 
-    if let Some(DemoCommands::GenerateDoc(cmd)) = &config.command {
-        cmd.execute(&mut Config::command()).unwrap();
-        return;
+    match &config.command {
+        Some(DemoCommands::GenerateDoc(cmd)) => {
+            cmd.execute(&mut Config::command()).unwrap();
+            return;
+        }
+        Some(DemoCommands::SimulateParameters(cmd)) => {
+            if let Err(err) = cmd.execute(&config) {
+                eprintln!(">> Parameters simulation failed: {err}");
+                std::process::exit(1);
+            }
+            return;
+        }
+        None => {}
     }
 
     println!(">> Launch Mithril protocol demonstrator with configuration: \n{config:#?}");
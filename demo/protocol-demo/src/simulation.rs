@@ -0,0 +1,76 @@
+use std::{error::Error, fs, path::PathBuf};
+
+use blake2::{digest::consts::U32, Blake2b};
+use clap::Parser;
+use mithril_common::entities::StakeDistribution;
+use mithril_stm::{simulation::ParametersSimulator, stm::StmParameters};
+use rand_chacha::ChaCha20Rng;
+use rand_core::SeedableRng;
+
+use crate::Config;
+
+/// Simulate the protocol parameters against a stake distribution
+#[derive(Parser, Debug, Clone, PartialEq)]
+pub struct SimulateParametersCommand {
+    /// Path to a JSON file with the stake of each party, ie: `{"pool1...": 1000, ...}`
+    #[clap(long)]
+    stake_distribution_file: PathBuf,
+
+    /// Participation rates of the signers to simulate
+    #[clap(long, value_delimiter = ',', default_value = "0.5,0.7,0.9,1.0")]
+    participation_rates: Vec<f64>,
+
+    /// Number of Monte Carlo runs for each participation rate
+    #[clap(long, default_value_t = 100)]
+    runs: usize,
+
+    /// Security level used to compute the adversarial stake threshold, in bits
+    #[clap(long, default_value_t = 128)]
+    security_bits: u32,
+
+    /// Seed of the random number generator
+    #[clap(long, default_value_t = 0)]
+    seed: u64,
+}
+
+impl SimulateParametersCommand {
+    pub fn execute(&self, config: &Config) -> Result<(), Box<dyn Error>> {
+        if let Some(rate) = self
+            .participation_rates
+            .iter()
+            .find(|rate| !(0.0..=1.0).contains(*rate))
+        {
+            return Err(format!("participation rate must be between 0 and 1, got {rate}").into());
+        }
+
+        let content = fs::read_to_string(&self.stake_distribution_file).map_err(|e| {
+            format!(
+                "could not read stake distribution file '{}': {e}",
+                self.stake_distribution_file.display()
+            )
+        })?;
+        let stake_distribution: StakeDistribution = serde_json::from_str(&content)
+            .map_err(|e| format!("could not parse stake distribution file: {e}"))?;
+        let stakes: Vec<_> = stake_distribution.into_values().collect();
+
+        let params = StmParameters {
+            m: config.m,
+            k: config.k,
+            phi_f: config.phi_f,
+        };
+        let simulator = ParametersSimulator::new(params, &stakes).ok_or(
+            "the total stake of the distribution must be strictly positive and fit in u64",
+        )?;
+
+        let mut rng = ChaCha20Rng::seed_from_u64(self.seed);
+        let report = simulator.simulate::<Blake2b<U32>, _>(
+            &self.participation_rates,
+            self.runs,
+            self.security_bits,
+            &mut rng,
+        );
+        println!("{}", serde_json::to_string_pretty(&report)?);
+
+        Ok(())
+    }
+}
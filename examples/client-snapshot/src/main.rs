@@ -145,6 +145,16 @@ impl FeedbackReceiver for IndicatifFeedbackReceiver {
                     progress_bar.set_position(downloaded_bytes);
                 }
             }
+            MithrilEvent::SnapshotDownloadResumed {
+                download_id: _,
+                resumed_offset,
+                size: _,
+            } => {
+                let download_pb = self.download_pb.read().await;
+                if let Some(progress_bar) = download_pb.as_ref() {
+                    progress_bar.set_position(resumed_offset);
+                }
+            }
             MithrilEvent::SnapshotDownloadCompleted { download_id: _ } => {
                 let mut download_pb = self.download_pb.write().await;
                 if let Some(progress_bar) = download_pb.as_ref() {
@@ -152,6 +162,19 @@ impl FeedbackReceiver for IndicatifFeedbackReceiver {
                 }
                 *download_pb = None;
             }
+            MithrilEvent::SnapshotDownloadLocationSwitched {
+                download_id: _,
+                failed_location,
+                next_location,
+                reason,
+            } => {
+                println!("Download from '{failed_location}' failed ({reason}), switching to '{next_location}'");
+            }
+            MithrilEvent::CardanoDatabaseDownloadStarted { .. }
+            | MithrilEvent::CardanoDatabaseDownloadLocationSwitched { .. }
+            | MithrilEvent::CardanoDatabaseImmutableDownloadCompleted { .. }
+            | MithrilEvent::CardanoDatabaseAncillaryDownloadCompleted { .. }
+            | MithrilEvent::CardanoDatabaseDownloadCompleted { .. } => {}
             MithrilEvent::CertificateChainValidationStarted {
                 certificate_chain_validation_id: _,
             } => {
@@ -119,5 +119,29 @@ delete from block_range_root;
 vacuum;
  "#,
         ),
+        // Migration 10
+        // Add the `cardano_block` and `block_hash_range_root` tables.
+        // The blocks of the already stored transactions are backfilled by the importers.
+        SqlMigration::new(
+            10,
+            r#"
+create table cardano_block (
+    block_number    integer   not null,
+    slot_number     integer   not null,
+    block_hash      text      not null,
+    primary key (block_number)
+);
+
+create index cardano_block_block_hash_index on cardano_block(block_hash);
+create index cardano_block_slot_number_index on cardano_block(slot_number);
+
+create table block_hash_range_root (
+    start         integer   not null,
+    end           integer   not null,
+    merkle_root   text      not null,
+    primary key (start, end)
+);
+"#,
+        ),
     ]
 }
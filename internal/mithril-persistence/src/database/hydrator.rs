@@ -93,6 +93,21 @@ impl Hydrator {
                 })?;
                 SignedEntityType::CardanoDatabase(beacon)
             }
+            SignedEntityTypeDiscriminants::CardanoBlocks => {
+                #[derive(Deserialize)]
+                struct CardanoBlocksBeacon {
+                    epoch: Epoch,
+                    block_number: BlockNumber,
+                }
+
+                let beacon: CardanoBlocksBeacon =
+                    serde_json::from_str(beacon_str).map_err(|e| {
+                        HydrationError::InvalidData(format!(
+                        "Invalid Beacon JSON in open_message.beacon: '{beacon_str}'. Error: {e}"
+                    ))
+                    })?;
+                SignedEntityType::CardanoBlocks(beacon.epoch, beacon.block_number)
+            }
         };
 
         Ok(signed_entity)
@@ -126,4 +141,16 @@ mod tests {
 
         assert_eq!(expected, signed_entity);
     }
+
+    #[test]
+    fn hydrate_cardano_blocks_signed_entity_type() {
+        let expected = SignedEntityType::CardanoBlocks(Epoch(35), BlockNumber(77));
+        let signed_entity = Hydrator::hydrate_signed_entity_type(
+            SignedEntityTypeDiscriminants::CardanoBlocks.index(),
+            &expected.get_json_beacon().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(expected, signed_entity);
+    }
 }
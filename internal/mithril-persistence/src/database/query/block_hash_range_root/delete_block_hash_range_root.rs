@@ -0,0 +1,91 @@
+use anyhow::Context;
+use sqlite::Value;
+
+use mithril_common::entities::{BlockNumber, BlockRange};
+use mithril_common::StdResult;
+
+use crate::database::record::BlockRangeRootRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Query to delete old block hashes [BlockRangeRootRecord] from the sqlite database
+pub struct DeleteBlockHashRangeRootQuery {
+    condition: WhereCondition,
+}
+
+impl Query for DeleteBlockHashRangeRootQuery {
+    type Entity = BlockRangeRootRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        // it is important to alias the fields with the same name as the table
+        // since the table cannot be aliased in a RETURNING statement in SQLite.
+        let aliases = SourceAlias::new(&[("{:block_range_root:}", "block_hash_range_root")]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!("delete from block_hash_range_root where {condition} returning {projection}")
+    }
+}
+
+impl DeleteBlockHashRangeRootQuery {
+    pub fn contains_or_above_block_number_threshold(
+        block_number_threshold: BlockNumber,
+    ) -> StdResult<Self> {
+        let block_range = BlockRange::from_block_number(block_number_threshold);
+        let threshold = Value::Integer(block_range.start.try_into().with_context(|| {
+            format!("Failed to convert threshold `{block_number_threshold}` to i64")
+        })?);
+
+        Ok(Self {
+            condition: WhereCondition::new("start >= ?*", vec![threshold]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::crypto_helper::MKTreeNode;
+
+    use crate::database::query::block_hash_range_root::test_helper::insert_block_hash_range_roots;
+    use crate::database::query::GetBlockHashRangeRootQuery;
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::sqlite::ConnectionExtensions;
+
+    use super::*;
+
+    #[test]
+    fn test_prune_block_hash_range_root_when_block_number_is_in_block_range() {
+        let connection = cardano_tx_db_connection().unwrap();
+        let dataset: Vec<BlockRangeRootRecord> = [
+            (BlockRange::LENGTH, "AAAA"),
+            (BlockRange::LENGTH * 2, "BBBB"),
+            (BlockRange::LENGTH * 3, "CCCC"),
+        ]
+        .into_iter()
+        .map(|(block_number, root)| {
+            BlockRangeRootRecord::from((
+                BlockRange::from_block_number(block_number),
+                MKTreeNode::from_hex(root).unwrap(),
+            ))
+        })
+        .collect();
+        insert_block_hash_range_roots(&connection, dataset.clone());
+
+        let cursor = connection
+            .fetch(
+                DeleteBlockHashRangeRootQuery::contains_or_above_block_number_threshold(
+                    BlockRange::LENGTH * 2 + 1,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(2, cursor.count());
+
+        let records: Vec<BlockRangeRootRecord> = connection
+            .fetch_collect(GetBlockHashRangeRootQuery::all())
+            .unwrap();
+        assert_eq!(dataset[0..1].to_vec(), records);
+    }
+}
@@ -0,0 +1,131 @@
+use sqlite::Value;
+
+use mithril_common::entities::BlockNumber;
+
+use crate::database::record::BlockRangeRootRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Simple queries to retrieve the block hashes [BlockRangeRootRecord] from the sqlite database.
+pub struct GetBlockHashRangeRootQuery {
+    condition: WhereCondition,
+}
+
+impl GetBlockHashRangeRootQuery {
+    pub fn all() -> Self {
+        Self {
+            condition: WhereCondition::default(),
+        }
+    }
+
+    pub fn contains_or_below_block_number(block_number: BlockNumber) -> Self {
+        Self {
+            condition: WhereCondition::new(
+                "start < ?*",
+                vec![Value::Integer(*block_number as i64)],
+            ),
+        }
+    }
+
+    pub fn highest() -> Self {
+        Self {
+            condition: WhereCondition::new(
+                "end = (select max(end) from block_hash_range_root)",
+                vec![],
+            ),
+        }
+    }
+}
+
+impl Query for GetBlockHashRangeRootQuery {
+    type Entity = BlockRangeRootRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[("{:block_range_root:}", "block_hash_range_root")]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!(
+            "select {projection} from block_hash_range_root where {condition} order by start, end"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::crypto_helper::MKTreeNode;
+    use mithril_common::entities::BlockRange;
+
+    use crate::database::query::block_hash_range_root::test_helper::insert_block_hash_range_roots;
+    use crate::database::query::block_range_root::GetBlockRangeRootQuery;
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::sqlite::ConnectionExtensions;
+
+    use super::*;
+
+    fn block_hash_range_root_dataset() -> Vec<BlockRangeRootRecord> {
+        [
+            (
+                BlockRange::from_block_number(BlockNumber(15)),
+                MKTreeNode::from_hex("AAAA").unwrap(),
+            ),
+            (
+                BlockRange::from_block_number(BlockNumber(30)),
+                MKTreeNode::from_hex("BBBB").unwrap(),
+            ),
+            (
+                BlockRange::from_block_number(BlockNumber(45)),
+                MKTreeNode::from_hex("CCCC").unwrap(),
+            ),
+        ]
+        .into_iter()
+        .map(BlockRangeRootRecord::from)
+        .collect()
+    }
+
+    #[test]
+    fn test_get_contains_or_below_block_number() {
+        let connection = cardano_tx_db_connection().unwrap();
+        let dataset = block_hash_range_root_dataset();
+        insert_block_hash_range_roots(&connection, dataset.clone());
+
+        let records: Vec<BlockRangeRootRecord> = connection
+            .fetch_collect(GetBlockHashRangeRootQuery::contains_or_below_block_number(
+                BlockNumber(44),
+            ))
+            .unwrap();
+
+        assert_eq!(&dataset[0..2], &records);
+    }
+
+    #[test]
+    fn test_get_highest() {
+        let connection = cardano_tx_db_connection().unwrap();
+        let record: Option<BlockRangeRootRecord> = connection
+            .fetch_first(GetBlockHashRangeRootQuery::highest())
+            .unwrap();
+        assert_eq!(None, record);
+
+        let dataset = block_hash_range_root_dataset();
+        insert_block_hash_range_roots(&connection, dataset.clone());
+
+        let record: Option<BlockRangeRootRecord> = connection
+            .fetch_first(GetBlockHashRangeRootQuery::highest())
+            .unwrap();
+        assert_eq!(dataset.last().cloned(), record);
+    }
+
+    #[test]
+    fn test_block_hash_range_roots_are_not_mixed_with_transactions_block_range_roots() {
+        let connection = cardano_tx_db_connection().unwrap();
+        insert_block_hash_range_roots(&connection, block_hash_range_root_dataset());
+
+        let records: Vec<BlockRangeRootRecord> = connection
+            .fetch_collect(GetBlockRangeRootQuery::all())
+            .unwrap();
+
+        assert_eq!(Vec::<BlockRangeRootRecord>::new(), records);
+    }
+}
@@ -0,0 +1,28 @@
+//! Queries on the Merkle roots of the block hashes of each block range.
+//!
+//! The records are stored in their own table but share the [BlockRangeRootRecord][crate::database::record::BlockRangeRootRecord]
+//! representation with the transactions block range roots.
+mod delete_block_hash_range_root;
+mod get_block_hash_range_root;
+mod insert_block_hash_range_root;
+
+pub use delete_block_hash_range_root::*;
+pub use get_block_hash_range_root::*;
+pub use insert_block_hash_range_root::*;
+
+#[cfg(test)]
+mod test_helper {
+    use crate::database::record::BlockRangeRootRecord;
+    use crate::sqlite::{ConnectionExtensions, SqliteConnection};
+
+    use super::*;
+
+    pub fn insert_block_hash_range_roots(
+        connection: &SqliteConnection,
+        records: Vec<BlockRangeRootRecord>,
+    ) {
+        connection
+            .fetch_first(InsertBlockHashRangeRootQuery::insert_many(records).unwrap())
+            .unwrap();
+    }
+}
@@ -0,0 +1,200 @@
+use std::ops::Range;
+
+use sqlite::Value;
+
+use mithril_common::entities::{BlockHash, BlockNumber, BlockRange, SlotNumber};
+
+use crate::database::record::CardanoBlockRecord;
+use crate::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+/// Simple queries to retrieve [CardanoBlockRecord] from the sqlite database.
+pub struct GetCardanoBlockQuery {
+    condition: WhereCondition,
+}
+
+impl GetCardanoBlockQuery {
+    pub fn all() -> Self {
+        Self {
+            condition: WhereCondition::default(),
+        }
+    }
+
+    pub fn by_block_hashes(blocks_hashes: Vec<BlockHash>, up_to_or_equal: BlockNumber) -> Self {
+        let hashes_values = blocks_hashes.into_iter().map(Value::String).collect();
+        let condition =
+            WhereCondition::where_in("block_hash", hashes_values).and_where(WhereCondition::new(
+                "block_number <= ?*",
+                vec![Value::Integer(*up_to_or_equal as i64)],
+            ));
+
+        Self { condition }
+    }
+
+    pub fn by_block_ranges(block_ranges: Vec<BlockRange>) -> Self {
+        let mut condition = WhereCondition::default();
+        for block_range in block_ranges {
+            condition = condition.or_where(WhereCondition::new(
+                "(block_number >= ?* and block_number < ?*)",
+                vec![
+                    Value::Integer(*block_range.start as i64),
+                    Value::Integer(*block_range.end as i64),
+                ],
+            ))
+        }
+
+        Self { condition }
+    }
+
+    pub fn between_blocks(range: Range<BlockNumber>) -> Self {
+        let condition = WhereCondition::new(
+            "block_number >= ?*",
+            vec![Value::Integer(*range.start as i64)],
+        )
+        .and_where(WhereCondition::new(
+            "block_number < ?*",
+            vec![Value::Integer(*range.end as i64)],
+        ));
+
+        Self { condition }
+    }
+
+    pub fn with_highest_block_number() -> Self {
+        Self {
+            condition: WhereCondition::new(
+                "block_number = (select max(block_number) from cardano_block)",
+                vec![],
+            ),
+        }
+    }
+
+    pub fn with_highest_block_number_below_slot_number(slot_number: SlotNumber) -> Self {
+        Self {
+            condition: WhereCondition::new(
+                "block_number = (select max(block_number) from cardano_block where slot_number <= ?*)",
+                vec![Value::Integer(*slot_number as i64)],
+            ),
+        }
+    }
+}
+
+impl Query for GetCardanoBlockQuery {
+    type Entity = CardanoBlockRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[("{:cardano_block:}", "cardano_block")]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+
+        format!("select {projection} from cardano_block where {condition} order by block_number")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::database::query::cardano_block::test_helper::insert_blocks;
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::sqlite::ConnectionExtensions;
+
+    use super::*;
+
+    fn block_record(block_number: u64) -> CardanoBlockRecord {
+        CardanoBlockRecord::new(
+            format!("block-hash-{block_number}"),
+            BlockNumber(block_number),
+            SlotNumber(block_number * 10),
+        )
+    }
+
+    #[test]
+    fn by_block_hashes_up_to_a_block_number() {
+        let connection = cardano_tx_db_connection().unwrap();
+        insert_blocks(
+            &connection,
+            vec![block_record(10), block_record(11), block_record(12)],
+        );
+
+        let records: Vec<CardanoBlockRecord> = connection
+            .fetch_collect(GetCardanoBlockQuery::by_block_hashes(
+                vec![
+                    "block-hash-10".to_string(),
+                    "block-hash-12".to_string(),
+                    "unknown".to_string(),
+                ],
+                BlockNumber(11),
+            ))
+            .unwrap();
+
+        assert_eq!(vec![block_record(10)], records);
+    }
+
+    #[test]
+    fn by_block_ranges() {
+        let connection = cardano_tx_db_connection().unwrap();
+        let length = *BlockRange::LENGTH;
+        insert_blocks(
+            &connection,
+            vec![
+                block_record(0),
+                block_record(length - 1),
+                block_record(length),
+                block_record(length * 2),
+            ],
+        );
+
+        let records: Vec<CardanoBlockRecord> = connection
+            .fetch_collect(GetCardanoBlockQuery::by_block_ranges(vec![
+                BlockRange::from_block_number(BlockNumber(0)),
+                BlockRange::from_block_number(BlockNumber(length * 2)),
+            ]))
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                block_record(0),
+                block_record(length - 1),
+                block_record(length * 2)
+            ],
+            records
+        );
+    }
+
+    #[test]
+    fn with_highest_block_number() {
+        let connection = cardano_tx_db_connection().unwrap();
+
+        let record: Option<CardanoBlockRecord> = connection
+            .fetch_first(GetCardanoBlockQuery::with_highest_block_number())
+            .unwrap();
+        assert_eq!(None, record);
+
+        insert_blocks(
+            &connection,
+            vec![block_record(10), block_record(14), block_record(11)],
+        );
+
+        let record: Option<CardanoBlockRecord> = connection
+            .fetch_first(GetCardanoBlockQuery::with_highest_block_number())
+            .unwrap();
+        assert_eq!(Some(block_record(14)), record);
+    }
+
+    #[test]
+    fn with_highest_block_number_below_slot_number() {
+        let connection = cardano_tx_db_connection().unwrap();
+        insert_blocks(
+            &connection,
+            vec![block_record(10), block_record(11), block_record(14)],
+        );
+
+        let record: Option<CardanoBlockRecord> = connection
+            .fetch_first(
+                GetCardanoBlockQuery::with_highest_block_number_below_slot_number(SlotNumber(139)),
+            )
+            .unwrap();
+
+        assert_eq!(Some(block_record(11)), record);
+    }
+}
@@ -0,0 +1,21 @@
+mod delete_cardano_block;
+mod get_cardano_block;
+mod insert_cardano_block;
+
+pub use delete_cardano_block::*;
+pub use get_cardano_block::*;
+pub use insert_cardano_block::*;
+
+#[cfg(test)]
+mod test_helper {
+    use crate::database::record::CardanoBlockRecord;
+    use crate::sqlite::{ConnectionExtensions, SqliteConnection};
+
+    use super::*;
+
+    pub fn insert_blocks(connection: &SqliteConnection, records: Vec<CardanoBlockRecord>) {
+        connection
+            .fetch_first(InsertCardanoBlockQuery::insert_many(records).unwrap())
+            .unwrap();
+    }
+}
@@ -1,6 +1,10 @@
 //! Shared database queries
+mod block_hash_range_root;
 mod block_range_root;
+mod cardano_block;
 mod cardano_transaction;
 
+pub use block_hash_range_root::*;
 pub use block_range_root::*;
+pub use cardano_block::*;
 pub use cardano_transaction::*;
@@ -1,7 +1,9 @@
 //! Shared database records
 
 mod block_range_root;
+mod cardano_block;
 mod cardano_transaction;
 
 pub use block_range_root::*;
+pub use cardano_block::*;
 pub use cardano_transaction::*;
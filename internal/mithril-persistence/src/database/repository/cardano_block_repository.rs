@@ -0,0 +1,494 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use anyhow::Context;
+use async_trait::async_trait;
+
+use mithril_common::crypto_helper::{MKTreeNode, MKTreeStorer};
+use mithril_common::entities::{
+    BlockHash, BlockNumber, BlockRange, CardanoBlock, ChainPoint, SlotNumber,
+};
+use mithril_common::signable_builder::BlockRangeRootRetriever;
+use mithril_common::StdResult;
+
+use crate::database::query::{
+    DeleteBlockHashRangeRootQuery, DeleteCardanoBlockQuery, GetBlockHashRangeRootQuery,
+    GetCardanoBlockQuery, InsertBlockHashRangeRootQuery, InsertCardanoBlockQuery,
+};
+use crate::database::record::{BlockRangeRootRecord, CardanoBlockRecord};
+use crate::sqlite::{ConnectionExtensions, SqliteConnectionPool};
+
+/// ## Cardano block repository
+///
+/// This is a business oriented layer to perform actions on the database through
+/// queries.
+///
+/// The Merkle roots of the block hashes of each block range are stored alongside the blocks.
+pub struct CardanoBlockRepository {
+    connection_pool: Arc<SqliteConnectionPool>,
+}
+
+impl CardanoBlockRepository {
+    /// Instantiate service
+    pub fn new(connection_pool: Arc<SqliteConnectionPool>) -> Self {
+        Self { connection_pool }
+    }
+
+    /// Retrieve all the [CardanoBlock] in database.
+    pub async fn get_all(&self) -> StdResult<Vec<CardanoBlock>> {
+        let records = self
+            .connection_pool
+            .connection()?
+            .fetch(GetCardanoBlockQuery::all())?
+            .map(|record| record.into())
+            .collect();
+
+        Ok(records)
+    }
+
+    /// Return the [ChainPoint] of the block with the highest block number stored in the database.
+    pub async fn get_block_highest_chain_point(&self) -> StdResult<Option<ChainPoint>> {
+        let block_with_highest_block_number = self
+            .connection_pool
+            .connection()?
+            .fetch_first(GetCardanoBlockQuery::with_highest_block_number())?;
+
+        Ok(block_with_highest_block_number.map(|record| {
+            ChainPoint::new(record.slot_number, record.block_number, record.block_hash)
+        }))
+    }
+
+    /// Return all the [CardanoBlockRecord]s in the database where block number is in the
+    /// given range.
+    pub async fn get_blocks_in_range_blocks(
+        &self,
+        range: Range<BlockNumber>,
+    ) -> StdResult<Vec<CardanoBlockRecord>> {
+        self.connection_pool
+            .connection()?
+            .fetch_collect(GetCardanoBlockQuery::between_blocks(range))
+    }
+
+    /// Get the [CardanoBlockRecord] for the given block hashes, up to a block number
+    pub async fn get_block_by_hashes<T: Into<BlockHash>>(
+        &self,
+        hashes: Vec<T>,
+        up_to: BlockNumber,
+    ) -> StdResult<Vec<CardanoBlockRecord>> {
+        let query = GetCardanoBlockQuery::by_block_hashes(
+            hashes.into_iter().map(Into::into).collect(),
+            up_to,
+        );
+        self.connection_pool.connection()?.fetch_collect(query)
+    }
+
+    /// Get the [CardanoBlockRecord] for the given block ranges.
+    pub async fn get_block_by_block_ranges(
+        &self,
+        block_ranges: Vec<BlockRange>,
+    ) -> StdResult<Vec<CardanoBlockRecord>> {
+        let mut blocks = vec![];
+        for block_range in block_ranges {
+            let block_range_blocks: Vec<CardanoBlockRecord> = self
+                .connection_pool
+                .connection()?
+                .fetch_collect(GetCardanoBlockQuery::by_block_ranges(vec![block_range]))?;
+            blocks.extend(block_range_blocks);
+        }
+
+        Ok(blocks)
+    }
+
+    /// Store the given blocks in the database.
+    ///
+    /// The storage is done in chunks to avoid exceeding sqlite binding limitations.
+    pub async fn store_blocks<T: Into<CardanoBlockRecord> + Clone>(
+        &self,
+        blocks: Vec<T>,
+    ) -> StdResult<()> {
+        let connection = self.connection_pool.connection()?;
+        let transaction = connection.begin_transaction()?;
+
+        // Chunk blocks to avoid an error when we exceed sqlite binding limitations
+        for blocks_in_chunk in blocks.chunks(100) {
+            let records: Vec<CardanoBlockRecord> =
+                blocks_in_chunk.iter().cloned().map(Into::into).collect();
+            connection
+                .fetch_first(InsertCardanoBlockQuery::insert_many(records)?)
+                .with_context(|| "CardanoBlockRepository can not store blocks")?;
+        }
+
+        transaction.commit()?;
+        Ok(())
+    }
+
+    /// Create new block hashes [BlockRangeRootRecord]s in the database.
+    pub async fn create_block_hash_range_roots<T: Into<BlockRangeRootRecord>>(
+        &self,
+        block_ranges: Vec<T>,
+    ) -> StdResult<Vec<BlockRangeRootRecord>> {
+        let records: Vec<BlockRangeRootRecord> =
+            block_ranges.into_iter().map(|root| root.into()).collect();
+        let connection = self.connection_pool.connection()?;
+
+        connection.fetch_collect(InsertBlockHashRangeRootQuery::insert_many(records)?)
+    }
+
+    /// Retrieve all the block hashes [BlockRangeRootRecord] in database.
+    pub fn get_all_block_hash_range_root(&self) -> StdResult<Vec<BlockRangeRootRecord>> {
+        self.connection_pool
+            .connection()?
+            .fetch_collect(GetBlockHashRangeRootQuery::all())
+    }
+
+    /// Get the highest start [BlockNumber] of the block hashes range roots stored in the database.
+    pub async fn get_highest_start_block_number_for_block_hash_range_roots(
+        &self,
+    ) -> StdResult<Option<BlockNumber>> {
+        let highest: Option<i64> = self.connection_pool.connection()?.query_single_cell(
+            "select max(start) as highest from block_hash_range_root;",
+            &[],
+        )?;
+        highest
+            .map(u64::try_from)
+            .transpose()
+            .map(|num| num.map(BlockNumber))
+            .with_context(||
+                format!("Integer field max(start) (value={highest:?}) is incompatible with u64 representation.")
+            )
+    }
+
+    /// Retrieve all the block hashes range roots in database up to the block range that contains
+    /// the given block number.
+    pub async fn retrieve_block_hash_range_roots_up_to(
+        &self,
+        block_number: BlockNumber,
+    ) -> StdResult<Box<dyn Iterator<Item = (BlockRange, MKTreeNode)> + '_>> {
+        let block_hash_range_roots = self
+            .connection_pool
+            .connection()?
+            .fetch(GetBlockHashRangeRootQuery::contains_or_below_block_number(
+                block_number,
+            ))?
+            .map(|record| -> (BlockRange, MKTreeNode) { record.into() })
+            .collect::<Vec<_>>();
+
+        Ok(Box::new(block_hash_range_roots.into_iter()))
+    }
+
+    /// Retrieve the block hashes range root with the highest bounds in the database.
+    pub async fn retrieve_highest_block_hash_range_root(
+        &self,
+    ) -> StdResult<Option<BlockRangeRootRecord>> {
+        self.connection_pool
+            .connection()?
+            .fetch_first(GetBlockHashRangeRootQuery::highest())
+    }
+
+    /// Prune the blocks older than the given number of blocks (based on the block hashes range
+    /// roots stored).
+    pub async fn prune_blocks(&self, number_of_blocks_to_keep: BlockNumber) -> StdResult<()> {
+        if let Some(highest_block_range_start) = self
+            .get_highest_start_block_number_for_block_hash_range_roots()
+            .await?
+        {
+            let threshold = highest_block_range_start - number_of_blocks_to_keep;
+            let query = DeleteCardanoBlockQuery::below_block_number_threshold(threshold)?;
+
+            let connection = self.connection_pool.connection()?;
+            connection.fetch_first(query)?;
+        }
+
+        Ok(())
+    }
+
+    /// Remove blocks and block hashes range roots that are in a rolled-back fork
+    ///
+    /// * Remove blocks with block number strictly greater than the given block number
+    /// * Remove block hashes range roots that have lower bound range strictly above the given block number
+    pub async fn remove_rolled_back_blocks_and_block_range_by_block_number(
+        &self,
+        block_number: BlockNumber,
+    ) -> StdResult<()> {
+        let connection = self.connection_pool.connection()?;
+        let transaction = connection.begin_transaction()?;
+        let query = DeleteCardanoBlockQuery::above_block_number_threshold(block_number)?;
+        connection.fetch_first(query)?;
+
+        let query =
+            DeleteBlockHashRangeRootQuery::contains_or_above_block_number_threshold(block_number)?;
+        connection.fetch_first(query)?;
+        transaction.commit()?;
+
+        Ok(())
+    }
+
+    /// Remove blocks and block hashes range roots that are in a rolled-back fork
+    ///
+    /// * Remove blocks with closest block number strictly greater than the given slot number if exists
+    /// * Remove block hashes range roots that have lower bound range strictly above the aforementioned block number
+    pub async fn remove_rolled_back_blocks_and_block_range_by_slot_number(
+        &self,
+        slot_number: SlotNumber,
+    ) -> StdResult<()> {
+        let query = GetCardanoBlockQuery::with_highest_block_number_below_slot_number(slot_number);
+        let record = self.connection_pool.connection()?.fetch_first(query)?;
+
+        if let Some(block_number) = record.map(|r| r.block_number) {
+            self.remove_rolled_back_blocks_and_block_range_by_block_number(block_number)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<S: MKTreeStorer> BlockRangeRootRetriever<S> for CardanoBlockRepository {
+    async fn retrieve_block_range_roots<'a>(
+        &'a self,
+        up_to_beacon: BlockNumber,
+    ) -> StdResult<Box<dyn Iterator<Item = (BlockRange, MKTreeNode)> + 'a>> {
+        self.retrieve_block_hash_range_roots_up_to(up_to_beacon)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::database::test_helper::cardano_tx_db_connection;
+
+    use super::*;
+
+    fn block(block_number: u64, slot_number: u64) -> CardanoBlock {
+        CardanoBlock::new(
+            format!("block-hash-{block_number}"),
+            BlockNumber(block_number),
+            SlotNumber(slot_number),
+        )
+    }
+
+    fn repository() -> CardanoBlockRepository {
+        CardanoBlockRepository::new(Arc::new(
+            SqliteConnectionPool::build(1, cardano_tx_db_connection).unwrap(),
+        ))
+    }
+
+    #[tokio::test]
+    async fn repository_store_and_get_blocks_by_hashes() {
+        let repository = repository();
+        repository
+            .store_blocks(vec![block(10, 50), block(11, 51), block(101, 100)])
+            .await
+            .unwrap();
+
+        let blocks = repository
+            .get_block_by_hashes(
+                vec!["block-hash-10", "block-hash-101", "not-exist"],
+                BlockNumber(100),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(vec![CardanoBlockRecord::from(block(10, 50))], blocks);
+    }
+
+    #[tokio::test]
+    async fn repository_get_block_highest_chain_point() {
+        let repository = repository();
+        assert_eq!(
+            None,
+            repository.get_block_highest_chain_point().await.unwrap()
+        );
+
+        repository
+            .store_blocks(vec![block(10, 50), block(12, 60), block(11, 55)])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            Some(ChainPoint::new(
+                SlotNumber(60),
+                BlockNumber(12),
+                "block-hash-12"
+            )),
+            repository.get_block_highest_chain_point().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn repository_get_blocks_by_block_ranges() {
+        let repository = repository();
+        let length = *BlockRange::LENGTH;
+        repository
+            .store_blocks(vec![
+                block(1, 1),
+                block(length + 1, length + 1),
+                block(length * 2 + 1, length * 2 + 1),
+            ])
+            .await
+            .unwrap();
+
+        let blocks = repository
+            .get_block_by_block_ranges(vec![
+                BlockRange::from_block_number(BlockNumber(0)),
+                BlockRange::from_block_number(BlockNumber(length * 2)),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                CardanoBlockRecord::from(block(1, 1)),
+                CardanoBlockRecord::from(block(length * 2 + 1, length * 2 + 1)),
+            ],
+            blocks
+        );
+    }
+
+    #[tokio::test]
+    async fn repository_retrieve_block_hash_range_roots_up_to() {
+        let repository = repository();
+        let block_hash_range_roots = vec![
+            (
+                BlockRange::from_block_number(BlockNumber(15)),
+                MKTreeNode::from_hex("AAAA").unwrap(),
+            ),
+            (
+                BlockRange::from_block_number(BlockNumber(30)),
+                MKTreeNode::from_hex("BBBB").unwrap(),
+            ),
+            (
+                BlockRange::from_block_number(BlockNumber(45)),
+                MKTreeNode::from_hex("CCCC").unwrap(),
+            ),
+        ];
+        repository
+            .create_block_hash_range_roots(block_hash_range_roots.clone())
+            .await
+            .unwrap();
+
+        let retrieved_block_ranges = repository
+            .retrieve_block_hash_range_roots_up_to(BlockNumber(44))
+            .await
+            .unwrap();
+        assert_eq!(
+            block_hash_range_roots[0..2].to_vec(),
+            retrieved_block_ranges.collect::<Vec<_>>()
+        );
+
+        let highest = repository
+            .retrieve_highest_block_hash_range_root()
+            .await
+            .unwrap();
+        assert_eq!(
+            Some(BlockRangeRootRecord::from(
+                block_hash_range_roots[2].clone()
+            )),
+            highest
+        );
+    }
+
+    #[tokio::test]
+    async fn repository_prune_blocks() {
+        let repository = repository();
+        repository
+            .store_blocks((0..50).map(|number| block(number, number)).collect())
+            .await
+            .unwrap();
+        repository
+            .create_block_hash_range_roots(vec![(
+                BlockRange::from_block_number(BlockNumber(45)),
+                MKTreeNode::from_hex("BBBB").unwrap(),
+            )])
+            .await
+            .unwrap();
+
+        repository
+            .prune_blocks(BlockNumber(10_000_000))
+            .await
+            .unwrap();
+        assert_eq!(50, repository.get_all().await.unwrap().len());
+
+        // Since the highest block range start is 45, pruning with 20 should remove blocks
+        // with a block number strictly below 25.
+        repository.prune_blocks(BlockNumber(20)).await.unwrap();
+        let blocks = repository.get_all().await.unwrap();
+        assert_eq!(25, blocks.len());
+        assert_eq!(BlockNumber(25), blocks[0].block_number);
+    }
+
+    #[tokio::test]
+    async fn remove_blocks_and_block_range_greater_than_given_block_number() {
+        let repository = repository();
+        repository
+            .store_blocks(vec![
+                block(*BlockRange::LENGTH, 50),
+                block(*BlockRange::LENGTH * 3 - 1, 51),
+                block(*BlockRange::LENGTH * 3, 52),
+            ])
+            .await
+            .unwrap();
+        repository
+            .create_block_hash_range_roots(
+                [1, 2, 3]
+                    .into_iter()
+                    .map(|multiple| {
+                        (
+                            BlockRange::from_block_number(BlockRange::LENGTH * multiple),
+                            MKTreeNode::from_hex("AAAA").unwrap(),
+                        )
+                    })
+                    .collect(),
+            )
+            .await
+            .unwrap();
+
+        repository
+            .remove_rolled_back_blocks_and_block_range_by_block_number(BlockRange::LENGTH * 3)
+            .await
+            .unwrap();
+        assert_eq!(3, repository.get_all().await.unwrap().len());
+        assert_eq!(2, repository.get_all_block_hash_range_root().unwrap().len());
+
+        repository
+            .remove_rolled_back_blocks_and_block_range_by_block_number(BlockRange::LENGTH * 2)
+            .await
+            .unwrap();
+        assert_eq!(1, repository.get_all().await.unwrap().len());
+        assert_eq!(1, repository.get_all_block_hash_range_root().unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn remove_rolled_back_blocks_and_block_range_by_slot_number() {
+        let repository = repository();
+        repository
+            .store_blocks(vec![
+                block(10, 50),
+                block(11, 51),
+                block(13, 52),
+                block(101, 100),
+                block(202, 200),
+            ])
+            .await
+            .unwrap();
+
+        repository
+            .remove_rolled_back_blocks_and_block_range_by_slot_number(SlotNumber(110))
+            .await
+            .unwrap();
+        assert_eq!(
+            vec![block(10, 50), block(11, 51), block(13, 52), block(101, 100)],
+            repository.get_all().await.unwrap()
+        );
+
+        repository
+            .remove_rolled_back_blocks_and_block_range_by_slot_number(SlotNumber(51))
+            .await
+            .unwrap();
+        assert_eq!(
+            vec![block(10, 50), block(11, 51)],
+            repository.get_all().await.unwrap()
+        );
+    }
+}
@@ -1,4 +1,6 @@
 //! Shared database repositories
+mod cardano_block_repository;
 mod cardano_transaction_repository;
 
+pub use cardano_block_repository::*;
 pub use cardano_transaction_repository::*;
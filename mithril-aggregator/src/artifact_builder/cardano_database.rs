@@ -136,6 +136,7 @@ mod tests {
 
     use crate::{
         artifact_builder::{MockAncillaryFileUploader, MockImmutableFilesUploader},
+        file_uploaders::MultiUploadFailurePolicy,
         immutable_file_digest_mapper::MockImmutableFileDigestMapper,
         services::FakeSnapshotter,
         test_tools::TestLogger,
@@ -204,6 +205,7 @@ mod tests {
 
             AncillaryArtifactBuilder::new(
                 vec![Arc::new(ancillary_uploader)],
+                MultiUploadFailurePolicy::default(),
                 snapshotter.clone(),
                 network,
                 CompressionAlgorithm::Gzip,
@@ -229,6 +231,7 @@ mod tests {
             ImmutableArtifactBuilder::new(
                 test_dir.join("immutable"),
                 vec![Arc::new(immutable_uploader)],
+                MultiUploadFailurePolicy::default(),
                 snapshotter,
                 CompressionAlgorithm::Gzip,
                 TestLogger::stdout(),
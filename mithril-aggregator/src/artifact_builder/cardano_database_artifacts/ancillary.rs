@@ -15,7 +15,7 @@ use mithril_common::{
 };
 
 use crate::{
-    file_uploaders::{GcpUploader, LocalUploader},
+    file_uploaders::{CloudUploader, LocalUploader, MultiUploadFailurePolicy},
     services::{OngoingSnapshot, Snapshotter},
     DumbUploader, FileUploader,
 };
@@ -47,7 +47,7 @@ impl AncillaryFileUploader for LocalUploader {
 }
 
 #[async_trait]
-impl AncillaryFileUploader for GcpUploader {
+impl AncillaryFileUploader for CloudUploader {
     async fn upload(&self, filepath: &Path) -> StdResult<AncillaryLocation> {
         let uri = FileUploader::upload(self, filepath).await?.into();
 
@@ -56,9 +56,11 @@ impl AncillaryFileUploader for GcpUploader {
 }
 
 /// The [AncillaryArtifactBuilder] creates an ancillary archive from the cardano database directory (including ledger and volatile directories).
-/// The archive is uploaded with the provided uploaders.
+/// The archive is uploaded with the provided uploaders, failures being tolerated according to the
+/// given [MultiUploadFailurePolicy].
 pub struct AncillaryArtifactBuilder {
     uploaders: Vec<Arc<dyn AncillaryFileUploader>>,
+    failure_policy: MultiUploadFailurePolicy,
     snapshotter: Arc<dyn Snapshotter>,
     cardano_network: CardanoNetwork,
     compression_algorithm: CompressionAlgorithm,
@@ -69,6 +71,7 @@ impl AncillaryArtifactBuilder {
     /// Creates a new [AncillaryArtifactBuilder].
     pub fn new(
         uploaders: Vec<Arc<dyn AncillaryFileUploader>>,
+        failure_policy: MultiUploadFailurePolicy,
         snapshotter: Arc<dyn Snapshotter>,
         cardano_network: CardanoNetwork,
         compression_algorithm: CompressionAlgorithm,
@@ -82,6 +85,7 @@ impl AncillaryArtifactBuilder {
 
         Ok(Self {
             uploaders,
+            failure_policy,
             logger: logger.new_with_component_name::<Self>(),
             cardano_network,
             compression_algorithm,
@@ -164,21 +168,17 @@ impl AncillaryArtifactBuilder {
         &self,
         archive_filepath: &Path,
     ) -> StdResult<Vec<AncillaryLocation>> {
-        let mut locations = Vec::new();
+        let mut results = Vec::new();
         for uploader in &self.uploaders {
             let result = uploader.upload(archive_filepath).await;
-            match result {
-                Ok(location) => {
-                    locations.push(location);
-                }
-                Err(e) => {
-                    error!(
-                        self.logger,
-                        "Failed to upload ancillary archive";
-                        "error" => e.to_string()
-                    );
-                }
+            if let Err(e) = &result {
+                error!(
+                    self.logger,
+                    "Failed to upload ancillary archive";
+                    "error" => e.to_string()
+                );
             }
+            results.push(result);
         }
 
         if let Err(error) = tokio::fs::remove_file(archive_filepath).await {
@@ -188,13 +188,9 @@ impl AncillaryArtifactBuilder {
             );
         }
 
-        if locations.is_empty() {
-            return Err(anyhow!(
-                "Failed to upload ancillary archive with all uploaders"
-            ));
-        }
-
-        Ok(locations)
+        self.failure_policy
+            .apply(results)
+            .with_context(|| "Failed to upload ancillary archive")
     }
 }
 
@@ -260,6 +256,7 @@ mod tests {
     fn create_ancillary_builder_should_error_when_no_uploader() {
         let result = AncillaryArtifactBuilder::new(
             vec![],
+            MultiUploadFailurePolicy::default(),
             Arc::new(DumbSnapshotter::new()),
             CardanoNetwork::DevNet(123),
             CompressionAlgorithm::Gzip,
@@ -285,6 +282,7 @@ mod tests {
         {
             let builder = AncillaryArtifactBuilder::new(
                 vec![Arc::new(uploader)],
+                MultiUploadFailurePolicy::default(),
                 Arc::new(DumbSnapshotter::new()),
                 CardanoNetwork::DevNet(123),
                 CompressionAlgorithm::Gzip,
@@ -307,6 +305,7 @@ mod tests {
 
         let builder = AncillaryArtifactBuilder::new(
             vec![Arc::new(uploader)],
+            MultiUploadFailurePolicy::default(),
             Arc::new(DumbSnapshotter::new()),
             CardanoNetwork::DevNet(123),
             CompressionAlgorithm::Gzip,
@@ -338,6 +337,7 @@ mod tests {
 
         let builder = AncillaryArtifactBuilder::new(
             uploaders,
+            MultiUploadFailurePolicy::RequireAny,
             Arc::new(DumbSnapshotter::new()),
             CardanoNetwork::DevNet(123),
             CompressionAlgorithm::Gzip,
@@ -358,6 +358,31 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn upload_ancillary_archive_should_fail_if_uploaders_errors_are_not_allowed_by_the_failure_policy(
+    ) {
+        let first_uploader = fake_uploader("archive_path", "an_uri");
+        let second_uploader = fake_uploader_returning_error();
+
+        let uploaders: Vec<Arc<dyn AncillaryFileUploader>> =
+            vec![Arc::new(first_uploader), Arc::new(second_uploader)];
+
+        let builder = AncillaryArtifactBuilder::new(
+            uploaders,
+            MultiUploadFailurePolicy::RequireAll,
+            Arc::new(DumbSnapshotter::new()),
+            CardanoNetwork::DevNet(123),
+            CompressionAlgorithm::Gzip,
+            TestLogger::stdout(),
+        )
+        .unwrap();
+
+        builder
+            .upload_ancillary_archive(Path::new("archive_path"))
+            .await
+            .expect_err("Should return an error when a mirror upload fails with 'RequireAll'");
+    }
+
     #[tokio::test]
     async fn upload_ancillary_archive_should_return_all_uploaders_returned_locations() {
         let first_uploader = fake_uploader("archive_path", "an_uri");
@@ -368,6 +393,7 @@ mod tests {
 
         let builder = AncillaryArtifactBuilder::new(
             uploaders,
+            MultiUploadFailurePolicy::default(),
             Arc::new(DumbSnapshotter::new()),
             CardanoNetwork::DevNet(123),
             CompressionAlgorithm::Gzip,
@@ -404,6 +430,7 @@ mod tests {
 
         let builder = AncillaryArtifactBuilder::new(
             vec![Arc::new(uploader)],
+            MultiUploadFailurePolicy::default(),
             Arc::new(DumbSnapshotter::new()),
             CardanoNetwork::DevNet(123),
             CompressionAlgorithm::Gzip,
@@ -429,6 +456,7 @@ mod tests {
 
         let builder = AncillaryArtifactBuilder::new(
             vec![Arc::new(uploader)],
+            MultiUploadFailurePolicy::default(),
             Arc::new(DumbSnapshotter::new()),
             CardanoNetwork::DevNet(123),
             CompressionAlgorithm::Gzip,
@@ -468,6 +496,7 @@ mod tests {
 
         let builder = AncillaryArtifactBuilder::new(
             vec![Arc::new(MockAncillaryFileUploader::new())],
+            MultiUploadFailurePolicy::default(),
             Arc::new(snapshotter),
             CardanoNetwork::DevNet(123),
             CompressionAlgorithm::Gzip,
@@ -525,6 +554,7 @@ mod tests {
 
         let builder = AncillaryArtifactBuilder::new(
             vec![Arc::new(uploader)],
+            MultiUploadFailurePolicy::default(),
             Arc::new(snapshotter),
             CardanoNetwork::DevNet(123),
             CompressionAlgorithm::Gzip,
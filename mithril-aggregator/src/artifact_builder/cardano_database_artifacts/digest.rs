@@ -15,7 +15,7 @@ use mithril_common::{
 use slog::{error, Logger};
 
 use crate::{
-    file_uploaders::{GcpUploader, LocalUploader},
+    file_uploaders::{CloudUploader, LocalUploader},
     tools::url_sanitizer::SanitizedUrlWithTrailingSlash,
     DumbUploader, FileUploader, ImmutableFileDigestMapper,
 };
@@ -47,7 +47,7 @@ impl DigestFileUploader for LocalUploader {
 }
 
 #[async_trait]
-impl DigestFileUploader for GcpUploader {
+impl DigestFileUploader for CloudUploader {
     async fn upload(&self, filepath: &Path) -> StdResult<DigestLocation> {
         let uri = FileUploader::upload(self, filepath).await?.into();
 
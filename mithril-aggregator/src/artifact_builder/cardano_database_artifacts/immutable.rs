@@ -17,7 +17,7 @@ use mithril_common::{
 };
 
 use crate::{
-    file_uploaders::{GcpUploader, LocalUploader},
+    file_uploaders::{CloudUploader, LocalUploader, MultiUploadFailurePolicy},
     services::Snapshotter,
     DumbUploader, FileUploader,
 };
@@ -86,7 +86,7 @@ impl ImmutableFilesUploader for LocalUploader {
 }
 
 #[async_trait]
-impl ImmutableFilesUploader for GcpUploader {
+impl ImmutableFilesUploader for CloudUploader {
     async fn batch_upload(&self, filepaths: &[PathBuf]) -> StdResult<ImmutablesLocation> {
         let mut file_uris = Vec::new();
         for filepath in filepaths {
@@ -96,7 +96,7 @@ impl ImmutableFilesUploader for GcpUploader {
         let template_uri =
             MultiFilesUri::extract_template_from_uris(file_uris, immmutable_file_number_extractor)?
                 .ok_or_else(|| {
-                    anyhow!("No matching template found in the uploaded files with 'GcpUploader'")
+                    anyhow!("No matching template found in the uploaded files with 'CloudUploader'")
                 })?;
 
         Ok(ImmutablesLocation::CloudStorage {
@@ -108,6 +108,7 @@ impl ImmutableFilesUploader for GcpUploader {
 pub struct ImmutableArtifactBuilder {
     immutables_storage_dir: PathBuf,
     uploaders: Vec<Arc<dyn ImmutableFilesUploader>>,
+    failure_policy: MultiUploadFailurePolicy,
     snapshotter: Arc<dyn Snapshotter>,
     compression_algorithm: CompressionAlgorithm,
     logger: Logger,
@@ -117,6 +118,7 @@ impl ImmutableArtifactBuilder {
     pub fn new(
         immutables_storage_dir: PathBuf,
         uploaders: Vec<Arc<dyn ImmutableFilesUploader>>,
+        failure_policy: MultiUploadFailurePolicy,
         snapshotter: Arc<dyn Snapshotter>,
         compression_algorithm: CompressionAlgorithm,
         logger: Logger,
@@ -139,6 +141,7 @@ impl ImmutableArtifactBuilder {
         Ok(Self {
             immutables_storage_dir,
             uploaders,
+            failure_policy,
             snapshotter,
             compression_algorithm,
             logger: logger.new_with_component_name::<Self>(),
@@ -211,30 +214,22 @@ impl ImmutableArtifactBuilder {
         &self,
         archive_paths: &[PathBuf],
     ) -> StdResult<Vec<ImmutablesLocation>> {
-        let mut locations = Vec::new();
+        let mut results = Vec::new();
         for uploader in &self.uploaders {
             let result = uploader.batch_upload(archive_paths).await;
-            match result {
-                Ok(location) => {
-                    locations.push(location);
-                }
-                Err(e) => {
-                    error!(
-                        self.logger,
-                        "Failed to upload immutable archive";
-                        "error" => e.to_string()
-                    );
-                }
+            if let Err(e) = &result {
+                error!(
+                    self.logger,
+                    "Failed to upload immutable archive";
+                    "error" => e.to_string()
+                );
             }
+            results.push(result);
         }
 
-        if locations.is_empty() {
-            return Err(anyhow!(
-                "Failed to upload immutable archive with all uploaders"
-            ));
-        }
-
-        Ok(locations)
+        self.failure_policy
+            .apply(results)
+            .with_context(|| "Failed to upload immutable archive")
     }
 
     fn retrieve_existing_snapshot_archive(&self, expected_archive_name: &str) -> Option<PathBuf> {
@@ -341,6 +336,7 @@ mod tests {
         let builder = ImmutableArtifactBuilder::new(
             work_dir,
             vec![Arc::new(uploader)],
+            MultiUploadFailurePolicy::default(),
             Arc::new(snapshotter),
             CompressionAlgorithm::Gzip,
             TestLogger::stdout(),
@@ -369,6 +365,7 @@ mod tests {
         ImmutableArtifactBuilder::new(
             immutable_storage_dir.clone(),
             vec![Arc::new(DumbUploader::default())],
+            MultiUploadFailurePolicy::default(),
             Arc::new(DumbSnapshotter::new()),
             CompressionAlgorithm::Gzip,
             TestLogger::stdout(),
@@ -389,6 +386,7 @@ mod tests {
         ImmutableArtifactBuilder::new(
             immutable_storage_dir,
             vec![Arc::new(DumbUploader::default())],
+            MultiUploadFailurePolicy::default(),
             Arc::new(DumbSnapshotter::new()),
             CompressionAlgorithm::Gzip,
             TestLogger::stdout(),
@@ -425,6 +423,7 @@ mod tests {
             let builder = ImmutableArtifactBuilder::new(
                 work_dir.clone(),
                 vec![Arc::new(MockImmutableFilesUploader::new())],
+                MultiUploadFailurePolicy::default(),
                 Arc::new(snapshotter),
                 CompressionAlgorithm::Gzip,
                 TestLogger::stdout(),
@@ -468,6 +467,7 @@ mod tests {
             let builder = ImmutableArtifactBuilder::new(
                 work_dir,
                 vec![Arc::new(MockImmutableFilesUploader::new())],
+                MultiUploadFailurePolicy::default(),
                 Arc::new(snapshotter),
                 CompressionAlgorithm::Gzip,
                 TestLogger::stdout(),
@@ -503,6 +503,7 @@ mod tests {
             let builder = ImmutableArtifactBuilder::new(
                 work_dir,
                 vec![Arc::new(MockImmutableFilesUploader::new())],
+                MultiUploadFailurePolicy::default(),
                 Arc::new(snapshotter),
                 CompressionAlgorithm::Gzip,
                 TestLogger::stdout(),
@@ -536,6 +537,7 @@ mod tests {
             let builder = ImmutableArtifactBuilder::new(
                 work_dir,
                 vec![Arc::new(MockImmutableFilesUploader::new())],
+                MultiUploadFailurePolicy::default(),
                 Arc::new(snapshotter),
                 CompressionAlgorithm::Gzip,
                 TestLogger::stdout(),
@@ -555,6 +557,7 @@ mod tests {
             let builder = ImmutableArtifactBuilder::new(
                 work_dir.clone(),
                 vec![Arc::new(MockImmutableFilesUploader::new())],
+                MultiUploadFailurePolicy::default(),
                 Arc::new(MockSnapshotter::new()),
                 CompressionAlgorithm::Gzip,
                 TestLogger::stdout(),
@@ -595,6 +598,7 @@ mod tests {
             let builder = ImmutableArtifactBuilder::new(
                 work_dir.clone(),
                 vec![Arc::new(MockImmutableFilesUploader::new())],
+                MultiUploadFailurePolicy::default(),
                 Arc::new(snapshotter),
                 CompressionAlgorithm::Gzip,
                 TestLogger::stdout(),
@@ -632,6 +636,7 @@ mod tests {
             let builder = ImmutableArtifactBuilder::new(
                 work_dir.clone(),
                 vec![Arc::new(MockImmutableFilesUploader::new())],
+                MultiUploadFailurePolicy::default(),
                 Arc::new(snapshotter),
                 CompressionAlgorithm::Gzip,
                 TestLogger::stdout(),
@@ -665,6 +670,7 @@ mod tests {
             let result = ImmutableArtifactBuilder::new(
                 get_builder_work_dir("create_immutable_builder_should_error_when_no_uploader"),
                 vec![],
+                MultiUploadFailurePolicy::default(),
                 Arc::new(DumbSnapshotter::new()),
                 CompressionAlgorithm::Gzip,
                 TestLogger::stdout(),
@@ -690,6 +696,7 @@ mod tests {
                 let builder = ImmutableArtifactBuilder::new(
                     get_builder_work_dir("upload_immutable_archives_should_log_upload_errors"),
                     vec![Arc::new(uploader)],
+                    MultiUploadFailurePolicy::default(),
                     Arc::new(MockSnapshotter::new()),
                     CompressionAlgorithm::Gzip,
                     TestLogger::file(&log_path),
@@ -716,6 +723,7 @@ mod tests {
             let builder = ImmutableArtifactBuilder::new(
                 get_builder_work_dir("upload_immutable_archives_should_error_when_no_location"),
                 uploaders,
+                MultiUploadFailurePolicy::default(),
                 Arc::new(MockSnapshotter::new()),
                 CompressionAlgorithm::Gzip,
                 TestLogger::stdout(),
@@ -751,6 +759,7 @@ mod tests {
                     "upload_immutable_archives_should_return_location_even_with_uploaders_errors",
                 ),
                 uploaders,
+                MultiUploadFailurePolicy::RequireAny,
                 Arc::new(MockSnapshotter::new()),
                 CompressionAlgorithm::Gzip,
                 TestLogger::stdout(),
@@ -791,6 +800,7 @@ mod tests {
                     "upload_immutable_archives_should_return_all_uploaders_returned_locations",
                 ),
                 uploaders,
+                MultiUploadFailurePolicy::default(),
                 Arc::new(MockSnapshotter::new()),
                 CompressionAlgorithm::Gzip,
                 TestLogger::stdout(),
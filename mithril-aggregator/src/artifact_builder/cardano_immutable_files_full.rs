@@ -9,7 +9,7 @@ use thiserror::Error;
 
 use crate::{
     services::{OngoingSnapshot, Snapshotter},
-    FileUploader,
+    MultiFileUploader,
 };
 
 use super::ArtifactBuilder;
@@ -35,7 +35,7 @@ pub struct CardanoImmutableFilesFullArtifactBuilder {
     cardano_network: CardanoNetwork,
     cardano_node_version: Version,
     snapshotter: Arc<dyn Snapshotter>,
-    snapshot_uploader: Arc<dyn FileUploader>,
+    snapshot_uploader: Arc<MultiFileUploader>,
     compression_algorithm: CompressionAlgorithm,
     logger: Logger,
 }
@@ -46,7 +46,7 @@ impl CardanoImmutableFilesFullArtifactBuilder {
         cardano_network: CardanoNetwork,
         cardano_node_version: &Version,
         snapshotter: Arc<dyn Snapshotter>,
-        snapshot_uploader: Arc<dyn FileUploader>,
+        snapshot_uploader: Arc<MultiFileUploader>,
         compression_algorithm: CompressionAlgorithm,
         logger: Logger,
     ) -> Self {
@@ -93,7 +93,7 @@ impl CardanoImmutableFilesFullArtifactBuilder {
         ongoing_snapshot: &OngoingSnapshot,
     ) -> StdResult<Vec<FileUri>> {
         debug!(self.logger, ">> upload_snapshot_archive");
-        let location = self
+        let locations = self
             .snapshot_uploader
             .upload(ongoing_snapshot.get_file_path())
             .await;
@@ -105,7 +105,7 @@ impl CardanoImmutableFilesFullArtifactBuilder {
             );
         }
 
-        Ok(vec![location?])
+        locations
     }
 
     async fn create_snapshot(
@@ -181,12 +181,25 @@ mod tests {
     use mithril_common::{entities::CompressionAlgorithm, test_utils::fake_data};
 
     use crate::{
-        file_uploaders::MockFileUploader, services::DumbSnapshotter, test_tools::TestLogger,
-        DumbUploader,
+        file_uploaders::{MockFileUploader, MultiUploadFailurePolicy},
+        services::DumbSnapshotter,
+        test_tools::TestLogger,
+        DumbUploader, FileUploader,
     };
 
     use super::*;
 
+    fn multi_uploader(uploaders: Vec<Arc<dyn FileUploader>>) -> Arc<MultiFileUploader> {
+        Arc::new(
+            MultiFileUploader::new(
+                uploaders,
+                MultiUploadFailurePolicy::RequireAll,
+                TestLogger::stdout(),
+            )
+            .unwrap(),
+        )
+    }
+
     #[tokio::test]
     async fn should_compute_valid_artifact() {
         let beacon = fake_data::beacon();
@@ -204,7 +217,7 @@ mod tests {
                 fake_data::network(),
                 &Version::parse("1.0.0").unwrap(),
                 dumb_snapshotter.clone(),
-                dumb_snapshot_uploader.clone(),
+                multi_uploader(vec![dumb_snapshot_uploader.clone()]),
                 CompressionAlgorithm::Zstandard,
                 TestLogger::stdout(),
             );
@@ -234,6 +247,47 @@ mod tests {
         assert_eq!(artifact_expected, artifact);
     }
 
+    #[tokio::test]
+    async fn upload_snapshot_archive_return_the_locations_of_all_the_destinations() {
+        let file = NamedTempFile::new().unwrap();
+        let snapshot = OngoingSnapshot::new(file.path().to_path_buf(), 7331);
+        let uploaders: Vec<Arc<dyn FileUploader>> = ["primary", "mirror"]
+            .into_iter()
+            .map(|location| {
+                let mut uploader = MockFileUploader::new();
+                uploader
+                    .expect_upload()
+                    .return_once(move |_| Ok(FileUri(location.to_string())))
+                    .once();
+                let uploader: Arc<dyn FileUploader> = Arc::new(uploader);
+                uploader
+            })
+            .collect();
+
+        let cardano_immutable_files_full_artifact_builder =
+            CardanoImmutableFilesFullArtifactBuilder::new(
+                fake_data::network(),
+                &Version::parse("1.0.0").unwrap(),
+                Arc::new(DumbSnapshotter::new()),
+                multi_uploader(uploaders),
+                CompressionAlgorithm::default(),
+                TestLogger::stdout(),
+            );
+
+        let locations = cardano_immutable_files_full_artifact_builder
+            .upload_snapshot_archive(&snapshot)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                FileUri("primary".to_string()),
+                FileUri("mirror".to_string())
+            ],
+            locations
+        );
+    }
+
     #[tokio::test]
     async fn remove_snapshot_archive_after_upload() {
         let file = NamedTempFile::new().unwrap();
@@ -245,7 +299,7 @@ mod tests {
                 fake_data::network(),
                 &Version::parse("1.0.0").unwrap(),
                 Arc::new(DumbSnapshotter::new()),
-                Arc::new(DumbUploader::default()),
+                multi_uploader(vec![Arc::new(DumbUploader::default())]),
                 CompressionAlgorithm::default(),
                 TestLogger::stdout(),
             );
@@ -272,7 +326,7 @@ mod tests {
                 network,
                 &Version::parse("1.0.0").unwrap(),
                 Arc::new(DumbSnapshotter::new()),
-                Arc::new(DumbUploader::default()),
+                multi_uploader(vec![Arc::new(DumbUploader::default())]),
                 CompressionAlgorithm::Gzip,
                 TestLogger::stdout(),
             );
@@ -301,7 +355,7 @@ mod tests {
                     fake_data::network(),
                     &Version::parse("1.0.0").unwrap(),
                     Arc::new(DumbSnapshotter::new()),
-                    Arc::new(DumbUploader::default()),
+                    multi_uploader(vec![Arc::new(DumbUploader::default())]),
                     algorithm,
                     TestLogger::stdout(),
                 );
@@ -344,7 +398,7 @@ mod tests {
                 fake_data::network(),
                 &Version::parse("1.0.0").unwrap(),
                 Arc::new(DumbSnapshotter::new()),
-                Arc::new(snapshot_uploader),
+                multi_uploader(vec![Arc::new(snapshot_uploader)]),
                 CompressionAlgorithm::default(),
                 TestLogger::stdout(),
             );
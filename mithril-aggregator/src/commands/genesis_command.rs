@@ -1,4 +1,4 @@
-use anyhow::Context;
+use anyhow::{anyhow, Context};
 use clap::{Parser, Subcommand};
 use config::{builder::DefaultState, ConfigBuilder};
 use mithril_common::{
@@ -43,6 +43,12 @@ pub enum GenesisSubCommand {
     /// Genesis certificate sign command.
     Sign(SignGenesisSubCommand),
 
+    /// Genesis certificate sign share command (threshold genesis).
+    SignShare(SignShareGenesisSubCommand),
+
+    /// Genesis signature shares bundle command (threshold genesis).
+    Bundle(BundleGenesisSubCommand),
+
     /// Genesis certificate bootstrap command.
     Bootstrap(BootstrapGenesisSubCommand),
 
@@ -61,6 +67,8 @@ impl GenesisSubCommand {
             Self::Export(cmd) => cmd.execute(root_logger, config_builder).await,
             Self::Import(cmd) => cmd.execute(root_logger, config_builder).await,
             Self::Sign(cmd) => cmd.execute(root_logger, config_builder).await,
+            Self::SignShare(cmd) => cmd.execute(root_logger, config_builder).await,
+            Self::Bundle(cmd) => cmd.execute(root_logger, config_builder).await,
             Self::GenerateKeypair(cmd) => cmd.execute(root_logger, config_builder).await,
         }
     }
@@ -112,8 +120,16 @@ impl ExportGenesisSubCommand {
 #[derive(Parser, Debug, Clone)]
 pub struct ImportGenesisSubCommand {
     /// Signed Payload Path
+    #[clap(
+        long,
+        required_unless_present = "signature_bundle_path",
+        conflicts_with = "signature_bundle_path"
+    )]
+    signed_payload_path: Option<PathBuf>,
+
+    /// Signature Bundle Path (threshold genesis)
     #[clap(long)]
-    signed_payload_path: PathBuf,
+    signature_bundle_path: Option<PathBuf>,
 }
 
 impl ImportGenesisSubCommand {
@@ -128,10 +144,6 @@ impl ImportGenesisSubCommand {
             .try_deserialize()
             .with_context(|| "configuration deserialize error")?;
         debug!(root_logger, "IMPORT GENESIS command"; "config" => format!("{config:?}"));
-        println!(
-            "Genesis import signed payload from {}",
-            self.signed_payload_path.to_string_lossy()
-        );
         let mut dependencies_builder =
             DependenciesBuilder::new(root_logger.clone(), config.clone());
         let dependencies = dependencies_builder
@@ -144,10 +156,30 @@ impl ImportGenesisSubCommand {
         let genesis_tools = GenesisTools::from_dependencies(dependencies)
             .await
             .with_context(|| "genesis-tools: initialization error")?;
-        genesis_tools
-            .import_payload_signature(&self.signed_payload_path)
-            .await
-            .with_context(|| "genesis-tools: import error")?;
+        match (&self.signed_payload_path, &self.signature_bundle_path) {
+            (Some(signed_payload_path), _) => {
+                println!(
+                    "Genesis import signed payload from {}",
+                    signed_payload_path.to_string_lossy()
+                );
+                genesis_tools
+                    .import_payload_signature(signed_payload_path)
+                    .await
+            }
+            (None, Some(signature_bundle_path)) => {
+                println!(
+                    "Genesis import signature bundle from {}",
+                    signature_bundle_path.to_string_lossy()
+                );
+                genesis_tools
+                    .import_payload_signature_bundle(signature_bundle_path)
+                    .await
+            }
+            (None, None) => Err(anyhow!(
+                "a signed payload path or a signature bundle path must be provided"
+            )),
+        }
+        .with_context(|| "genesis-tools: import error")?;
         Ok(())
     }
 }
@@ -191,6 +223,81 @@ impl SignGenesisSubCommand {
         Ok(())
     }
 }
+
+#[derive(Parser, Debug, Clone)]
+pub struct SignShareGenesisSubCommand {
+    /// To Sign Payload Path
+    #[clap(long)]
+    to_sign_payload_path: PathBuf,
+
+    /// Target Signature Share Path
+    #[clap(long)]
+    target_signature_share_path: PathBuf,
+
+    /// Genesis Secret Key Path
+    #[clap(long)]
+    genesis_secret_key_path: PathBuf,
+}
+
+impl SignShareGenesisSubCommand {
+    pub async fn execute(
+        &self,
+        root_logger: Logger,
+        _config_builder: ConfigBuilder<DefaultState>,
+    ) -> StdResult<()> {
+        debug!(root_logger, "SIGN SHARE GENESIS command");
+        println!(
+            "Genesis sign share of payload from {} to {}",
+            self.to_sign_payload_path.to_string_lossy(),
+            self.target_signature_share_path.to_string_lossy()
+        );
+
+        GenesisTools::sign_genesis_certificate_share(
+            &self.to_sign_payload_path,
+            &self.target_signature_share_path,
+            &self.genesis_secret_key_path,
+        )
+        .await
+        .with_context(|| "genesis-tools: sign share error")?;
+
+        Ok(())
+    }
+}
+
+#[derive(Parser, Debug, Clone)]
+pub struct BundleGenesisSubCommand {
+    /// Signature Share Paths
+    #[clap(long = "signature-share-path", required = true)]
+    signature_share_paths: Vec<PathBuf>,
+
+    /// Target Signature Bundle Path
+    #[clap(long)]
+    target_signature_bundle_path: PathBuf,
+}
+
+impl BundleGenesisSubCommand {
+    pub async fn execute(
+        &self,
+        root_logger: Logger,
+        _config_builder: ConfigBuilder<DefaultState>,
+    ) -> StdResult<()> {
+        debug!(root_logger, "BUNDLE GENESIS command");
+        println!(
+            "Genesis bundle {} signature shares to {}",
+            self.signature_share_paths.len(),
+            self.target_signature_bundle_path.to_string_lossy()
+        );
+
+        GenesisTools::bundle_genesis_signature_shares(
+            &self.signature_share_paths,
+            &self.target_signature_bundle_path,
+        )
+        .with_context(|| "genesis-tools: bundle error")?;
+
+        Ok(())
+    }
+}
+
 #[derive(Parser, Debug, Clone)]
 pub struct BootstrapGenesisSubCommand {
     /// Genesis Secret Key (test only)
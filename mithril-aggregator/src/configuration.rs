@@ -4,19 +4,21 @@ use serde::{Deserialize, Serialize};
 use std::collections::{BTreeSet, HashMap};
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Duration;
 
 use mithril_common::chain_observer::ChainObserverType;
-use mithril_common::crypto_helper::ProtocolGenesisSigner;
+use mithril_common::crypto_helper::{ProtocolGenesisSigner, ProtocolGenesisVerificationKeySet};
 use mithril_common::entities::{
     BlockNumber, CardanoTransactionsSigningConfig, CompressionAlgorithm,
-    HexEncodedGenesisVerificationKey, ProtocolParameters, SignedEntityConfig,
-    SignedEntityTypeDiscriminants,
+    HexEncodedGenesisVerificationKey, HexEncodedGenesisVerificationKeySet, ProtocolParameters,
+    SignedEntityConfig, SignedEntityTypeDiscriminants,
 };
 use mithril_common::era::adapters::EraReaderAdapterType;
 use mithril_common::{CardanoNetwork, StdResult};
 use mithril_doc::{Documenter, DocumenterDefault, StructDoc};
 
 use crate::entities::AggregatorEpochSettings;
+use crate::file_uploaders::{FileUploadRetryPolicy, MultiUploadFailurePolicy};
 use crate::http_server::SERVER_BASE_PATH;
 use crate::tools::url_sanitizer::SanitizedUrlWithTrailingSlash;
 
@@ -85,15 +87,46 @@ pub struct Configuration {
     pub protocol_parameters: ProtocolParameters,
 
     /// Type of snapshot uploader to use
-    #[example = "`gcp` or `local`"]
+    #[example = "`gcp`, `s3` or `local`"]
     pub snapshot_uploader_type: SnapshotUploaderType,
 
-    /// Bucket name where the snapshots are stored if snapshot_uploader_type is Gcp
+    /// Bucket name where the snapshots are stored if snapshot_uploader_type is Gcp or S3
     pub snapshot_bucket_name: Option<String>,
 
     /// Use CDN domain to construct snapshot urls if snapshot_uploader_type is Gcp
     pub snapshot_use_cdn_domain: bool,
 
+    /// Endpoint of the S3 compatible service if snapshot_uploader_type is S3
+    #[example = "`https://s3.eu-west-1.amazonaws.com` or `http://localhost:9000`"]
+    pub snapshot_s3_endpoint: Option<String>,
+
+    /// Region of the bucket if snapshot_uploader_type is S3
+    pub snapshot_s3_region: String,
+
+    /// Prefix prepended to the key of the uploaded files if snapshot_uploader_type is S3
+    pub snapshot_s3_prefix: Option<String>,
+
+    /// Use path-style addressing instead of virtual-hosted style addressing if snapshot_uploader_type is S3
+    pub snapshot_s3_path_style: bool,
+
+    /// Template of the public URL of the uploaded files if snapshot_uploader_type is S3,
+    /// `{bucket}` and `{key}` are replaced by the bucket name and the key of the file
+    #[example = "`https://cdn.mithril.network/{key}`"]
+    pub snapshot_s3_public_url_template: Option<String>,
+
+    /// Mirror destinations where the snapshot artifacts are also uploaded (JSON encoded list of
+    /// [SnapshotUploaderDestination])
+    #[example = "`[{ \"type\": \"gcp\", \"bucket_name\": \"mirror-bucket\" }]`"]
+    pub snapshot_mirror_uploaders: Option<String>,
+
+    /// Policy applied when the upload of a snapshot artifact fails for some of its destinations
+    #[example = "`require_all`, `require_primary` or `require_any`"]
+    pub snapshot_upload_failure_policy: MultiUploadFailurePolicy,
+
+    /// Retry parameters of the uploads of the snapshot artifacts
+    #[example = "`{ attempts: 3, delay_between_attempts_ms: 5000, backoff_multiplier: 2, max_jitter_ms: 1000 }`"]
+    pub file_upload_retry_parameters: Option<FileUploadRetryParameters>,
+
     /// Server listening IP
     pub server_ip: String,
 
@@ -120,6 +153,10 @@ pub struct Configuration {
     /// Genesis verification key
     pub genesis_verification_key: HexEncodedGenesisVerificationKey,
 
+    /// Genesis verification key set of a threshold genesis (JSON hex encoded verification keys
+    /// of the holders of the genesis keys and minimum number of genesis signatures)
+    pub genesis_verification_key_set: Option<HexEncodedGenesisVerificationKeySet>,
+
     /// Should the immutable cache be reset or not
     pub reset_digests_cache: bool,
 
@@ -201,10 +238,98 @@ pub struct Configuration {
 pub enum SnapshotUploaderType {
     /// Uploader to GCP storage.
     Gcp,
+    /// Uploader to a S3 compatible storage.
+    S3,
     /// Uploader to local storage.
     Local,
 }
 
+/// A destination where the snapshot artifacts are uploaded.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SnapshotUploaderDestination {
+    /// Type of uploader to use
+    #[serde(rename = "type")]
+    pub uploader_type: SnapshotUploaderType,
+
+    /// Bucket name where the snapshots are stored if the uploader type is Gcp or S3
+    pub bucket_name: Option<String>,
+
+    /// Use CDN domain to construct snapshot urls if the uploader type is Gcp
+    #[serde(default)]
+    pub use_cdn_domain: bool,
+
+    /// Endpoint of the S3 compatible service if the uploader type is S3
+    pub s3_endpoint: Option<String>,
+
+    /// Region of the bucket if the uploader type is S3
+    #[serde(default = "SnapshotUploaderDestination::default_s3_region")]
+    pub s3_region: String,
+
+    /// Prefix prepended to the key of the uploaded files if the uploader type is S3
+    pub s3_prefix: Option<String>,
+
+    /// Use path-style addressing if the uploader type is S3
+    #[serde(default)]
+    pub s3_path_style: bool,
+
+    /// Template of the public URL of the uploaded files if the uploader type is S3
+    pub s3_public_url_template: Option<String>,
+}
+
+impl SnapshotUploaderDestination {
+    fn default_s3_region() -> String {
+        "us-east-1".to_string()
+    }
+}
+
+/// Retry parameters of the file uploads, converted to a [FileUploadRetryPolicy]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct FileUploadRetryParameters {
+    /// Number of attempts to upload a file, default to 3.
+    pub attempts: usize,
+
+    /// Delay in milliseconds between the first two attempts, default to 5000.
+    pub delay_between_attempts_ms: u64,
+
+    /// Factor applied to the delay after each retry, default to 1 (constant delay).
+    pub backoff_multiplier: u32,
+
+    /// Maximum random delay in milliseconds added to each delay, default to 0 (no jitter).
+    pub max_jitter_ms: u64,
+
+    /// Maximum duration in milliseconds of all the attempts, unlimited by default.
+    pub max_total_duration_ms: Option<u64>,
+}
+
+impl Default for FileUploadRetryParameters {
+    fn default() -> Self {
+        let policy = FileUploadRetryPolicy::default();
+
+        Self {
+            attempts: policy.attempts,
+            delay_between_attempts_ms: policy.delay_between_attempts.as_millis() as u64,
+            backoff_multiplier: policy.backoff_multiplier,
+            max_jitter_ms: policy.max_jitter.as_millis() as u64,
+            max_total_duration_ms: policy
+                .max_total_duration
+                .map(|duration| duration.as_millis() as u64),
+        }
+    }
+}
+
+impl From<FileUploadRetryParameters> for FileUploadRetryPolicy {
+    fn from(parameters: FileUploadRetryParameters) -> Self {
+        Self {
+            attempts: parameters.attempts,
+            delay_between_attempts: Duration::from_millis(parameters.delay_between_attempts_ms),
+            backoff_multiplier: parameters.backoff_multiplier,
+            max_jitter: Duration::from_millis(parameters.max_jitter_ms),
+            max_total_duration: parameters.max_total_duration_ms.map(Duration::from_millis),
+        }
+    }
+}
+
 /// [Zstandard][CompressionAlgorithm::Zstandard] specific parameters
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ZstandardCompressionParameters {
@@ -247,6 +372,14 @@ impl Configuration {
             snapshot_uploader_type: SnapshotUploaderType::Local,
             snapshot_bucket_name: None,
             snapshot_use_cdn_domain: false,
+            snapshot_s3_endpoint: None,
+            snapshot_s3_region: "us-east-1".to_string(),
+            snapshot_s3_prefix: None,
+            snapshot_s3_path_style: false,
+            snapshot_s3_public_url_template: None,
+            snapshot_mirror_uploaders: None,
+            snapshot_upload_failure_policy: MultiUploadFailurePolicy::RequirePrimary,
+            file_upload_retry_parameters: None,
             server_ip: "0.0.0.0".to_string(),
             server_port: 8000,
             public_server_url: None,
@@ -260,6 +393,7 @@ impl Configuration {
             snapshot_directory: std::env::temp_dir(),
             data_stores_directory: PathBuf::from(":memory:"),
             genesis_verification_key: genesis_verification_key.to_json_hex().unwrap(),
+            genesis_verification_key_set: None,
             reset_digests_cache: false,
             disable_digests_cache: false,
             store_retention_limit: None,
@@ -310,6 +444,19 @@ impl Configuration {
             .map_err(|e| anyhow!(ConfigError::Message(e.to_string())))
     }
 
+    /// Return the genesis verification key set of a threshold genesis, if configured
+    pub fn get_genesis_verification_key_set(
+        &self,
+    ) -> StdResult<Option<ProtocolGenesisVerificationKeySet>> {
+        self.genesis_verification_key_set
+            .as_ref()
+            .map(|key_set| {
+                ProtocolGenesisVerificationKeySet::from_json_hex(key_set)
+                    .with_context(|| "Could not decode the genesis verification key set")
+            })
+            .transpose()
+    }
+
     /// Return the directory of the SQLite stores. If the directory does not exist, it is created.
     pub fn get_sqlite_dir(&self) -> PathBuf {
         let store_dir = &self.data_stores_directory;
@@ -361,10 +508,54 @@ impl Configuration {
 
     /// Check if the HTTP server can serve static directories.
     // TODO: This function should be completed when the configuration of the uploaders for the Cardano database is done.
-    pub fn allow_http_serve_directory(&self) -> bool {
-        match self.snapshot_uploader_type {
-            SnapshotUploaderType::Local => true,
-            SnapshotUploaderType::Gcp => false,
+    pub fn allow_http_serve_directory(&self) -> StdResult<bool> {
+        let primary_uploader_type = [self.snapshot_uploader_type];
+        let mirror_uploader_types = self
+            .get_snapshot_mirror_uploaders()?
+            .into_iter()
+            .map(|destination| destination.uploader_type);
+
+        Ok(primary_uploader_type
+            .into_iter()
+            .chain(mirror_uploader_types)
+            .any(|uploader_type| match uploader_type {
+                SnapshotUploaderType::Local => true,
+                SnapshotUploaderType::Gcp | SnapshotUploaderType::S3 => false,
+            }))
+    }
+
+    /// Get the retry policy of the file uploads, the default policy being used if no retry
+    /// parameters are configured.
+    pub fn get_file_upload_retry_policy(&self) -> FileUploadRetryPolicy {
+        self.file_upload_retry_parameters.unwrap_or_default().into()
+    }
+
+    /// Get the destinations where the snapshot artifacts are uploaded: the primary one, configured
+    /// by the `snapshot_*` settings, followed by its mirrors.
+    pub fn get_snapshot_uploader_destinations(
+        &self,
+    ) -> StdResult<Vec<SnapshotUploaderDestination>> {
+        let primary_destination = SnapshotUploaderDestination {
+            uploader_type: self.snapshot_uploader_type,
+            bucket_name: self.snapshot_bucket_name.clone(),
+            use_cdn_domain: self.snapshot_use_cdn_domain,
+            s3_endpoint: self.snapshot_s3_endpoint.clone(),
+            s3_region: self.snapshot_s3_region.clone(),
+            s3_prefix: self.snapshot_s3_prefix.clone(),
+            s3_path_style: self.snapshot_s3_path_style,
+            s3_public_url_template: self.snapshot_s3_public_url_template.clone(),
+        };
+        let mut destinations = vec![primary_destination];
+        destinations.extend(self.get_snapshot_mirror_uploaders()?);
+
+        Ok(destinations)
+    }
+
+    fn get_snapshot_mirror_uploaders(&self) -> StdResult<Vec<SnapshotUploaderDestination>> {
+        match &self.snapshot_mirror_uploaders {
+            Some(mirror_uploaders) => serde_json::from_str(mirror_uploaders)
+                .with_context(|| "Invalid 'snapshot_mirror_uploaders' configuration"),
+            None => Ok(vec![]),
         }
     }
 
@@ -420,6 +611,15 @@ pub struct DefaultConfiguration {
     /// Use CDN domain to construct snapshot urls default setting (if snapshot_uploader_type is Gcp)
     pub snapshot_use_cdn_domain: String,
 
+    /// Region of the bucket default setting (if snapshot_uploader_type is S3)
+    pub snapshot_s3_region: String,
+
+    /// Use path-style addressing default setting (if snapshot_uploader_type is S3)
+    pub snapshot_s3_path_style: String,
+
+    /// Snapshot upload failure policy default setting
+    pub snapshot_upload_failure_policy: String,
+
     /// Signer importer run interval default setting
     pub signer_importer_run_interval: u64,
 
@@ -472,6 +672,9 @@ impl Default for DefaultConfiguration {
             disable_digests_cache: "false".to_string(),
             snapshot_compression_algorithm: "zstandard".to_string(),
             snapshot_use_cdn_domain: "false".to_string(),
+            snapshot_s3_region: "us-east-1".to_string(),
+            snapshot_s3_path_style: "false".to_string(),
+            snapshot_upload_failure_policy: "require_primary".to_string(),
             signer_importer_run_interval: 720,
             allow_unparsable_block: "false".to_string(),
             cardano_transactions_prover_cache_pool_size: 10,
@@ -537,6 +740,9 @@ impl Source for DefaultConfiguration {
         insert_default_configuration!(result, myself.disable_digests_cache);
         insert_default_configuration!(result, myself.snapshot_compression_algorithm);
         insert_default_configuration!(result, myself.snapshot_use_cdn_domain);
+        insert_default_configuration!(result, myself.snapshot_s3_region);
+        insert_default_configuration!(result, myself.snapshot_s3_path_style);
+        insert_default_configuration!(result, myself.snapshot_upload_failure_policy);
         insert_default_configuration!(result, myself.signer_importer_run_interval);
         insert_default_configuration!(result, myself.allow_unparsable_block);
         insert_default_configuration!(result, myself.cardano_transactions_prover_cache_pool_size);
@@ -650,14 +856,160 @@ mod test {
             ..Configuration::new_sample()
         };
 
-        assert!(config.allow_http_serve_directory());
+        assert!(config.allow_http_serve_directory().unwrap());
 
         let config = Configuration {
             snapshot_uploader_type: SnapshotUploaderType::Gcp,
             ..Configuration::new_sample()
         };
 
-        assert!(!config.allow_http_serve_directory());
+        assert!(!config.allow_http_serve_directory().unwrap());
+
+        let config = Configuration {
+            snapshot_uploader_type: SnapshotUploaderType::S3,
+            ..Configuration::new_sample()
+        };
+
+        assert!(!config.allow_http_serve_directory().unwrap());
+
+        let config = Configuration {
+            snapshot_uploader_type: SnapshotUploaderType::Gcp,
+            snapshot_mirror_uploaders: Some(r#"[{"type": "local"}]"#.to_string()),
+            ..Configuration::new_sample()
+        };
+
+        assert!(config.allow_http_serve_directory().unwrap());
+    }
+
+    #[test]
+    fn get_file_upload_retry_policy_default_to_the_default_policy() {
+        let config = Configuration {
+            file_upload_retry_parameters: None,
+            ..Configuration::new_sample()
+        };
+
+        assert_eq!(
+            FileUploadRetryPolicy::default(),
+            config.get_file_upload_retry_policy()
+        );
+    }
+
+    #[test]
+    fn get_file_upload_retry_policy_from_the_configured_parameters() {
+        let config = Configuration {
+            file_upload_retry_parameters: Some(FileUploadRetryParameters {
+                attempts: 5,
+                delay_between_attempts_ms: 100,
+                backoff_multiplier: 3,
+                max_jitter_ms: 20,
+                max_total_duration_ms: Some(60_000),
+            }),
+            ..Configuration::new_sample()
+        };
+
+        assert_eq!(
+            FileUploadRetryPolicy {
+                attempts: 5,
+                delay_between_attempts: Duration::from_millis(100),
+                backoff_multiplier: 3,
+                max_jitter: Duration::from_millis(20),
+                max_total_duration: Some(Duration::from_secs(60)),
+            },
+            config.get_file_upload_retry_policy()
+        );
+    }
+
+    #[test]
+    fn file_upload_retry_parameters_missing_fields_use_the_default_values() {
+        let parameters: FileUploadRetryParameters =
+            serde_json::from_str(r#"{ "backoff_multiplier": 4 }"#).unwrap();
+
+        assert_eq!(
+            FileUploadRetryParameters {
+                backoff_multiplier: 4,
+                ..FileUploadRetryParameters::default()
+            },
+            parameters
+        );
+    }
+
+    #[test]
+    fn allow_http_serve_directory_fails_with_invalid_mirror_uploaders() {
+        let config = Configuration {
+            snapshot_uploader_type: SnapshotUploaderType::Local,
+            snapshot_mirror_uploaders: Some("not a json".to_string()),
+            ..Configuration::new_sample()
+        };
+
+        config
+            .allow_http_serve_directory()
+            .expect_err("Should fail with invalid mirror uploaders");
+    }
+
+    #[test]
+    fn get_snapshot_uploader_destinations_return_the_primary_destination_followed_by_the_mirrors() {
+        let config = Configuration {
+            snapshot_uploader_type: SnapshotUploaderType::Gcp,
+            snapshot_bucket_name: Some("primary-bucket".to_string()),
+            snapshot_mirror_uploaders: Some(
+                r#"[
+                    {"type": "s3", "bucket_name": "mirror-bucket", "s3_endpoint": "http://localhost:9000", "s3_path_style": true},
+                    {"type": "local"}
+                ]"#
+                .to_string(),
+            ),
+            ..Configuration::new_sample()
+        };
+
+        let destinations = config.get_snapshot_uploader_destinations().unwrap();
+
+        assert_eq!(
+            vec![
+                SnapshotUploaderDestination {
+                    uploader_type: SnapshotUploaderType::Gcp,
+                    bucket_name: Some("primary-bucket".to_string()),
+                    use_cdn_domain: false,
+                    s3_endpoint: None,
+                    s3_region: "us-east-1".to_string(),
+                    s3_prefix: None,
+                    s3_path_style: false,
+                    s3_public_url_template: None,
+                },
+                SnapshotUploaderDestination {
+                    uploader_type: SnapshotUploaderType::S3,
+                    bucket_name: Some("mirror-bucket".to_string()),
+                    use_cdn_domain: false,
+                    s3_endpoint: Some("http://localhost:9000".to_string()),
+                    s3_region: "us-east-1".to_string(),
+                    s3_prefix: None,
+                    s3_path_style: true,
+                    s3_public_url_template: None,
+                },
+                SnapshotUploaderDestination {
+                    uploader_type: SnapshotUploaderType::Local,
+                    bucket_name: None,
+                    use_cdn_domain: false,
+                    s3_endpoint: None,
+                    s3_region: "us-east-1".to_string(),
+                    s3_prefix: None,
+                    s3_path_style: false,
+                    s3_public_url_template: None,
+                },
+            ],
+            destinations
+        );
+    }
+
+    #[test]
+    fn get_snapshot_uploader_destinations_fails_with_invalid_mirror_uploaders() {
+        let config = Configuration {
+            snapshot_mirror_uploaders: Some("not a json".to_string()),
+            ..Configuration::new_sample()
+        };
+
+        config
+            .get_snapshot_uploader_destinations()
+            .expect_err("Should fail with invalid mirror uploaders");
     }
 
     #[test]
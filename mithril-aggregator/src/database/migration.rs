@@ -892,5 +892,37 @@ create table immutable_file_digest (
 );
         "#,
         ),
+        // Migration 35
+        // Add an index on the `signed_entity_type_id` of the `certificate` table to look up the
+        // certificates by signed entity type.
+        SqlMigration::new(
+            35,
+            r#"
+create index certificate_signed_entity_type_id_index on certificate(signed_entity_type_id);
+        "#,
+        ),
+        // Migration 36
+        // Add the `signed_entity_type` record for 'CardanoBlocks'
+        SqlMigration::new(
+            36,
+            r#"
+insert into signed_entity_type (signed_entity_type_id, name) 
+    values  (5, 'Cardano Blocks');
+        "#,
+        ),
+        // Migration 37
+        // Add indexes on the beacon fields of the `certificate` table to look up the certificates
+        // by signed entity type and beacon.
+        // The indexed expressions must be the exact `json_extract` expressions of the
+        // `GetCertificateRecordQuery::by_signed_entity_type` query to be used.
+        SqlMigration::new(
+            37,
+            r#"
+create index certificate_beacon_index on certificate(signed_entity_type_id, json_extract(signed_entity_beacon, '$'));
+create index certificate_beacon_epoch_index on certificate(signed_entity_type_id, json_extract(signed_entity_beacon, '$.epoch'));
+create index certificate_beacon_immutable_file_number_index on certificate(signed_entity_type_id, json_extract(signed_entity_beacon, '$.immutable_file_number'));
+create index certificate_beacon_block_number_index on certificate(signed_entity_type_id, json_extract(signed_entity_beacon, '$.block_number'));
+        "#,
+        ),
     ]
 }
@@ -1,8 +1,6 @@
 use sqlite::Value;
 
-#[cfg(test)]
-use mithril_common::entities::Epoch;
-#[cfg(test)]
+use mithril_common::entities::{BeaconFilter, Epoch, ListFilter, SignedEntityTypeDiscriminants};
 use mithril_common::StdResult;
 use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
 
@@ -11,12 +9,14 @@ use crate::database::record::CertificateRecord;
 /// Simple queries to retrieve [CertificateRecord] from the sqlite database.
 pub struct GetCertificateRecordQuery {
     condition: WhereCondition,
+    ascending_order: bool,
 }
 
 impl GetCertificateRecordQuery {
     pub fn all() -> Self {
         Self {
             condition: WhereCondition::default(),
+            ascending_order: false,
         }
     }
 
@@ -26,13 +26,99 @@ impl GetCertificateRecordQuery {
                 "certificate_id = ?*",
                 vec![Value::String(certificate_id.to_owned())],
             ),
+            ascending_order: false,
         }
     }
 
-    #[cfg(test)]
     pub fn by_epoch(epoch: Epoch) -> StdResult<Self> {
         Ok(Self {
             condition: WhereCondition::new("epoch = ?*", vec![Value::Integer(epoch.try_into()?)]),
+            ascending_order: false,
+        })
+    }
+
+    /// Select the certificates of the given signed entity type whose beacon matches the given
+    /// filter.
+    pub fn by_signed_entity_type(
+        signed_entity_type: SignedEntityTypeDiscriminants,
+        beacon_filter: &BeaconFilter,
+    ) -> StdResult<Self> {
+        let mut condition = WhereCondition::new(
+            "signed_entity_type_id = ?*",
+            vec![Value::Integer(signed_entity_type.index() as i64)],
+        );
+        // The `json_extract` expressions below are indexed (see migration 37): keep them in sync.
+        if let Some(epoch) = beacon_filter.epoch {
+            // The beacon of the stake distributions is only their epoch
+            let epoch_path = match signed_entity_type {
+                SignedEntityTypeDiscriminants::MithrilStakeDistribution
+                | SignedEntityTypeDiscriminants::CardanoStakeDistribution => "$",
+                _ => "$.epoch",
+            };
+            condition = condition.and_where(WhereCondition::new(
+                &format!("json_extract(signed_entity_beacon, '{epoch_path}') = ?*"),
+                vec![Value::Integer(epoch.try_into()?)],
+            ));
+        }
+        if let Some(immutable_file_number) = beacon_filter.immutable_file_number {
+            condition = condition.and_where(WhereCondition::new(
+                "json_extract(signed_entity_beacon, '$.immutable_file_number') = ?*",
+                vec![Value::Integer(immutable_file_number.try_into()?)],
+            ));
+        }
+        if let Some(block_number) = beacon_filter.block_number {
+            condition = condition.and_where(WhereCondition::new(
+                "json_extract(signed_entity_beacon, '$.block_number') = ?*",
+                vec![Value::Integer(block_number.try_into()?)],
+            ));
+        }
+
+        Ok(Self {
+            condition,
+            ascending_order: false,
+        })
+    }
+
+    /// Select the certificates matching the given cursor and filters.
+    ///
+    /// If an `after` cursor is given the certificates are returned from the oldest to the most
+    /// recent, so the ones right after the cursor come first.
+    pub fn with_filter(filter: &ListFilter) -> StdResult<Self> {
+        let mut condition = WhereCondition::default();
+        if let Some(before) = &filter.before {
+            condition = condition.and_where(WhereCondition::new(
+                "ROWID < (select ROWID from certificate where certificate_id = ?*)",
+                vec![Value::String(before.to_owned())],
+            ));
+        }
+        if let Some(after) = &filter.after {
+            condition = condition.and_where(WhereCondition::new(
+                "ROWID > (select ROWID from certificate where certificate_id = ?*)",
+                vec![Value::String(after.to_owned())],
+            ));
+        }
+        if let Some(from_epoch) = filter.from_epoch {
+            condition = condition.and_where(WhereCondition::new(
+                "epoch >= ?*",
+                vec![Value::Integer(from_epoch.try_into()?)],
+            ));
+        }
+        if let Some(to_epoch) = filter.to_epoch {
+            condition = condition.and_where(WhereCondition::new(
+                "epoch <= ?*",
+                vec![Value::Integer(to_epoch.try_into()?)],
+            ));
+        }
+        if let Some(signed_entity_type) = filter.signed_entity_type {
+            condition = condition.and_where(WhereCondition::new(
+                "signed_entity_type_id = ?*",
+                vec![Value::Integer(signed_entity_type.index() as i64)],
+            ));
+        }
+
+        Ok(Self {
+            condition,
+            ascending_order: filter.after.is_some(),
         })
     }
 }
@@ -47,14 +133,19 @@ impl Query for GetCertificateRecordQuery {
     fn get_definition(&self, condition: &str) -> String {
         let aliases = SourceAlias::new(&[("{:certificate:}", "c")]);
         let projection = Self::Entity::get_projection().expand(aliases);
-        format!("select {projection} from certificate as c where {condition} order by ROWID desc")
+        let order = if self.ascending_order { "asc" } else { "desc" };
+        format!(
+            "select {projection} from certificate as c where {condition} order by ROWID {order}"
+        )
     }
 }
 
 #[cfg(test)]
 mod tests {
     use mithril_common::crypto_helper::tests_setup::setup_certificate_chain;
+    use mithril_common::entities::{BlockNumber, SignedEntityType};
     use mithril_persistence::sqlite::ConnectionExtensions;
+    use sqlite::ConnectionThreadSafe;
 
     use crate::database::test_helper::{insert_certificate_records, main_db_connection};
 
@@ -93,6 +184,91 @@ mod tests {
         assert_eq!(0, cursor.count());
     }
 
+    #[test]
+    fn test_get_certificate_records_by_signed_entity_type_and_beacon() {
+        let records = vec![
+            CertificateRecord::dummy_genesis("genesis", Epoch(1)),
+            CertificateRecord::dummy_db_snapshot("snapshot-1", "genesis", Epoch(1), 10),
+            CertificateRecord::dummy(
+                "msd-2",
+                "genesis",
+                Epoch(2),
+                SignedEntityType::MithrilStakeDistribution(Epoch(2)),
+            ),
+            CertificateRecord::dummy_db_snapshot("snapshot-2", "msd-2", Epoch(2), 11),
+            CertificateRecord::dummy(
+                "tx-2",
+                "msd-2",
+                Epoch(2),
+                SignedEntityType::CardanoTransactions(Epoch(2), BlockNumber(99)),
+            ),
+            CertificateRecord::dummy_db_snapshot("snapshot-3", "msd-2", Epoch(2), 12),
+        ];
+
+        let connection = main_db_connection().unwrap();
+        insert_certificate_records(&connection, records);
+
+        let fetch_ids = |signed_entity_type, beacon_filter: BeaconFilter| -> Vec<String> {
+            connection
+                .fetch_collect::<_, Vec<CertificateRecord>>(
+                    GetCertificateRecordQuery::by_signed_entity_type(
+                        signed_entity_type,
+                        &beacon_filter,
+                    )
+                    .unwrap(),
+                )
+                .unwrap()
+                .into_iter()
+                .map(|record| record.certificate_id)
+                .collect()
+        };
+
+        assert_eq!(
+            vec!["snapshot-3", "snapshot-2", "snapshot-1"],
+            fetch_ids(
+                SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
+                BeaconFilter::default()
+            )
+        );
+        assert_eq!(
+            vec!["snapshot-3", "snapshot-2"],
+            fetch_ids(
+                SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
+                BeaconFilter::default().epoch(Epoch(2))
+            )
+        );
+        assert_eq!(
+            vec!["snapshot-2"],
+            fetch_ids(
+                SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
+                BeaconFilter::default().immutable_file_number(11)
+            )
+        );
+        assert_eq!(
+            vec!["msd-2"],
+            fetch_ids(
+                SignedEntityTypeDiscriminants::MithrilStakeDistribution,
+                BeaconFilter::default().epoch(Epoch(2))
+            )
+        );
+        assert_eq!(
+            vec!["tx-2"],
+            fetch_ids(
+                SignedEntityTypeDiscriminants::CardanoTransactions,
+                BeaconFilter::default()
+                    .epoch(Epoch(2))
+                    .block_number(BlockNumber(99))
+            )
+        );
+        assert_eq!(
+            Vec::<String>::new(),
+            fetch_ids(
+                SignedEntityTypeDiscriminants::CardanoTransactions,
+                BeaconFilter::default().block_number(BlockNumber(100))
+            )
+        );
+    }
+
     #[test]
     fn test_get_all_certificate_records() {
         let (certificates, _) = setup_certificate_chain(5, 2);
@@ -110,4 +286,72 @@ mod tests {
             .unwrap();
         assert_eq!(expected_certificate_records, certificate_records);
     }
+
+    fn query_plan(connection: &ConnectionThreadSafe, query: GetCertificateRecordQuery) -> String {
+        let (condition, params) = query.filters().expand();
+        let sql = format!("explain query plan {}", query.get_definition(&condition));
+        let mut statement = connection.prepare(sql).unwrap();
+        statement.bind(&params[..]).unwrap();
+
+        let mut plan = vec![];
+        while let Ok(sqlite::State::Row) = statement.next() {
+            plan.push(statement.read::<String, _>("detail").unwrap());
+        }
+        plan.join("\n")
+    }
+
+    #[test]
+    fn test_get_certificate_records_by_signed_entity_type_use_the_beacon_indexes() {
+        let connection = main_db_connection().unwrap();
+        let cases = [
+            (
+                SignedEntityTypeDiscriminants::MithrilStakeDistribution,
+                BeaconFilter {
+                    epoch: Some(Epoch(3)),
+                    ..BeaconFilter::default()
+                },
+                "certificate_beacon_index",
+            ),
+            (
+                SignedEntityTypeDiscriminants::CardanoDatabase,
+                BeaconFilter {
+                    epoch: Some(Epoch(3)),
+                    ..BeaconFilter::default()
+                },
+                "certificate_beacon_epoch_index",
+            ),
+            (
+                SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
+                BeaconFilter {
+                    immutable_file_number: Some(15),
+                    ..BeaconFilter::default()
+                },
+                "certificate_beacon_immutable_file_number_index",
+            ),
+            (
+                SignedEntityTypeDiscriminants::CardanoTransactions,
+                BeaconFilter {
+                    block_number: Some(BlockNumber(120)),
+                    ..BeaconFilter::default()
+                },
+                "certificate_beacon_block_number_index",
+            ),
+        ];
+
+        for (signed_entity_type, beacon_filter, expected_index) in cases {
+            let plan = query_plan(
+                &connection,
+                GetCertificateRecordQuery::by_signed_entity_type(
+                    signed_entity_type,
+                    &beacon_filter,
+                )
+                .unwrap(),
+            );
+
+            assert!(
+                plan.contains(expected_index),
+                "{signed_entity_type:?} query should use the '{expected_index}' index, plan: {plan}"
+            );
+        }
+    }
 }
@@ -0,0 +1,146 @@
+use sqlite::Value;
+
+use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
+
+use crate::database::record::CertificateRecord;
+
+/// Query to retrieve a segment of a chain of [CertificateRecord], following the parents of a
+/// certificate.
+pub struct GetCertificateChainQuery {
+    condition: WhereCondition,
+    max_depth: usize,
+}
+
+impl GetCertificateChainQuery {
+    /// Select the certificate with the given id followed by its ancestors, up to the first
+    /// genesis certificate or until `max_depth` certificates are selected.
+    pub fn from_certificate_id(certificate_id: &str, max_depth: usize) -> Self {
+        Self {
+            condition: WhereCondition::new(
+                "certificate_id = ?*",
+                vec![Value::String(certificate_id.to_owned())],
+            ),
+            max_depth,
+        }
+    }
+}
+
+impl Query for GetCertificateChainQuery {
+    type Entity = CertificateRecord;
+
+    fn filters(&self) -> WhereCondition {
+        self.condition.clone()
+    }
+
+    fn get_definition(&self, condition: &str) -> String {
+        let aliases = SourceAlias::new(&[("{:certificate:}", "c")]);
+        let projection = Self::Entity::get_projection().expand(aliases);
+        let max_depth = self.max_depth;
+
+        format!(
+            r#"
+with recursive certificate_chain(certificate_id, depth) as (
+    select certificate_id, 1 from certificate where {condition}
+    union all
+    select certificate.parent_certificate_id, certificate_chain.depth + 1
+    from certificate_chain
+        join certificate on certificate.certificate_id = certificate_chain.certificate_id
+    where certificate.parent_certificate_id is not null
+        and certificate_chain.depth < {max_depth}
+)
+select {projection}
+from certificate_chain
+    join certificate as c on c.certificate_id = certificate_chain.certificate_id
+order by certificate_chain.depth asc"#
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::crypto_helper::tests_setup::setup_certificate_chain;
+    use mithril_common::entities::Epoch;
+    use mithril_persistence::sqlite::ConnectionExtensions;
+
+    use crate::database::test_helper::{insert_certificate_records, main_db_connection};
+
+    use super::*;
+
+    fn certificate_ids(records: Vec<CertificateRecord>) -> Vec<String> {
+        records
+            .into_iter()
+            .map(|record| record.certificate_id)
+            .collect()
+    }
+
+    #[test]
+    fn test_get_certificate_chain_up_to_the_genesis_certificate() {
+        let connection = main_db_connection().unwrap();
+        insert_certificate_records(
+            &connection,
+            vec![
+                CertificateRecord::dummy_genesis("genesis-1", Epoch(1)),
+                CertificateRecord::dummy_db_snapshot("1-a", "genesis-1", Epoch(1), 1),
+                CertificateRecord::dummy_genesis("genesis-2", Epoch(2)),
+                CertificateRecord::dummy_db_snapshot("2-a", "genesis-2", Epoch(2), 2),
+                CertificateRecord::dummy_db_snapshot("2-b", "2-a", Epoch(2), 3),
+            ],
+        );
+
+        let records: Vec<CertificateRecord> = connection
+            .fetch_collect(GetCertificateChainQuery::from_certificate_id("2-b", 100))
+            .unwrap();
+        assert_eq!(vec!["2-b", "2-a", "genesis-2"], certificate_ids(records));
+
+        let records: Vec<CertificateRecord> = connection
+            .fetch_collect(GetCertificateChainQuery::from_certificate_id(
+                "genesis-1",
+                100,
+            ))
+            .unwrap();
+        assert_eq!(vec!["genesis-1"], certificate_ids(records));
+    }
+
+    #[test]
+    fn test_get_certificate_chain_up_to_the_max_depth() {
+        let (certificates, _) = setup_certificate_chain(10, 3);
+        let connection = main_db_connection().unwrap();
+        insert_certificate_records(&connection, certificates.clone());
+        let last_certificate = certificates.first().unwrap();
+
+        let records: Vec<CertificateRecord> = connection
+            .fetch_collect(GetCertificateChainQuery::from_certificate_id(
+                &last_certificate.hash,
+                4,
+            ))
+            .unwrap();
+
+        let mut expected_certificate_id = last_certificate.hash.clone();
+        let mut expected_certificate_ids = vec![];
+        for _ in 0..4 {
+            let certificate = certificates
+                .iter()
+                .find(|c| c.hash == expected_certificate_id)
+                .unwrap();
+            expected_certificate_ids.push(certificate.hash.clone());
+            expected_certificate_id = certificate.previous_hash.clone();
+        }
+        assert_eq!(expected_certificate_ids, certificate_ids(records));
+    }
+
+    #[test]
+    fn test_get_certificate_chain_of_an_unknown_certificate_is_empty() {
+        let connection = main_db_connection().unwrap();
+        insert_certificate_records(
+            &connection,
+            vec![CertificateRecord::dummy_genesis("genesis-1", Epoch(1))],
+        );
+
+        let records: Vec<CertificateRecord> = connection
+            .fetch_collect(GetCertificateChainQuery::from_certificate_id(
+                "unknown", 100,
+            ))
+            .unwrap();
+        assert!(records.is_empty());
+    }
+}
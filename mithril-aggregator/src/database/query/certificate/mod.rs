@@ -1,9 +1,11 @@
 mod delete_certificate;
 mod get_certificate;
+mod get_certificate_chain;
 mod get_master_certificate;
 mod insert_certificate;
 
 pub use delete_certificate::*;
 pub use get_certificate::*;
+pub use get_certificate_chain::*;
 pub use get_master_certificate::*;
 pub use insert_certificate::*;
@@ -1,6 +1,6 @@
 use sqlite::Value;
 
-use mithril_common::entities::{Epoch, SignedEntityTypeDiscriminants};
+use mithril_common::entities::{Epoch, ListFilter, SignedEntityTypeDiscriminants};
 use mithril_common::StdResult;
 use mithril_persistence::sqlite::{Query, SourceAlias, SqLiteEntity, WhereCondition};
 
@@ -9,6 +9,7 @@ use crate::database::record::SignedEntityRecord;
 /// Simple queries to retrieve [SignedEntityRecord] from the sqlite database.
 pub struct GetSignedEntityRecordQuery {
     condition: WhereCondition,
+    ascending_order: bool,
 }
 
 impl GetSignedEntityRecordQuery {
@@ -16,6 +17,7 @@ impl GetSignedEntityRecordQuery {
     pub fn all() -> Self {
         Self {
             condition: WhereCondition::default(),
+            ascending_order: false,
         }
     }
 
@@ -25,6 +27,7 @@ impl GetSignedEntityRecordQuery {
                 "signed_entity_id = ?*",
                 vec![Value::String(signed_entity_id.to_owned())],
             ),
+            ascending_order: false,
         }
     }
 
@@ -34,6 +37,7 @@ impl GetSignedEntityRecordQuery {
                 "certificate_id = ?*",
                 vec![Value::String(certificate_id.to_owned())],
             ),
+            ascending_order: false,
         }
     }
 
@@ -45,6 +49,7 @@ impl GetSignedEntityRecordQuery {
 
         Self {
             condition: WhereCondition::where_in("certificate_id", ids_values),
+            ascending_order: false,
         }
     }
 
@@ -58,6 +63,48 @@ impl GetSignedEntityRecordQuery {
                 "signed_entity_type_id = ?*",
                 vec![Value::Integer(signed_entity_type_id)],
             ),
+            ascending_order: false,
+        })
+    }
+
+    /// Select the signed entities of the given type matching the given cursor and filters.
+    ///
+    /// The cursors can either be a signed entity id or a certificate id.
+    /// If an `after` cursor is given the signed entities are returned from the oldest to the
+    /// most recent, so the ones right after the cursor come first.
+    pub fn by_signed_entity_type_with_filter(
+        signed_entity_type: &SignedEntityTypeDiscriminants,
+        filter: &ListFilter,
+    ) -> StdResult<Self> {
+        let mut condition = Self::by_signed_entity_type(signed_entity_type)?.condition;
+        if let Some(before) = &filter.before {
+            condition = condition.and_where(WhereCondition::new(
+                "ROWID < (select ROWID from signed_entity where signed_entity_id = ?* or certificate_id = ?*)",
+                vec![Value::String(before.to_owned()), Value::String(before.to_owned())],
+            ));
+        }
+        if let Some(after) = &filter.after {
+            condition = condition.and_where(WhereCondition::new(
+                "ROWID > (select ROWID from signed_entity where signed_entity_id = ?* or certificate_id = ?*)",
+                vec![Value::String(after.to_owned()), Value::String(after.to_owned())],
+            ));
+        }
+        if let Some(from_epoch) = filter.from_epoch {
+            condition = condition.and_where(WhereCondition::new(
+                "certificate_id in (select certificate_id from certificate where epoch >= ?*)",
+                vec![Value::Integer(from_epoch.try_into()?)],
+            ));
+        }
+        if let Some(to_epoch) = filter.to_epoch {
+            condition = condition.and_where(WhereCondition::new(
+                "certificate_id in (select certificate_id from certificate where epoch <= ?*)",
+                vec![Value::Integer(to_epoch.try_into()?)],
+            ));
+        }
+
+        Ok(Self {
+            condition,
+            ascending_order: filter.after.is_some(),
         })
     }
 
@@ -71,6 +118,7 @@ impl GetSignedEntityRecordQuery {
                 "signed_entity_type_id = ?* and beacon = ?*",
                 vec![Value::Integer(signed_entity_type_id), Value::Integer(epoch)],
             ),
+            ascending_order: false,
         }
     }
 }
@@ -85,8 +133,9 @@ impl Query for GetSignedEntityRecordQuery {
     fn get_definition(&self, condition: &str) -> String {
         let aliases = SourceAlias::new(&[("{:signed_entity:}", "se")]);
         let projection = Self::Entity::get_projection().expand(aliases);
+        let order = if self.ascending_order { "asc" } else { "desc" };
         format!(
-            "select {projection} from signed_entity as se where {condition} order by ROWID desc"
+            "select {projection} from signed_entity as se where {condition} order by ROWID {order}"
         )
     }
 }
@@ -132,6 +132,9 @@ impl From<Certificate> for CertificateRecord {
         let signed_entity_type = other.signed_entity_type();
         let (signature, parent_certificate_id) = match other.signature {
             CertificateSignature::GenesisSignature(signature) => (signature.to_bytes_hex(), None),
+            CertificateSignature::GenesisThresholdSignature(bundle) => {
+                (bundle.to_json_hex().unwrap(), None)
+            }
             CertificateSignature::MultiSignature(_, signature) => {
                 (signature.to_json_hex().unwrap(), Some(other.previous_hash))
             }
@@ -169,7 +172,7 @@ impl From<CertificateRecord> for Certificate {
         let (previous_hash, signature) = match other.parent_certificate_id {
             None => (
                 String::new(),
-                CertificateSignature::GenesisSignature(other.signature.try_into().unwrap()),
+                CertificateSignature::decode_genesis_signature(&other.signature).unwrap(),
             ),
             Some(parent_certificate_id) => (
                 parent_certificate_id,
@@ -5,13 +5,15 @@ use async_trait::async_trait;
 use sqlite::ConnectionThreadSafe;
 
 use mithril_common::certificate_chain::{CertificateRetriever, CertificateRetrieverError};
-use mithril_common::entities::{Certificate, Epoch};
+use mithril_common::entities::{
+    BeaconFilter, Certificate, Epoch, ListFilter, SignedEntityTypeDiscriminants,
+};
 use mithril_common::StdResult;
 use mithril_persistence::sqlite::ConnectionExtensions;
 
 use crate::database::query::{
-    DeleteCertificateQuery, GetCertificateRecordQuery, InsertCertificateRecordQuery,
-    MasterCertificateQuery,
+    DeleteCertificateQuery, GetCertificateChainQuery, GetCertificateRecordQuery,
+    InsertCertificateRecordQuery, MasterCertificateQuery,
 };
 use crate::database::record::CertificateRecord;
 
@@ -48,6 +50,74 @@ impl CertificateRepository {
         Ok(cursor.take(last_n).map(|v| v.into()).collect())
     }
 
+    /// Return the certificates matching the given cursor and filters, from the most recent to
+    /// the oldest.
+    pub async fn get_certificates_with_filter<T>(
+        &self,
+        filter: &ListFilter,
+        limit: usize,
+    ) -> StdResult<Vec<T>>
+    where
+        T: From<CertificateRecord>,
+    {
+        let cursor = self
+            .connection
+            .fetch(GetCertificateRecordQuery::with_filter(filter)?)?;
+        let mut certificates: Vec<T> = cursor.take(limit).map(|v| v.into()).collect();
+        if filter.after.is_some() {
+            certificates.reverse();
+        }
+
+        Ok(certificates)
+    }
+
+    /// Return the most recent certificate of the given signed entity type whose beacon matches
+    /// the given filter.
+    pub async fn get_latest_certificate_by_signed_entity_type<T>(
+        &self,
+        signed_entity_type: SignedEntityTypeDiscriminants,
+        beacon_filter: &BeaconFilter,
+    ) -> StdResult<Option<T>>
+    where
+        T: From<CertificateRecord>,
+    {
+        let record =
+            self.connection
+                .fetch_first(GetCertificateRecordQuery::by_signed_entity_type(
+                    signed_entity_type,
+                    beacon_filter,
+                )?)?;
+
+        Ok(record.map(|c| c.into()))
+    }
+
+    /// Return the certificates of the given epoch, from the most recent to the oldest.
+    pub async fn get_certificates_by_epoch<T>(&self, epoch: Epoch) -> StdResult<Vec<T>>
+    where
+        T: From<CertificateRecord>,
+    {
+        let cursor = self
+            .connection
+            .fetch(GetCertificateRecordQuery::by_epoch(epoch)?)?;
+
+        Ok(cursor.map(|v| v.into()).collect())
+    }
+
+    /// Return the certificate with the given hash followed by its ancestors, up to the first
+    /// genesis certificate or until `max_depth` certificates are returned.
+    pub async fn get_certificate_chain<T>(&self, hash: &str, max_depth: usize) -> StdResult<Vec<T>>
+    where
+        T: From<CertificateRecord>,
+    {
+        let cursor = self
+            .connection
+            .fetch(GetCertificateChainQuery::from_certificate_id(
+                hash, max_depth,
+            ))?;
+
+        Ok(cursor.map(|v| v.into()).collect())
+    }
+
     /// Return the first certificate signed per epoch as the reference
     /// certificate for this Epoch. This will be the parent certificate for all
     /// other certificates issued within this Epoch.
@@ -129,7 +199,11 @@ impl CertificateRetriever for CertificateRepository {
 mod tests {
     use mithril_common::crypto_helper::tests_setup::setup_certificate_chain;
 
-    use crate::database::test_helper::{insert_certificate_records, main_db_connection};
+    use mithril_common::entities::{BlockNumber, SignedEntityType};
+
+    use crate::database::test_helper::{
+        insert_certificate_records, main_db_connection, main_db_connection_with_foreign_keys,
+    };
 
     use super::*;
 
@@ -270,6 +344,152 @@ mod tests {
         assert_eq!(expected, latest_certificates);
     }
 
+    #[tokio::test]
+    async fn repository_get_certificates_with_filter() {
+        let (certificates, _) = setup_certificate_chain(10, 2);
+        let connection = Arc::new(main_db_connection().unwrap());
+        insert_certificate_records(&connection, certificates.clone());
+        let repository = CertificateRepository::new(connection);
+        let latest_certificates: Vec<Certificate> = certificates.into_iter().rev().collect();
+
+        let certificates: Vec<Certificate> = repository
+            .get_certificates_with_filter(
+                &ListFilter::default().before(&latest_certificates[2].hash),
+                3,
+            )
+            .await
+            .unwrap();
+        assert_eq!(latest_certificates[3..6].to_vec(), certificates);
+
+        let certificates: Vec<Certificate> = repository
+            .get_certificates_with_filter(
+                &ListFilter::default().after(&latest_certificates[6].hash),
+                3,
+            )
+            .await
+            .unwrap();
+        assert_eq!(latest_certificates[3..6].to_vec(), certificates);
+
+        let epoch = latest_certificates[4].epoch;
+        let certificates: Vec<Certificate> = repository
+            .get_certificates_with_filter(
+                &ListFilter::default().from_epoch(epoch).to_epoch(epoch),
+                latest_certificates.len(),
+            )
+            .await
+            .unwrap();
+        let expected: Vec<Certificate> = latest_certificates
+            .iter()
+            .filter(|c| c.epoch == epoch)
+            .cloned()
+            .collect();
+        assert!(!expected.is_empty());
+        assert_eq!(expected, certificates);
+
+        let certificates: Vec<Certificate> = repository
+            .get_certificates_with_filter(
+                &ListFilter::default().before("unknown-hash"),
+                latest_certificates.len(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(Vec::<Certificate>::new(), certificates);
+    }
+
+    #[tokio::test]
+    async fn repository_get_latest_certificate_by_signed_entity_type() {
+        let connection = Arc::new(main_db_connection().unwrap());
+        insert_certificate_records(
+            &connection,
+            vec![
+                CertificateRecord::dummy_genesis("1", Epoch(1)),
+                CertificateRecord::dummy_db_snapshot("2", "1", Epoch(1), 2),
+                CertificateRecord::dummy_db_snapshot("3", "1", Epoch(1), 3),
+            ],
+        );
+        let repository = CertificateRepository::new(connection);
+
+        let certificate: Option<CertificateRecord> = repository
+            .get_latest_certificate_by_signed_entity_type(
+                SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
+                &BeaconFilter::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(Some("3".to_string()), certificate.map(|c| c.certificate_id));
+
+        let certificate: Option<CertificateRecord> = repository
+            .get_latest_certificate_by_signed_entity_type(
+                SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
+                &BeaconFilter::default().immutable_file_number(2),
+            )
+            .await
+            .unwrap();
+        assert_eq!(Some("2".to_string()), certificate.map(|c| c.certificate_id));
+
+        let certificate: Option<CertificateRecord> = repository
+            .get_latest_certificate_by_signed_entity_type(
+                SignedEntityTypeDiscriminants::CardanoTransactions,
+                &BeaconFilter::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(None, certificate);
+    }
+
+    #[tokio::test]
+    async fn repository_get_certificates_by_epoch() {
+        let (certificates, _) = setup_certificate_chain(10, 3);
+        let connection = Arc::new(main_db_connection().unwrap());
+        insert_certificate_records(&connection, certificates.clone());
+        let repository = CertificateRepository::new(connection);
+
+        let epoch = certificates[4].epoch;
+        let epoch_certificates: Vec<Certificate> =
+            repository.get_certificates_by_epoch(epoch).await.unwrap();
+        let expected: Vec<Certificate> = certificates
+            .into_iter()
+            .rev()
+            .filter(|c| c.epoch == epoch)
+            .collect();
+        assert!(!expected.is_empty());
+        assert_eq!(expected, epoch_certificates);
+
+        let epoch_certificates: Vec<Certificate> = repository
+            .get_certificates_by_epoch(Epoch(999))
+            .await
+            .unwrap();
+        assert_eq!(Vec::<Certificate>::new(), epoch_certificates);
+    }
+
+    #[tokio::test]
+    async fn repository_get_certificate_chain() {
+        let (certificates, _) = setup_certificate_chain(10, 3);
+        let connection = Arc::new(main_db_connection().unwrap());
+        insert_certificate_records(&connection, certificates.clone());
+        let repository = CertificateRepository::new(connection);
+        let last_certificate = certificates.first().unwrap();
+
+        let chain: Vec<Certificate> = repository
+            .get_certificate_chain(&last_certificate.hash, 2)
+            .await
+            .unwrap();
+        let parent_certificate = certificates
+            .iter()
+            .find(|c| c.hash == last_certificate.previous_hash)
+            .unwrap();
+        assert_eq!(
+            vec![last_certificate.clone(), parent_certificate.clone()],
+            chain
+        );
+
+        let chain: Vec<Certificate> = repository
+            .get_certificate_chain("unknown", 2)
+            .await
+            .unwrap();
+        assert_eq!(Vec::<Certificate>::new(), chain);
+    }
+
     #[tokio::test]
     async fn get_master_certificate_no_certificate_recorded_returns_none() {
         let connection = Arc::new(main_db_connection().unwrap());
@@ -523,6 +743,34 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn save_cardano_blocks_certificate_with_foreign_keys_enabled() {
+        let connection = Arc::new(main_db_connection_with_foreign_keys().unwrap());
+        let repository = CertificateRepository::new(connection.clone());
+        let genesis_certificate = CertificateRecord::dummy_genesis("1", Epoch(1));
+        let cardano_blocks_certificate = CertificateRecord::dummy(
+            "2",
+            "1",
+            Epoch(1),
+            SignedEntityType::CardanoBlocks(Epoch(1), BlockNumber(120)),
+        );
+
+        repository
+            .create_certificate(genesis_certificate.into())
+            .await
+            .unwrap();
+        repository
+            .create_certificate(cardano_blocks_certificate.clone().into())
+            .await
+            .unwrap();
+
+        let certificate: Option<CertificateRecord> = repository.get_certificate("2").await.unwrap();
+        assert_eq!(
+            Some(cardano_blocks_certificate.signed_entity_type),
+            certificate.map(|c| c.signed_entity_type)
+        );
+    }
+
     #[tokio::test]
     async fn delete_only_given_certificates() {
         let connection = Arc::new(main_db_connection().unwrap());
@@ -1,5 +1,6 @@
 //! Aggregator related database repositories
 mod buffered_single_signature_repository;
+mod cardano_block_repository;
 mod cardano_transaction_repository;
 mod certificate_repository;
 mod epoch_settings_store;
@@ -109,7 +109,7 @@ mod tests {
     use crate::database::record::SingleSignatureRecord;
     use crate::database::test_helper::{
         insert_epoch_settings, insert_single_signatures_in_db, main_db_connection,
-        setup_single_signature_records,
+        main_db_connection_with_foreign_keys, setup_single_signature_records,
     };
 
     use super::*;
@@ -263,6 +263,21 @@ mod tests {
         assert_eq!(open_message.epoch, message.epoch);
     }
 
+    #[tokio::test]
+    async fn repository_create_open_message_for_cardano_blocks_with_foreign_keys_enabled() {
+        let connection = main_db_connection_with_foreign_keys().unwrap();
+        insert_epoch_settings(&connection, &[1]).unwrap();
+        let repository = OpenMessageRepository::new(Arc::new(connection));
+        let signed_entity_type = SignedEntityType::CardanoBlocks(Epoch(1), BlockNumber(120));
+
+        let open_message = repository
+            .create_open_message(Epoch(1), &signed_entity_type, &ProtocolMessage::new())
+            .await
+            .unwrap();
+
+        assert_eq!(signed_entity_type, open_message.signed_entity_type);
+    }
+
     #[tokio::test]
     async fn repository_update_open_message() {
         let connection = get_connection().await;
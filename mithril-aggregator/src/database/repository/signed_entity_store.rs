@@ -3,7 +3,7 @@ use std::sync::Arc;
 use anyhow::Context;
 use async_trait::async_trait;
 
-use mithril_common::entities::{Epoch, SignedEntityTypeDiscriminants};
+use mithril_common::entities::{Epoch, ListFilter, SignedEntityTypeDiscriminants};
 use mithril_common::StdResult;
 use mithril_persistence::sqlite::{ConnectionExtensions, SqliteConnection};
 
@@ -44,6 +44,15 @@ pub trait SignedEntityStorer: Sync + Send {
         total: usize,
     ) -> StdResult<Vec<SignedEntityRecord>>;
 
+    /// Get signed entities by signed entity type matching the given cursor and filters, from the
+    /// most recent to the oldest
+    async fn get_signed_entities_by_type_with_filter(
+        &self,
+        signed_entity_type_id: &SignedEntityTypeDiscriminants,
+        filter: &ListFilter,
+        total: usize,
+    ) -> StdResult<Vec<SignedEntityRecord>>;
+
     /// Get Cardano stake distribution signed entity by epoch
     async fn get_cardano_stake_distribution_signed_entity_by_epoch(
         &self,
@@ -133,6 +142,33 @@ impl SignedEntityStorer for SignedEntityStore {
         Ok(signed_entities)
     }
 
+    async fn get_signed_entities_by_type_with_filter(
+        &self,
+        signed_entity_type_id: &SignedEntityTypeDiscriminants,
+        filter: &ListFilter,
+        total: usize,
+    ) -> StdResult<Vec<SignedEntityRecord>> {
+        let cursor = self
+            .connection
+            .fetch(
+                GetSignedEntityRecordQuery::by_signed_entity_type_with_filter(
+                    signed_entity_type_id,
+                    filter,
+                )?,
+            )
+            .with_context(|| {
+                format!(
+                    "get signed entities by type with filter failure, type: {signed_entity_type_id:?}, filter: {filter:?}"
+                )
+            })?;
+        let mut signed_entities: Vec<SignedEntityRecord> = cursor.take(total).collect();
+        if filter.after.is_some() {
+            signed_entities.reverse();
+        }
+
+        Ok(signed_entities)
+    }
+
     async fn get_cardano_stake_distribution_signed_entity_by_epoch(
         &self,
         epoch: Epoch,
@@ -165,12 +201,14 @@ impl SignedEntityStorer for SignedEntityStore {
 #[cfg(test)]
 mod tests {
     use mithril_common::{
-        entities::{Epoch, MithrilStakeDistribution, Snapshot},
+        entities::{Certificate, Epoch, MithrilStakeDistribution, Snapshot},
         signable_builder::SignedEntity,
         test_utils::fake_data,
     };
 
-    use crate::database::test_helper::{insert_signed_entities, main_db_connection};
+    use crate::database::test_helper::{
+        insert_certificate_records, insert_signed_entities, main_db_connection,
+    };
 
     use super::*;
 
@@ -292,6 +330,87 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn get_signed_entities_by_type_with_filter() {
+        let records = SignedEntityRecord::fake_records(6);
+        let connection = main_db_connection().unwrap();
+        insert_signed_entities(&connection, records.clone()).unwrap();
+        let store = SignedEntityStore::new(Arc::new(connection));
+        let latest_records: Vec<SignedEntityRecord> = records.into_iter().rev().collect();
+        let signed_entity_type = SignedEntityTypeDiscriminants::CardanoImmutableFilesFull;
+
+        let queried_records = store
+            .get_signed_entities_by_type_with_filter(
+                &signed_entity_type,
+                &ListFilter::default().before(&latest_records[1].certificate_id),
+                2,
+            )
+            .await
+            .unwrap();
+        assert_eq!(latest_records[2..4].to_vec(), queried_records);
+
+        let queried_records = store
+            .get_signed_entities_by_type_with_filter(
+                &signed_entity_type,
+                &ListFilter::default().after(&latest_records[4].signed_entity_id),
+                2,
+            )
+            .await
+            .unwrap();
+        assert_eq!(latest_records[2..4].to_vec(), queried_records);
+
+        let queried_records = store
+            .get_signed_entities_by_type_with_filter(
+                &SignedEntityTypeDiscriminants::MithrilStakeDistribution,
+                &ListFilter::default(),
+                10,
+            )
+            .await
+            .unwrap();
+        assert_eq!(Vec::<SignedEntityRecord>::new(), queried_records);
+    }
+
+    #[tokio::test]
+    async fn get_signed_entities_by_type_with_epoch_filter() {
+        let certificates = [
+            ("certificate-1", Epoch(2)),
+            ("certificate-2", Epoch(3)),
+            ("certificate-3", Epoch(4)),
+        ]
+        .map(|(hash, epoch)| Certificate {
+            epoch,
+            ..fake_data::certificate(hash)
+        });
+        let records: Vec<SignedEntityRecord> = SignedEntityRecord::fake_records(3)
+            .into_iter()
+            .zip(certificates.iter())
+            .map(|(record, certificate)| SignedEntityRecord {
+                certificate_id: certificate.hash.clone(),
+                ..record
+            })
+            .collect();
+        let connection = main_db_connection().unwrap();
+        insert_certificate_records(&connection, certificates.to_vec());
+        insert_signed_entities(&connection, records.clone()).unwrap();
+        let store = SignedEntityStore::new(Arc::new(connection));
+
+        let queried_records = store
+            .get_signed_entities_by_type_with_filter(
+                &SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
+                &ListFilter::default()
+                    .from_epoch(Epoch(3))
+                    .to_epoch(Epoch(4)),
+                10,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            vec![records[2].clone(), records[1].clone()],
+            queried_records
+        );
+    }
+
     #[tokio::test]
     async fn update_only_given_entities() {
         let mut signed_entity_records = SignedEntityRecord::fake_records(5);
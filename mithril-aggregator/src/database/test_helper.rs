@@ -38,6 +38,15 @@ pub fn main_db_file_connection(db_path: &Path) -> StdResult<SqliteConnection> {
     build_main_db_connection(builder)
 }
 
+/// In-memory sqlite database with foreign key support and migrations applied
+pub fn main_db_connection_with_foreign_keys() -> StdResult<SqliteConnection> {
+    let connection = ConnectionBuilder::open_memory()
+        .with_options(&[ConnectionOptions::EnableForeignKeys])
+        .with_migrations(crate::database::migration::get_migrations())
+        .build()?;
+    Ok(connection)
+}
+
 fn build_main_db_connection(connection_builder: ConnectionBuilder) -> StdResult<SqliteConnection> {
     let connection = connection_builder
         .with_options(&[ConnectionOptions::ForceDisableForeignKeys])
@@ -141,10 +141,12 @@ impl DependenciesBuilder {
     pub async fn create_cardano_transactions_preloader(
         &mut self,
     ) -> Result<Arc<CardanoTransactionsPreloader>> {
-        let activation = self
+        let allowed_discriminants = self
             .configuration
-            .compute_allowed_signed_entity_types_discriminants()?
-            .contains(&SignedEntityTypeDiscriminants::CardanoTransactions);
+            .compute_allowed_signed_entity_types_discriminants()?;
+        let activation = allowed_discriminants
+            .contains(&SignedEntityTypeDiscriminants::CardanoTransactions)
+            || allowed_discriminants.contains(&SignedEntityTypeDiscriminants::CardanoBlocks);
         let cardano_transactions_preloader = CardanoTransactionsPreloader::new(
             self.get_signed_entity_lock().await?,
             self.get_transactions_importer().await?,
@@ -198,6 +200,11 @@ mod tests {
             true,
         )
         .await;
+        assert_cardano_transactions_preloader_activation(
+            SignedEntityTypeDiscriminants::CardanoBlocks.to_string(),
+            true,
+        )
+        .await;
         assert_cardano_transactions_preloader_activation(
             SignedEntityTypeDiscriminants::MithrilStakeDistribution.to_string(),
             false,
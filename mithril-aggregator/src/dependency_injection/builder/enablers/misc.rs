@@ -10,7 +10,10 @@ use mithril_signed_entity_lock::SignedEntityTypeLock;
 
 use crate::database::repository::CertificateRepository;
 use crate::dependency_injection::{DependenciesBuilder, Result};
-use crate::services::{MessageService, MithrilMessageService};
+use crate::services::{
+    AggregatorEventBroadcaster, MessageService, MithrilMessageService,
+    DEFAULT_EVENTS_HISTORY_CAPACITY,
+};
 
 impl DependenciesBuilder {
     async fn build_signed_entity_lock(&mut self) -> Result<Arc<SignedEntityTypeLock>> {
@@ -51,4 +54,25 @@ impl DependenciesBuilder {
 
         Ok(self.message_service.as_ref().cloned().unwrap())
     }
+
+    async fn build_aggregator_event_broadcaster(
+        &mut self,
+    ) -> Result<Arc<AggregatorEventBroadcaster>> {
+        let broadcaster =
+            AggregatorEventBroadcaster::new(DEFAULT_EVENTS_HISTORY_CAPACITY, self.root_logger());
+
+        Ok(Arc::new(broadcaster))
+    }
+
+    /// [AggregatorEventBroadcaster] service
+    pub async fn get_aggregator_event_broadcaster(
+        &mut self,
+    ) -> Result<Arc<AggregatorEventBroadcaster>> {
+        if self.aggregator_event_broadcaster.is_none() {
+            self.aggregator_event_broadcaster =
+                Some(self.build_aggregator_event_broadcaster().await?);
+        }
+
+        Ok(self.aggregator_event_broadcaster.as_ref().cloned().unwrap())
+    }
 }
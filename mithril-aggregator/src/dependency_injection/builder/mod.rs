@@ -29,7 +29,7 @@ use mithril_common::{
     TickerService,
 };
 use mithril_persistence::{
-    database::repository::CardanoTransactionRepository,
+    database::repository::{CardanoBlockRepository, CardanoTransactionRepository},
     sqlite::{SqliteConnection, SqliteConnectionPool},
 };
 use mithril_signed_entity_lock::SignedEntityTypeLock;
@@ -41,11 +41,11 @@ use crate::{
         SignerStore, StakePoolStore,
     },
     event_store::{EventMessage, TransmitterService},
-    file_uploaders::FileUploader,
+    file_uploaders::MultiFileUploader,
     http_server::routes::router::{self, RouterConfig, RouterState},
     services::{
-        CertifierService, MessageService, ProverService, SignedEntityService, Snapshotter,
-        StakeDistributionService, UpkeepService,
+        AggregatorEventBroadcaster, BlocksProverService, CertifierService, MessageService,
+        ProverService, SignedEntityService, Snapshotter, StakeDistributionService, UpkeepService,
     },
     store::CertificatePendingStorer,
     tools::GenesisToolsDependency,
@@ -92,7 +92,7 @@ pub struct DependenciesBuilder {
     pub stake_store: Option<Arc<StakePoolStore>>,
 
     /// Snapshot uploader service.
-    pub snapshot_uploader: Option<Arc<dyn FileUploader>>,
+    pub snapshot_uploader: Option<Arc<MultiFileUploader>>,
 
     /// Multisigner service.
     pub multi_signer: Option<Arc<dyn MultiSigner>>,
@@ -124,6 +124,9 @@ pub struct DependenciesBuilder {
     /// Cardano transactions repository.
     pub transaction_repository: Option<Arc<CardanoTransactionRepository>>,
 
+    /// Cardano blocks repository.
+    pub block_repository: Option<Arc<CardanoBlockRepository>>,
+
     /// Cardano block scanner.
     pub block_scanner: Option<Arc<dyn BlockScanner>>,
 
@@ -208,6 +211,9 @@ pub struct DependenciesBuilder {
     /// Prover service
     pub prover_service: Option<Arc<dyn ProverService>>,
 
+    /// Blocks prover service
+    pub blocks_prover_service: Option<Arc<dyn BlocksProverService>>,
+
     /// Signed Entity Type Lock
     pub signed_entity_type_lock: Option<Arc<SignedEntityTypeLock>>,
 
@@ -222,6 +228,9 @@ pub struct DependenciesBuilder {
 
     /// Metrics service
     pub metrics_service: Option<Arc<MetricsService>>,
+
+    /// Aggregator events broadcaster
+    pub aggregator_event_broadcaster: Option<Arc<AggregatorEventBroadcaster>>,
 }
 
 impl DependenciesBuilder {
@@ -246,6 +255,7 @@ impl DependenciesBuilder {
             chain_block_reader: None,
             block_scanner: None,
             transaction_repository: None,
+            block_repository: None,
             immutable_digester: None,
             immutable_file_observer: None,
             immutable_cache_provider: None,
@@ -272,11 +282,13 @@ impl DependenciesBuilder {
             signed_entity_storer: None,
             message_service: None,
             prover_service: None,
+            blocks_prover_service: None,
             signed_entity_type_lock: None,
             transactions_importer: None,
             upkeep_service: None,
             single_signer_authenticator: None,
             metrics_service: None,
+            aggregator_event_broadcaster: None,
         }
     }
 
@@ -341,10 +353,12 @@ impl DependenciesBuilder {
             block_scanner: self.get_block_scanner().await?,
             transaction_store: self.get_transaction_repository().await?,
             prover_service: self.get_prover_service().await?,
+            blocks_prover_service: self.get_blocks_prover_service().await?,
             signed_entity_type_lock: self.get_signed_entity_lock().await?,
             upkeep_service: self.get_upkeep_service().await?,
             single_signer_authenticator: self.get_single_signature_authenticator().await?,
             metrics_service: self.get_metrics_service().await?,
+            aggregator_event_broadcaster: self.get_aggregator_event_broadcaster().await?,
         };
 
         Ok(dependency_manager)
@@ -394,7 +408,7 @@ impl DependenciesBuilder {
                 cardano_db_artifacts_directory: self.get_cardano_db_artifacts_dir()?,
                 snapshot_directory: snapshot_dir.join(SNAPSHOT_ARTIFACTS_DIR),
                 cardano_node_version: self.configuration.cardano_node_version.clone(),
-                allow_http_serve_directory: self.configuration.allow_http_serve_directory(),
+                allow_http_serve_directory: self.configuration.allow_http_serve_directory()?,
             },
         );
 
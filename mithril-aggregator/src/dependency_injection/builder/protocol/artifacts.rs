@@ -4,15 +4,18 @@ use std::sync::Arc;
 use mithril_common::entities::CompressionAlgorithm;
 
 use crate::artifact_builder::{
-    AncillaryArtifactBuilder, AncillaryFileUploader, CardanoDatabaseArtifactBuilder,
-    CardanoImmutableFilesFullArtifactBuilder, CardanoStakeDistributionArtifactBuilder,
-    CardanoTransactionsArtifactBuilder, DigestArtifactBuilder, DigestFileUploader,
-    ImmutableArtifactBuilder, ImmutableFilesUploader, MithrilStakeDistributionArtifactBuilder,
+    AncillaryArtifactBuilder, AncillaryFileUploader, CardanoBlocksArtifactBuilder,
+    CardanoDatabaseArtifactBuilder, CardanoImmutableFilesFullArtifactBuilder,
+    CardanoStakeDistributionArtifactBuilder, CardanoTransactionsArtifactBuilder,
+    DigestArtifactBuilder, DigestFileUploader, ImmutableArtifactBuilder, ImmutableFilesUploader,
+    MithrilStakeDistributionArtifactBuilder,
 };
 use crate::dependency_injection::builder::SNAPSHOT_ARTIFACTS_DIR;
 use crate::dependency_injection::{DependenciesBuilder, DependenciesBuilderError, Result};
 use crate::file_uploaders::{
-    CloudRemotePath, FileUploadRetryPolicy, GcpBackendUploader, GcpUploader, LocalUploader,
+    CloudBackendUploader, CloudRemotePath, CloudUploader, FileUploadRetryPolicy,
+    GcpBackendUploader, LocalUploader, MultiFileUploader, S3BackendUploader,
+    S3BackendUploaderConfiguration,
 };
 use crate::http_server::CARDANO_DATABASE_DOWNLOAD_PATH;
 use crate::services::{
@@ -20,7 +23,8 @@ use crate::services::{
     SignedEntityServiceArtifactsDependencies, Snapshotter, SnapshotterCompressionAlgorithm,
 };
 use crate::{
-    DumbUploader, ExecutionEnvironment, FileUploader, LocalSnapshotUploader, SnapshotUploaderType,
+    DumbUploader, ExecutionEnvironment, FileUploader, LocalSnapshotUploader,
+    SnapshotUploaderDestination, SnapshotUploaderType,
 };
 
 impl DependenciesBuilder {
@@ -55,12 +59,17 @@ impl DependenciesBuilder {
             self.build_cardano_database_artifact_builder(cardano_node_version)
                 .await?,
         );
+        let blocks_prover_service = self.get_blocks_prover_service().await?;
+        let cardano_blocks_artifact_builder = Arc::new(CardanoBlocksArtifactBuilder::new(
+            blocks_prover_service.clone(),
+        ));
         let dependencies = SignedEntityServiceArtifactsDependencies::new(
             mithril_stake_distribution_artifact_builder,
             cardano_immutable_files_full_artifact_builder,
             cardano_transactions_artifact_builder,
             cardano_stake_distribution_artifact_builder,
             cardano_database_artifact_builder,
+            cardano_blocks_artifact_builder,
         );
         let signed_entity_service = Arc::new(MithrilSignedEntityService::new(
             signed_entity_storer,
@@ -81,6 +90,14 @@ impl DependenciesBuilder {
                 .compute_cache(signed_entity.artifact.block_number)
                 .await?;
         }
+        if let Some(signed_entity) = signed_entity_service
+            .get_last_cardano_blocks_snapshot()
+            .await?
+        {
+            blocks_prover_service
+                .compute_cache(signed_entity.artifact.block_number)
+                .await?;
+        }
 
         Ok(signed_entity_service)
     }
@@ -133,46 +150,63 @@ impl DependenciesBuilder {
         Ok(self.snapshotter.as_ref().cloned().unwrap())
     }
 
-    async fn build_snapshot_uploader(&mut self) -> Result<Arc<dyn FileUploader>> {
-        let logger = self.root_logger();
-        if self.configuration.environment == ExecutionEnvironment::Production {
-            match self.configuration.snapshot_uploader_type {
-                SnapshotUploaderType::Gcp => {
-                    let allow_overwrite = true;
-                    let remote_folder_path = CloudRemotePath::new("cardano-immutable-files-full");
-
-                    Ok(Arc::new(
-                        self.build_gcp_uploader(remote_folder_path, allow_overwrite)?,
-                    ))
-                }
-                SnapshotUploaderType::Local => {
-                    let snapshot_artifacts_dir = self
-                        .configuration
-                        .get_snapshot_dir()?
-                        .join(SNAPSHOT_ARTIFACTS_DIR);
-                    std::fs::create_dir_all(&snapshot_artifacts_dir).map_err(|e| {
-                        DependenciesBuilderError::Initialization {
-                            message: format!(
-                                "Cannot create '{snapshot_artifacts_dir:?}' directory."
-                            ),
-                            error: Some(e.into()),
-                        }
-                    })?;
-
-                    Ok(Arc::new(LocalSnapshotUploader::new(
-                        self.configuration.get_server_url()?,
-                        &snapshot_artifacts_dir,
-                        logger,
-                    )))
-                }
+    async fn build_snapshot_uploader(&mut self) -> Result<Arc<MultiFileUploader>> {
+        let uploaders: Vec<Arc<dyn FileUploader>> =
+            if self.configuration.environment == ExecutionEnvironment::Production {
+                self.configuration
+                    .get_snapshot_uploader_destinations()?
+                    .iter()
+                    .map(|destination| self.build_snapshot_uploader_for_destination(destination))
+                    .collect::<Result<_>>()?
+            } else {
+                vec![Arc::new(DumbUploader::new(FileUploadRetryPolicy::never()))]
+            };
+
+        Ok(Arc::new(MultiFileUploader::new(
+            uploaders,
+            self.configuration.snapshot_upload_failure_policy,
+            self.root_logger(),
+        )?))
+    }
+
+    fn build_snapshot_uploader_for_destination(
+        &self,
+        destination: &SnapshotUploaderDestination,
+    ) -> Result<Arc<dyn FileUploader>> {
+        match destination.uploader_type {
+            SnapshotUploaderType::Gcp | SnapshotUploaderType::S3 => {
+                let allow_overwrite = true;
+                let remote_folder_path = CloudRemotePath::new("cardano-immutable-files-full");
+
+                Ok(Arc::new(self.build_cloud_uploader(
+                    destination,
+                    remote_folder_path,
+                    allow_overwrite,
+                )?))
+            }
+            SnapshotUploaderType::Local => {
+                let snapshot_artifacts_dir = self
+                    .configuration
+                    .get_snapshot_dir()?
+                    .join(SNAPSHOT_ARTIFACTS_DIR);
+                std::fs::create_dir_all(&snapshot_artifacts_dir).map_err(|e| {
+                    DependenciesBuilderError::Initialization {
+                        message: format!("Cannot create '{snapshot_artifacts_dir:?}' directory."),
+                        error: Some(e.into()),
+                    }
+                })?;
+
+                Ok(Arc::new(LocalSnapshotUploader::new(
+                    self.configuration.get_server_url()?,
+                    &snapshot_artifacts_dir,
+                    self.root_logger(),
+                )))
             }
-        } else {
-            Ok(Arc::new(DumbUploader::new(FileUploadRetryPolicy::never())))
         }
     }
 
-    /// Get a [FileUploader]
-    pub async fn get_snapshot_uploader(&mut self) -> Result<Arc<dyn FileUploader>> {
+    /// Get a [MultiFileUploader] that uploads the snapshots to all the configured destinations
+    pub async fn get_snapshot_uploader(&mut self) -> Result<Arc<MultiFileUploader>> {
         if self.snapshot_uploader.is_none() {
             self.snapshot_uploader = Some(self.build_snapshot_uploader().await?);
         }
@@ -180,69 +214,61 @@ impl DependenciesBuilder {
         Ok(self.snapshot_uploader.as_ref().cloned().unwrap())
     }
 
-    fn build_gcp_uploader(
+    fn build_cloud_uploader(
         &self,
+        destination: &SnapshotUploaderDestination,
         remote_folder_path: CloudRemotePath,
         allow_overwrite: bool,
-    ) -> Result<GcpUploader> {
+    ) -> Result<CloudUploader> {
         let logger = self.root_logger();
-        let bucket = self
-            .configuration
-            .snapshot_bucket_name
-            .to_owned()
-            .ok_or_else(|| {
-                DependenciesBuilderError::MissingConfiguration("snapshot_bucket_name".to_string())
-            })?;
-
-        Ok(GcpUploader::new(
-            Arc::new(GcpBackendUploader::try_new(
+        let bucket = destination.bucket_name.to_owned().ok_or_else(|| {
+            DependenciesBuilderError::MissingConfiguration("snapshot_bucket_name".to_string())
+        })?;
+        let cloud_backend_uploader: Arc<dyn CloudBackendUploader> = match destination.uploader_type
+        {
+            SnapshotUploaderType::S3 => {
+                let endpoint = destination.s3_endpoint.to_owned().ok_or_else(|| {
+                    DependenciesBuilderError::MissingConfiguration(
+                        "snapshot_s3_endpoint".to_string(),
+                    )
+                })?;
+
+                Arc::new(S3BackendUploader::try_new(
+                    S3BackendUploaderConfiguration {
+                        endpoint,
+                        region: destination.s3_region.clone(),
+                        bucket,
+                        prefix: destination.s3_prefix.clone(),
+                        use_path_style: destination.s3_path_style,
+                        public_url_template: destination.s3_public_url_template.clone(),
+                    },
+                    logger.clone(),
+                )?)
+            }
+            _ => Arc::new(GcpBackendUploader::try_new(
                 bucket,
-                self.configuration.snapshot_use_cdn_domain,
+                destination.use_cdn_domain,
                 logger.clone(),
             )?),
+        };
+
+        Ok(CloudUploader::new(
+            cloud_backend_uploader,
             remote_folder_path,
             allow_overwrite,
-            FileUploadRetryPolicy::default(),
+            self.configuration.get_file_upload_retry_policy(),
         ))
     }
 
     fn build_cardano_database_ancillary_uploaders(
         &self,
     ) -> Result<Vec<Arc<dyn AncillaryFileUploader>>> {
-        let logger = self.root_logger();
         if self.configuration.environment == ExecutionEnvironment::Production {
-            match self.configuration.snapshot_uploader_type {
-                SnapshotUploaderType::Gcp => {
-                    let allow_overwrite = true;
-                    let remote_folder_path =
-                        CloudRemotePath::new("cardano-database").join("ancillary");
-
-                    Ok(vec![Arc::new(self.build_gcp_uploader(
-                        remote_folder_path,
-                        allow_overwrite,
-                    )?)])
-                }
-                SnapshotUploaderType::Local => {
-                    let server_url_prefix = self.configuration.get_server_url()?;
-                    let ancillary_url_prefix = server_url_prefix
-                        .sanitize_join(&format!("{CARDANO_DATABASE_DOWNLOAD_PATH}/ancillary/"))?;
-                    let target_dir = self.get_cardano_db_artifacts_dir()?.join("ancillary");
-
-                    std::fs::create_dir_all(&target_dir).map_err(|e| {
-                        DependenciesBuilderError::Initialization {
-                            message: format!("Cannot create '{target_dir:?}' directory."),
-                            error: Some(e.into()),
-                        }
-                    })?;
-
-                    Ok(vec![Arc::new(LocalUploader::new(
-                        ancillary_url_prefix,
-                        &target_dir,
-                        FileUploadRetryPolicy::default(),
-                        logger,
-                    ))])
-                }
-            }
+            self.configuration
+                .get_snapshot_uploader_destinations()?
+                .iter()
+                .map(|destination| self.build_cardano_database_ancillary_uploader(destination))
+                .collect()
         } else {
             Ok(vec![Arc::new(DumbUploader::new(
                 FileUploadRetryPolicy::never(),
@@ -250,34 +276,53 @@ impl DependenciesBuilder {
         }
     }
 
+    fn build_cardano_database_ancillary_uploader(
+        &self,
+        destination: &SnapshotUploaderDestination,
+    ) -> Result<Arc<dyn AncillaryFileUploader>> {
+        match destination.uploader_type {
+            SnapshotUploaderType::Gcp | SnapshotUploaderType::S3 => {
+                let allow_overwrite = true;
+                let remote_folder_path = CloudRemotePath::new("cardano-database").join("ancillary");
+
+                Ok(Arc::new(self.build_cloud_uploader(
+                    destination,
+                    remote_folder_path,
+                    allow_overwrite,
+                )?))
+            }
+            SnapshotUploaderType::Local => {
+                let server_url_prefix = self.configuration.get_server_url()?;
+                let ancillary_url_prefix = server_url_prefix
+                    .sanitize_join(&format!("{CARDANO_DATABASE_DOWNLOAD_PATH}/ancillary/"))?;
+                let target_dir = self.get_cardano_db_artifacts_dir()?.join("ancillary");
+
+                std::fs::create_dir_all(&target_dir).map_err(|e| {
+                    DependenciesBuilderError::Initialization {
+                        message: format!("Cannot create '{target_dir:?}' directory."),
+                        error: Some(e.into()),
+                    }
+                })?;
+
+                Ok(Arc::new(LocalUploader::new(
+                    ancillary_url_prefix,
+                    &target_dir,
+                    self.configuration.get_file_upload_retry_policy(),
+                    self.root_logger(),
+                )))
+            }
+        }
+    }
+
     fn build_cardano_database_immutable_uploaders(
         &self,
     ) -> Result<Vec<Arc<dyn ImmutableFilesUploader>>> {
-        let logger = self.root_logger();
         if self.configuration.environment == ExecutionEnvironment::Production {
-            match self.configuration.snapshot_uploader_type {
-                SnapshotUploaderType::Gcp => {
-                    let allow_overwrite = false;
-                    let remote_folder_path =
-                        CloudRemotePath::new("cardano-database").join("immutable");
-
-                    Ok(vec![Arc::new(self.build_gcp_uploader(
-                        remote_folder_path,
-                        allow_overwrite,
-                    )?)])
-                }
-                SnapshotUploaderType::Local => {
-                    let server_url_prefix = self.configuration.get_server_url()?;
-                    let immutable_url_prefix = server_url_prefix
-                        .sanitize_join(&format!("{CARDANO_DATABASE_DOWNLOAD_PATH}/immutable/"))?;
-
-                    Ok(vec![Arc::new(LocalUploader::new_without_copy(
-                        immutable_url_prefix,
-                        FileUploadRetryPolicy::default(),
-                        logger,
-                    ))])
-                }
-            }
+            self.configuration
+                .get_snapshot_uploader_destinations()?
+                .iter()
+                .map(|destination| self.build_cardano_database_immutable_uploader(destination))
+                .collect()
         } else {
             Ok(vec![Arc::new(DumbUploader::new(
                 FileUploadRetryPolicy::never(),
@@ -285,41 +330,42 @@ impl DependenciesBuilder {
         }
     }
 
+    fn build_cardano_database_immutable_uploader(
+        &self,
+        destination: &SnapshotUploaderDestination,
+    ) -> Result<Arc<dyn ImmutableFilesUploader>> {
+        match destination.uploader_type {
+            SnapshotUploaderType::Gcp | SnapshotUploaderType::S3 => {
+                let allow_overwrite = false;
+                let remote_folder_path = CloudRemotePath::new("cardano-database").join("immutable");
+
+                Ok(Arc::new(self.build_cloud_uploader(
+                    destination,
+                    remote_folder_path,
+                    allow_overwrite,
+                )?))
+            }
+            SnapshotUploaderType::Local => {
+                let server_url_prefix = self.configuration.get_server_url()?;
+                let immutable_url_prefix = server_url_prefix
+                    .sanitize_join(&format!("{CARDANO_DATABASE_DOWNLOAD_PATH}/immutable/"))?;
+
+                Ok(Arc::new(LocalUploader::new_without_copy(
+                    immutable_url_prefix,
+                    self.configuration.get_file_upload_retry_policy(),
+                    self.root_logger(),
+                )))
+            }
+        }
+    }
+
     fn build_cardano_database_digests_uploaders(&self) -> Result<Vec<Arc<dyn DigestFileUploader>>> {
-        let logger = self.root_logger();
         if self.configuration.environment == ExecutionEnvironment::Production {
-            match self.configuration.snapshot_uploader_type {
-                SnapshotUploaderType::Gcp => {
-                    let allow_overwrite = false;
-                    let remote_folder_path =
-                        CloudRemotePath::new("cardano-database").join("digests");
-
-                    Ok(vec![Arc::new(self.build_gcp_uploader(
-                        remote_folder_path,
-                        allow_overwrite,
-                    )?)])
-                }
-                SnapshotUploaderType::Local => {
-                    let server_url_prefix = self.configuration.get_server_url()?;
-                    let digests_url_prefix = server_url_prefix
-                        .sanitize_join(&format!("{CARDANO_DATABASE_DOWNLOAD_PATH}/digests/"))?;
-                    let target_dir = self.get_cardano_db_artifacts_dir()?.join("digests");
-
-                    std::fs::create_dir_all(&target_dir).map_err(|e| {
-                        DependenciesBuilderError::Initialization {
-                            message: format!("Cannot create '{target_dir:?}' directory."),
-                            error: Some(e.into()),
-                        }
-                    })?;
-
-                    Ok(vec![Arc::new(LocalUploader::new(
-                        digests_url_prefix,
-                        &target_dir,
-                        FileUploadRetryPolicy::default(),
-                        logger,
-                    ))])
-                }
-            }
+            self.configuration
+                .get_snapshot_uploader_destinations()?
+                .iter()
+                .map(|destination| self.build_cardano_database_digests_uploader(destination))
+                .collect()
         } else {
             Ok(vec![Arc::new(DumbUploader::new(
                 FileUploadRetryPolicy::never(),
@@ -327,6 +373,44 @@ impl DependenciesBuilder {
         }
     }
 
+    fn build_cardano_database_digests_uploader(
+        &self,
+        destination: &SnapshotUploaderDestination,
+    ) -> Result<Arc<dyn DigestFileUploader>> {
+        match destination.uploader_type {
+            SnapshotUploaderType::Gcp | SnapshotUploaderType::S3 => {
+                let allow_overwrite = false;
+                let remote_folder_path = CloudRemotePath::new("cardano-database").join("digests");
+
+                Ok(Arc::new(self.build_cloud_uploader(
+                    destination,
+                    remote_folder_path,
+                    allow_overwrite,
+                )?))
+            }
+            SnapshotUploaderType::Local => {
+                let server_url_prefix = self.configuration.get_server_url()?;
+                let digests_url_prefix = server_url_prefix
+                    .sanitize_join(&format!("{CARDANO_DATABASE_DOWNLOAD_PATH}/digests/"))?;
+                let target_dir = self.get_cardano_db_artifacts_dir()?.join("digests");
+
+                std::fs::create_dir_all(&target_dir).map_err(|e| {
+                    DependenciesBuilderError::Initialization {
+                        message: format!("Cannot create '{target_dir:?}' directory."),
+                        error: Some(e.into()),
+                    }
+                })?;
+
+                Ok(Arc::new(LocalUploader::new(
+                    digests_url_prefix,
+                    &target_dir,
+                    self.configuration.get_file_upload_retry_policy(),
+                    self.root_logger(),
+                )))
+            }
+        }
+    }
+
     async fn build_cardano_database_artifact_builder(
         &mut self,
         cardano_node_version: Version,
@@ -336,6 +420,7 @@ impl DependenciesBuilder {
 
         let ancillary_builder = Arc::new(AncillaryArtifactBuilder::new(
             self.build_cardano_database_ancillary_uploaders()?,
+            self.configuration.snapshot_upload_failure_policy,
             self.get_snapshotter().await?,
             self.configuration.get_network()?,
             self.configuration.snapshot_compression_algorithm,
@@ -345,6 +430,7 @@ impl DependenciesBuilder {
         let immutable_builder = Arc::new(ImmutableArtifactBuilder::new(
             immutable_dir,
             self.build_cardano_database_immutable_uploaders()?,
+            self.configuration.snapshot_upload_failure_policy,
             self.get_snapshotter().await?,
             self.configuration.snapshot_compression_algorithm,
             self.root_logger(),
@@ -3,7 +3,8 @@ use std::sync::Arc;
 
 use mithril_common::certificate_chain::{CertificateVerifier, MithrilCertificateVerifier};
 use mithril_common::crypto_helper::{
-    ProtocolGenesisSigner, ProtocolGenesisVerificationKey, ProtocolGenesisVerifier,
+    ProtocolGenesisSigner, ProtocolGenesisVerificationKey, ProtocolGenesisVerificationKeySet,
+    ProtocolGenesisVerifier,
 };
 
 use crate::database::repository::{BufferedSingleSignatureRepository, SingleSignatureRepository};
@@ -76,12 +77,16 @@ impl DependenciesBuilder {
     }
 
     async fn build_certificate_verifier(&mut self) -> Result<Arc<dyn CertificateVerifier>> {
-        let verifier = Arc::new(MithrilCertificateVerifier::new(
+        let mut verifier = MithrilCertificateVerifier::new(
             self.root_logger(),
             self.get_certificate_repository().await?,
-        ));
+        )
+        .with_batch_multi_signature_verification();
+        if let Some(genesis_verification_key_set) = self.get_genesis_verification_key_set()? {
+            verifier = verifier.with_genesis_verification_key_set(genesis_verification_key_set);
+        }
 
-        Ok(verifier)
+        Ok(Arc::new(verifier))
     }
 
     /// [CertificateVerifier] service.
@@ -110,10 +115,30 @@ impl DependenciesBuilder {
             _ => ProtocolGenesisSigner::create_deterministic_genesis_signer()
                 .create_genesis_verifier(),
         };
+        let genesis_verifier = match self.get_genesis_verification_key_set()? {
+            Some(genesis_verification_key_set) => {
+                genesis_verifier.with_verification_key_set(genesis_verification_key_set)
+            }
+            None => genesis_verifier,
+        };
 
         Ok(Arc::new(genesis_verifier))
     }
 
+    fn get_genesis_verification_key_set(
+        &self,
+    ) -> Result<Option<ProtocolGenesisVerificationKeySet>> {
+        self.configuration
+            .get_genesis_verification_key_set()
+            .map_err(|e| DependenciesBuilderError::Initialization {
+                message: format!(
+                    "Could not decode hex key set to build genesis verifier: '{:?}'",
+                    self.configuration.genesis_verification_key_set
+                ),
+                error: Some(e),
+            })
+    }
+
     /// Return a [ProtocolGenesisVerifier]
     pub async fn get_genesis_verifier(&mut self) -> Result<Arc<ProtocolGenesisVerifier>> {
         if self.genesis_verifier.is_none() {
@@ -3,7 +3,9 @@ use std::sync::Arc;
 use mithril_common::crypto_helper::MKTreeStoreInMemory;
 
 use crate::dependency_injection::{DependenciesBuilder, Result};
-use crate::services::{MithrilProverService, ProverService};
+use crate::services::{
+    BlocksProverService, MithrilBlocksProverService, MithrilProverService, ProverService,
+};
 
 impl DependenciesBuilder {
     /// Build Prover service
@@ -32,4 +34,31 @@ impl DependenciesBuilder {
 
         Ok(self.prover_service.as_ref().cloned().unwrap())
     }
+
+    /// Build Blocks Prover service
+    pub async fn build_blocks_prover_service(&mut self) -> Result<Arc<dyn BlocksProverService>> {
+        let mk_map_pool_size = self
+            .configuration
+            .cardano_transactions_prover_cache_pool_size;
+        let blocks_retriever = self.get_block_repository().await?;
+        let block_range_root_retriever = self.get_block_repository().await?;
+        let logger = self.root_logger();
+        let blocks_prover_service = MithrilBlocksProverService::<MKTreeStoreInMemory>::new(
+            blocks_retriever,
+            block_range_root_retriever,
+            mk_map_pool_size,
+            logger,
+        );
+
+        Ok(Arc::new(blocks_prover_service))
+    }
+
+    /// [BlocksProverService] service
+    pub async fn get_blocks_prover_service(&mut self) -> Result<Arc<dyn BlocksProverService>> {
+        if self.blocks_prover_service.is_none() {
+            self.blocks_prover_service = Some(self.build_blocks_prover_service().await?);
+        }
+
+        Ok(self.blocks_prover_service.as_ref().cloned().unwrap())
+    }
 }
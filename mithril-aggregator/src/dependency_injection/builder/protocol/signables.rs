@@ -2,9 +2,10 @@ use std::sync::Arc;
 
 use mithril_common::crypto_helper::MKTreeStoreInMemory;
 use mithril_common::signable_builder::{
-    CardanoDatabaseSignableBuilder, CardanoImmutableFilesFullSignableBuilder,
-    CardanoStakeDistributionSignableBuilder, CardanoTransactionsSignableBuilder,
-    MithrilSignableBuilderService, MithrilStakeDistributionSignableBuilder, SignableBuilderService,
+    CardanoBlocksSignableBuilder, CardanoDatabaseSignableBuilder,
+    CardanoImmutableFilesFullSignableBuilder, CardanoStakeDistributionSignableBuilder,
+    CardanoTransactionsSignableBuilder, MithrilSignableBuilderService,
+    MithrilStakeDistributionSignableBuilder, SignableBuilderService,
     SignableBuilderServiceDependencies, SignableSeedBuilder, TransactionsImporter,
 };
 
@@ -37,12 +38,18 @@ impl DependenciesBuilder {
             &self.configuration.db_directory,
             self.root_logger(),
         ));
+        let cardano_blocks_builder =
+            Arc::new(CardanoBlocksSignableBuilder::<MKTreeStoreInMemory>::new(
+                self.get_transactions_importer().await?,
+                self.get_block_repository().await?,
+            ));
         let signable_builders_dependencies = SignableBuilderServiceDependencies::new(
             mithril_stake_distribution_builder,
             immutable_signable_builder,
             cardano_transactions_builder,
             cardano_stake_distribution_builder,
             cardano_database_signable_builder,
+            cardano_blocks_builder,
         );
         let signable_builder_service = Arc::new(MithrilSignableBuilderService::new(
             seed_signable_builder,
@@ -82,11 +89,14 @@ impl DependenciesBuilder {
     }
 
     async fn build_transactions_importer(&mut self) -> Result<Arc<dyn TransactionsImporter>> {
-        let transactions_importer = Arc::new(CardanoTransactionsImporter::new(
-            self.get_block_scanner().await?,
-            self.get_transaction_repository().await?,
-            self.root_logger(),
-        ));
+        let transactions_importer = Arc::new(
+            CardanoTransactionsImporter::new(
+                self.get_block_scanner().await?,
+                self.get_transaction_repository().await?,
+                self.root_logger(),
+            )
+            .with_block_store(self.get_block_repository().await?),
+        );
 
         Ok(transactions_importer)
     }
@@ -4,7 +4,9 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use mithril_common::digesters::cache::ImmutableFileDigestCacheProvider;
-use mithril_persistence::database::repository::CardanoTransactionRepository;
+use mithril_persistence::database::repository::{
+    CardanoBlockRepository, CardanoTransactionRepository,
+};
 
 use crate::database::repository::{
     CertificatePendingRepository, CertificateRepository, EpochSettingsStore,
@@ -211,6 +213,24 @@ impl DependenciesBuilder {
         Ok(self.transaction_repository.as_ref().cloned().unwrap())
     }
 
+    async fn build_block_repository(&mut self) -> Result<Arc<CardanoBlockRepository>> {
+        let block_store = CardanoBlockRepository::new(
+            self.get_sqlite_connection_cardano_transaction_pool()
+                .await?,
+        );
+
+        Ok(Arc::new(block_store))
+    }
+
+    /// Block repository.
+    pub async fn get_block_repository(&mut self) -> Result<Arc<CardanoBlockRepository>> {
+        if self.block_repository.is_none() {
+            self.block_repository = Some(self.build_block_repository().await?);
+        }
+
+        Ok(self.block_repository.as_ref().cloned().unwrap())
+    }
+
     async fn build_immutable_file_digest_mapper(
         &mut self,
     ) -> Result<Arc<dyn ImmutableFileDigestMapper>> {
@@ -33,11 +33,12 @@ use crate::{
     },
     entities::AggregatorEpochSettings,
     event_store::{EventMessage, TransmitterService},
-    file_uploaders::FileUploader,
+    file_uploaders::MultiFileUploader,
     multi_signer::MultiSigner,
     services::{
-        CertifierService, EpochService, MessageService, ProverService, SignedEntityService,
-        Snapshotter, StakeDistributionService, TransactionStore, UpkeepService,
+        AggregatorEventBroadcaster, BlocksProverService, CertifierService, EpochService,
+        MessageService, ProverService, SignedEntityService, Snapshotter, StakeDistributionService,
+        TransactionStore, UpkeepService,
     },
     signer_registerer::SignerRecorder,
     store::CertificatePendingStorer,
@@ -72,7 +73,7 @@ pub struct DependencyContainer {
     pub stake_store: Arc<StakePoolStore>,
 
     /// Snapshot uploader service.
-    pub snapshot_uploader: Arc<dyn FileUploader>,
+    pub snapshot_uploader: Arc<MultiFileUploader>,
 
     /// Multisigner service.
     pub multi_signer: Arc<dyn MultiSigner>,
@@ -167,6 +168,9 @@ pub struct DependencyContainer {
     /// Prover service
     pub prover_service: Arc<dyn ProverService>,
 
+    /// Blocks prover service
+    pub blocks_prover_service: Arc<dyn BlocksProverService>,
+
     /// Signed Entity Type Lock
     pub signed_entity_type_lock: Arc<SignedEntityTypeLock>,
 
@@ -178,6 +182,9 @@ pub struct DependencyContainer {
 
     /// Metrics service
     pub metrics_service: Arc<MetricsService>,
+
+    /// Aggregator events broadcaster
+    pub aggregator_event_broadcaster: Arc<AggregatorEventBroadcaster>,
 }
 
 #[doc(hidden)]
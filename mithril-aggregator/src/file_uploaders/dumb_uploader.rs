@@ -88,10 +88,7 @@ mod tests {
 
     #[tokio::test]
     async fn retry_policy_from_file_uploader_trait_should_be_implemented() {
-        let expected_policy = FileUploadRetryPolicy {
-            attempts: 10,
-            delay_between_attempts: Duration::from_millis(123),
-        };
+        let expected_policy = FileUploadRetryPolicy::constant_delay(10, Duration::from_millis(123));
 
         let uploader: Box<dyn FileUploader> = Box::new(DumbUploader::new(expected_policy.clone()));
 
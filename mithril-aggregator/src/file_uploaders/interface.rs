@@ -1,14 +1,28 @@
 use async_trait::async_trait;
-use mithril_common::{entities::FileUri, StdResult};
-use std::{path::Path, time::Duration};
+use mithril_common::{entities::FileUri, StdError, StdResult};
+use rand::Rng;
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+use thiserror::Error;
 
 /// Policy for retrying file uploads.
+///
+/// The delay between two attempts starts at `delay_between_attempts` and is multiplied by
+/// `backoff_multiplier` after each retry, a random jitter up to `max_jitter` being added to it.
 #[derive(Debug, PartialEq, Clone)]
 pub struct FileUploadRetryPolicy {
     /// Number of attempts to upload a file.
     pub attempts: usize,
-    /// Delay between two attempts.
+    /// Delay between the first two attempts.
     pub delay_between_attempts: Duration,
+    /// Factor applied to the delay after each retry (`1` keeps a constant delay).
+    pub backoff_multiplier: u32,
+    /// Maximum random duration added to each delay.
+    pub max_jitter: Duration,
+    /// Maximum duration of all the attempts, no retry is started once it is elapsed.
+    pub max_total_duration: Option<Duration>,
 }
 
 impl FileUploadRetryPolicy {
@@ -17,6 +31,36 @@ impl FileUploadRetryPolicy {
         Self {
             attempts: 1,
             delay_between_attempts: Duration::from_secs(0),
+            backoff_multiplier: 1,
+            max_jitter: Duration::ZERO,
+            max_total_duration: None,
+        }
+    }
+
+    /// Create a policy that retries with a constant delay between attempts.
+    pub fn constant_delay(attempts: usize, delay_between_attempts: Duration) -> Self {
+        Self {
+            attempts,
+            delay_between_attempts,
+            ..Self::never()
+        }
+    }
+
+    /// Compute the delay to wait before the given retry (starting at `1`), without jitter.
+    pub fn delay_before_retry(&self, retry_number: u32) -> Duration {
+        let factor = self
+            .backoff_multiplier
+            .max(1)
+            .saturating_pow(retry_number.saturating_sub(1));
+
+        self.delay_between_attempts.saturating_mul(factor)
+    }
+
+    fn jitter(&self) -> Duration {
+        if self.max_jitter.is_zero() {
+            Duration::ZERO
+        } else {
+            rand::thread_rng().gen_range(Duration::ZERO..=self.max_jitter)
         }
     }
 }
@@ -24,11 +68,81 @@ impl FileUploadRetryPolicy {
 impl Default for FileUploadRetryPolicy {
     /// Create a default retry policy.
     fn default() -> Self {
+        Self::constant_delay(3, Duration::from_secs(5))
+    }
+}
+
+/// Error returned when a remote destination rejects an upload request.
+#[derive(Debug, Error)]
+#[error("request rejected with status '{status}', response: '{body}'")]
+pub struct UploadRequestRejectedError {
+    /// HTTP status code of the response
+    pub status: u16,
+    /// Body of the response
+    pub body: String,
+}
+
+/// Error returned when a file to upload can not be accessed on the local filesystem.
+#[derive(Debug, Error)]
+#[error("could not access the local file: '{}'", path.display())]
+pub struct LocalFileAccessError {
+    /// Path of the local file
+    pub path: PathBuf,
+    /// Cause of the failure
+    #[source]
+    pub source: std::io::Error,
+}
+
+impl LocalFileAccessError {
+    /// `LocalFileAccessError` factory
+    pub fn new(path: &Path, source: std::io::Error) -> Self {
         Self {
-            attempts: 3,
-            delay_between_attempts: Duration::from_secs(5),
+            path: path.to_path_buf(),
+            source,
+        }
+    }
+}
+
+/// Returns `true` if a request rejected with the given HTTP status may succeed if retried.
+///
+/// Client error statuses are permanent (for example because of bad credentials), except for
+/// timeouts and rate limiting.
+pub fn is_transient_http_status(status: u16) -> bool {
+    !(400..500).contains(&status) || status == 408 || status == 429
+}
+
+/// Returns `false` if retrying an upload that failed with this error is pointless.
+///
+/// This generic classification only knows about the errors that are common to all the uploaders:
+/// an error is permanent if its chain contains an [UploadRequestRejectedError] with a permanent
+/// status, an I/O error such as a missing file or a denied permission, or a
+/// [LocalFileAccessError] caused by an invalid input (for example a directory given as the file
+/// to upload). Any other error is considered transient.
+///
+/// Uploaders relying on a specific client should classify its errors in their
+/// [FileUploader::is_transient_error] implementation.
+pub fn is_transient_upload_error(error: &StdError) -> bool {
+    for cause in error.chain() {
+        if let Some(rejected_error) = cause.downcast_ref::<UploadRequestRejectedError>() {
+            return is_transient_http_status(rejected_error.status);
+        }
+        if let Some(local_file_error) = cause.downcast_ref::<LocalFileAccessError>() {
+            return !matches!(
+                local_file_error.source.kind(),
+                std::io::ErrorKind::NotFound
+                    | std::io::ErrorKind::PermissionDenied
+                    | std::io::ErrorKind::InvalidInput
+            );
+        }
+        if let Some(io_error) = cause.downcast_ref::<std::io::Error>() {
+            return !matches!(
+                io_error.kind(),
+                std::io::ErrorKind::NotFound | std::io::ErrorKind::PermissionDenied
+            );
         }
     }
+
+    true
 }
 
 /// FileUploader represents a file uploader interactor.
@@ -44,23 +158,58 @@ pub trait FileUploader: Sync + Send {
         FileUploadRetryPolicy::never()
     }
 
+    /// Returns `false` if retrying an upload that failed with the given error is pointless.
+    fn is_transient_error(&self, error: &StdError) -> bool {
+        is_transient_upload_error(error)
+    }
+
     /// Upload a file with retries according to the retry policy.
+    ///
+    /// Only transient errors are retried.
+    ///
+    /// The returned error is the one of the last attempt, so its typed causes can still be
+    /// inspected, with a context summarizing the causes of the previous attempts.
     async fn upload(&self, filepath: &Path) -> StdResult<FileUri> {
         let retry_policy = self.retry_policy();
+        let start = Instant::now();
 
-        let mut nb_attempts = 0;
+        let mut previous_errors: Vec<String> = vec![];
         loop {
-            nb_attempts += 1;
-            match self.upload_without_retry(filepath).await {
+            let error = match self.upload_without_retry(filepath).await {
                 Ok(result) => return Ok(result),
-                Err(_) if nb_attempts >= retry_policy.attempts => {
-                    return Err(anyhow::anyhow!(
-                        "Upload failed after {} attempts",
-                        nb_attempts
-                    ));
-                }
-                _ => tokio::time::sleep(retry_policy.delay_between_attempts).await,
+                Err(error) => error,
+            };
+
+            let nb_attempts = previous_errors.len() + 1;
+            let stop_reason = if nb_attempts >= retry_policy.attempts {
+                Some("no attempts left")
+            } else if !self.is_transient_error(&error) {
+                Some("a permanent error occurred")
+            } else {
+                None
+            };
+            let delay = retry_policy.delay_before_retry(nb_attempts as u32) + retry_policy.jitter();
+            let stop_reason = stop_reason.or_else(|| {
+                retry_policy
+                    .max_total_duration
+                    .filter(|max_duration| start.elapsed() + delay > *max_duration)
+                    .map(|_| "maximum total duration reached")
+            });
+
+            if let Some(stop_reason) = stop_reason {
+                let context = if previous_errors.is_empty() {
+                    format!("Upload failed after {nb_attempts} attempts ({stop_reason})")
+                } else {
+                    format!(
+                        "Upload failed after {nb_attempts} attempts ({stop_reason}), previous attempts: [{}]",
+                        previous_errors.join(", ")
+                    )
+                };
+                return Err(error.context(context));
             }
+            previous_errors.push(format!("attempt #{nb_attempts}: {error:#}"));
+
+            tokio::time::sleep(delay).await;
         }
     }
 }
@@ -127,10 +276,7 @@ mod tests {
 
         uploader
             .expect_retry_policy()
-            .returning(|| FileUploadRetryPolicy {
-                attempts: 50,
-                delay_between_attempts: Duration::ZERO,
-            });
+            .returning(|| FileUploadRetryPolicy::constant_delay(50, Duration::ZERO));
 
         uploader
             .expect_upload_without_retry()
@@ -153,10 +299,7 @@ mod tests {
 
         uploader
             .expect_retry_policy()
-            .returning(|| FileUploadRetryPolicy {
-                attempts: 4,
-                delay_between_attempts: Duration::ZERO,
-            });
+            .returning(|| FileUploadRetryPolicy::constant_delay(4, Duration::ZERO));
 
         uploader
             .expect_upload_without_retry()
@@ -177,10 +320,7 @@ mod tests {
         let delay = Duration::from_millis(50);
         uploader
             .expect_retry_policy()
-            .returning(move || FileUploadRetryPolicy {
-                attempts: 4,
-                delay_between_attempts: delay,
-            });
+            .returning(move || FileUploadRetryPolicy::constant_delay(4, delay));
 
         uploader
             .expect_upload_without_retry()
@@ -207,4 +347,216 @@ mod tests {
             duration.as_millis()
         );
     }
+
+    #[test]
+    fn delay_before_retry_is_multiplied_by_the_backoff_multiplier() {
+        let policy = FileUploadRetryPolicy {
+            backoff_multiplier: 3,
+            ..FileUploadRetryPolicy::constant_delay(5, Duration::from_millis(10))
+        };
+
+        assert_eq!(Duration::from_millis(10), policy.delay_before_retry(1));
+        assert_eq!(Duration::from_millis(30), policy.delay_before_retry(2));
+        assert_eq!(Duration::from_millis(90), policy.delay_before_retry(3));
+    }
+
+    #[test]
+    fn delay_before_retry_is_constant_without_backoff_multiplier() {
+        let policy = FileUploadRetryPolicy::constant_delay(5, Duration::from_millis(10));
+
+        assert_eq!(Duration::from_millis(10), policy.delay_before_retry(1));
+        assert_eq!(Duration::from_millis(10), policy.delay_before_retry(4));
+    }
+
+    #[test]
+    fn jitter_is_bounded_by_the_max_jitter() {
+        let policy = FileUploadRetryPolicy {
+            max_jitter: Duration::from_millis(20),
+            ..FileUploadRetryPolicy::never()
+        };
+
+        for _ in 0..100 {
+            assert!(policy.jitter() <= Duration::from_millis(20));
+        }
+        assert_eq!(Duration::ZERO, FileUploadRetryPolicy::never().jitter());
+    }
+
+    #[test]
+    fn classify_upload_errors() {
+        let rejected = |status| {
+            anyhow!(UploadRequestRejectedError {
+                status,
+                body: String::new(),
+            })
+            .context("Upload failed")
+        };
+        let io_error = |kind| anyhow!(std::io::Error::new(kind, "io error"));
+
+        assert!(is_transient_upload_error(&anyhow!("unknown error")));
+        assert!(is_transient_upload_error(&rejected(500)));
+        assert!(is_transient_upload_error(&rejected(503)));
+        assert!(is_transient_upload_error(&rejected(408)));
+        assert!(is_transient_upload_error(&rejected(429)));
+        assert!(!is_transient_upload_error(&rejected(403)));
+        assert!(!is_transient_upload_error(&rejected(404)));
+        assert!(is_transient_upload_error(&io_error(
+            std::io::ErrorKind::ConnectionReset
+        )));
+        assert!(!is_transient_upload_error(&io_error(
+            std::io::ErrorKind::NotFound
+        )));
+        assert!(!is_transient_upload_error(&io_error(
+            std::io::ErrorKind::PermissionDenied
+        )));
+        assert!(is_transient_upload_error(&io_error(
+            std::io::ErrorKind::InvalidInput
+        )));
+    }
+
+    #[test]
+    fn classify_local_file_access_errors() {
+        let local_file_error = |kind| {
+            anyhow!(LocalFileAccessError::new(
+                Path::new("file_to_upload"),
+                std::io::Error::new(kind, "io error"),
+            ))
+            .context("Upload failed")
+        };
+
+        assert!(!is_transient_upload_error(&local_file_error(
+            std::io::ErrorKind::NotFound
+        )));
+        assert!(!is_transient_upload_error(&local_file_error(
+            std::io::ErrorKind::PermissionDenied
+        )));
+        assert!(!is_transient_upload_error(&local_file_error(
+            std::io::ErrorKind::InvalidInput
+        )));
+        assert!(is_transient_upload_error(&local_file_error(
+            std::io::ErrorKind::Interrupted
+        )));
+    }
+
+    #[test]
+    fn default_policy_retries_with_a_constant_delay() {
+        assert_eq!(
+            FileUploadRetryPolicy::constant_delay(3, Duration::from_secs(5)),
+            FileUploadRetryPolicy::default()
+        );
+    }
+
+    #[tokio::test]
+    async fn should_not_retry_if_the_error_is_permanent() {
+        let mut uploader = MockTestFileUploader::new();
+        uploader
+            .expect_retry_policy()
+            .returning(|| FileUploadRetryPolicy::constant_delay(4, Duration::ZERO));
+        uploader
+            .expect_upload_without_retry()
+            .times(1)
+            .returning(|_| {
+                Err(anyhow!(UploadRequestRejectedError {
+                    status: 403,
+                    body: "InvalidAccessKeyId".to_string(),
+                }))
+            });
+
+        let error = uploader
+            .upload(Path::new("file_to_upload"))
+            .await
+            .expect_err("An error should be returned on a permanent error");
+
+        assert!(
+            error.to_string().contains("permanent error"),
+            "Unexpected error: {error}"
+        );
+    }
+
+    #[tokio::test]
+    async fn error_aggregates_the_cause_of_each_attempt() {
+        let mut uploader = MockTestFileUploader::new();
+        uploader
+            .expect_retry_policy()
+            .returning(|| FileUploadRetryPolicy::constant_delay(2, Duration::ZERO));
+        uploader
+            .expect_upload_without_retry()
+            .times(1)
+            .returning(|_| Err(anyhow!("connection reset")));
+        uploader
+            .expect_upload_without_retry()
+            .times(1)
+            .returning(|_| Err(anyhow!("timeout")));
+
+        let error = uploader
+            .upload(Path::new("file_to_upload"))
+            .await
+            .expect_err("An error should be returned when all retries are done");
+
+        let message = format!("{error:#}");
+        assert!(
+            message.contains("attempt #1: connection reset"),
+            "{message}"
+        );
+        assert!(message.ends_with(": timeout"), "{message}");
+    }
+
+    #[tokio::test]
+    async fn error_keeps_the_typed_cause_of_the_last_attempt() {
+        let mut uploader = MockTestFileUploader::new();
+        uploader
+            .expect_retry_policy()
+            .returning(|| FileUploadRetryPolicy::constant_delay(2, Duration::ZERO));
+        uploader
+            .expect_upload_without_retry()
+            .times(1)
+            .returning(|_| Err(anyhow!("connection reset")));
+        uploader
+            .expect_upload_without_retry()
+            .times(1)
+            .returning(|_| {
+                Err(anyhow!(UploadRequestRejectedError {
+                    status: 403,
+                    body: "InvalidAccessKeyId".to_string(),
+                }))
+            });
+
+        let error = uploader
+            .upload(Path::new("file_to_upload"))
+            .await
+            .expect_err("An error should be returned on a permanent error");
+
+        let rejected_error = error
+            .downcast_ref::<UploadRequestRejectedError>()
+            .expect("The error of the last attempt should be kept");
+        assert_eq!(403, rejected_error.status);
+        assert!(
+            error.to_string().contains("attempt #1: connection reset"),
+            "Unexpected error: {error}"
+        );
+    }
+
+    #[tokio::test]
+    async fn should_stop_retrying_when_the_max_total_duration_is_reached() {
+        let mut uploader = MockTestFileUploader::new();
+        uploader
+            .expect_retry_policy()
+            .returning(|| FileUploadRetryPolicy {
+                max_total_duration: Some(Duration::from_millis(120)),
+                ..FileUploadRetryPolicy::constant_delay(50, Duration::from_millis(50))
+            });
+        uploader
+            .expect_upload_without_retry()
+            .times(3)
+            .returning(|_| Err(anyhow!("Failure while uploading...")));
+
+        let error = uploader
+            .upload(Path::new("file_to_upload"))
+            .await
+            .expect_err("An error should be returned when the max total duration is reached");
+
+        assert!(
+            error.to_string().contains("maximum total duration"),
+            "Unexpected error: {error}"
+        );
+    }
 }
@@ -7,7 +7,7 @@ use std::path::{Path, PathBuf};
 use mithril_common::logging::LoggerExtensions;
 use mithril_common::StdResult;
 
-use crate::file_uploaders::FileUploader;
+use crate::file_uploaders::{FileUploader, LocalFileAccessError};
 use crate::tools::{self, url_sanitizer::SanitizedUrlWithTrailingSlash};
 
 // It's only used by the legacy snapshot that uploads the entire Cardano database.
@@ -47,7 +47,8 @@ impl FileUploader for LocalSnapshotUploader {
         let target_path = &self.target_location.join(archive_name);
         tokio::fs::copy(filepath, target_path)
             .await
-            .with_context(|| "File copy failure")?;
+            .map_err(|error| LocalFileAccessError::new(filepath, error))
+                .with_context(|| "File copy failure")?;
 
         let digest = tools::extract_digest_from_path(Path::new(archive_name))?;
         let location = &self
@@ -6,7 +6,7 @@ use std::path::{Path, PathBuf};
 use mithril_common::StdResult;
 use mithril_common::{entities::FileUri, logging::LoggerExtensions};
 
-use crate::file_uploaders::{FileUploadRetryPolicy, FileUploader};
+use crate::file_uploaders::{FileUploadRetryPolicy, FileUploader, LocalFileAccessError};
 use crate::tools::url_sanitizer::SanitizedUrlWithTrailingSlash;
 
 /// LocalUploader is a file uploader working using local files
@@ -67,6 +67,7 @@ impl FileUploader for LocalUploader {
             let target_path = target_location.join(archive_name);
             tokio::fs::copy(filepath, &target_path)
                 .await
+                .map_err(|error| LocalFileAccessError::new(filepath, error))
                 .with_context(|| "File copy failure")?;
             target_path
         } else {
@@ -188,10 +189,7 @@ mod tests {
     #[tokio::test]
     async fn retry_policy_from_file_uploader_trait_should_be_implemented() {
         let target_dir = TempDir::create("local_uploader", "test_retry_policy");
-        let expected_policy = FileUploadRetryPolicy {
-            attempts: 10,
-            delay_between_attempts: Duration::from_millis(123),
-        };
+        let expected_policy = FileUploadRetryPolicy::constant_delay(10, Duration::from_millis(123));
 
         let uploader: Box<dyn FileUploader> = Box::new(LocalUploader::new(
             SanitizedUrlWithTrailingSlash::parse("http://test.com:8080/base-root/").unwrap(),
@@ -1,14 +1,23 @@
+mod cloud_uploader;
 mod dumb_uploader;
 mod gcp_uploader;
 mod interface;
 mod local_snapshot_uploader;
 mod local_uploader;
+mod multi_uploader;
+mod s3_uploader;
 
+pub use cloud_uploader::{CloudBackendUploader, CloudRemotePath, CloudUploader};
 pub use dumb_uploader::*;
-pub use gcp_uploader::{CloudRemotePath, GcpBackendUploader, GcpUploader};
-pub use interface::{FileUploadRetryPolicy, FileUploader};
+pub use gcp_uploader::GcpBackendUploader;
+pub use interface::{
+    is_transient_http_status, is_transient_upload_error, FileUploadRetryPolicy, FileUploader,
+    LocalFileAccessError, UploadRequestRejectedError,
+};
 pub use local_snapshot_uploader::LocalSnapshotUploader;
 pub use local_uploader::LocalUploader;
+pub use multi_uploader::{MultiFileUploader, MultiUploadFailurePolicy};
+pub use s3_uploader::{S3BackendUploader, S3BackendUploaderConfiguration};
 
 #[cfg(test)]
 pub use interface::MockFileUploader;
@@ -0,0 +1,352 @@
+use anyhow::{anyhow, Context};
+use futures::future::join_all;
+use serde::{Deserialize, Serialize};
+use slog::{error, warn, Logger};
+use std::{path::Path, sync::Arc};
+
+use mithril_common::{entities::FileUri, logging::LoggerExtensions, StdResult};
+
+use crate::FileUploader;
+
+/// Policy applied when a file is uploaded to several destinations and some of the uploads fail.
+///
+/// The first destination is the primary one, the others are its mirrors.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MultiUploadFailurePolicy {
+    /// All the uploads must succeed.
+    RequireAll,
+    /// The upload to the primary destination must succeed, failures of the mirrors are tolerated.
+    #[default]
+    RequirePrimary,
+    /// At least one of the uploads must succeed.
+    RequireAny,
+}
+
+impl MultiUploadFailurePolicy {
+    /// Apply the policy to the results of uploads, ordered as their destinations (primary first).
+    ///
+    /// Returns the successful results if the policy is satisfied, else an error listing all the
+    /// failures.
+    pub fn apply<T>(&self, results: Vec<StdResult<T>>) -> StdResult<Vec<T>> {
+        let total_uploads = results.len();
+        let is_primary_successful = results.first().is_some_and(|result| result.is_ok());
+        let mut successes = Vec::new();
+        let mut failures = Vec::new();
+        for (index, result) in results.into_iter().enumerate() {
+            match result {
+                Ok(value) => successes.push(value),
+                Err(error) => failures.push(format!("destination #{index}: {error:#}")),
+            }
+        }
+
+        let is_satisfied = match self {
+            Self::RequireAll => failures.is_empty() && total_uploads > 0,
+            Self::RequirePrimary => is_primary_successful,
+            Self::RequireAny => !successes.is_empty(),
+        };
+
+        if is_satisfied {
+            Ok(successes)
+        } else {
+            Err(anyhow!(
+                "{} of {total_uploads} uploads failed, which is not allowed by the '{self:?}' policy: [{}]",
+                failures.len(),
+                failures.join(", ")
+            ))
+        }
+    }
+}
+
+/// MultiFileUploader uploads a file to several destinations: a primary one and its mirrors.
+///
+/// The location of each successful upload is returned, failures are tolerated according to a
+/// [MultiUploadFailurePolicy].
+pub struct MultiFileUploader {
+    uploaders: Vec<Arc<dyn FileUploader>>,
+    failure_policy: MultiUploadFailurePolicy,
+    logger: Logger,
+}
+
+impl MultiFileUploader {
+    /// MultiFileUploader factory, the first uploader is the primary one.
+    pub fn new(
+        uploaders: Vec<Arc<dyn FileUploader>>,
+        failure_policy: MultiUploadFailurePolicy,
+        logger: Logger,
+    ) -> StdResult<Self> {
+        if uploaders.is_empty() {
+            return Err(anyhow!(
+                "At least one uploader is required to create a 'MultiFileUploader'"
+            ));
+        }
+
+        Ok(Self {
+            uploaders,
+            failure_policy,
+            logger: logger.new_with_component_name::<Self>(),
+        })
+    }
+
+    /// Upload a file to all the destinations, in parallel, and return the locations of the
+    /// successful uploads.
+    pub async fn upload(&self, filepath: &Path) -> StdResult<Vec<FileUri>> {
+        let results = join_all(self.uploaders.iter().enumerate().map(
+            |(index, uploader)| async move {
+                let result = uploader.upload(filepath).await;
+                if let Err(error) = &result {
+                    if index == 0 {
+                        error!(
+                            self.logger, "Failed to upload file to the primary destination";
+                            "filepath" => filepath.display(),
+                            "error" => ?error
+                        );
+                    } else {
+                        warn!(
+                            self.logger, "Failed to upload file to the mirror destination #{index}";
+                            "filepath" => filepath.display(),
+                            "error" => ?error
+                        );
+                    }
+                }
+
+                result
+            },
+        ))
+        .await;
+
+        self.failure_policy
+            .apply(results)
+            .with_context(|| format!("Failed to upload file: '{}'", filepath.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use mockall::predicate::eq;
+    use std::path::PathBuf;
+    use std::time::Duration;
+    use tokio::sync::Barrier;
+
+    use crate::file_uploaders::MockFileUploader;
+    use crate::test_tools::TestLogger;
+
+    use super::*;
+
+    fn success(location: &str) -> StdResult<FileUri> {
+        Ok(FileUri(location.to_string()))
+    }
+
+    fn failure() -> StdResult<FileUri> {
+        Err(anyhow!("upload failure"))
+    }
+
+    fn uploader_returning(result: fn() -> StdResult<FileUri>) -> Arc<dyn FileUploader> {
+        let mut uploader = MockFileUploader::new();
+        uploader
+            .expect_upload()
+            .with(eq(PathBuf::from("file_to_upload")))
+            .times(1)
+            .returning(move |_| result());
+
+        Arc::new(uploader)
+    }
+
+    mod failure_policy {
+        use super::*;
+
+        #[test]
+        fn require_all_fails_if_any_upload_fails() {
+            let policy = MultiUploadFailurePolicy::RequireAll;
+
+            assert_eq!(
+                vec![FileUri("a".to_string()), FileUri("b".to_string())],
+                policy.apply(vec![success("a"), success("b")]).unwrap()
+            );
+            policy
+                .apply(vec![success("a"), failure()])
+                .expect_err("RequireAll should fail if a mirror upload fails");
+            policy
+                .apply(Vec::<StdResult<FileUri>>::new())
+                .expect_err("RequireAll should fail without any upload");
+        }
+
+        #[test]
+        fn require_primary_fails_only_if_the_primary_upload_fails() {
+            let policy = MultiUploadFailurePolicy::RequirePrimary;
+
+            assert_eq!(
+                vec![FileUri("a".to_string())],
+                policy.apply(vec![success("a"), failure()]).unwrap()
+            );
+            policy
+                .apply(vec![failure(), success("b")])
+                .expect_err("RequirePrimary should fail if the primary upload fails");
+        }
+
+        #[test]
+        fn require_any_fails_only_if_all_uploads_fail() {
+            let policy = MultiUploadFailurePolicy::RequireAny;
+
+            assert_eq!(
+                vec![FileUri("b".to_string())],
+                policy.apply(vec![failure(), success("b")]).unwrap()
+            );
+            policy
+                .apply(vec![failure(), failure()])
+                .expect_err("RequireAny should fail if all uploads fail");
+        }
+
+        #[test]
+        fn error_lists_all_the_failed_destinations() {
+            let error = MultiUploadFailurePolicy::RequireAll
+                .apply(vec![failure(), success("b"), failure()])
+                .unwrap_err();
+
+            let message = error.to_string();
+            assert!(message.contains("2 of 3 uploads failed"), "{message}");
+            assert!(message.contains("destination #0"), "{message}");
+            assert!(message.contains("destination #2"), "{message}");
+        }
+
+        #[test]
+        fn default_policy_requires_the_primary_upload() {
+            assert_eq!(
+                MultiUploadFailurePolicy::RequirePrimary,
+                MultiUploadFailurePolicy::default()
+            );
+        }
+
+        #[test]
+        fn deserialize_from_snake_case() {
+            let policy: MultiUploadFailurePolicy =
+                serde_json::from_str(r#""require_primary""#).unwrap();
+
+            assert_eq!(MultiUploadFailurePolicy::RequirePrimary, policy);
+        }
+    }
+
+    #[test]
+    fn new_fails_without_uploaders() {
+        let result = MultiFileUploader::new(
+            vec![],
+            MultiUploadFailurePolicy::default(),
+            TestLogger::stdout(),
+        );
+
+        assert!(result.is_err(), "Should return an error when no uploaders");
+    }
+
+    #[tokio::test]
+    async fn upload_to_all_destinations_and_return_all_locations() {
+        let multi_uploader = MultiFileUploader::new(
+            vec![
+                uploader_returning(|| success("primary")),
+                uploader_returning(|| success("mirror")),
+            ],
+            MultiUploadFailurePolicy::RequireAll,
+            TestLogger::stdout(),
+        )
+        .unwrap();
+
+        let locations = multi_uploader
+            .upload(Path::new("file_to_upload"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                FileUri("primary".to_string()),
+                FileUri("mirror".to_string())
+            ],
+            locations
+        );
+    }
+
+    #[tokio::test]
+    async fn upload_to_all_destinations_in_parallel() {
+        /// Uploader that completes only when all the uploaders sharing its barrier are uploading
+        struct BarrierUploader {
+            barrier: Arc<Barrier>,
+        }
+
+        #[async_trait]
+        impl FileUploader for BarrierUploader {
+            async fn upload_without_retry(&self, _filepath: &Path) -> StdResult<FileUri> {
+                self.barrier.wait().await;
+                Ok(FileUri("location".to_string()))
+            }
+        }
+
+        let barrier = Arc::new(Barrier::new(3));
+        let uploaders: Vec<Arc<dyn FileUploader>> = (0..3)
+            .map(|_| {
+                Arc::new(BarrierUploader {
+                    barrier: barrier.clone(),
+                }) as Arc<dyn FileUploader>
+            })
+            .collect();
+        let multi_uploader = MultiFileUploader::new(
+            uploaders,
+            MultiUploadFailurePolicy::RequireAll,
+            TestLogger::stdout(),
+        )
+        .unwrap();
+
+        let locations = tokio::time::timeout(
+            Duration::from_secs(5),
+            multi_uploader.upload(Path::new("file_to_upload")),
+        )
+        .await
+        .expect("Uploads should run in parallel")
+        .unwrap();
+
+        assert_eq!(3, locations.len());
+    }
+
+    #[tokio::test]
+    async fn upload_tolerate_failures_allowed_by_the_policy() {
+        let multi_uploader = MultiFileUploader::new(
+            vec![
+                uploader_returning(|| success("primary")),
+                uploader_returning(failure),
+                uploader_returning(|| success("mirror")),
+            ],
+            MultiUploadFailurePolicy::RequirePrimary,
+            TestLogger::stdout(),
+        )
+        .unwrap();
+
+        let locations = multi_uploader
+            .upload(Path::new("file_to_upload"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                FileUri("primary".to_string()),
+                FileUri("mirror".to_string())
+            ],
+            locations
+        );
+    }
+
+    #[tokio::test]
+    async fn upload_fails_if_the_failures_are_not_allowed_by_the_policy() {
+        let multi_uploader = MultiFileUploader::new(
+            vec![
+                uploader_returning(failure),
+                uploader_returning(|| success("mirror")),
+            ],
+            MultiUploadFailurePolicy::RequirePrimary,
+            TestLogger::stdout(),
+        )
+        .unwrap();
+
+        multi_uploader
+            .upload(Path::new("file_to_upload"))
+            .await
+            .expect_err("Upload should fail if the primary upload fails");
+    }
+}
@@ -1,5 +1,6 @@
 use crate::http_server::routes::middlewares;
 use crate::http_server::routes::router::RouterState;
+use mithril_common::entities::ListFilter;
 use warp::Filter;
 
 pub fn routes(
@@ -17,6 +18,7 @@ fn artifact_cardano_database_list(
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
     warp::path!("artifact" / "cardano-database")
         .and(warp::get())
+        .and(warp::query::<ListFilter>())
         .and(middlewares::with_logger(router_state))
         .and(middlewares::with_http_message_service(router_state))
         .and_then(handlers::list_artifacts)
@@ -66,6 +68,7 @@ mod handlers {
     use crate::http_server::routes::reply;
     use crate::services::MessageService;
     use crate::MetricsService;
+    use mithril_common::entities::ListFilter;
     use slog::{debug, warn, Logger};
     use std::convert::Infallible;
     use std::sync::Arc;
@@ -75,11 +78,12 @@ mod handlers {
 
     /// List artifacts
     pub async fn list_artifacts(
+        filter: ListFilter,
         logger: Logger,
         http_message_service: Arc<dyn MessageService>,
     ) -> Result<impl warp::Reply, Infallible> {
         match http_message_service
-            .get_cardano_database_list_message(LIST_MAX_ITEMS)
+            .get_cardano_database_list_message(LIST_MAX_ITEMS, &filter)
             .await
         {
             Ok(message) => Ok(reply::json(&message, StatusCode::OK)),
@@ -200,7 +204,7 @@ mod tests {
         let mut mock_http_message_service = MockMessageService::new();
         mock_http_message_service
             .expect_get_cardano_database_list_message()
-            .return_once(|_| Ok(vec![CardanoDatabaseSnapshotListItemMessage::dummy()]))
+            .return_once(|_, _| Ok(vec![CardanoDatabaseSnapshotListItemMessage::dummy()]))
             .once();
         let mut dependency_manager = initialize_dependencies().await;
         dependency_manager.message_service = Arc::new(mock_http_message_service);
@@ -233,7 +237,7 @@ mod tests {
         let mut mock_http_message_service = MockMessageService::new();
         mock_http_message_service
             .expect_get_cardano_database_list_message()
-            .return_once(|_| Err(HydrationError::InvalidData("invalid data".to_string()).into()))
+            .return_once(|_, _| Err(HydrationError::InvalidData("invalid data".to_string()).into()))
             .once();
         let mut dependency_manager = initialize_dependencies().await;
         dependency_manager.message_service = Arc::new(mock_http_message_service);
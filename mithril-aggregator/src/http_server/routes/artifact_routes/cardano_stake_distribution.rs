@@ -1,5 +1,6 @@
 use crate::http_server::routes::middlewares;
 use crate::http_server::routes::router::RouterState;
+use mithril_common::entities::ListFilter;
 use warp::Filter;
 
 pub fn routes(
@@ -16,6 +17,7 @@ fn artifact_cardano_stake_distributions(
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
     warp::path!("artifact" / "cardano-stake-distributions")
         .and(warp::get())
+        .and(warp::query::<ListFilter>())
         .and(middlewares::with_logger(router_state))
         .and(middlewares::with_http_message_service(router_state))
         .and_then(handlers::list_artifacts)
@@ -50,7 +52,7 @@ pub mod handlers {
     use crate::services::MessageService;
     use crate::MetricsService;
 
-    use mithril_common::entities::Epoch;
+    use mithril_common::entities::{Epoch, ListFilter};
     use slog::{warn, Logger};
     use std::convert::Infallible;
     use std::sync::Arc;
@@ -60,11 +62,12 @@ pub mod handlers {
 
     /// List CardanoStakeDistribution artifacts
     pub async fn list_artifacts(
+        filter: ListFilter,
         logger: Logger,
         http_message_service: Arc<dyn MessageService>,
     ) -> Result<impl warp::Reply, Infallible> {
         match http_message_service
-            .get_cardano_stake_distribution_list_message(LIST_MAX_ITEMS)
+            .get_cardano_stake_distribution_list_message(LIST_MAX_ITEMS, &filter)
             .await
         {
             Ok(message) => Ok(reply::json(&message, StatusCode::OK)),
@@ -180,7 +183,7 @@ pub mod tests {
         let mut mock_http_message_service = MockMessageService::new();
         mock_http_message_service
             .expect_get_cardano_stake_distribution_list_message()
-            .return_once(|_| Ok(message))
+            .return_once(|_, _| Ok(message))
             .once();
         let mut dependency_manager = initialize_dependencies().await;
         dependency_manager.message_service = Arc::new(mock_http_message_service);
@@ -213,7 +216,7 @@ pub mod tests {
         let mut mock_http_message_service = MockMessageService::new();
         mock_http_message_service
             .expect_get_cardano_stake_distribution_list_message()
-            .return_once(|_| Err(anyhow!("an error occured")))
+            .return_once(|_, _| Err(anyhow!("an error occured")))
             .once();
         let mut dependency_manager = initialize_dependencies().await;
         dependency_manager.message_service = Arc::new(mock_http_message_service);
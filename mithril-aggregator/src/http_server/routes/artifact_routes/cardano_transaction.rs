@@ -1,5 +1,6 @@
 use crate::http_server::routes::middlewares;
 use crate::http_server::routes::router::RouterState;
+use mithril_common::entities::ListFilter;
 use warp::Filter;
 
 pub fn routes(
@@ -14,6 +15,7 @@ fn artifact_cardano_transactions(
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
     warp::path!("artifact" / "cardano-transactions")
         .and(warp::get())
+        .and(warp::query::<ListFilter>())
         .and(middlewares::with_logger(router_state))
         .and(middlewares::with_http_message_service(router_state))
         .and_then(handlers::list_artifacts)
@@ -36,6 +38,7 @@ pub mod handlers {
     use crate::services::MessageService;
     use crate::MetricsService;
 
+    use mithril_common::entities::ListFilter;
     use slog::{warn, Logger};
     use std::convert::Infallible;
     use std::sync::Arc;
@@ -45,11 +48,12 @@ pub mod handlers {
 
     /// List Cardano Transactions set artifacts
     pub async fn list_artifacts(
+        filter: ListFilter,
         logger: Logger,
         http_message_service: Arc<dyn MessageService>,
     ) -> Result<impl warp::Reply, Infallible> {
         match http_message_service
-            .get_cardano_transaction_list_message(LIST_MAX_ITEMS)
+            .get_cardano_transaction_list_message(LIST_MAX_ITEMS, &filter)
             .await
         {
             Ok(message) => Ok(reply::json(&message, StatusCode::OK)),
@@ -124,7 +128,7 @@ pub mod tests {
         let mut mock_http_message_service = MockMessageService::new();
         mock_http_message_service
             .expect_get_cardano_transaction_list_message()
-            .return_once(|_| Ok(vec![CardanoTransactionSnapshotListItemMessage::dummy()]))
+            .return_once(|_, _| Ok(vec![CardanoTransactionSnapshotListItemMessage::dummy()]))
             .once();
         let mut dependency_manager = initialize_dependencies().await;
         dependency_manager.message_service = Arc::new(mock_http_message_service);
@@ -157,7 +161,7 @@ pub mod tests {
         let mut mock_http_message_service = MockMessageService::new();
         mock_http_message_service
             .expect_get_cardano_transaction_list_message()
-            .return_once(|_| Err(HydrationError::InvalidData("invalid data".to_string()).into()))
+            .return_once(|_, _| Err(HydrationError::InvalidData("invalid data".to_string()).into()))
             .once();
         let mut dependency_manager = initialize_dependencies().await;
         dependency_manager.message_service = Arc::new(mock_http_message_service);
@@ -1,5 +1,6 @@
 use crate::http_server::routes::middlewares;
 use crate::http_server::routes::router::RouterState;
+use mithril_common::entities::ListFilter;
 use warp::Filter;
 
 pub fn routes(
@@ -15,6 +16,7 @@ fn artifact_mithril_stake_distributions(
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
     warp::path!("artifact" / "mithril-stake-distributions")
         .and(warp::get())
+        .and(warp::query::<ListFilter>())
         .and(middlewares::with_logger(router_state))
         .and(middlewares::with_http_message_service(router_state))
         .and_then(handlers::list_artifacts)
@@ -37,6 +39,7 @@ pub mod handlers {
     use crate::services::MessageService;
     use crate::MetricsService;
 
+    use mithril_common::entities::ListFilter;
     use slog::{warn, Logger};
     use std::convert::Infallible;
     use std::sync::Arc;
@@ -46,11 +49,12 @@ pub mod handlers {
 
     /// List MithrilStakeDistribution artifacts
     pub async fn list_artifacts(
+        filter: ListFilter,
         logger: Logger,
         http_message_service: Arc<dyn MessageService>,
     ) -> Result<impl warp::Reply, Infallible> {
         match http_message_service
-            .get_mithril_stake_distribution_list_message(LIST_MAX_ITEMS)
+            .get_mithril_stake_distribution_list_message(LIST_MAX_ITEMS, &filter)
             .await
         {
             Ok(message) => Ok(reply::json(&message, StatusCode::OK)),
@@ -98,6 +102,7 @@ pub mod tests {
         test::request,
     };
 
+    use mithril_common::entities::Epoch;
     use mithril_common::messages::{
         MithrilStakeDistributionListItemMessage, MithrilStakeDistributionMessage,
     };
@@ -124,7 +129,7 @@ pub mod tests {
         let mut mock_http_message_service = MockMessageService::new();
         mock_http_message_service
             .expect_get_mithril_stake_distribution_list_message()
-            .return_once(|_| Ok(vec![MithrilStakeDistributionListItemMessage::dummy()]))
+            .return_once(|_, _| Ok(vec![MithrilStakeDistributionListItemMessage::dummy()]))
             .once();
         let mut dependency_manager = initialize_dependencies().await;
         dependency_manager.message_service = Arc::new(mock_http_message_service);
@@ -152,12 +157,51 @@ pub mod tests {
         .unwrap();
     }
 
+    #[tokio::test]
+    async fn test_mithril_stake_distributions_with_filter_get_ok() {
+        let mut mock_http_message_service = MockMessageService::new();
+        mock_http_message_service
+            .expect_get_mithril_stake_distribution_list_message()
+            .withf(|_, filter| {
+                filter
+                    == &ListFilter::default()
+                        .after("certificate-hash-123")
+                        .to_epoch(Epoch(12))
+            })
+            .return_once(|_, _| Ok(vec![MithrilStakeDistributionListItemMessage::dummy()]))
+            .once();
+        let mut dependency_manager = initialize_dependencies().await;
+        dependency_manager.message_service = Arc::new(mock_http_message_service);
+
+        let method = Method::GET.as_str();
+        let path = "/artifact/mithril-stake-distributions?after=certificate-hash-123&to_epoch=12";
+
+        let response = request()
+            .method(method)
+            .path(path)
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::OK,
+        )
+        .unwrap();
+    }
+
     #[tokio::test]
     async fn test_mithril_stake_distributions_get_ko() {
         let mut mock_http_message_service = MockMessageService::new();
         mock_http_message_service
             .expect_get_mithril_stake_distribution_list_message()
-            .return_once(|_| Err(HydrationError::InvalidData("invalid data".to_string()).into()))
+            .return_once(|_, _| Err(HydrationError::InvalidData("invalid data".to_string()).into()))
             .once();
         let mut dependency_manager = initialize_dependencies().await;
         dependency_manager.message_service = Arc::new(mock_http_message_service);
@@ -1,5 +1,6 @@
 use crate::http_server::routes::middlewares;
 use crate::http_server::routes::router::RouterState;
+use mithril_common::entities::ListFilter;
 use warp::Filter;
 
 pub fn routes(
@@ -17,6 +18,7 @@ fn artifact_cardano_full_immutable_snapshots(
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
     warp::path!("artifact" / "snapshots")
         .and(warp::get())
+        .and(warp::query::<ListFilter>())
         .and(middlewares::with_logger(router_state))
         .and(middlewares::with_http_message_service(router_state))
         .and_then(handlers::list_artifacts)
@@ -70,7 +72,7 @@ mod handlers {
     use std::sync::Arc;
     use warp::http::{StatusCode, Uri};
 
-    use mithril_common::StdResult;
+    use mithril_common::{entities::ListFilter, StdResult};
 
     use crate::http_server::routes::reply;
     use crate::services::{MessageService, SignedEntityService};
@@ -81,11 +83,12 @@ mod handlers {
 
     /// List Snapshot artifacts
     pub async fn list_artifacts(
+        filter: ListFilter,
         logger: Logger,
         http_message_service: Arc<dyn MessageService>,
     ) -> Result<impl warp::Reply, Infallible> {
         match http_message_service
-            .get_snapshot_list_message(LIST_MAX_ITEMS)
+            .get_snapshot_list_message(LIST_MAX_ITEMS, &filter)
             .await
         {
             Ok(message) => Ok(reply::json(&message, StatusCode::OK)),
@@ -246,7 +249,7 @@ mod tests {
         let mut mock_http_message_service = MockMessageService::new();
         mock_http_message_service
             .expect_get_snapshot_list_message()
-            .return_once(|_| Ok(vec![SnapshotListItemMessage::dummy()]))
+            .return_once(|_, _| Ok(vec![SnapshotListItemMessage::dummy()]))
             .once();
         let mut dependency_manager = initialize_dependencies().await;
         dependency_manager.message_service = Arc::new(mock_http_message_service);
@@ -279,7 +282,7 @@ mod tests {
         let mut mock_http_message_service = MockMessageService::new();
         mock_http_message_service
             .expect_get_snapshot_list_message()
-            .return_once(|_| Err(HydrationError::InvalidData("invalid data".to_string()).into()))
+            .return_once(|_, _| Err(HydrationError::InvalidData("invalid data".to_string()).into()))
             .once();
         let mut dependency_manager = initialize_dependencies().await;
         dependency_manager.message_service = Arc::new(mock_http_message_service);
@@ -307,6 +310,21 @@ mod tests {
         .unwrap();
     }
 
+    #[tokio::test]
+    async fn test_snapshots_with_unknown_cursor_returns_400() {
+        let dependency_manager = initialize_dependencies().await;
+
+        let response = request()
+            .method(Method::GET.as_str())
+            .path("/artifact/snapshots?before=unknown-snapshot-hash")
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, response.status());
+    }
+
     #[tokio::test]
     async fn test_snapshot_digest_increments_artifact_detail_total_served_since_startup_metric() {
         let method = Method::GET.as_str();
@@ -1,14 +1,27 @@
+use serde::Deserialize;
 use warp::Filter;
 
+use mithril_common::entities::{BeaconFilter, ListFilter};
+
 use crate::http_server::routes::middlewares;
 use crate::http_server::routes::router::RouterState;
 
+/// Query parameters of the certificate chain route
+#[derive(Debug, Default, Deserialize)]
+struct CertificateChainQueryParams {
+    /// Maximum number of certificates to return, capped by the server
+    depth: Option<usize>,
+}
+
 pub fn routes(
     router_state: &RouterState,
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
     certificate_pending(router_state)
         .or(certificate_certificates(router_state))
+        .or(certificate_certificates_by_epoch(router_state))
         .or(certificate_certificate_hash(router_state))
+        .or(certificate_by_signed_entity_type(router_state))
+        .or(certificate_chain(router_state))
 }
 
 /// GET /certificate-pending
@@ -31,11 +44,23 @@ fn certificate_certificates(
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
     warp::path!("certificates")
         .and(warp::get())
+        .and(warp::query::<ListFilter>())
         .and(middlewares::with_logger(router_state))
         .and(middlewares::with_http_message_service(router_state))
         .and_then(handlers::certificate_certificates)
 }
 
+/// GET /certificates/epoch/{epoch}
+fn certificate_certificates_by_epoch(
+    router_state: &RouterState,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("certificates" / "epoch" / String)
+        .and(warp::get())
+        .and(middlewares::with_logger(router_state))
+        .and(middlewares::with_http_message_service(router_state))
+        .and_then(handlers::certificate_certificates_by_epoch)
+}
+
 /// GET /certificate/{certificate_hash}
 fn certificate_certificate_hash(
     router_state: &RouterState,
@@ -48,6 +73,32 @@ fn certificate_certificate_hash(
         .and_then(handlers::certificate_certificate_hash)
 }
 
+/// GET /certificate/signed-entity-type/{signed_entity_type}
+fn certificate_by_signed_entity_type(
+    router_state: &RouterState,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("certificate" / "signed-entity-type" / String)
+        .and(warp::get())
+        .and(warp::query::<BeaconFilter>())
+        .and(middlewares::with_logger(router_state))
+        .and(middlewares::with_http_message_service(router_state))
+        .and(middlewares::with_metrics_service(router_state))
+        .and_then(handlers::certificate_by_signed_entity_type)
+}
+
+/// GET /certificate/{certificate_hash}/chain
+fn certificate_chain(
+    router_state: &RouterState,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("certificate" / String / "chain")
+        .and(warp::get())
+        .and(warp::query::<CertificateChainQueryParams>())
+        .and(warp::header::optional::<String>("accept-encoding"))
+        .and(middlewares::with_logger(router_state))
+        .and(middlewares::with_http_message_service(router_state))
+        .and_then(handlers::certificate_chain)
+}
+
 mod handlers {
     use crate::store::CertificatePendingStorer;
     use crate::MetricsService;
@@ -55,14 +106,23 @@ mod handlers {
         http_server::routes::reply, services::MessageService, ToCertificatePendingMessageAdapter,
     };
 
+    use mithril_common::entities::{
+        BeaconFilter, Epoch, ListFilter, SignedEntityTypeDiscriminants,
+    };
     use mithril_common::CardanoNetwork;
     use slog::{warn, Logger};
     use std::convert::Infallible;
+    use std::str::FromStr;
     use std::sync::Arc;
     use warp::http::StatusCode;
 
+    use super::CertificateChainQueryParams;
+
     pub const LIST_MAX_ITEMS: usize = 20;
 
+    /// Maximum number of certificates returned by the certificate chain route
+    pub const CERTIFICATE_CHAIN_MAX_DEPTH: usize = 500;
+
     /// Certificate Pending
     pub async fn certificate_pending(
         logger: Logger,
@@ -84,11 +144,12 @@ mod handlers {
 
     /// List all Certificates
     pub async fn certificate_certificates(
+        filter: ListFilter,
         logger: Logger,
         http_message_service: Arc<dyn MessageService>,
     ) -> Result<impl warp::Reply, Infallible> {
         match http_message_service
-            .get_certificate_list_message(LIST_MAX_ITEMS)
+            .get_certificate_list_message(LIST_MAX_ITEMS, &filter)
             .await
         {
             Ok(certificates) => Ok(reply::json(&certificates, StatusCode::OK)),
@@ -99,6 +160,35 @@ mod handlers {
         }
     }
 
+    /// List the Certificates of an epoch
+    pub async fn certificate_certificates_by_epoch(
+        epoch: String,
+        logger: Logger,
+        http_message_service: Arc<dyn MessageService>,
+    ) -> Result<impl warp::Reply, Infallible> {
+        let epoch = match epoch.parse::<u64>() {
+            Ok(epoch) => Epoch(epoch),
+            Err(err) => {
+                warn!(logger, "certificate_certificates_by_epoch::invalid_epoch"; "error" => ?err);
+                return Ok(reply::bad_request(
+                    "invalid_epoch".to_string(),
+                    err.to_string(),
+                ));
+            }
+        };
+
+        match http_message_service
+            .get_certificate_list_message_by_epoch(epoch)
+            .await
+        {
+            Ok(certificates) => Ok(reply::json(&certificates, StatusCode::OK)),
+            Err(err) => {
+                warn!(logger,"certificate_certificates_by_epoch::error"; "error" => ?err);
+                Ok(reply::server_error(err))
+            }
+        }
+    }
+
     /// Certificate by certificate hash
     pub async fn certificate_certificate_hash(
         certificate_hash: String,
@@ -122,6 +212,94 @@ mod handlers {
             }
         }
     }
+
+    /// Most recent Certificate of a signed entity type matching a beacon filter
+    pub async fn certificate_by_signed_entity_type(
+        signed_entity_type: String,
+        beacon_filter: BeaconFilter,
+        logger: Logger,
+        http_message_service: Arc<dyn MessageService>,
+        metrics_service: Arc<MetricsService>,
+    ) -> Result<impl warp::Reply, Infallible> {
+        metrics_service
+            .get_certificate_detail_total_served_since_startup()
+            .increment();
+
+        let signed_entity_type = match SignedEntityTypeDiscriminants::from_str(&signed_entity_type)
+        {
+            Ok(signed_entity_type) => signed_entity_type,
+            Err(err) => {
+                warn!(logger, "certificate_by_signed_entity_type::invalid_signed_entity_type"; "error" => ?err);
+                return Ok(reply::bad_request(
+                    "invalid_signed_entity_type".to_string(),
+                    err.to_string(),
+                ));
+            }
+        };
+        if let Err(err) = beacon_filter.validate_for(signed_entity_type) {
+            warn!(logger, "certificate_by_signed_entity_type::invalid_beacon_filter"; "error" => ?err);
+            return Ok(reply::bad_request(
+                "invalid_beacon_filter".to_string(),
+                err.to_string(),
+            ));
+        }
+
+        match http_message_service
+            .get_certificate_message_by_signed_entity_type(signed_entity_type, &beacon_filter)
+            .await
+        {
+            Ok(Some(certificate)) => Ok(reply::json(&certificate, StatusCode::OK)),
+            Ok(None) => Ok(reply::empty(StatusCode::NOT_FOUND)),
+            Err(err) => {
+                warn!(logger,"certificate_by_signed_entity_type::error"; "error" => ?err);
+                Ok(reply::server_error(err))
+            }
+        }
+    }
+
+    /// Certificate with the given hash followed by its ancestors
+    pub async fn certificate_chain(
+        certificate_hash: String,
+        query_params: CertificateChainQueryParams,
+        accept_encoding: Option<String>,
+        logger: Logger,
+        http_message_service: Arc<dyn MessageService>,
+    ) -> Result<impl warp::Reply, Infallible> {
+        let depth = match query_params.depth {
+            Some(0) => {
+                warn!(logger, "certificate_chain::invalid_depth");
+                return Ok(reply::bad_request(
+                    "invalid_depth".to_string(),
+                    "The depth of the certificate chain must be greater than 0".to_string(),
+                ));
+            }
+            Some(depth) => depth.min(CERTIFICATE_CHAIN_MAX_DEPTH),
+            None => CERTIFICATE_CHAIN_MAX_DEPTH,
+        };
+
+        match http_message_service
+            .get_certificate_chain_message(&certificate_hash, depth)
+            .await
+        {
+            Ok(chain) if chain.is_empty() => Ok(reply::empty(StatusCode::NOT_FOUND)),
+            Ok(chain) if accepts_gzip(accept_encoding.as_deref()) => {
+                Ok(reply::gzip_json(&chain, StatusCode::OK))
+            }
+            Ok(chain) => Ok(reply::json(&chain, StatusCode::OK)),
+            Err(err) => {
+                warn!(logger,"certificate_chain::error"; "error" => ?err);
+                Ok(reply::server_error(err))
+            }
+        }
+    }
+
+    fn accepts_gzip(accept_encoding: Option<&str>) -> bool {
+        accept_encoding.is_some_and(|encodings| {
+            encodings
+                .split(',')
+                .any(|encoding| encoding.split(';').next().unwrap_or_default().trim() == "gzip")
+        })
+    }
 }
 
 #[cfg(test)]
@@ -131,9 +309,11 @@ mod tests {
     use crate::{initialize_dependencies, services::MockMessageService};
     use anyhow::anyhow;
     use mithril_common::{
-        entities::CertificatePending,
+        entities::{CertificatePending, Epoch, SignedEntityTypeDiscriminants},
+        messages::{CertificateListItemMessage, CertificateMessage},
         test_utils::{apispec::APISpec, fake_data},
     };
+    use mockall::predicate::eq;
     use serde_json::Value::Null;
     use std::sync::Arc;
     use warp::{
@@ -282,13 +462,83 @@ mod tests {
         .unwrap();
     }
 
+    #[tokio::test]
+    async fn test_certificate_certificates_with_filter_get_ok() {
+        let mut dependency_manager = initialize_dependencies().await;
+        let mut message_service = MockMessageService::new();
+        message_service
+            .expect_get_certificate_list_message()
+            .withf(|_, filter| {
+                filter
+                    == &ListFilter::default()
+                        .before("certificate-hash-123")
+                        .from_epoch(Epoch(4))
+                        .signed_entity_type(SignedEntityTypeDiscriminants::CardanoTransactions)
+            })
+            .return_once(|_, _| Ok(vec![]))
+            .once();
+        dependency_manager.message_service = Arc::new(message_service);
+
+        let method = Method::GET.as_str();
+        let path = "/certificates?before=certificate-hash-123&from_epoch=4&signed_entity_type=CardanoTransactions";
+
+        let response = request()
+            .method(method)
+            .path(path)
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::OK,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_certificate_certificates_with_invalid_filter_returns_400() {
+        let dependency_manager = initialize_dependencies().await;
+
+        let response = request()
+            .method(Method::GET.as_str())
+            .path("/certificates?from_epoch=not-an-epoch")
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, response.status());
+    }
+
+    #[tokio::test]
+    async fn test_certificate_certificates_with_unknown_cursor_returns_400() {
+        let dependency_manager = initialize_dependencies().await;
+
+        let response = request()
+            .method(Method::GET.as_str())
+            .path("/certificates?after=unknown-certificate-hash")
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, response.status());
+    }
+
     #[tokio::test]
     async fn test_certificate_when_error_retrieving_certificates_returns_ko_500() {
         let mut dependency_manager = initialize_dependencies().await;
         let mut message_service = MockMessageService::new();
         message_service
             .expect_get_certificate_list_message()
-            .returning(|_| Err(anyhow!("an error")));
+            .returning(|_, _| Err(anyhow!("an error")));
         dependency_manager.message_service = Arc::new(message_service);
 
         let method = Method::GET.as_str();
@@ -314,6 +564,214 @@ mod tests {
         .unwrap();
     }
 
+    #[tokio::test]
+    async fn test_certificate_certificates_by_epoch_get_ok() {
+        let mut dependency_manager = initialize_dependencies().await;
+        let mut message_service = MockMessageService::new();
+        message_service
+            .expect_get_certificate_list_message_by_epoch()
+            .with(eq(Epoch(123)))
+            .return_once(|_| Ok(vec![CertificateListItemMessage::dummy()]))
+            .once();
+        dependency_manager.message_service = Arc::new(message_service);
+
+        let method = Method::GET.as_str();
+        let base_path = "/certificates/epoch";
+
+        let response = request()
+            .method(method)
+            .path(&format!("{base_path}/123"))
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            &format!("{base_path}/{{epoch}}"),
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::OK,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_certificate_certificates_by_epoch_returns_400_when_invalid_epoch() {
+        let dependency_manager = initialize_dependencies().await;
+
+        let response = request()
+            .method(Method::GET.as_str())
+            .path("/certificates/epoch/invalid-epoch")
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, response.status());
+    }
+
+    #[tokio::test]
+    async fn test_certificate_certificates_by_epoch_returns_ko_500_when_error() {
+        let mut dependency_manager = initialize_dependencies().await;
+        let mut message_service = MockMessageService::new();
+        message_service
+            .expect_get_certificate_list_message_by_epoch()
+            .return_once(|_| Err(anyhow!("an error")))
+            .once();
+        dependency_manager.message_service = Arc::new(message_service);
+
+        let method = Method::GET.as_str();
+        let base_path = "/certificates/epoch";
+
+        let response = request()
+            .method(method)
+            .path(&format!("{base_path}/123"))
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            &format!("{base_path}/{{epoch}}"),
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::INTERNAL_SERVER_ERROR,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_certificate_by_signed_entity_type_get_ok() {
+        let mut dependency_manager = initialize_dependencies().await;
+        let mut message_service = MockMessageService::new();
+        message_service
+            .expect_get_certificate_message_by_signed_entity_type()
+            .withf(|signed_entity_type, beacon_filter| {
+                signed_entity_type == &SignedEntityTypeDiscriminants::CardanoImmutableFilesFull
+                    && beacon_filter == &BeaconFilter::default().immutable_file_number(1234)
+            })
+            .return_once(|_, _| Ok(Some(CertificateMessage::dummy())))
+            .once();
+        dependency_manager.message_service = Arc::new(message_service);
+
+        let method = Method::GET.as_str();
+        let path =
+            "/certificate/signed-entity-type/CardanoImmutableFilesFull?immutable_file_number=1234";
+
+        let response = request()
+            .method(method)
+            .path(path)
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            "/certificate/signed-entity-type/{signed_entity_type}",
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::OK,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_certificate_by_signed_entity_type_returns_404_when_no_certificate() {
+        let mut dependency_manager = initialize_dependencies().await;
+        let mut message_service = MockMessageService::new();
+        message_service
+            .expect_get_certificate_message_by_signed_entity_type()
+            .return_once(|_, _| Ok(None))
+            .once();
+        dependency_manager.message_service = Arc::new(message_service);
+
+        let method = Method::GET.as_str();
+        let path = "/certificate/signed-entity-type/CardanoTransactions";
+
+        let response = request()
+            .method(method)
+            .path(path)
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            "/certificate/signed-entity-type/{signed_entity_type}",
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::NOT_FOUND,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_certificate_by_signed_entity_type_returns_400_when_invalid_signed_entity_type_or_beacon_filter(
+    ) {
+        for path in [
+            "/certificate/signed-entity-type/UnknownType",
+            "/certificate/signed-entity-type/MithrilStakeDistribution?block_number=12",
+            "/certificate/signed-entity-type/CardanoTransactions?epoch=not-an-epoch",
+        ] {
+            let dependency_manager = initialize_dependencies().await;
+
+            let response = request()
+                .method(Method::GET.as_str())
+                .path(path)
+                .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                    dependency_manager,
+                ))))
+                .await;
+
+            assert_eq!(StatusCode::BAD_REQUEST, response.status(), "path: {path}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_certificate_by_signed_entity_type_returns_ko_500_when_error() {
+        let mut dependency_manager = initialize_dependencies().await;
+        let mut message_service = MockMessageService::new();
+        message_service
+            .expect_get_certificate_message_by_signed_entity_type()
+            .return_once(|_, _| Err(anyhow!("an error")))
+            .once();
+        dependency_manager.message_service = Arc::new(message_service);
+
+        let method = Method::GET.as_str();
+        let path = "/certificate/signed-entity-type/MithrilStakeDistribution?epoch=12";
+
+        let response = request()
+            .method(method)
+            .path(path)
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            "/certificate/signed-entity-type/{signed_entity_type}",
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::INTERNAL_SERVER_ERROR,
+        )
+        .unwrap();
+    }
+
     #[tokio::test]
     async fn test_certificate_certificate_hash_increments_certificate_detail_total_served_since_startup_metric(
     ) {
@@ -432,4 +890,181 @@ mod tests {
         )
         .unwrap();
     }
+
+    #[tokio::test]
+    async fn test_certificate_chain_get_ok() {
+        let dependency_manager = initialize_dependencies().await;
+        dependency_manager
+            .certificate_repository
+            .create_certificate(fake_data::genesis_certificate("{certificate_hash}"))
+            .await
+            .expect("certificate store save should have succeeded");
+
+        let method = Method::GET.as_str();
+        let path = "/certificate/{certificate_hash}/chain";
+
+        let response = request()
+            .method(method)
+            .path(path)
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::OK,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_certificate_chain_get_ok_404() {
+        let dependency_manager = initialize_dependencies().await;
+
+        let method = Method::GET.as_str();
+        let path = "/certificate/{certificate_hash}/chain";
+
+        let response = request()
+            .method(method)
+            .path(path)
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::NOT_FOUND,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_certificate_chain_depth_is_capped_by_the_server() {
+        for (path, expected_depth) in [
+            (
+                "/certificate/whatever/chain",
+                handlers::CERTIFICATE_CHAIN_MAX_DEPTH,
+            ),
+            ("/certificate/whatever/chain?depth=3", 3),
+            (
+                "/certificate/whatever/chain?depth=100000",
+                handlers::CERTIFICATE_CHAIN_MAX_DEPTH,
+            ),
+        ] {
+            let mut dependency_manager = initialize_dependencies().await;
+            let mut message_service = MockMessageService::new();
+            message_service
+                .expect_get_certificate_chain_message()
+                .withf(move |_, depth| *depth == expected_depth)
+                .return_once(|_, _| {
+                    Ok(vec![fake_data::certificate("whatever").try_into().unwrap()])
+                })
+                .once();
+            dependency_manager.message_service = Arc::new(message_service);
+
+            let response = request()
+                .method(Method::GET.as_str())
+                .path(path)
+                .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                    dependency_manager,
+                ))))
+                .await;
+
+            assert_eq!(StatusCode::OK, response.status(), "path: {path}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_certificate_chain_returns_400_when_invalid_depth() {
+        for path in [
+            "/certificate/whatever/chain?depth=0",
+            "/certificate/whatever/chain?depth=invalid",
+        ] {
+            let dependency_manager = initialize_dependencies().await;
+
+            let response = request()
+                .method(Method::GET.as_str())
+                .path(path)
+                .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                    dependency_manager,
+                ))))
+                .await;
+
+            assert_eq!(StatusCode::BAD_REQUEST, response.status(), "path: {path}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_certificate_chain_is_compressed_when_the_client_accepts_gzip() {
+        let dependency_manager = initialize_dependencies().await;
+        dependency_manager
+            .certificate_repository
+            .create_certificate(fake_data::genesis_certificate("genesis_hash"))
+            .await
+            .expect("certificate store save should have succeeded");
+
+        let response = request()
+            .method(Method::GET.as_str())
+            .path("/certificate/genesis_hash/chain")
+            .header("accept-encoding", "deflate, gzip;q=0.9")
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!("gzip", response.headers().get("content-encoding").unwrap());
+        let chain: Vec<CertificateMessage> =
+            serde_json::from_reader(flate2::read::GzDecoder::new(response.body().as_ref()))
+                .unwrap();
+        assert_eq!(
+            vec!["genesis_hash"],
+            chain.iter().map(|c| c.hash.as_str()).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_certificate_chain_returns_ko_500_when_error() {
+        let mut dependency_manager = initialize_dependencies().await;
+        let mut message_service = MockMessageService::new();
+        message_service
+            .expect_get_certificate_chain_message()
+            .return_once(|_, _| Err(anyhow!("an error")))
+            .once();
+        dependency_manager.message_service = Arc::new(message_service);
+
+        let method = Method::GET.as_str();
+        let path = "/certificate/{certificate_hash}/chain";
+
+        let response = request()
+            .method(method)
+            .path(&path.replace("{certificate_hash}", "whatever"))
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::INTERNAL_SERVER_ERROR,
+        )
+        .unwrap();
+    }
 }
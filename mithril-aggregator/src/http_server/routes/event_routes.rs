@@ -0,0 +1,154 @@
+use serde::Deserialize;
+use std::time::Duration;
+use warp::Filter;
+
+use crate::http_server::routes::middlewares;
+use crate::http_server::routes::router::RouterState;
+
+/// Interval between two keep-alive comments sent on an idle events stream
+const EVENTS_KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Query parameters of the events route
+#[derive(Debug, Default, Deserialize)]
+struct EventsQueryParams {
+    /// Id of the last event received by the subscriber, used to resume the stream
+    last_event_id: Option<u64>,
+}
+
+pub fn routes(
+    router_state: &RouterState,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    events(router_state)
+}
+
+/// GET /events
+fn events(
+    router_state: &RouterState,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("events")
+        .and(warp::get())
+        .and(warp::header::optional::<u64>("last-event-id"))
+        .and(warp::query::<EventsQueryParams>())
+        .and(middlewares::with_aggregator_event_broadcaster(router_state))
+        .and_then(handlers::events)
+}
+
+mod handlers {
+    use futures::StreamExt;
+    use std::convert::Infallible;
+    use std::sync::Arc;
+    use warp::sse::Event;
+
+    use crate::services::{AggregatorEvent, AggregatorEventBroadcaster};
+
+    use super::{EventsQueryParams, EVENTS_KEEP_ALIVE_INTERVAL};
+
+    /// Events stream
+    pub async fn events(
+        last_event_id_header: Option<u64>,
+        query_params: EventsQueryParams,
+        aggregator_event_broadcaster: Arc<AggregatorEventBroadcaster>,
+    ) -> Result<impl warp::Reply, Infallible> {
+        let last_event_id = last_event_id_header.or(query_params.last_event_id);
+        let events = aggregator_event_broadcaster
+            .subscribe(last_event_id)
+            .map(to_server_sent_event);
+
+        Ok(warp::sse::reply(
+            warp::sse::keep_alive()
+                .interval(EVENTS_KEEP_ALIVE_INTERVAL)
+                .stream(events),
+        ))
+    }
+
+    pub(super) fn to_server_sent_event(event: AggregatorEvent) -> Result<Event, serde_json::Error> {
+        Event::default()
+            .id(event.id.to_string())
+            .event(event.message.event_name())
+            .json_data(&event.message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use warp::http::{Method, StatusCode};
+    use warp::test::request;
+    use warp::Reply;
+
+    use mithril_common::entities::Epoch;
+    use mithril_common::messages::AggregatorEventMessage;
+
+    use crate::initialize_dependencies;
+    use crate::services::AggregatorEvent;
+
+    use super::*;
+
+    fn setup_router(
+        state: RouterState,
+    ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+        let cors = warp::cors()
+            .allow_any_origin()
+            .allow_headers(vec!["content-type"])
+            .allow_methods(vec![Method::GET, Method::POST, Method::OPTIONS]);
+
+        warp::any().and(routes(&state).with(cors))
+    }
+
+    #[test]
+    fn server_sent_event_contains_the_event_id_name_and_message() {
+        let event = AggregatorEvent {
+            id: 123,
+            message: AggregatorEventMessage::EpochChanged { epoch: Epoch(5) },
+        };
+
+        let sse_event = handlers::to_server_sent_event(event).unwrap();
+
+        assert_eq!(
+            "event:epoch_changed\ndata:{\"type\":\"epoch_changed\",\"data\":{\"epoch\":5}}\nid:123\n\n",
+            sse_event.to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_events_get_ok() {
+        let method = Method::GET.as_str();
+        let path = "/events";
+        let dependency_manager = initialize_dependencies().await;
+
+        // The events stream never ends, only the response headers are checked
+        let response = request()
+            .method(method)
+            .path(path)
+            .header("last-event-id", "123")
+            .filter(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await
+            .unwrap()
+            .into_response();
+
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!(
+            "text/event-stream",
+            response.headers().get("content-type").unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_events_with_invalid_last_event_id_returns_400() {
+        let method = Method::GET.as_str();
+        let path = "/events?last_event_id=invalid";
+        let dependency_manager = initialize_dependencies().await;
+
+        let response = request()
+            .method(method)
+            .path(path)
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, response.status());
+    }
+}
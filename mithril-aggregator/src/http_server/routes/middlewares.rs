@@ -10,7 +10,10 @@ use crate::dependency_injection::EpochServiceWrapper;
 use crate::event_store::{EventMessage, TransmitterService};
 use crate::http_server::routes::http_server_child_logger;
 use crate::http_server::routes::router::{RouterConfig, RouterState};
-use crate::services::{CertifierService, MessageService, ProverService, SignedEntityService};
+use crate::services::{
+    AggregatorEventBroadcaster, BlocksProverService, CertifierService, MessageService,
+    ProverService, SignedEntityService,
+};
 use crate::store::CertificatePendingStorer;
 use crate::{
     MetricsService, SignerRegisterer, SingleSignatureAuthenticator, VerificationKeyStorer,
@@ -83,6 +86,17 @@ pub fn with_event_transmitter(
     warp::any().map(move || event_transmitter.clone())
 }
 
+/// With aggregator event broadcaster middleware
+pub fn with_aggregator_event_broadcaster(
+    router_state: &RouterState,
+) -> impl Filter<Extract = (Arc<AggregatorEventBroadcaster>,), Error = Infallible> + Clone {
+    let aggregator_event_broadcaster = router_state
+        .dependencies
+        .aggregator_event_broadcaster
+        .clone();
+    warp::any().map(move || aggregator_event_broadcaster.clone())
+}
+
 /// With certifier service middleware
 pub fn with_certifier_service(
     router_state: &RouterState,
@@ -139,6 +153,14 @@ pub fn with_prover_service(
     warp::any().map(move || prover_service.clone())
 }
 
+/// With Blocks Prover service
+pub fn with_blocks_prover_service(
+    router_state: &RouterState,
+) -> impl Filter<Extract = (Arc<dyn BlocksProverService>,), Error = Infallible> + Clone {
+    let blocks_prover_service = router_state.dependencies.blocks_prover_service.clone();
+    warp::any().map(move || blocks_prover_service.clone())
+}
+
 /// With Single Signature Authenticator
 pub fn with_single_signature_authenticator(
     router_state: &RouterState,
@@ -159,7 +181,9 @@ pub fn with_metrics_service(
 }
 
 pub mod validators {
-    use crate::http_server::validators::ProverTransactionsHashValidator;
+    use crate::http_server::validators::{
+        ProverBlocksHashValidator, ProverTransactionsHashValidator,
+    };
 
     use super::*;
 
@@ -173,4 +197,15 @@ pub mod validators {
 
         warp::any().map(move || ProverTransactionsHashValidator::new(max_hashes))
     }
+
+    /// With Prover Blocks Hash Validator
+    pub fn with_prover_blocks_hash_validator(
+        router_state: &RouterState,
+    ) -> impl Filter<Extract = (ProverBlocksHashValidator,), Error = Infallible> + Clone {
+        let max_hashes = router_state
+            .configuration
+            .cardano_transactions_prover_max_hashes_allowed_by_request;
+
+        warp::any().map(move || ProverBlocksHashValidator::new(max_hashes))
+    }
 }
@@ -1,6 +1,7 @@
 mod artifact_routes;
 mod certificate_routes;
 mod epoch_routes;
+mod event_routes;
 mod middlewares;
 mod proof_routes;
 pub(crate) mod reply;
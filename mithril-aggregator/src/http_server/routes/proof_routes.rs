@@ -25,10 +25,31 @@ impl CardanoTransactionProofQueryParams {
     }
 }
 
+#[derive(Deserialize, Serialize, Debug)]
+struct CardanoBlockProofQueryParams {
+    block_hashes: String,
+}
+
+impl CardanoBlockProofQueryParams {
+    pub fn split_blocks_hashes(&self) -> Vec<String> {
+        self.block_hashes
+            .split(',')
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    pub fn sanitize(&self) -> Vec<String> {
+        let mut block_hashes = self.split_blocks_hashes();
+        block_hashes.sort();
+        block_hashes.dedup();
+        block_hashes
+    }
+}
+
 pub fn routes(
     router_state: &RouterState,
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
-    proof_cardano_transaction(router_state)
+    proof_cardano_transaction(router_state).or(proof_cardano_block(router_state))
 }
 
 /// GET /proof/cardano-transaction
@@ -46,23 +67,46 @@ fn proof_cardano_transaction(
         .and_then(handlers::proof_cardano_transaction)
 }
 
+/// GET /proof/cardano-block
+fn proof_cardano_block(
+    router_state: &RouterState,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("proof" / "cardano-block")
+        .and(warp::get())
+        .and(warp::query::<CardanoBlockProofQueryParams>())
+        .and(middlewares::with_logger(router_state))
+        .and(middlewares::with_signed_entity_service(router_state))
+        .and(middlewares::validators::with_prover_blocks_hash_validator(
+            router_state,
+        ))
+        .and(middlewares::with_blocks_prover_service(router_state))
+        .and_then(handlers::proof_cardano_block)
+}
+
 mod handlers {
     use mithril_common::{
-        entities::CardanoTransactionsSnapshot, messages::CardanoTransactionsProofsMessage,
-        signable_builder::SignedEntity, StdResult,
+        entities::{CardanoBlocksSnapshot, CardanoTransactionsSnapshot},
+        messages::{CardanoBlocksProofsMessage, CardanoTransactionsProofsMessage},
+        signable_builder::SignedEntity,
+        StdResult,
     };
     use slog::{debug, warn, Logger};
     use std::{convert::Infallible, sync::Arc};
     use warp::http::StatusCode;
 
     use crate::{
-        http_server::{routes::reply, validators::ProverTransactionsHashValidator},
-        message_adapters::ToCardanoTransactionsProofsMessageAdapter,
-        services::{ProverService, SignedEntityService},
+        http_server::{
+            routes::reply,
+            validators::{ProverBlocksHashValidator, ProverTransactionsHashValidator},
+        },
+        message_adapters::{
+            ToCardanoBlocksProofsMessageAdapter, ToCardanoTransactionsProofsMessageAdapter,
+        },
+        services::{BlocksProverService, ProverService, SignedEntityService},
         unwrap_to_internal_server_error, MetricsService,
     };
 
-    use super::CardanoTransactionProofQueryParams;
+    use super::{CardanoBlockProofQueryParams, CardanoTransactionProofQueryParams};
 
     pub async fn proof_cardano_transaction(
         transaction_parameters: CardanoTransactionProofQueryParams,
@@ -133,6 +177,68 @@ mod handlers {
 
         Ok(message)
     }
+
+    pub async fn proof_cardano_block(
+        block_parameters: CardanoBlockProofQueryParams,
+        logger: Logger,
+        signed_entity_service: Arc<dyn SignedEntityService>,
+        validator: ProverBlocksHashValidator,
+        blocks_prover_service: Arc<dyn BlocksProverService>,
+    ) -> Result<impl warp::Reply, Infallible> {
+        let block_hashes = block_parameters.split_blocks_hashes();
+        debug!(
+            logger, ">> proof_cardano_block";
+            "block_hashes" => &block_parameters.block_hashes
+        );
+
+        if let Err(error) = validator.validate(&block_hashes) {
+            warn!(logger, "proof_cardano_block::bad_request");
+            return Ok(reply::bad_request(error.label, error.message));
+        }
+
+        let sanitized_hashes = block_parameters.sanitize();
+
+        match unwrap_to_internal_server_error!(
+            signed_entity_service
+                .get_last_cardano_blocks_snapshot()
+                .await,
+            logger => "proof_cardano_block::error"
+        ) {
+            Some(signed_entity) => {
+                let message = unwrap_to_internal_server_error!(
+                    build_blocks_response_message(
+                        blocks_prover_service,
+                        signed_entity,
+                        sanitized_hashes
+                    )
+                    .await,
+                    logger => "proof_cardano_block"
+                );
+                Ok(reply::json(&message, StatusCode::OK))
+            }
+            None => {
+                warn!(logger, "proof_cardano_block::not_found");
+                Ok(reply::empty(StatusCode::NOT_FOUND))
+            }
+        }
+    }
+
+    pub async fn build_blocks_response_message(
+        blocks_prover_service: Arc<dyn BlocksProverService>,
+        signed_entity: SignedEntity<CardanoBlocksSnapshot>,
+        block_hashes: Vec<String>,
+    ) -> StdResult<CardanoBlocksProofsMessage> {
+        let blocks_set_proofs = blocks_prover_service
+            .compute_blocks_proofs(signed_entity.artifact.block_number, block_hashes.as_slice())
+            .await?;
+        let message = ToCardanoBlocksProofsMessageAdapter::try_adapt(
+            signed_entity,
+            blocks_set_proofs,
+            block_hashes,
+        )?;
+
+        Ok(message)
+    }
 }
 
 #[cfg(test)]
@@ -147,12 +253,15 @@ mod tests {
     };
 
     use mithril_common::{
-        entities::{BlockNumber, CardanoTransactionsSetProof, CardanoTransactionsSnapshot},
+        entities::{
+            BlockNumber, CardanoBlocksSetProof, CardanoBlocksSnapshot, CardanoTransactionsSetProof,
+            CardanoTransactionsSnapshot,
+        },
         signable_builder::SignedEntity,
         test_utils::{apispec::APISpec, assert_equivalent, fake_data},
     };
 
-    use crate::services::MockProverService;
+    use crate::services::{MockBlocksProverService, MockProverService};
     use crate::{initialize_dependencies, services::MockSignedEntityService};
 
     use super::*;
@@ -424,4 +533,169 @@ mod tests {
 
         assert_equivalent(params.sanitize(), vec![tx1, tx2]);
     }
+
+    #[tokio::test]
+    async fn build_blocks_response_message_return_latest_block_number_from_artifact_beacon() {
+        let mut mock_blocks_prover_service = MockBlocksProverService::new();
+        mock_blocks_prover_service
+            .expect_compute_blocks_proofs()
+            .returning(|_, _| Ok(vec![CardanoBlocksSetProof::dummy()]));
+
+        let signed_entity = SignedEntity::<CardanoBlocksSnapshot> {
+            artifact: CardanoBlocksSnapshot::new(String::new(), BlockNumber(2309)),
+            ..SignedEntity::<CardanoBlocksSnapshot>::dummy()
+        };
+
+        let message = handlers::build_blocks_response_message(
+            Arc::new(mock_blocks_prover_service),
+            signed_entity,
+            vec![],
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(message.latest_block_number, 2309)
+    }
+
+    #[tokio::test]
+    async fn proof_cardano_block_ok() {
+        let mut dependency_manager = initialize_dependencies().await;
+        let mut mock_signed_entity_service = MockSignedEntityService::new();
+        mock_signed_entity_service
+            .expect_get_last_cardano_blocks_snapshot()
+            .returning(|| Ok(Some(SignedEntity::<CardanoBlocksSnapshot>::dummy())));
+        dependency_manager.signed_entity_service = Arc::new(mock_signed_entity_service);
+
+        let mut mock_blocks_prover_service = MockBlocksProverService::new();
+        mock_blocks_prover_service
+            .expect_compute_blocks_proofs()
+            .returning(|_, _| Ok(vec![CardanoBlocksSetProof::dummy()]));
+        dependency_manager.blocks_prover_service = Arc::new(mock_blocks_prover_service);
+
+        let method = Method::GET.as_str();
+        let path = "/proof/cardano-block";
+
+        let response = request()
+            .method(method)
+            .path(&format!(
+                "{path}?block_hashes={},{}",
+                "a".repeat(64),
+                "b".repeat(64)
+            ))
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::OK,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn proof_cardano_block_not_found() {
+        let dependency_manager = initialize_dependencies().await;
+
+        let method = Method::GET.as_str();
+        let path = "/proof/cardano-block";
+
+        let response = request()
+            .method(method)
+            .path(&format!("{path}?block_hashes={}", "a".repeat(64)))
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::NOT_FOUND,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn proof_cardano_block_ko() {
+        let mut dependency_manager = initialize_dependencies().await;
+        let mut mock_signed_entity_service = MockSignedEntityService::new();
+        mock_signed_entity_service
+            .expect_get_last_cardano_blocks_snapshot()
+            .returning(|| Err(anyhow!("Error")));
+        dependency_manager.signed_entity_service = Arc::new(mock_signed_entity_service);
+
+        let method = Method::GET.as_str();
+        let path = "/proof/cardano-block";
+
+        let response = request()
+            .method(method)
+            .path(&format!("{path}?block_hashes={}", "a".repeat(64)))
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::INTERNAL_SERVER_ERROR,
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn proof_cardano_block_return_bad_request_with_invalid_hashes() {
+        let dependency_manager = initialize_dependencies().await;
+
+        let method = Method::GET.as_str();
+        let path = "/proof/cardano-block";
+
+        let response = request()
+            .method(method)
+            .path(&format!(
+                "{path}?block_hashes=invalid%3A%2F%2Fid,,block-456"
+            ))
+            .reply(&setup_router(RouterState::new_with_dummy_config(Arc::new(
+                dependency_manager,
+            ))))
+            .await;
+
+        APISpec::verify_conformity(
+            APISpec::get_all_spec_files(),
+            method,
+            path,
+            "application/json",
+            &Null,
+            &response,
+            &StatusCode::BAD_REQUEST,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn sanitize_cardano_block_proof_query_params_remove_duplicate() {
+        let block1 = "a".repeat(64);
+        let block2 = "b".repeat(64);
+
+        let params = CardanoBlockProofQueryParams {
+            block_hashes: format!("{block1},{block2},{block2},{block1},{block2}"),
+        };
+
+        assert_equivalent(params.sanitize(), vec![block1, block2]);
+    }
 }
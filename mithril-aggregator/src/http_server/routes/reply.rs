@@ -1,5 +1,8 @@
+use std::io::Write;
 use std::path::Path;
 
+use flate2::{write::GzEncoder, Compression};
+
 use serde::Serialize;
 use warp::http::StatusCode;
 
@@ -7,6 +10,7 @@ use mithril_common::entities::{ClientError, ServerError};
 use mithril_common::StdError;
 use mithril_persistence::sqlite::error::{SqliteError, SQLITE_BUSY};
 
+use crate::services::MessageServiceError;
 use crate::tools::downcast_check;
 use crate::SignerRegistrationError;
 
@@ -20,6 +24,31 @@ where
     ))
 }
 
+/// Serialize the value as JSON and compress it with gzip, setting the `Content-Encoding` header
+pub fn gzip_json<T>(value: &T, status_code: StatusCode) -> Box<dyn warp::Reply>
+where
+    T: Serialize,
+{
+    let compress = || -> std::io::Result<Vec<u8>> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        serde_json::to_writer(&mut encoder, value)?;
+        encoder.flush()?;
+        encoder.finish()
+    };
+
+    match compress() {
+        Ok(body) => Box::new(warp::reply::with_status(
+            warp::reply::with_header(
+                warp::reply::with_header(body, "Content-Type", "application/json"),
+                "Content-Encoding",
+                "gzip",
+            ),
+            status_code,
+        )),
+        Err(err) => server_error(err),
+    }
+}
+
 pub fn empty(status_code: StatusCode) -> Box<dyn warp::Reply> {
     Box::new(warp::reply::with_status(warp::reply::reply(), status_code))
 }
@@ -34,6 +63,11 @@ pub fn gone(label: String, message: String) -> Box<dyn warp::Reply> {
 
 pub fn server_error<E: Into<StdError>>(error: E) -> Box<dyn warp::Reply> {
     let std_error: StdError = error.into();
+    if let Some(err @ MessageServiceError::UnknownListCursor(_)) =
+        std_error.downcast_ref::<MessageServiceError>()
+    {
+        return bad_request("invalid_cursor".to_string(), err.to_string());
+    }
     let status_code = {
         let mut code = StatusCode::INTERNAL_SERVER_ERROR;
 
@@ -84,6 +118,26 @@ mod tests {
 
     use super::*;
 
+    #[tokio::test]
+    async fn test_gzip_json_compress_the_serialized_value() {
+        let value = vec!["value-1".to_string(), "value-2".to_string()];
+        let response = gzip_json(&value, StatusCode::OK).into_response();
+
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!("gzip", response.headers().get("Content-Encoding").unwrap());
+        assert_eq!(
+            "application/json",
+            response.headers().get("Content-Type").unwrap()
+        );
+
+        let body = warp::hyper::body::to_bytes(response.into_body())
+            .await
+            .unwrap();
+        let decoded: Vec<String> =
+            serde_json::from_reader(flate2::read::GzDecoder::new(body.as_ref())).unwrap();
+        assert_eq!(value, decoded);
+    }
+
     #[test]
     fn test_server_error_convert_std_error_to_500_by_default() {
         let error = anyhow!("Some error");
@@ -125,4 +179,14 @@ mod tests {
 
         assert_eq!(StatusCode::SERVICE_UNAVAILABLE, response.status());
     }
+
+    #[test]
+    fn test_server_error_convert_unknown_list_cursor_to_400() {
+        let err = anyhow!(MessageServiceError::UnknownListCursor(
+            "unknown-hash".to_string()
+        ));
+        let response = server_error(err).into_response();
+
+        assert_eq!(StatusCode::BAD_REQUEST, response.status());
+    }
 }
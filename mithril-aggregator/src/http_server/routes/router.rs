@@ -1,6 +1,6 @@
 use crate::http_server::routes::{
-    artifact_routes, certificate_routes, epoch_routes, http_server_child_logger, root_routes,
-    signatures_routes, signer_routes, statistics_routes, status,
+    artifact_routes, certificate_routes, epoch_routes, event_routes, http_server_child_logger,
+    root_routes, signatures_routes, signer_routes, statistics_routes, status,
 };
 use crate::http_server::SERVER_BASE_PATH;
 use crate::tools::url_sanitizer::SanitizedUrlWithTrailingSlash;
@@ -116,6 +116,7 @@ pub fn routes(
                 .or(signer_routes::routes(&state))
                 .or(signatures_routes::routes(&state))
                 .or(epoch_routes::routes(&state))
+                .or(event_routes::routes(&state))
                 .or(statistics_routes::routes(&state))
                 .or(root_routes::routes(&state))
                 .or(status::routes(&state)),
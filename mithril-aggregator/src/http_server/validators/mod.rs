@@ -1,3 +1,5 @@
+mod prover_blocks_hash_validator;
 mod prover_transactions_hash_validator;
 
+pub use prover_blocks_hash_validator::*;
 pub use prover_transactions_hash_validator::*;
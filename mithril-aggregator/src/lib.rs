@@ -32,13 +32,15 @@ mod tools;
 
 pub use crate::artifact_builder::ArtifactBuilder;
 pub use crate::configuration::{
-    Configuration, DefaultConfiguration, ExecutionEnvironment, SnapshotUploaderType,
-    ZstandardCompressionParameters,
+    Configuration, DefaultConfiguration, ExecutionEnvironment, SnapshotUploaderDestination,
+    SnapshotUploaderType, ZstandardCompressionParameters,
 };
 pub use crate::multi_signer::{MultiSigner, MultiSignerImpl};
 pub use commands::{CommandType, MainOpts};
 pub use dependency_injection::DependencyContainer;
-pub use file_uploaders::{DumbUploader, FileUploader, LocalSnapshotUploader};
+pub use file_uploaders::{
+    DumbUploader, FileUploader, LocalSnapshotUploader, MultiFileUploader, MultiUploadFailurePolicy,
+};
 pub use message_adapters::{FromRegisterSignerAdapter, ToCertificatePendingMessageAdapter};
 pub use metrics::*;
 pub use runtime::{
@@ -1,9 +1,11 @@
 mod from_register_signature;
 mod from_register_signer;
+mod to_cardano_blocks_proof_message;
 mod to_cardano_transactions_proof_message;
 mod to_certificate_pending_message;
 
 pub use from_register_signature::FromRegisterSingleSignatureAdapter;
 pub use from_register_signer::FromRegisterSignerAdapter;
+pub use to_cardano_blocks_proof_message::ToCardanoBlocksProofsMessageAdapter;
 pub use to_cardano_transactions_proof_message::ToCardanoTransactionsProofsMessageAdapter;
 pub use to_certificate_pending_message::ToCertificatePendingMessageAdapter;
@@ -8,6 +8,7 @@ use mithril_common::entities::{
     Certificate, CertificatePending, Epoch, ProtocolMessage, SignedEntityType, Signer, TimePoint,
 };
 use mithril_common::logging::LoggerExtensions;
+use mithril_common::messages::AggregatorEventMessage;
 use mithril_common::StdResult;
 use mithril_persistence::store::StakeStorer;
 
@@ -399,11 +400,18 @@ impl AggregatorRunnerTrait for AggregatorRunner {
                 )
             })?;
 
-        if certificate.is_some() {
+        if let Some(certificate) = &certificate {
             self.dependencies
                 .metrics_service
                 .get_certificate_total_produced_since_startup()
                 .increment();
+            self.dependencies.aggregator_event_broadcaster.publish(
+                AggregatorEventMessage::CertificateCreated {
+                    certificate_hash: certificate.hash.clone(),
+                    epoch: certificate.epoch,
+                    signed_entity_type: certificate.signed_entity_type(),
+                },
+            );
         }
 
         Ok(certificate)
@@ -431,6 +439,13 @@ impl AggregatorRunnerTrait for AggregatorRunner {
                 )
             })?;
 
+        self.dependencies.aggregator_event_broadcaster.publish(
+            AggregatorEventMessage::ArtifactCreated {
+                signed_entity_type: signed_entity_type.to_owned(),
+                certificate_hash: certificate.hash.clone(),
+            },
+        );
+
         Ok(())
     }
 
@@ -494,6 +509,10 @@ impl AggregatorRunnerTrait for AggregatorRunner {
             .inform_epoch(epoch)
             .await?;
 
+        self.dependencies
+            .aggregator_event_broadcaster
+            .publish(AggregatorEventMessage::EpochChanged { epoch });
+
         Ok(())
     }
 
@@ -508,10 +527,20 @@ impl AggregatorRunnerTrait for AggregatorRunner {
         protocol_message: &ProtocolMessage,
     ) -> StdResult<OpenMessage> {
         debug!(self.logger, ">> create_open_message");
-        self.dependencies
+        let open_message = self
+            .dependencies
             .certifier_service
             .create_open_message(signed_entity_type, protocol_message)
-            .await
+            .await?;
+
+        self.dependencies.aggregator_event_broadcaster.publish(
+            AggregatorEventMessage::OpenMessageOpened {
+                signed_entity_type: open_message.signed_entity_type.clone(),
+                epoch: open_message.epoch,
+            },
+        );
+
+        Ok(open_message)
     }
 
     async fn is_open_message_outdated(
@@ -573,10 +602,12 @@ pub mod tests {
     };
     use async_trait::async_trait;
     use chrono::{DateTime, Utc};
+    use futures::StreamExt;
     use mithril_common::entities::{
         CardanoTransactionsSigningConfig, ChainPoint, Epoch, SignedEntityConfig,
         SignedEntityTypeDiscriminants,
     };
+    use mithril_common::messages::AggregatorEventMessage;
     use mithril_common::{
         chain_observer::FakeObserver,
         digesters::DumbImmutableFileObserver,
@@ -980,8 +1011,20 @@ pub mod tests {
         )));
 
         let runner = AggregatorRunner::new(Arc::new(deps));
+        let events = runner
+            .dependencies
+            .aggregator_event_broadcaster
+            .subscribe(None);
 
         runner.inform_new_epoch(current_epoch).await.unwrap();
+
+        let published_events: Vec<_> = events.take(1).collect().await;
+        assert_eq!(
+            AggregatorEventMessage::EpochChanged {
+                epoch: current_epoch
+            },
+            published_events[0].message
+        );
     }
 
     #[tokio::test]
@@ -0,0 +1,241 @@
+use futures::Stream;
+use slog::{debug, warn, Logger};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::broadcast::{self, error::RecvError, Receiver, Sender};
+
+use mithril_common::logging::LoggerExtensions;
+use mithril_common::messages::AggregatorEventMessage;
+
+/// Default number of recent events kept to resume the subscriptions
+pub const DEFAULT_EVENTS_HISTORY_CAPACITY: usize = 1000;
+
+/// An event published by the aggregator, identified by an increasing id
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AggregatorEvent {
+    /// Id of the event, strictly greater than the ids of the previously published events
+    pub id: u64,
+
+    /// Content of the event
+    pub message: AggregatorEventMessage,
+}
+
+struct EventsHistory {
+    next_event_id: u64,
+    events: VecDeque<AggregatorEvent>,
+}
+
+/// Broadcast the events produced by the aggregator runtime to the subscribers of the events
+/// stream, keeping the most recent ones so a subscriber can resume from its last received event.
+pub struct AggregatorEventBroadcaster {
+    sender: Sender<AggregatorEvent>,
+    history: Mutex<EventsHistory>,
+    history_capacity: usize,
+    logger: Logger,
+}
+
+impl AggregatorEventBroadcaster {
+    /// Create a new `AggregatorEventBroadcaster`
+    ///
+    /// The ids of the events start from the current time in microseconds so they keep increasing
+    /// across the restarts of the aggregator.
+    pub fn new(history_capacity: usize, logger: Logger) -> Self {
+        let first_event_id = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_micros() as u64)
+            .unwrap_or_default();
+
+        Self::new_with_first_event_id(first_event_id, history_capacity, logger)
+    }
+
+    fn new_with_first_event_id(
+        first_event_id: u64,
+        history_capacity: usize,
+        logger: Logger,
+    ) -> Self {
+        let (sender, _) = broadcast::channel(history_capacity.max(1));
+
+        Self {
+            sender,
+            history: Mutex::new(EventsHistory {
+                next_event_id: first_event_id,
+                events: VecDeque::with_capacity(history_capacity),
+            }),
+            history_capacity,
+            logger: logger.new_with_component_name::<Self>(),
+        }
+    }
+
+    /// Publish the given message to all the subscribers
+    pub fn publish(&self, message: AggregatorEventMessage) {
+        let mut history = self.history.lock().unwrap();
+        let event = AggregatorEvent {
+            id: history.next_event_id,
+            message,
+        };
+        debug!(
+            self.logger, "Publish event";
+            "event_id" => event.id, "event" => event.message.event_name()
+        );
+
+        history.next_event_id += 1;
+        history.events.push_back(event.clone());
+        while history.events.len() > self.history_capacity {
+            history.events.pop_front();
+        }
+
+        // An error only means that there is no subscriber at the moment
+        let _ = self.sender.send(event);
+    }
+
+    /// Subscribe to the published events
+    ///
+    /// If a `last_event_id` is given, the stream starts with the events of the history published
+    /// after this event.
+    ///
+    /// The stream ends if the subscriber lags behind the published events.
+    pub fn subscribe(&self, last_event_id: Option<u64>) -> impl Stream<Item = AggregatorEvent> {
+        let (backlog, receiver) = self.subscribe_from(last_event_id);
+        let logger = self.logger.clone();
+
+        futures::stream::unfold(
+            (backlog, receiver, logger),
+            |(mut backlog, mut receiver, logger)| async move {
+                if let Some(event) = backlog.pop_front() {
+                    return Some((event, (backlog, receiver, logger)));
+                }
+
+                match receiver.recv().await {
+                    Ok(event) => Some((event, (backlog, receiver, logger))),
+                    // End the stream so the subscriber reconnects with its last received event id
+                    // and gets the skipped events from the history.
+                    Err(RecvError::Lagged(skipped_events)) => {
+                        warn!(
+                            logger, "Subscriber lagged behind the published events, ending its stream";
+                            "skipped_events" => skipped_events
+                        );
+                        None
+                    }
+                    Err(RecvError::Closed) => None,
+                }
+            },
+        )
+    }
+
+    fn subscribe_from(
+        &self,
+        last_event_id: Option<u64>,
+    ) -> (VecDeque<AggregatorEvent>, Receiver<AggregatorEvent>) {
+        // Subscribing while holding the history lock guarantees that no event is published
+        // between the backlog snapshot and the receiver creation.
+        let history = self.history.lock().unwrap();
+        let backlog = match last_event_id {
+            Some(last_event_id) => history
+                .events
+                .iter()
+                .filter(|event| event.id > last_event_id)
+                .cloned()
+                .collect(),
+            None => VecDeque::new(),
+        };
+
+        (backlog, self.sender.subscribe())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+
+    use mithril_common::entities::Epoch;
+
+    use crate::test_tools::TestLogger;
+
+    use super::*;
+
+    fn epoch_changed(epoch: u64) -> AggregatorEventMessage {
+        AggregatorEventMessage::EpochChanged {
+            epoch: Epoch(epoch),
+        }
+    }
+
+    fn event(id: u64, epoch: u64) -> AggregatorEvent {
+        AggregatorEvent {
+            id,
+            message: epoch_changed(epoch),
+        }
+    }
+
+    #[test]
+    fn published_events_ids_start_from_the_current_time() {
+        let before = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_micros() as u64;
+        let broadcaster = AggregatorEventBroadcaster::new(10, TestLogger::stdout());
+
+        broadcaster.publish(epoch_changed(1));
+
+        let history = broadcaster.history.lock().unwrap();
+        assert!(history.events[0].id >= before);
+    }
+
+    #[tokio::test]
+    async fn subscriber_receives_events_published_after_its_subscription() {
+        let broadcaster =
+            AggregatorEventBroadcaster::new_with_first_event_id(100, 10, TestLogger::stdout());
+        broadcaster.publish(epoch_changed(1));
+
+        let stream = broadcaster.subscribe(None);
+        broadcaster.publish(epoch_changed(2));
+        broadcaster.publish(epoch_changed(3));
+
+        let events: Vec<AggregatorEvent> = stream.take(2).collect().await;
+        assert_eq!(vec![event(101, 2), event(102, 3)], events);
+    }
+
+    #[tokio::test]
+    async fn subscriber_resumes_from_its_last_event_id() {
+        let broadcaster =
+            AggregatorEventBroadcaster::new_with_first_event_id(100, 10, TestLogger::stdout());
+        broadcaster.publish(epoch_changed(1));
+        broadcaster.publish(epoch_changed(2));
+        broadcaster.publish(epoch_changed(3));
+
+        let stream = broadcaster.subscribe(Some(100));
+        broadcaster.publish(epoch_changed(4));
+
+        let events: Vec<AggregatorEvent> = stream.take(3).collect().await;
+        assert_eq!(vec![event(101, 2), event(102, 3), event(103, 4)], events);
+    }
+
+    #[tokio::test]
+    async fn history_only_keeps_the_most_recent_events() {
+        let broadcaster =
+            AggregatorEventBroadcaster::new_with_first_event_id(100, 2, TestLogger::stdout());
+        for epoch in 1..=4 {
+            broadcaster.publish(epoch_changed(epoch));
+        }
+
+        let (backlog, _receiver) = broadcaster.subscribe_from(Some(0));
+        assert_eq!(VecDeque::from(vec![event(102, 3), event(103, 4)]), backlog);
+    }
+
+    #[tokio::test]
+    async fn stream_ends_when_the_subscriber_lags_behind_and_resumes_from_the_history() {
+        let broadcaster =
+            AggregatorEventBroadcaster::new_with_first_event_id(100, 2, TestLogger::stdout());
+        let stream = broadcaster.subscribe(None);
+        for epoch in 1..=3 {
+            broadcaster.publish(epoch_changed(epoch));
+        }
+
+        let events: Vec<AggregatorEvent> = stream.collect().await;
+        assert_eq!(Vec::<AggregatorEvent>::new(), events);
+
+        let stream = broadcaster.subscribe(Some(100));
+        let events: Vec<AggregatorEvent> = stream.take(2).collect().await;
+        assert_eq!(vec![event(101, 2), event(102, 3)], events);
+    }
+}
@@ -0,0 +1,291 @@
+use async_trait::async_trait;
+use rayon::prelude::*;
+use slog::{debug, info, Logger};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+    time::Duration,
+};
+
+use mithril_common::{
+    crypto_helper::{MKMap, MKMapNode, MKTree, MKTreeStorer},
+    entities::{BlockHash, BlockNumber, BlockRange, CardanoBlock, CardanoBlocksSetProof},
+    logging::LoggerExtensions,
+    signable_builder::BlockRangeRootRetriever,
+    StdResult,
+};
+use mithril_resource_pool::ResourcePool;
+
+/// Blocks prover service is the cryptographic engine in charge of producing cryptographic proofs
+/// for block hashes
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait BlocksProverService: Sync + Send {
+    /// Compute the cryptographic proofs for the given block hashes
+    async fn compute_blocks_proofs(
+        &self,
+        up_to: BlockNumber,
+        block_hashes: &[BlockHash],
+    ) -> StdResult<Vec<CardanoBlocksSetProof>>;
+
+    /// Compute the cache
+    async fn compute_cache(&self, up_to: BlockNumber) -> StdResult<()>;
+}
+
+/// Blocks retriever
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait BlocksRetriever: Sync + Send {
+    /// Get a list of blocks by hashes using chronological order
+    async fn get_by_hashes(
+        &self,
+        hashes: Vec<BlockHash>,
+        up_to: BlockNumber,
+    ) -> StdResult<Vec<CardanoBlock>>;
+
+    /// Get by block ranges
+    async fn get_by_block_ranges(
+        &self,
+        block_ranges: Vec<BlockRange>,
+    ) -> StdResult<Vec<CardanoBlock>>;
+}
+
+/// Mithril blocks prover
+pub struct MithrilBlocksProverService<S: MKTreeStorer> {
+    blocks_retriever: Arc<dyn BlocksRetriever>,
+    block_range_root_retriever: Arc<dyn BlockRangeRootRetriever<S>>,
+    mk_map_pool: ResourcePool<MKMap<BlockRange, MKMapNode<BlockRange, S>, S>>,
+    logger: Logger,
+}
+
+impl<S: MKTreeStorer> MithrilBlocksProverService<S> {
+    /// Create a new Mithril blocks prover
+    pub fn new(
+        blocks_retriever: Arc<dyn BlocksRetriever>,
+        block_range_root_retriever: Arc<dyn BlockRangeRootRetriever<S>>,
+        mk_map_pool_size: usize,
+        logger: Logger,
+    ) -> Self {
+        Self {
+            blocks_retriever,
+            block_range_root_retriever,
+            mk_map_pool: ResourcePool::new(mk_map_pool_size, vec![]),
+            logger: logger.new_with_component_name::<Self>(),
+        }
+    }
+
+    /// Get all the blocks of the block ranges
+    async fn get_all_blocks_for_block_ranges(
+        &self,
+        block_ranges: &[BlockRange],
+    ) -> StdResult<BTreeMap<BlockRange, Vec<CardanoBlock>>> {
+        let mut block_ranges_map = BTreeMap::new();
+        let blocks = self
+            .blocks_retriever
+            .get_by_block_ranges(block_ranges.to_vec())
+            .await?;
+        for block in blocks {
+            let block_range = BlockRange::from_block_number(block.block_number);
+            let block_range_blocks: &mut Vec<_> =
+                block_ranges_map.entry(block_range).or_insert(vec![]);
+            block_range_blocks.push(block)
+        }
+
+        Ok(block_ranges_map)
+    }
+}
+
+#[async_trait]
+impl<S: MKTreeStorer> BlocksProverService for MithrilBlocksProverService<S> {
+    async fn compute_blocks_proofs(
+        &self,
+        up_to: BlockNumber,
+        block_hashes: &[BlockHash],
+    ) -> StdResult<Vec<CardanoBlocksSetProof>> {
+        // 1 - Retrieve the blocks to prove and the set of their block ranges
+        let blocks_to_prove = self
+            .blocks_retriever
+            .get_by_hashes(block_hashes.to_vec(), up_to)
+            .await?;
+        if blocks_to_prove.is_empty() {
+            return Ok(vec![]);
+        }
+        let block_ranges = blocks_to_prove
+            .iter()
+            .map(|b| BlockRange::from_block_number(b.block_number))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let block_range_blocks = self.get_all_blocks_for_block_ranges(&block_ranges).await?;
+
+        // 2 - Compute block ranges sub Merkle trees
+        let mk_trees: StdResult<Vec<(BlockRange, MKTree<S>)>> = block_range_blocks
+            .into_iter()
+            .map(|(block_range, blocks)| {
+                let mk_tree = MKTree::new(&blocks)?;
+                Ok((block_range, mk_tree))
+            })
+            .collect();
+
+        // 3 - Compute block range roots Merkle map
+        let acquire_timeout = Duration::from_millis(1000);
+        let mut mk_map = self.mk_map_pool.acquire_resource(acquire_timeout)?;
+
+        // 4 - Enrich the Merkle map with the block ranges Merkle trees
+        for (block_range, mk_tree) in mk_trees? {
+            mk_map.replace(block_range, mk_tree.into())?;
+        }
+
+        // 5 - Compute the proof for all blocks
+        if let Ok(mk_proof) = mk_map.compute_proof(&blocks_to_prove) {
+            self.mk_map_pool.give_back_resource_pool_item(mk_map)?;
+
+            Ok(vec![CardanoBlocksSetProof::new(blocks_to_prove, mk_proof)])
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    async fn compute_cache(&self, up_to: BlockNumber) -> StdResult<()> {
+        let pool_size = self.mk_map_pool.size();
+        info!(
+            self.logger, "Starts computing the Merkle map pool resource of size {pool_size}";
+            "up_to_block_number" => *up_to,
+        );
+        let mk_map_cache = self
+            .block_range_root_retriever
+            .compute_merkle_map_from_block_range_roots(up_to)
+            .await?;
+        let mk_maps_new = (1..=pool_size)
+            .into_par_iter()
+            .map(|i| {
+                debug!(
+                    self.logger,
+                    "Computing the Merkle map pool resource {i}/{pool_size}"
+                );
+                mk_map_cache.clone()
+            })
+            .collect::<Vec<MKMap<_, _, _>>>();
+        debug!(self.logger, "Draining the Merkle map pool");
+        let discriminant_new = self.mk_map_pool.discriminant()? + 1;
+        self.mk_map_pool.set_discriminant(discriminant_new)?;
+        self.mk_map_pool.clear();
+        debug!(
+            self.logger,
+            "Giving back new resources to the Merkle map pool"
+        );
+        mk_maps_new
+            .into_iter()
+            .map(|mk_map| {
+                self.mk_map_pool
+                    .give_back_resource(mk_map, discriminant_new)
+            })
+            .collect::<StdResult<Vec<_>>>()?;
+        info!(
+            self.logger,
+            "Completed computing the Merkle map pool resource of size {pool_size}"
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::crypto_helper::{MKTreeNode, MKTreeStoreInMemory};
+    use mithril_common::entities::SlotNumber;
+    use mithril_persistence::database::repository::CardanoBlockRepository;
+    use mithril_persistence::sqlite::SqliteConnectionPool;
+
+    use crate::database::test_helper::cardano_tx_db_connection;
+    use crate::test_tools::TestLogger;
+
+    use super::*;
+
+    fn build_blocks(total: u64) -> Vec<CardanoBlock> {
+        (0..total)
+            .map(|number| {
+                CardanoBlock::new(
+                    format!("block-hash-{number}"),
+                    BlockNumber(number),
+                    SlotNumber(number * 10),
+                )
+            })
+            .collect()
+    }
+
+    async fn build_prover(
+        blocks: &[CardanoBlock],
+    ) -> MithrilBlocksProverService<MKTreeStoreInMemory> {
+        let repository = Arc::new(CardanoBlockRepository::new(Arc::new(
+            SqliteConnectionPool::build_from_connection(cardano_tx_db_connection().unwrap()),
+        )));
+        repository.store_blocks(blocks.to_vec()).await.unwrap();
+        let block_range_roots: BTreeMap<BlockRange, Vec<CardanoBlock>> =
+            blocks.iter().fold(BTreeMap::new(), |mut acc, block| {
+                acc.entry(BlockRange::from_block_number(block.block_number))
+                    .or_insert(vec![])
+                    .push(block.clone());
+                acc
+            });
+        repository
+            .create_block_hash_range_roots(
+                block_range_roots
+                    .into_iter()
+                    .map(|(block_range, blocks)| {
+                        let root = MKTree::<MKTreeStoreInMemory>::new(&blocks)
+                            .unwrap()
+                            .compute_root()
+                            .unwrap();
+                        (block_range, root)
+                    })
+                    .collect::<Vec<(BlockRange, MKTreeNode)>>(),
+            )
+            .await
+            .unwrap();
+
+        MithrilBlocksProverService::new(repository.clone(), repository, 1, TestLogger::stdout())
+    }
+
+    #[tokio::test]
+    async fn compute_proof_for_a_set_of_known_blocks() {
+        let blocks = build_blocks(*BlockRange::LENGTH * 3);
+        let up_to = BlockNumber(*BlockRange::LENGTH * 3);
+        let prover = build_prover(&blocks).await;
+        prover.compute_cache(up_to).await.unwrap();
+
+        let blocks_to_prove = vec![blocks[2].clone(), blocks[blocks.len() - 1].clone()];
+        let block_hashes: Vec<BlockHash> = blocks_to_prove
+            .iter()
+            .map(|b| b.block_hash.clone())
+            .collect();
+        let proofs = prover
+            .compute_blocks_proofs(up_to, &block_hashes)
+            .await
+            .unwrap();
+
+        assert_eq!(1, proofs.len());
+        assert_eq!(blocks_to_prove, proofs[0].blocks());
+        proofs[0].verify().unwrap();
+        let expected_merkle_root =
+            CardanoBlocksSetProof::from_leaves::<MKTreeStoreInMemory>(&blocks)
+                .unwrap()
+                .merkle_root();
+        assert_eq!(expected_merkle_root, proofs[0].merkle_root());
+    }
+
+    #[tokio::test]
+    async fn cant_compute_proof_for_unknown_blocks() {
+        let blocks = build_blocks(20);
+        let up_to = BlockNumber(20);
+        let prover = build_prover(&blocks).await;
+        prover.compute_cache(up_to).await.unwrap();
+
+        let proofs = prover
+            .compute_blocks_proofs(up_to, &["unknown-hash".to_string()])
+            .await
+            .unwrap();
+
+        assert!(proofs.is_empty());
+    }
+}
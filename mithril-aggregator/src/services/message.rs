@@ -6,19 +6,21 @@ use async_trait::async_trait;
 use thiserror::Error;
 
 use mithril_common::{
-    entities::{Epoch, SignedEntityTypeDiscriminants},
+    entities::{BeaconFilter, Epoch, ListFilter, SignedEntityTypeDiscriminants},
     messages::{
         CardanoDatabaseDigestListItemMessage, CardanoDatabaseDigestListMessage,
         CardanoDatabaseSnapshotListMessage, CardanoDatabaseSnapshotMessage,
         CardanoStakeDistributionListMessage, CardanoStakeDistributionMessage,
         CardanoTransactionSnapshotListMessage, CardanoTransactionSnapshotMessage,
-        CertificateListMessage, CertificateMessage, MithrilStakeDistributionListMessage,
-        MithrilStakeDistributionMessage, SnapshotListMessage, SnapshotMessage,
+        CertificateChainMessage, CertificateListMessage, CertificateMessage,
+        MithrilStakeDistributionListMessage, MithrilStakeDistributionMessage, SnapshotListMessage,
+        SnapshotMessage,
     },
     StdResult,
 };
 
 use crate::{
+    database::record::CertificateRecord,
     database::repository::{CertificateRepository, SignedEntityStorer},
     ImmutableFileDigestMapper,
 };
@@ -29,6 +31,10 @@ pub enum MessageServiceError {
     /// There is no current PendingCertificate
     #[error("There is no current pending certificate.")]
     PendingCertificateDoesNotExist,
+
+    /// The cursor of a list request does not identify any item of the list
+    #[error("Unknown list cursor: '{0}'.")]
+    UnknownListCursor(String),
 }
 /// HTTP Message service trait.
 #[cfg_attr(test, mockall::automock)]
@@ -40,9 +46,35 @@ pub trait MessageService: Sync + Send {
         certificate_hash: &str,
     ) -> StdResult<Option<CertificateMessage>>;
 
-    /// Return the message representation of the last N certificates.
-    async fn get_certificate_list_message(&self, limit: usize)
-        -> StdResult<CertificateListMessage>;
+    /// Return the message representation of the last N certificates matching the given cursor
+    /// and filters.
+    async fn get_certificate_list_message(
+        &self,
+        limit: usize,
+        filter: &ListFilter,
+    ) -> StdResult<CertificateListMessage>;
+
+    /// Return the message representation of the most recent certificate of the given signed
+    /// entity type whose beacon matches the given filter, if any.
+    async fn get_certificate_message_by_signed_entity_type(
+        &self,
+        signed_entity_type: SignedEntityTypeDiscriminants,
+        beacon_filter: &BeaconFilter,
+    ) -> StdResult<Option<CertificateMessage>>;
+
+    /// Return the message representation of the certificates of the given epoch.
+    async fn get_certificate_list_message_by_epoch(
+        &self,
+        epoch: Epoch,
+    ) -> StdResult<CertificateListMessage>;
+
+    /// Return the message representation of the certificate with the given hash followed by its
+    /// ancestors, up to the first genesis certificate or until `max_depth` certificates.
+    async fn get_certificate_chain_message(
+        &self,
+        certificate_hash: &str,
+        max_depth: usize,
+    ) -> StdResult<CertificateChainMessage>;
 
     /// Return the information regarding the given snapshot.
     async fn get_snapshot_message(
@@ -50,9 +82,13 @@ pub trait MessageService: Sync + Send {
         signed_entity_id: &str,
     ) -> StdResult<Option<SnapshotMessage>>;
 
-    /// Return the list of the last signed snapshots. The limit of the list is
-    /// passed as argument.
-    async fn get_snapshot_list_message(&self, limit: usize) -> StdResult<SnapshotListMessage>;
+    /// Return the list of the last signed snapshots matching the given cursor and filters.
+    /// The limit of the list is passed as argument.
+    async fn get_snapshot_list_message(
+        &self,
+        limit: usize,
+        filter: &ListFilter,
+    ) -> StdResult<SnapshotListMessage>;
 
     /// Return the information regarding the Cardano database for the given identifier.
     async fn get_cardano_database_message(
@@ -60,10 +96,12 @@ pub trait MessageService: Sync + Send {
         signed_entity_id: &str,
     ) -> StdResult<Option<CardanoDatabaseSnapshotMessage>>;
 
-    /// Return the list of the last Cardano database message.
+    /// Return the list of the last Cardano database message matching the given cursor
+    /// and filters.
     async fn get_cardano_database_list_message(
         &self,
         limit: usize,
+        filter: &ListFilter,
     ) -> StdResult<CardanoDatabaseSnapshotListMessage>;
 
     /// Return the list of the Cardano database immutable file names and their digests.
@@ -77,10 +115,12 @@ pub trait MessageService: Sync + Send {
         signed_entity_id: &str,
     ) -> StdResult<Option<MithrilStakeDistributionMessage>>;
 
-    /// Return the list of the last Mithril stake distributions message.
+    /// Return the list of the last Mithril stake distributions message matching the given cursor
+    /// and filters.
     async fn get_mithril_stake_distribution_list_message(
         &self,
         limit: usize,
+        filter: &ListFilter,
     ) -> StdResult<MithrilStakeDistributionListMessage>;
 
     /// Return the information regarding the Cardano transactions set for the given identifier.
@@ -89,10 +129,12 @@ pub trait MessageService: Sync + Send {
         signed_entity_id: &str,
     ) -> StdResult<Option<CardanoTransactionSnapshotMessage>>;
 
-    /// Return the list of the last Cardano transactions set message.
+    /// Return the list of the last Cardano transactions set message matching the given cursor
+    /// and filters.
     async fn get_cardano_transaction_list_message(
         &self,
         limit: usize,
+        filter: &ListFilter,
     ) -> StdResult<CardanoTransactionSnapshotListMessage>;
 
     /// Return the information regarding the Cardano stake distribution for the given identifier.
@@ -107,10 +149,12 @@ pub trait MessageService: Sync + Send {
         epoch: Epoch,
     ) -> StdResult<Option<CardanoStakeDistributionMessage>>;
 
-    /// Return the list of the last Cardano stake distributions message.
+    /// Return the list of the last Cardano stake distributions message matching the given cursor
+    /// and filters.
     async fn get_cardano_stake_distribution_list_message(
         &self,
         limit: usize,
+        filter: &ListFilter,
     ) -> StdResult<CardanoStakeDistributionListMessage>;
 }
 
@@ -134,6 +178,49 @@ impl MithrilMessageService {
             immutable_file_digest_mapper,
         }
     }
+
+    /// Check that the cursors of the given filter identify a certificate.
+    async fn check_certificate_cursors(&self, filter: &ListFilter) -> StdResult<()> {
+        for cursor in filter.before.iter().chain(filter.after.iter()) {
+            if self
+                .certificate_repository
+                .get_certificate::<CertificateRecord>(cursor)
+                .await?
+                .is_none()
+            {
+                return Err(MessageServiceError::UnknownListCursor(cursor.to_owned()).into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check that the cursors of the given filter identify a signed entity of the given type,
+    /// either by its id or by the hash of its certificate.
+    async fn check_signed_entity_cursors(
+        &self,
+        signed_entity_type_id: &SignedEntityTypeDiscriminants,
+        filter: &ListFilter,
+    ) -> StdResult<()> {
+        for cursor in filter.before.iter().chain(filter.after.iter()) {
+            let signed_entity = match self.signed_entity_storer.get_signed_entity(cursor).await? {
+                Some(signed_entity) => Some(signed_entity),
+                None => {
+                    self.signed_entity_storer
+                        .get_signed_entity_by_certificate_id(cursor)
+                        .await?
+                }
+            };
+            if !signed_entity.is_some_and(|signed_entity| {
+                SignedEntityTypeDiscriminants::from(&signed_entity.signed_entity_type)
+                    == *signed_entity_type_id
+            }) {
+                return Err(MessageServiceError::UnknownListCursor(cursor.to_owned()).into());
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -150,9 +237,40 @@ impl MessageService for MithrilMessageService {
     async fn get_certificate_list_message(
         &self,
         limit: usize,
+        filter: &ListFilter,
+    ) -> StdResult<CertificateListMessage> {
+        self.check_certificate_cursors(filter).await?;
+        self.certificate_repository
+            .get_certificates_with_filter(filter, limit)
+            .await
+    }
+
+    async fn get_certificate_message_by_signed_entity_type(
+        &self,
+        signed_entity_type: SignedEntityTypeDiscriminants,
+        beacon_filter: &BeaconFilter,
+    ) -> StdResult<Option<CertificateMessage>> {
+        self.certificate_repository
+            .get_latest_certificate_by_signed_entity_type(signed_entity_type, beacon_filter)
+            .await
+    }
+
+    async fn get_certificate_list_message_by_epoch(
+        &self,
+        epoch: Epoch,
     ) -> StdResult<CertificateListMessage> {
         self.certificate_repository
-            .get_latest_certificates(limit)
+            .get_certificates_by_epoch(epoch)
+            .await
+    }
+
+    async fn get_certificate_chain_message(
+        &self,
+        certificate_hash: &str,
+        max_depth: usize,
+    ) -> StdResult<CertificateChainMessage> {
+        self.certificate_repository
+            .get_certificate_chain(certificate_hash, max_depth)
             .await
     }
 
@@ -168,11 +286,17 @@ impl MessageService for MithrilMessageService {
         signed_entity.map(|s| s.try_into()).transpose()
     }
 
-    async fn get_snapshot_list_message(&self, limit: usize) -> StdResult<SnapshotListMessage> {
+    async fn get_snapshot_list_message(
+        &self,
+        limit: usize,
+        filter: &ListFilter,
+    ) -> StdResult<SnapshotListMessage> {
         let signed_entity_type_id = SignedEntityTypeDiscriminants::CardanoImmutableFilesFull;
+        self.check_signed_entity_cursors(&signed_entity_type_id, filter)
+            .await?;
         let entities = self
             .signed_entity_storer
-            .get_last_signed_entities_by_type(&signed_entity_type_id, limit)
+            .get_signed_entities_by_type_with_filter(&signed_entity_type_id, filter, limit)
             .await?;
 
         entities.into_iter().map(|i| i.try_into()).collect()
@@ -193,11 +317,14 @@ impl MessageService for MithrilMessageService {
     async fn get_cardano_database_list_message(
         &self,
         limit: usize,
+        filter: &ListFilter,
     ) -> StdResult<CardanoDatabaseSnapshotListMessage> {
         let signed_entity_type_id = SignedEntityTypeDiscriminants::CardanoDatabase;
+        self.check_signed_entity_cursors(&signed_entity_type_id, filter)
+            .await?;
         let entities = self
             .signed_entity_storer
-            .get_last_signed_entities_by_type(&signed_entity_type_id, limit)
+            .get_signed_entities_by_type_with_filter(&signed_entity_type_id, filter, limit)
             .await?;
 
         entities.into_iter().map(|i| i.try_into()).collect()
@@ -235,11 +362,14 @@ impl MessageService for MithrilMessageService {
     async fn get_mithril_stake_distribution_list_message(
         &self,
         limit: usize,
+        filter: &ListFilter,
     ) -> StdResult<MithrilStakeDistributionListMessage> {
         let signed_entity_type_id = SignedEntityTypeDiscriminants::MithrilStakeDistribution;
+        self.check_signed_entity_cursors(&signed_entity_type_id, filter)
+            .await?;
         let entities = self
             .signed_entity_storer
-            .get_last_signed_entities_by_type(&signed_entity_type_id, limit)
+            .get_signed_entities_by_type_with_filter(&signed_entity_type_id, filter, limit)
             .await?;
 
         entities.into_iter().map(|i| i.try_into()).collect()
@@ -260,11 +390,14 @@ impl MessageService for MithrilMessageService {
     async fn get_cardano_transaction_list_message(
         &self,
         limit: usize,
+        filter: &ListFilter,
     ) -> StdResult<CardanoTransactionSnapshotListMessage> {
         let signed_entity_type_id = SignedEntityTypeDiscriminants::CardanoTransactions;
+        self.check_signed_entity_cursors(&signed_entity_type_id, filter)
+            .await?;
         let entities = self
             .signed_entity_storer
-            .get_last_signed_entities_by_type(&signed_entity_type_id, limit)
+            .get_signed_entities_by_type_with_filter(&signed_entity_type_id, filter, limit)
             .await?;
 
         entities.into_iter().map(|i| i.try_into()).collect()
@@ -297,11 +430,14 @@ impl MessageService for MithrilMessageService {
     async fn get_cardano_stake_distribution_list_message(
         &self,
         limit: usize,
+        filter: &ListFilter,
     ) -> StdResult<CardanoStakeDistributionListMessage> {
         let signed_entity_type_id = SignedEntityTypeDiscriminants::CardanoStakeDistribution;
+        self.check_signed_entity_cursors(&signed_entity_type_id, filter)
+            .await?;
         let entities = self
             .signed_entity_storer
-            .get_last_signed_entities_by_type(&signed_entity_type_id, limit)
+            .get_signed_entities_by_type_with_filter(&signed_entity_type_id, filter, limit)
             .await?;
 
         entities.into_iter().map(|i| i.try_into()).collect()
@@ -310,8 +446,10 @@ impl MessageService for MithrilMessageService {
 
 #[cfg(test)]
 mod tests {
-    use mithril_common::entities::{BlockNumber, Certificate, SignedEntityType};
-    use mithril_common::test_utils::fake_data;
+    use mithril_common::entities::{
+        BlockNumber, Certificate, CertificateSignature, SignedEntityType,
+    };
+    use mithril_common::test_utils::{fake_data, fake_keys};
 
     use crate::database::record::SignedEntityRecord;
     use crate::database::repository::{ImmutableFileDigestRepository, SignedEntityStore};
@@ -436,11 +574,170 @@ mod tests {
                 .build()
                 .await;
 
-            let certificate_messages = service.get_certificate_list_message(5).await.unwrap();
+            let certificate_messages = service
+                .get_certificate_list_message(5, &ListFilter::default())
+                .await
+                .unwrap();
 
             assert_eq!(2, certificate_messages.len());
             assert_eq!(last_certificate_hash, certificate_messages[0].hash);
         }
+
+        #[tokio::test]
+        async fn get_last_certificates_with_filter() {
+            let certificates = [
+                fake_data::genesis_certificate("certificate_1"),
+                fake_data::genesis_certificate("certificate_2"),
+                fake_data::genesis_certificate("certificate_3"),
+            ];
+            let service = MessageServiceBuilder::new()
+                .with_certificates(&certificates)
+                .build()
+                .await;
+
+            let certificate_messages = service
+                .get_certificate_list_message(5, &ListFilter::default().before("certificate_3"))
+                .await
+                .unwrap();
+
+            assert_eq!(
+                vec!["certificate_2", "certificate_1"],
+                certificate_messages
+                    .iter()
+                    .map(|c| c.hash.as_str())
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        #[tokio::test]
+        async fn get_last_certificates_with_an_unknown_cursor_fails() {
+            let certificates = [fake_data::genesis_certificate("certificate_1")];
+            let service = MessageServiceBuilder::new()
+                .with_certificates(&certificates)
+                .build()
+                .await;
+
+            let error = service
+                .get_certificate_list_message(5, &ListFilter::default().after("unknown_hash"))
+                .await
+                .expect_err("Listing with an unknown cursor should fail");
+
+            assert!(matches!(
+                error.downcast_ref::<MessageServiceError>(),
+                Some(MessageServiceError::UnknownListCursor(cursor)) if cursor == "unknown_hash"
+            ));
+        }
+
+        #[tokio::test]
+        async fn get_certificate_by_signed_entity_type() {
+            let beacon = fake_data::beacon();
+            let certificates = [
+                fake_data::genesis_certificate("genesis"),
+                fake_data::certificate("certificate_1"),
+                Certificate {
+                    signature: CertificateSignature::MultiSignature(
+                        SignedEntityType::CardanoTransactions(beacon.epoch, BlockNumber(50)),
+                        fake_keys::multi_signature()[0].try_into().unwrap(),
+                    ),
+                    ..fake_data::certificate("certificate_2")
+                },
+            ];
+            let service = MessageServiceBuilder::new()
+                .with_certificates(&certificates)
+                .build()
+                .await;
+
+            let certificate_message = service
+                .get_certificate_message_by_signed_entity_type(
+                    SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
+                    &BeaconFilter::default().immutable_file_number(beacon.immutable_file_number),
+                )
+                .await
+                .unwrap()
+                .expect("There should be a certificate.");
+            assert_eq!("certificate_1", certificate_message.hash);
+
+            let certificate_message = service
+                .get_certificate_message_by_signed_entity_type(
+                    SignedEntityTypeDiscriminants::CardanoTransactions,
+                    &BeaconFilter::default().block_number(BlockNumber(51)),
+                )
+                .await
+                .unwrap();
+            assert!(certificate_message.is_none());
+        }
+
+        #[tokio::test]
+        async fn get_certificates_by_epoch() {
+            let certificates = [
+                fake_data::certificate("certificate_1"),
+                Certificate {
+                    epoch: Epoch(999),
+                    ..fake_data::certificate("certificate_2")
+                },
+                fake_data::certificate("certificate_3"),
+            ];
+            let service = MessageServiceBuilder::new()
+                .with_certificates(&certificates)
+                .build()
+                .await;
+
+            let certificate_messages = service
+                .get_certificate_list_message_by_epoch(certificates[0].epoch)
+                .await
+                .unwrap();
+
+            assert_eq!(
+                vec!["certificate_3", "certificate_1"],
+                certificate_messages
+                    .iter()
+                    .map(|c| c.hash.as_str())
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        #[tokio::test]
+        async fn get_certificate_chain() {
+            let certificates = [
+                fake_data::genesis_certificate("genesis"),
+                Certificate {
+                    previous_hash: "genesis".to_string(),
+                    ..fake_data::certificate("certificate_1")
+                },
+                Certificate {
+                    previous_hash: "certificate_1".to_string(),
+                    ..fake_data::certificate("certificate_2")
+                },
+            ];
+            let service = MessageServiceBuilder::new()
+                .with_certificates(&certificates)
+                .build()
+                .await;
+
+            let chain_message = service
+                .get_certificate_chain_message("certificate_2", 10)
+                .await
+                .unwrap();
+            assert_eq!(
+                vec!["certificate_2", "certificate_1", "genesis"],
+                chain_message
+                    .iter()
+                    .map(|c| c.hash.as_str())
+                    .collect::<Vec<_>>()
+            );
+
+            let chain_message = service
+                .get_certificate_chain_message("certificate_2", 2)
+                .await
+                .unwrap();
+            assert_eq!(
+                vec!["certificate_2", "certificate_1"],
+                chain_message
+                    .iter()
+                    .map(|c| c.hash.as_str())
+                    .collect::<Vec<_>>()
+            );
+        }
     }
 
     mod snapshot {
@@ -507,12 +804,49 @@ mod tests {
                 .build()
                 .await;
 
-            let response = service.get_snapshot_list_message(0).await.unwrap();
+            let response = service
+                .get_snapshot_list_message(0, &ListFilter::default())
+                .await
+                .unwrap();
             assert!(response.is_empty());
 
-            let response = service.get_snapshot_list_message(3).await.unwrap();
+            let response = service
+                .get_snapshot_list_message(3, &ListFilter::default())
+                .await
+                .unwrap();
             assert_eq!(message, response);
         }
+
+        #[tokio::test]
+        async fn get_snapshot_list_message_with_a_cursor_of_another_signed_entity_type_fails() {
+            let record = SignedEntityRecord {
+                signed_entity_id: "signed_entity_id-1".to_string(),
+                signed_entity_type: SignedEntityType::CardanoDatabase(fake_data::beacon()),
+                certificate_id: "cert_id-1".to_string(),
+                artifact: serde_json::to_string(&fake_data::cardano_database_snapshots(1)[0])
+                    .unwrap(),
+                created_at: Default::default(),
+            };
+            let service = MessageServiceBuilder::new()
+                .with_signed_entity_records(&[record])
+                .build()
+                .await;
+
+            for cursor in ["signed_entity_id-1", "cert_id-1", "unknown"] {
+                let error = service
+                    .get_snapshot_list_message(3, &ListFilter::default().before(cursor))
+                    .await
+                    .expect_err("Listing with a cursor of another list should fail");
+
+                assert!(
+                    matches!(
+                        error.downcast_ref::<MessageServiceError>(),
+                        Some(MessageServiceError::UnknownListCursor(_))
+                    ),
+                    "unexpected error for cursor '{cursor}': {error:?}"
+                );
+            }
+        }
     }
 
     mod cardano_database {
@@ -584,10 +918,16 @@ mod tests {
                 .build()
                 .await;
 
-            let response = service.get_cardano_database_list_message(0).await.unwrap();
+            let response = service
+                .get_cardano_database_list_message(0, &ListFilter::default())
+                .await
+                .unwrap();
             assert!(response.is_empty());
 
-            let response = service.get_cardano_database_list_message(3).await.unwrap();
+            let response = service
+                .get_cardano_database_list_message(3, &ListFilter::default())
+                .await
+                .unwrap();
             assert_eq!(message, response);
         }
 
@@ -690,13 +1030,13 @@ mod tests {
                 .await;
 
             let response = service
-                .get_mithril_stake_distribution_list_message(0)
+                .get_mithril_stake_distribution_list_message(0, &ListFilter::default())
                 .await
                 .unwrap();
             assert!(response.is_empty());
 
             let response = service
-                .get_mithril_stake_distribution_list_message(3)
+                .get_mithril_stake_distribution_list_message(3, &ListFilter::default())
                 .await
                 .unwrap();
             assert_eq!(message, response);
@@ -781,13 +1121,13 @@ mod tests {
                 .await;
 
             let response = service
-                .get_cardano_transaction_list_message(0)
+                .get_cardano_transaction_list_message(0, &ListFilter::default())
                 .await
                 .unwrap();
             assert!(response.is_empty());
 
             let response = service
-                .get_cardano_transaction_list_message(3)
+                .get_cardano_transaction_list_message(3, &ListFilter::default())
                 .await
                 .unwrap();
             assert_eq!(message, response);
@@ -904,13 +1244,13 @@ mod tests {
                 .await;
 
             let response = service
-                .get_cardano_stake_distribution_list_message(0)
+                .get_cardano_stake_distribution_list_message(0, &ListFilter::default())
                 .await
                 .unwrap();
             assert!(response.is_empty());
 
             let response = service
-                .get_cardano_stake_distribution_list_message(3)
+                .get_cardano_stake_distribution_list_message(3, &ListFilter::default())
                 .await
                 .unwrap();
             assert_eq!(message, response);
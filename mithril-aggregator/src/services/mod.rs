@@ -6,9 +6,12 @@
 //! * StakeEntity: fetches Cardano stake distribution information
 //! * Certifier: registers signers and create certificates once ready
 //! * SignedEntity: provides information about signed entities.
+//! * AggregatorEventBroadcaster: pushes the events of the runtime to the events stream subscribers.
 //!
 //! Each service is defined by a public API (a trait) that is used in the controllers (runtimes).
 
+mod aggregator_event_broadcaster;
+mod cardano_blocks_prover;
 mod cardano_transactions_importer;
 mod certifier;
 mod epoch_service;
@@ -21,6 +24,8 @@ mod stake_distribution;
 mod upkeep;
 mod usage_reporter;
 
+pub use aggregator_event_broadcaster::*;
+pub use cardano_blocks_prover::*;
 pub use cardano_transactions_importer::*;
 pub use certifier::*;
 pub use epoch_service::*;
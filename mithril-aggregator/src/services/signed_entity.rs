@@ -11,9 +11,9 @@ use tokio::task::JoinHandle;
 
 use mithril_common::{
     entities::{
-        BlockNumber, CardanoDatabaseSnapshot, CardanoDbBeacon, CardanoStakeDistribution,
-        CardanoTransactionsSnapshot, Certificate, Epoch, MithrilStakeDistribution,
-        SignedEntityType, SignedEntityTypeDiscriminants, Snapshot,
+        BlockNumber, CardanoBlocksSnapshot, CardanoDatabaseSnapshot, CardanoDbBeacon,
+        CardanoStakeDistribution, CardanoTransactionsSnapshot, Certificate, Epoch,
+        MithrilStakeDistribution, SignedEntityType, SignedEntityTypeDiscriminants, Snapshot,
     },
     logging::LoggerExtensions,
     signable_builder::{Artifact, SignedEntity},
@@ -80,6 +80,11 @@ pub trait SignedEntityService: Send + Sync {
         &self,
     ) -> StdResult<Option<SignedEntity<CardanoTransactionsSnapshot>>>;
 
+    /// Return the last signed Cardano Blocks Snapshot.
+    async fn get_last_cardano_blocks_snapshot(
+        &self,
+    ) -> StdResult<Option<SignedEntity<CardanoBlocksSnapshot>>>;
+
     /// Return a list of signed Cardano stake distribution ordered by creation
     /// date descending.
     async fn get_last_signed_cardano_stake_distributions(
@@ -103,6 +108,7 @@ pub struct MithrilSignedEntityService {
         Arc<dyn ArtifactBuilder<Epoch, CardanoStakeDistribution>>,
     cardano_database_artifact_builder:
         Arc<dyn ArtifactBuilder<CardanoDbBeacon, CardanoDatabaseSnapshot>>,
+    cardano_blocks_artifact_builder: Arc<dyn ArtifactBuilder<BlockNumber, CardanoBlocksSnapshot>>,
     metrics_service: Arc<MetricsService>,
     logger: Logger,
 }
@@ -119,6 +125,7 @@ pub struct SignedEntityServiceArtifactsDependencies {
         Arc<dyn ArtifactBuilder<Epoch, CardanoStakeDistribution>>,
     cardano_database_artifact_builder:
         Arc<dyn ArtifactBuilder<CardanoDbBeacon, CardanoDatabaseSnapshot>>,
+    cardano_blocks_artifact_builder: Arc<dyn ArtifactBuilder<BlockNumber, CardanoBlocksSnapshot>>,
 }
 
 impl SignedEntityServiceArtifactsDependencies {
@@ -139,6 +146,9 @@ impl SignedEntityServiceArtifactsDependencies {
         cardano_database_artifact_builder: Arc<
             dyn ArtifactBuilder<CardanoDbBeacon, CardanoDatabaseSnapshot>,
         >,
+        cardano_blocks_artifact_builder: Arc<
+            dyn ArtifactBuilder<BlockNumber, CardanoBlocksSnapshot>,
+        >,
     ) -> Self {
         Self {
             mithril_stake_distribution_artifact_builder,
@@ -146,6 +156,7 @@ impl SignedEntityServiceArtifactsDependencies {
             cardano_transactions_artifact_builder,
             cardano_stake_distribution_artifact_builder,
             cardano_database_artifact_builder,
+            cardano_blocks_artifact_builder,
         }
     }
 }
@@ -170,6 +181,7 @@ impl MithrilSignedEntityService {
             cardano_stake_distribution_artifact_builder: dependencies
                 .cardano_stake_distribution_artifact_builder,
             cardano_database_artifact_builder: dependencies.cardano_database_artifact_builder,
+            cardano_blocks_artifact_builder: dependencies.cardano_blocks_artifact_builder,
             signed_entity_type_lock,
             metrics_service,
             logger: logger.new_with_component_name::<Self>(),
@@ -278,6 +290,16 @@ impl MithrilSignedEntityService {
                         )
                     })?
             )),
+            SignedEntityType::CardanoBlocks(_epoch, block_number) => Ok(Arc::new(
+                self.cardano_blocks_artifact_builder
+                    .compute_artifact(block_number, certificate)
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Signed Entity Service can not compute artifact for entity type: '{signed_entity_type}'"
+                        )
+                    })?,
+            )),
         }
     }
 
@@ -318,6 +340,9 @@ impl MithrilSignedEntityService {
             SignedEntityType::CardanoDatabase(_) => {
                 metrics.get_artifact_cardano_database_total_produced_since_startup()
             }
+            SignedEntityType::CardanoBlocks(_, _) => {
+                metrics.get_artifact_cardano_blocks_total_produced_since_startup()
+            }
         };
 
         metric_counter.increment();
@@ -490,6 +515,19 @@ impl SignedEntityService for MithrilSignedEntityService {
         }
     }
 
+    async fn get_last_cardano_blocks_snapshot(
+        &self,
+    ) -> StdResult<Option<SignedEntity<CardanoBlocksSnapshot>>> {
+        let mut signed_entities_records = self
+            .get_last_signed_entities(1, &SignedEntityTypeDiscriminants::CardanoBlocks)
+            .await?;
+
+        match signed_entities_records.pop() {
+            Some(record) => Ok(Some(record.try_into()?)),
+            None => Ok(None),
+        }
+    }
+
     async fn get_last_signed_cardano_stake_distributions(
         &self,
         total: usize,
@@ -566,6 +604,8 @@ mod tests {
             MockArtifactBuilder<Epoch, CardanoStakeDistribution>,
         mock_cardano_database_artifact_builder:
             MockArtifactBuilder<CardanoDbBeacon, CardanoDatabaseSnapshot>,
+        mock_cardano_blocks_artifact_builder:
+            MockArtifactBuilder<BlockNumber, CardanoBlocksSnapshot>,
     }
 
     impl MockDependencyInjector {
@@ -592,6 +632,10 @@ mod tests {
                     CardanoDbBeacon,
                     CardanoDatabaseSnapshot,
                 >::new(),
+                mock_cardano_blocks_artifact_builder: MockArtifactBuilder::<
+                    BlockNumber,
+                    CardanoBlocksSnapshot,
+                >::new(),
             }
         }
 
@@ -602,6 +646,7 @@ mod tests {
                 Arc::new(self.mock_cardano_transactions_artifact_builder),
                 Arc::new(self.mock_cardano_stake_distribution_artifact_builder),
                 Arc::new(self.mock_cardano_database_artifact_builder),
+                Arc::new(self.mock_cardano_blocks_artifact_builder),
             );
             MithrilSignedEntityService::new(
                 Arc::new(self.mock_signed_entity_storer),
@@ -659,6 +704,7 @@ mod tests {
                 Arc::new(self.mock_cardano_transactions_artifact_builder),
                 Arc::new(self.mock_cardano_stake_distribution_artifact_builder),
                 Arc::new(self.mock_cardano_database_artifact_builder),
+                Arc::new(self.mock_cardano_blocks_artifact_builder),
             );
             MithrilSignedEntityService::new(
                 Arc::new(self.mock_signed_entity_storer),
@@ -723,6 +769,9 @@ mod tests {
             SignedEntityType::CardanoDatabase(_) => metrics_service
                 .get_artifact_cardano_database_total_produced_since_startup()
                 .get(),
+            SignedEntityType::CardanoBlocks(_, _) => metrics_service
+                .get_artifact_cardano_blocks_total_produced_since_startup()
+                .get(),
         }
     }
 
@@ -883,6 +932,47 @@ mod tests {
         .await;
     }
 
+    #[tokio::test]
+    async fn build_cardano_blocks_snapshot_artifact_when_given_cardano_blocks_type() {
+        let mut mock_container = MockDependencyInjector::new();
+
+        let block_number = BlockNumber(151);
+        let expected = CardanoBlocksSnapshot::new("merkle_root".to_string(), block_number);
+
+        mock_container
+            .mock_cardano_blocks_artifact_builder
+            .expect_compute_artifact()
+            .times(1)
+            .returning(move |_, _| {
+                Ok(CardanoBlocksSnapshot::new(
+                    "merkle_root".to_string(),
+                    block_number,
+                ))
+            });
+
+        let artifact_builder_service = mock_container.build_artifact_builder_service();
+
+        let certificate = fake_data::certificate("hash".to_string());
+        let signed_entity_type = SignedEntityType::CardanoBlocks(Epoch(1), block_number);
+        let artifact = artifact_builder_service
+            .compute_artifact(signed_entity_type.clone(), &certificate)
+            .await
+            .unwrap();
+
+        assert_expected(&expected, &artifact);
+    }
+
+    #[tokio::test]
+    async fn should_store_the_artifact_when_creating_artifact_for_cardano_blocks() {
+        let block_number = BlockNumber(149);
+        generic_test_that_the_artifact_is_stored(
+            SignedEntityType::CardanoBlocks(Epoch(1), block_number),
+            CardanoBlocksSnapshot::new("merkle_root".to_string(), block_number),
+            &|mock_injector| &mut mock_injector.mock_cardano_blocks_artifact_builder,
+        )
+        .await;
+    }
+
     #[tokio::test]
     async fn build_cardano_database_artifact_when_given_cardano_database_entity_type() {
         let mut mock_container = MockDependencyInjector::new();
@@ -302,6 +302,9 @@ mod test {
                             beacon.epoch, beacon.immutable_file_number
                         )
                     }
+                    SignedEntityType::CardanoBlocks(epoch, block_number) => {
+                        format!("cardano-blocks-{epoch}-{block_number}")
+                    }
                 };
 
                 let signed_entity_record = SignedEntityRecord {
@@ -1,6 +1,6 @@
 use anyhow::{anyhow, Context};
 use std::{
-    fs::File,
+    fs::{self, File},
     io::{prelude::*, Write},
     path::{Path, PathBuf},
     sync::Arc,
@@ -10,9 +10,10 @@ use mithril_common::{
     certificate_chain::{CertificateGenesisProducer, CertificateVerifier},
     crypto_helper::{
         ProtocolAggregateVerificationKey, ProtocolGenesisSecretKey, ProtocolGenesisSignature,
-        ProtocolGenesisSigner, ProtocolGenesisVerifier,
+        ProtocolGenesisSignatureBundle, ProtocolGenesisSignatureShare, ProtocolGenesisSigner,
+        ProtocolGenesisVerifier,
     },
-    entities::{ProtocolParameters, TimePoint},
+    entities::{Certificate, ProtocolParameters, TimePoint},
     protocol::SignerBuilder,
     CardanoNetwork, StdResult, TickerService,
 };
@@ -132,6 +133,39 @@ impl GenesisTools {
             .await
     }
 
+    /// Import a bundle of threshold genesis signatures of the AVK of the genesis stake distribution
+    /// from a file
+    pub async fn import_payload_signature_bundle(
+        &self,
+        signature_bundle_path: &Path,
+    ) -> StdResult<()> {
+        let genesis_signature_bundle: ProtocolGenesisSignatureBundle = serde_json::from_str(
+            &fs::read_to_string(signature_bundle_path)?,
+        )
+        .with_context(|| {
+            format!(
+                "Could not parse genesis signature bundle from '{}'",
+                signature_bundle_path.display()
+            )
+        })?;
+        let genesis_certificate = CertificateGenesisProducer::create_threshold_genesis_certificate(
+            self.genesis_protocol_parameters.clone(),
+            self.network,
+            self.time_point.epoch,
+            self.genesis_avk.clone(),
+            genesis_signature_bundle.clone(),
+        )?;
+        self.genesis_verifier
+            .verify_signature_bundle(
+                genesis_certificate.signed_message.as_bytes(),
+                &genesis_signature_bundle,
+            )
+            .with_context(|| "Genesis tool can not verify the genesis signature bundle")?;
+
+        self.verify_and_save_genesis_certificate(genesis_certificate)
+            .await
+    }
+
     /// Automatic bootstrap of the genesis certificate (test only)
     pub async fn bootstrap_test_genesis_certificate(
         &self,
@@ -172,6 +206,55 @@ impl GenesisTools {
         Ok(())
     }
 
+    /// Sign the genesis certificate with one of the genesis keys of a threshold genesis
+    pub async fn sign_genesis_certificate_share(
+        to_sign_payload_path: &Path,
+        target_signature_share_path: &Path,
+        genesis_secret_key_path: &Path,
+    ) -> StdResult<()> {
+        let genesis_secret_key =
+            ProtocolGenesisSecretKey::read_json_hex_from_file(genesis_secret_key_path)?;
+        let genesis_signer = ProtocolGenesisSigner::from_secret_key(genesis_secret_key);
+
+        let to_sign_payload = fs::read(to_sign_payload_path)?;
+        let genesis_signature_share = genesis_signer.sign_share(&to_sign_payload);
+
+        fs::write(
+            target_signature_share_path,
+            serde_json::to_string(&genesis_signature_share)?,
+        )?;
+
+        Ok(())
+    }
+
+    /// Gather the genesis signature shares of a threshold genesis in a bundle
+    pub fn bundle_genesis_signature_shares(
+        signature_share_paths: &[PathBuf],
+        target_signature_bundle_path: &Path,
+    ) -> StdResult<()> {
+        let genesis_signature_shares = signature_share_paths
+            .iter()
+            .map(|path| {
+                serde_json::from_str::<ProtocolGenesisSignatureShare>(&fs::read_to_string(path)?)
+                    .with_context(|| {
+                        format!(
+                            "Could not parse genesis signature share from '{}'",
+                            path.display()
+                        )
+                    })
+            })
+            .collect::<StdResult<Vec<_>>>()?;
+        let genesis_signature_bundle =
+            ProtocolGenesisSignatureBundle::new(genesis_signature_shares);
+
+        fs::write(
+            target_signature_bundle_path,
+            serde_json::to_string(&genesis_signature_bundle)?,
+        )?;
+
+        Ok(())
+    }
+
     async fn create_and_save_genesis_certificate(
         &self,
         genesis_signature: ProtocolGenesisSignature,
@@ -183,6 +266,15 @@ impl GenesisTools {
             self.genesis_avk.clone(),
             genesis_signature,
         )?;
+
+        self.verify_and_save_genesis_certificate(genesis_certificate)
+            .await
+    }
+
+    async fn verify_and_save_genesis_certificate(
+        &self,
+        genesis_certificate: Certificate,
+    ) -> StdResult<()> {
         self.certificate_verifier
             .verify_genesis_certificate(
                 &genesis_certificate,
@@ -195,7 +287,7 @@ impl GenesisTools {
             .with_context(|| {
                 format!(
                     "Genesis tool can not create certificate with genesis signature: '{:?}'",
-                    genesis_signature
+                    genesis_certificate.signature
                 )
             })?;
         Ok(())
@@ -223,7 +315,9 @@ mod tests {
         certificate_chain::MithrilCertificateVerifier,
         crypto_helper::{
             ProtocolGenesisSecretKey, ProtocolGenesisSigner, ProtocolGenesisVerificationKey,
+            ProtocolGenesisVerificationKeySet,
         },
+        entities::CertificateSignature,
         test_utils::{fake_data, MithrilFixtureBuilder, TempDir},
     };
     use std::{fs::read_to_string, path::PathBuf};
@@ -250,15 +344,33 @@ mod tests {
         Arc<CertificateRepository>,
         Arc<ProtocolGenesisVerifier>,
         Arc<dyn CertificateVerifier>,
+    ) {
+        build_tools_with_genesis_verification_key_set(genesis_signer, None)
+    }
+
+    fn build_tools_with_genesis_verification_key_set(
+        genesis_signer: &ProtocolGenesisSigner,
+        genesis_verification_key_set: Option<ProtocolGenesisVerificationKeySet>,
+    ) -> (
+        GenesisTools,
+        Arc<CertificateRepository>,
+        Arc<ProtocolGenesisVerifier>,
+        Arc<dyn CertificateVerifier>,
     ) {
         let connection = main_db_connection().unwrap();
         let certificate_store = Arc::new(CertificateRepository::new(Arc::new(connection)));
-        let certificate_verifier = Arc::new(MithrilCertificateVerifier::new(
-            TestLogger::stdout(),
-            certificate_store.clone(),
-        ));
+        let mut certificate_verifier =
+            MithrilCertificateVerifier::new(TestLogger::stdout(), certificate_store.clone());
+        let mut genesis_verifier = genesis_signer.create_genesis_verifier();
+        if let Some(genesis_verification_key_set) = genesis_verification_key_set {
+            certificate_verifier = certificate_verifier
+                .with_genesis_verification_key_set(genesis_verification_key_set.clone());
+            genesis_verifier =
+                genesis_verifier.with_verification_key_set(genesis_verification_key_set);
+        }
+        let certificate_verifier = Arc::new(certificate_verifier);
         let genesis_avk = create_fake_genesis_avk();
-        let genesis_verifier = Arc::new(genesis_signer.create_genesis_verifier());
+        let genesis_verifier = Arc::new(genesis_verifier);
         let genesis_tools = GenesisTools::new(
             fake_data::network(),
             TimePoint::dummy(),
@@ -322,6 +434,135 @@ mod tests {
             );
     }
 
+    fn create_and_save_genesis_keypairs(
+        test_dir: &Path,
+        total: usize,
+    ) -> (Vec<PathBuf>, ProtocolGenesisVerificationKeySet) {
+        let mut genesis_secret_key_paths = vec![];
+        let mut genesis_verification_keys = vec![];
+        for index in 0..total {
+            let keypair_dir = test_dir.join(format!("holder-{index}"));
+            fs::create_dir_all(&keypair_dir).unwrap();
+            let (genesis_secret_key_path, genesis_verification_key_path) =
+                GenesisTools::create_and_save_genesis_keypair(&keypair_dir)
+                    .expect("exporting the keypair should not fail");
+            genesis_secret_key_paths.push(genesis_secret_key_path);
+            genesis_verification_keys.push(
+                ProtocolGenesisVerificationKey::read_json_hex_from_file(
+                    &genesis_verification_key_path,
+                )
+                .unwrap(),
+            );
+        }
+
+        (
+            genesis_secret_key_paths,
+            ProtocolGenesisVerificationKeySet::new(2, genesis_verification_keys).unwrap(),
+        )
+    }
+
+    async fn sign_and_bundle_genesis_payload(
+        payload_path: &Path,
+        genesis_secret_key_paths: &[PathBuf],
+        target_signature_bundle_path: &Path,
+    ) {
+        let mut signature_share_paths = vec![];
+        for (index, genesis_secret_key_path) in genesis_secret_key_paths.iter().enumerate() {
+            let signature_share_path = payload_path.with_extension(format!("share-{index}.json"));
+            GenesisTools::sign_genesis_certificate_share(
+                payload_path,
+                &signature_share_path,
+                genesis_secret_key_path,
+            )
+            .await
+            .expect("sign_genesis_certificate_share should not fail");
+            signature_share_paths.push(signature_share_path);
+        }
+        GenesisTools::bundle_genesis_signature_shares(
+            &signature_share_paths,
+            target_signature_bundle_path,
+        )
+        .expect("bundle_genesis_signature_shares should not fail");
+    }
+
+    #[tokio::test]
+    async fn export_sign_shares_bundle_then_import_threshold_genesis_payload() {
+        let test_dir = get_temp_dir("import_threshold_genesis_payload");
+        let payload_path = test_dir.join("payload.txt");
+        let signature_bundle_path = test_dir.join("payload-bundle.json");
+        let (genesis_secret_key_paths, genesis_verification_key_set) =
+            create_and_save_genesis_keypairs(&test_dir, 3);
+        let (genesis_tools, certificate_store, genesis_verifier, certificate_verifier) =
+            build_tools_with_genesis_verification_key_set(
+                &ProtocolGenesisSigner::create_deterministic_genesis_signer(),
+                Some(genesis_verification_key_set),
+            );
+
+        genesis_tools
+            .export_payload_to_sign(&payload_path)
+            .expect("export_payload_to_sign should not fail");
+        sign_and_bundle_genesis_payload(
+            &payload_path,
+            &genesis_secret_key_paths[1..3],
+            &signature_bundle_path,
+        )
+        .await;
+        genesis_tools
+            .import_payload_signature_bundle(&signature_bundle_path)
+            .await
+            .expect("import_payload_signature_bundle should not fail");
+
+        let last_certificates: Vec<Certificate> =
+            certificate_store.get_latest_certificates(10).await.unwrap();
+
+        assert_eq!(1, last_certificates.len());
+        assert!(matches!(
+            last_certificates[0].signature,
+            CertificateSignature::GenesisThresholdSignature(_)
+        ));
+        certificate_verifier
+            .verify_genesis_certificate(
+                &last_certificates[0],
+                &genesis_verifier.to_verification_key(),
+            )
+            .await
+            .expect(
+                "verify_genesis_certificate should successfully validate the genesis certificate",
+            );
+    }
+
+    #[tokio::test]
+    async fn import_threshold_genesis_payload_fails_if_threshold_is_not_reached() {
+        let test_dir = get_temp_dir("import_threshold_genesis_payload_below_threshold");
+        let payload_path = test_dir.join("payload.txt");
+        let signature_bundle_path = test_dir.join("payload-bundle.json");
+        let (genesis_secret_key_paths, genesis_verification_key_set) =
+            create_and_save_genesis_keypairs(&test_dir, 3);
+        let (genesis_tools, certificate_store, _, _) =
+            build_tools_with_genesis_verification_key_set(
+                &ProtocolGenesisSigner::create_deterministic_genesis_signer(),
+                Some(genesis_verification_key_set),
+            );
+
+        genesis_tools
+            .export_payload_to_sign(&payload_path)
+            .expect("export_payload_to_sign should not fail");
+        sign_and_bundle_genesis_payload(
+            &payload_path,
+            &genesis_secret_key_paths[0..1],
+            &signature_bundle_path,
+        )
+        .await;
+        genesis_tools
+            .import_payload_signature_bundle(&signature_bundle_path)
+            .await
+            .expect_err("import_payload_signature_bundle should fail");
+
+        let last_certificates: Vec<Certificate> =
+            certificate_store.get_latest_certificates(10).await.unwrap();
+        assert!(last_certificates.is_empty());
+    }
+
     #[tokio::test]
     async fn bootstrap_test_genesis_certificate_works() {
         let genesis_signer = ProtocolGenesisSigner::create_deterministic_genesis_signer();
@@ -148,6 +148,13 @@ impl AggregatorObserver {
                     .await?
                     .first()
                     .map(|s| &s.signed_entity_type)),
+            SignedEntityType::CardanoBlocks(_, _) => Ok(Some(signed_entity_type_expected)
+                == self
+                    .signed_entity_service
+                    .get_last_cardano_blocks_snapshot()
+                    .await?
+                    .map(|s| s.signed_entity_type)
+                    .as_ref()),
         }
     }
 }
@@ -8,7 +8,8 @@ use mithril_aggregator::{
     dependency_injection::DependenciesBuilder,
     event_store::EventMessage,
     services::FakeSnapshotter,
-    AggregatorRuntime, Configuration, DependencyContainer, DumbUploader, SignerRegistrationError,
+    AggregatorRuntime, Configuration, DependencyContainer, DumbUploader, MultiFileUploader,
+    MultiUploadFailurePolicy, SignerRegistrationError,
 };
 use mithril_common::{
     cardano_block_scanner::{DumbBlockScanner, ScannedBlock},
@@ -150,7 +151,14 @@ impl RuntimeTester {
             )]));
         let block_scanner = Arc::new(DumbBlockScanner::new());
         let mut deps_builder = DependenciesBuilder::new(logger.clone(), configuration);
-        deps_builder.snapshot_uploader = Some(snapshot_uploader.clone());
+        deps_builder.snapshot_uploader = Some(Arc::new(
+            MultiFileUploader::new(
+                vec![snapshot_uploader.clone()],
+                MultiUploadFailurePolicy::RequireAll,
+                logger.clone(),
+            )
+            .unwrap(),
+        ));
         deps_builder.chain_observer = Some(chain_observer.clone());
         deps_builder.immutable_file_observer = Some(immutable_file_observer.clone());
         deps_builder.immutable_digester = Some(digester.clone());
@@ -590,7 +598,8 @@ impl RuntimeTester {
         let certificate = self.observer.get_last_certificate().await?;
 
         let signed_entity = match &certificate.signature {
-            CertificateSignature::GenesisSignature(..) => None,
+            CertificateSignature::GenesisSignature(..)
+            | CertificateSignature::GenesisThresholdSignature(..) => None,
             CertificateSignature::MultiSignature(..) => {
                 let record = self
                     .dependencies
@@ -0,0 +1,492 @@
+use anyhow::{anyhow, Context};
+use chrono::Utc;
+use clap::Parser;
+use slog::{debug, warn, Logger};
+use std::{
+    collections::HashMap,
+    fs::File,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::{
+    commands::{client_builder, SharedArgs},
+    configuration::{ConfigError, ConfigSource},
+    utils::{
+        CardanoDbDownloadChecker, CardanoDbUtils, ExpanderUtils, IndicatifFeedbackReceiver,
+        ProgressOutputType, ProgressPrinter,
+    },
+    CommandContext,
+};
+use mithril_client::{
+    cardano_database_client::{DownloadUnpackOptions, ImmutableFileRange},
+    common::{ImmutableFileNumber, MKProof, ProtocolMessage},
+    CardanoDatabaseSnapshot, Client, MessageBuilder, MithrilCertificate, MithrilResult,
+};
+
+/// Clap command to download a Cardano db v2 snapshot, or a range of its immutable files,
+/// and verify its associated certificate.
+#[derive(Parser, Debug, Clone)]
+pub struct CardanoDbDownloadCommand {
+    #[clap(flatten)]
+    shared_args: SharedArgs,
+
+    /// Hash of the cardano db to download. Use the `list` command to get that information.
+    ///
+    /// If `latest` is specified as hash, the command will return the latest cardano db.
+    hash: String,
+
+    /// Directory where the immutable and ancillary files will be downloaded. By default, a
+    /// subdirectory will be created in this directory to extract and verify the
+    /// certificate.
+    #[clap(long)]
+    download_dir: Option<PathBuf>,
+
+    /// Genesis Verification Key to check the certificate chain.
+    #[clap(long, env = "GENESIS_VERIFICATION_KEY")]
+    genesis_verification_key: Option<String>,
+
+    /// The first immutable file number to download.
+    ///
+    /// If not set, the download process will start from the first immutable file.
+    #[clap(long)]
+    start: Option<ImmutableFileNumber>,
+
+    /// The last immutable file number to download.
+    ///
+    /// If not set, the download will continue until the last certified immutable file.
+    #[clap(long)]
+    end: Option<ImmutableFileNumber>,
+
+    /// Include ancillary files in the download (ledger state, volatile and last immutable files).
+    ///
+    /// Ancillary files can only be included if the download ends with the last certified immutable file.
+    #[clap(long)]
+    include_ancillary: bool,
+
+    /// Allow existing files in the download directory to be overridden.
+    #[clap(long)]
+    allow_override: bool,
+}
+
+impl CardanoDbDownloadCommand {
+    /// Is JSON output enabled
+    pub fn is_json_output_enabled(&self) -> bool {
+        self.shared_args.json
+    }
+
+    /// Command execution
+    pub async fn execute(&self, context: CommandContext) -> MithrilResult<()> {
+        let params = context.config_parameters()?.add_source(self)?;
+        let download_dir: &String = &params.require("download_dir")?;
+        let db_dir = Path::new(download_dir).join("db");
+        let logger = context.logger();
+
+        let progress_output_type = if self.is_json_output_enabled() {
+            ProgressOutputType::JsonReporter
+        } else {
+            ProgressOutputType::Tty
+        };
+        let progress_printer = ProgressPrinter::new(progress_output_type, 6);
+        let client = client_builder(&params)?
+            .add_feedback_receiver(Arc::new(IndicatifFeedbackReceiver::new(
+                progress_output_type,
+                logger.clone(),
+            )))
+            .with_logger(logger.clone())
+            .build()?;
+
+        let get_list_of_artifact_ids = || async {
+            let cardano_db_snapshots =
+                client.cardano_database().list().await.with_context(|| {
+                    "Can not get the list of artifacts while retrieving the latest cardano db hash"
+                })?;
+
+            Ok(cardano_db_snapshots
+                .iter()
+                .map(|cardano_db| cardano_db.hash.to_owned())
+                .collect::<Vec<String>>())
+        };
+
+        let cardano_db_message = client
+            .cardano_database()
+            .get(
+                &ExpanderUtils::expand_eventual_id_alias(&self.hash, get_list_of_artifact_ids())
+                    .await?,
+            )
+            .await?
+            .with_context(|| format!("Can not get the cardano db for hash: '{}'", self.hash))?;
+
+        let immutable_file_range = Self::immutable_file_range(self.start, self.end);
+        let last_immutable_file_number = cardano_db_message.beacon.immutable_file_number;
+        let restored_immutable_file_number_range =
+            immutable_file_range.to_range_inclusive(last_immutable_file_number)?;
+        if self.include_ancillary
+            && *restored_immutable_file_number_range.end() != last_immutable_file_number
+        {
+            return Err(anyhow!(
+                "Ancillary files can only be included if the download ends with the last certified immutable file: '{last_immutable_file_number}'"
+            ));
+        }
+
+        Self::check_local_disk_info(
+            1,
+            &progress_printer,
+            &db_dir,
+            &cardano_db_message,
+            self.allow_override,
+        )?;
+
+        let certificate = Self::fetch_certificate_and_verifying_chain(
+            2,
+            &progress_printer,
+            &client,
+            &cardano_db_message.certificate_hash,
+        )
+        .await?;
+
+        Self::download_and_unpack_cardano_db(
+            logger,
+            3,
+            &progress_printer,
+            &client,
+            &cardano_db_message,
+            &immutable_file_range,
+            &db_dir,
+            DownloadUnpackOptions {
+                allow_override: self.allow_override,
+                include_ancillary: self.include_ancillary,
+            },
+        )
+        .await
+        .with_context(|| {
+            format!(
+                "Can not download and unpack cardano db snapshot for hash: '{}'",
+                self.hash
+            )
+        })?;
+
+        let merkle_proof = Self::compute_verify_merkle_proof(
+            4,
+            &progress_printer,
+            &client,
+            &certificate,
+            &cardano_db_message,
+            &immutable_file_range,
+            &db_dir,
+        )
+        .await?;
+
+        let message = Self::compute_cardano_db_snapshot_message(
+            5,
+            &progress_printer,
+            &certificate,
+            &merkle_proof,
+        )?;
+
+        Self::verify_cardano_db_snapshot_signature(
+            logger,
+            6,
+            &progress_printer,
+            &certificate,
+            &message,
+            &cardano_db_message,
+            &db_dir,
+            self.allow_override,
+        )?;
+
+        Self::log_download_information(
+            &db_dir,
+            &cardano_db_message,
+            &immutable_file_range,
+            self.is_json_output_enabled(),
+        )?;
+
+        Ok(())
+    }
+
+    fn immutable_file_range(
+        start: Option<ImmutableFileNumber>,
+        end: Option<ImmutableFileNumber>,
+    ) -> ImmutableFileRange {
+        match (start, end) {
+            (None, None) => ImmutableFileRange::Full,
+            (Some(start), None) => ImmutableFileRange::From(start),
+            (Some(start), Some(end)) => ImmutableFileRange::Range(start, end),
+            (None, Some(end)) => ImmutableFileRange::UpTo(end),
+        }
+    }
+
+    fn check_local_disk_info(
+        step_number: u16,
+        progress_printer: &ProgressPrinter,
+        db_dir: &Path,
+        cardano_db: &CardanoDatabaseSnapshot,
+        allow_override: bool,
+    ) -> MithrilResult<()> {
+        progress_printer.report_step(step_number, "Checking local disk info…")?;
+
+        CardanoDbDownloadChecker::ensure_dir_exist(db_dir)?;
+        if allow_override {
+            return Ok(());
+        }
+
+        if let Err(e) = CardanoDbDownloadChecker::check_prerequisites(
+            db_dir,
+            cardano_db.total_db_size_uncompressed,
+            cardano_db.compression_algorithm,
+        ) {
+            progress_printer
+                .report_step(step_number, &CardanoDbUtils::check_disk_space_error(e)?)?;
+        }
+
+        Ok(())
+    }
+
+    async fn fetch_certificate_and_verifying_chain(
+        step_number: u16,
+        progress_printer: &ProgressPrinter,
+        client: &Client,
+        certificate_hash: &str,
+    ) -> MithrilResult<MithrilCertificate> {
+        progress_printer.report_step(
+            step_number,
+            "Fetching the certificate and verifying the certificate chain…",
+        )?;
+        let certificate = client
+            .certificate()
+            .verify_chain(certificate_hash)
+            .await
+            .with_context(|| {
+                format!(
+                    "Can not verify the certificate chain from certificate_hash: '{}'",
+                    certificate_hash
+                )
+            })?;
+
+        Ok(certificate)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn download_and_unpack_cardano_db(
+        logger: &Logger,
+        step_number: u16,
+        progress_printer: &ProgressPrinter,
+        client: &Client,
+        cardano_db: &CardanoDatabaseSnapshot,
+        immutable_file_range: &ImmutableFileRange,
+        db_dir: &Path,
+        download_unpack_options: DownloadUnpackOptions,
+    ) -> MithrilResult<()> {
+        progress_printer.report_step(step_number, "Downloading and unpacking the cardano db")?;
+        client
+            .cardano_database()
+            .download_unpack(
+                cardano_db,
+                immutable_file_range,
+                db_dir,
+                download_unpack_options,
+            )
+            .await?;
+
+        // The cardano db download does not fail if the statistic call fails.
+        let last_immutable_file_number = cardano_db.beacon.immutable_file_number;
+        let full_restoration = immutable_file_range.is_full(last_immutable_file_number)?;
+        let number_of_immutable_files_restored =
+            immutable_file_range.length(last_immutable_file_number)?;
+        if let Err(e) = client
+            .cardano_database()
+            .add_statistics(
+                full_restoration,
+                download_unpack_options.include_ancillary,
+                number_of_immutable_files_restored,
+            )
+            .await
+        {
+            warn!(
+                logger, "Could not increment cardano db download statistics";
+                "error" => ?e
+            );
+        }
+
+        // Append 'clean' file to speedup node bootstrap
+        if download_unpack_options.include_ancillary {
+            if let Err(error) = File::create(db_dir.join("clean")) {
+                warn!(
+                    logger, "Could not create clean shutdown marker file in directory '{}'", db_dir.display();
+                    "error" => error.to_string()
+                );
+            };
+        }
+
+        Ok(())
+    }
+
+    async fn compute_verify_merkle_proof(
+        step_number: u16,
+        progress_printer: &ProgressPrinter,
+        client: &Client,
+        certificate: &MithrilCertificate,
+        cardano_db: &CardanoDatabaseSnapshot,
+        immutable_file_range: &ImmutableFileRange,
+        db_dir: &Path,
+    ) -> MithrilResult<MKProof> {
+        progress_printer.report_step(step_number, "Computing and verifying the Merkle proof…")?;
+        let merkle_proof = client
+            .cardano_database()
+            .compute_merkle_proof(certificate, cardano_db, immutable_file_range, db_dir)
+            .await?;
+
+        merkle_proof
+            .verify()
+            .with_context(|| "Merkle proof verification failed")?;
+
+        Ok(merkle_proof)
+    }
+
+    fn compute_cardano_db_snapshot_message(
+        step_number: u16,
+        progress_printer: &ProgressPrinter,
+        certificate: &MithrilCertificate,
+        merkle_proof: &MKProof,
+    ) -> MithrilResult<ProtocolMessage> {
+        progress_printer.report_step(step_number, "Computing the cardano db snapshot message")?;
+        let message = MessageBuilder::new()
+            .compute_cardano_database_message(certificate, merkle_proof)
+            .with_context(|| "Can not compute the cardano db snapshot message")?;
+
+        Ok(message)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn verify_cardano_db_snapshot_signature(
+        logger: &Logger,
+        step_number: u16,
+        progress_printer: &ProgressPrinter,
+        certificate: &MithrilCertificate,
+        message: &ProtocolMessage,
+        cardano_db: &CardanoDatabaseSnapshot,
+        db_dir: &Path,
+        allow_override: bool,
+    ) -> MithrilResult<()> {
+        progress_printer.report_step(step_number, "Verifying the cardano db signature…")?;
+        if !certificate.match_message(message) {
+            // Files that were already in the directory must not be removed if overriding is allowed.
+            if !allow_override {
+                debug!(
+                    logger,
+                    "Merkle root verification failed, removing unpacked files & directory."
+                );
+
+                if let Err(error) = std::fs::remove_dir_all(db_dir) {
+                    warn!(
+                        logger, "Error while removing unpacked files & directory";
+                        "error" => error.to_string()
+                    );
+                }
+            }
+
+            return Err(anyhow!(
+                "Certificate verification failed (cardano db hash = '{}').",
+                cardano_db.hash.clone()
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn log_download_information(
+        db_dir: &Path,
+        cardano_db: &CardanoDatabaseSnapshot,
+        immutable_file_range: &ImmutableFileRange,
+        json_output: bool,
+    ) -> MithrilResult<()> {
+        let canonicalized_filepath = &db_dir.canonicalize().with_context(|| {
+            format!(
+                "Could not get canonicalized filepath of '{}'",
+                db_dir.display()
+            )
+        })?;
+        let restored_immutable_file_number_range =
+            immutable_file_range.to_range_inclusive(cardano_db.beacon.immutable_file_number)?;
+
+        if json_output {
+            println!(
+                r#"{{"timestamp": "{}", "db_directory": "{}", "immutable_file_range": {{"start": {}, "end": {}}}}}"#,
+                Utc::now().to_rfc3339(),
+                canonicalized_filepath.display(),
+                restored_immutable_file_number_range.start(),
+                restored_immutable_file_number_range.end(),
+            );
+        } else {
+            let cardano_node_version = &cardano_db.cardano_node_version;
+            println!(
+                r###"Cardano db snapshot '{}' immutable files {} to {} have been unpacked in the directory '{}'.
+
+    The files have been checked against the Mithril multi-signature of the snapshot, they can be used with a Cardano node with version >= {cardano_node_version}.
+    "###,
+                cardano_db.hash,
+                restored_immutable_file_number_range.start(),
+                restored_immutable_file_number_range.end(),
+                canonicalized_filepath.display(),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl ConfigSource for CardanoDbDownloadCommand {
+    fn collect(&self) -> Result<HashMap<String, String>, ConfigError> {
+        let mut map = HashMap::new();
+
+        if let Some(download_dir) = self.download_dir.clone() {
+            map.insert(
+                "download_dir".to_string(),
+                download_dir
+                    .to_str()
+                    .ok_or_else(|| {
+                        ConfigError::Conversion(format!(
+                            "Could not read download directory: '{}'.",
+                            download_dir.display()
+                        ))
+                    })?
+                    .to_string(),
+            );
+        }
+
+        if let Some(genesis_verification_key) = self.genesis_verification_key.clone() {
+            map.insert(
+                "genesis_verification_key".to_string(),
+                genesis_verification_key,
+            );
+        }
+
+        Ok(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn immutable_file_range_from_start_and_end_arguments() {
+        assert_eq!(
+            ImmutableFileRange::Full,
+            CardanoDbDownloadCommand::immutable_file_range(None, None)
+        );
+        assert_eq!(
+            ImmutableFileRange::From(12),
+            CardanoDbDownloadCommand::immutable_file_range(Some(12), None)
+        );
+        assert_eq!(
+            ImmutableFileRange::Range(12, 345),
+            CardanoDbDownloadCommand::immutable_file_range(Some(12), Some(345))
+        );
+        assert_eq!(
+            ImmutableFileRange::UpTo(345),
+            CardanoDbDownloadCommand::immutable_file_range(None, Some(345))
+        );
+    }
+}
@@ -0,0 +1,211 @@
+use anyhow::{anyhow, Context};
+use clap::Parser;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::{
+    commands::{certificate::expand_eventual_certificate_hash_alias, client_builder, SharedArgs},
+    configuration::{ConfigError, ConfigSource},
+    utils::{IndicatifFeedbackReceiver, ProgressOutputType, ProgressPrinter},
+    CommandContext,
+};
+use mithril_client::{MithrilCertificate, MithrilResult};
+
+/// Clap command to verify the certificate chain starting from a given certificate and export it
+/// to a JSON file. If the verification fails, the file is not written.
+#[derive(Parser, Debug, Clone)]
+pub struct CertificateExportCommand {
+    #[clap(flatten)]
+    shared_args: SharedArgs,
+
+    /// Hash of the certificate to start the export from.
+    ///
+    /// If `latest` is specified as hash, the export will start from the latest certificate.
+    hash: String,
+
+    /// Directory where the certificate chain will be exported.
+    #[clap(long)]
+    download_dir: Option<PathBuf>,
+
+    /// Genesis Verification Key to check the certificate chain.
+    #[clap(long, env = "GENESIS_VERIFICATION_KEY")]
+    genesis_verification_key: Option<String>,
+}
+
+impl CertificateExportCommand {
+    /// Is JSON output enabled
+    pub fn is_json_output_enabled(&self) -> bool {
+        self.shared_args.json
+    }
+
+    /// Main command execution
+    pub async fn execute(&self, context: CommandContext) -> MithrilResult<()> {
+        let params = context.config_parameters()?.add_source(self)?;
+        let download_dir = params.get_or("download_dir", ".");
+        let download_dir = Path::new(&download_dir);
+        let logger = context.logger();
+
+        let progress_output_type = if self.is_json_output_enabled() {
+            ProgressOutputType::JsonReporter
+        } else {
+            ProgressOutputType::Tty
+        };
+        let progress_printer = ProgressPrinter::new(progress_output_type, 3);
+        let client = client_builder(&params)?
+            .add_feedback_receiver(Arc::new(IndicatifFeedbackReceiver::new(
+                progress_output_type,
+                logger.clone(),
+            )))
+            .with_logger(logger.clone())
+            .build()?;
+
+        progress_printer.report_step(
+            1,
+            "Fetching the certificate and verifying the certificate chain…",
+        )?;
+        let certificate_hash = expand_eventual_certificate_hash_alias(&client, &self.hash).await?;
+        let certificate_chain = client
+            .certificate()
+            .verify_chain_and_collect(&certificate_hash)
+            .await
+            .with_context(|| {
+                format!(
+                    "Can not verify the certificate chain from certificate_hash: '{}'",
+                    certificate_hash
+                )
+            })?;
+
+        progress_printer.report_step(2, "Checking the links of the verified chain…")?;
+        Self::check_certificate_chain_links(&certificate_chain)?;
+
+        progress_printer.report_step(3, "Writing the certificate chain to a file")?;
+        if !download_dir.is_dir() {
+            std::fs::create_dir_all(download_dir)?;
+        }
+        let filepath = download_dir.join(format!("certificate_chain-{certificate_hash}.json"));
+        std::fs::write(
+            &filepath,
+            serde_json::to_string(&certificate_chain).with_context(|| {
+                format!("Can not serialize the certificate chain from certificate_hash: '{certificate_hash}'")
+            })?,
+        )?;
+
+        if self.is_json_output_enabled() {
+            println!(
+                r#"{{"certificate_hash": "{}", "total_certificates": {}, "filepath": "{}"}}"#,
+                certificate_hash,
+                certificate_chain.len(),
+                filepath.display()
+            );
+        } else {
+            println!(
+                "The certificate chain starting from certificate '{}' ({} certificates) has been verified and saved as '{}'.",
+                certificate_hash,
+                certificate_chain.len(),
+                filepath.display()
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Check that the verified certificates form a single chain, from the first certificate to
+    /// a genesis certificate, so that the exported file can be verified offline.
+    fn check_certificate_chain_links(
+        certificate_chain: &[MithrilCertificate],
+    ) -> MithrilResult<()> {
+        let (genesis_certificate, certificates) = certificate_chain
+            .split_last()
+            .ok_or_else(|| anyhow!("The verified certificate chain is empty"))?;
+        if genesis_certificate.genesis_signature.is_empty() {
+            return Err(anyhow!(
+                "The verified certificate chain does not end with a genesis certificate: '{}'",
+                genesis_certificate.hash
+            ));
+        }
+        for (certificate, previous_certificate) in
+            certificates.iter().zip(certificate_chain.iter().skip(1))
+        {
+            if certificate.previous_hash != previous_certificate.hash {
+                return Err(anyhow!(
+                    "Certificate '{}' is not linked to the next certificate of the verified chain '{}'",
+                    certificate.hash,
+                    previous_certificate.hash
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ConfigSource for CertificateExportCommand {
+    fn collect(&self) -> Result<HashMap<String, String>, ConfigError> {
+        let mut map = HashMap::new();
+
+        if let Some(download_dir) = self.download_dir.clone() {
+            map.insert(
+                "download_dir".to_string(),
+                download_dir
+                    .to_str()
+                    .ok_or_else(|| {
+                        ConfigError::Conversion(format!(
+                            "Could not read download directory: '{}'.",
+                            download_dir.display()
+                        ))
+                    })?
+                    .to_string(),
+            );
+        }
+
+        if let Some(genesis_verification_key) = self.genesis_verification_key.clone() {
+            map.insert(
+                "genesis_verification_key".to_string(),
+                genesis_verification_key,
+            );
+        }
+
+        Ok(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::test_utils::CertificateChainBuilder;
+
+    use super::*;
+
+    fn build_chain(total_certificates: u64) -> Vec<MithrilCertificate> {
+        let (chain, _) = CertificateChainBuilder::new()
+            .with_total_certificates(total_certificates)
+            .with_certificates_per_epoch(1)
+            .build();
+
+        chain.into_iter().map(|c| c.try_into().unwrap()).collect()
+    }
+
+    #[test]
+    fn check_certificate_chain_links_succeed_when_chain_is_linked_up_to_genesis() {
+        let chain = build_chain(4);
+
+        CertificateExportCommand::check_certificate_chain_links(&chain).unwrap();
+    }
+
+    #[test]
+    fn check_certificate_chain_links_fails_when_chain_does_not_end_with_genesis() {
+        let chain = build_chain(4);
+
+        CertificateExportCommand::check_certificate_chain_links(&chain[..3])
+            .expect_err("Check should fail when the chain does not end with a genesis");
+    }
+
+    #[test]
+    fn check_certificate_chain_links_fails_when_a_certificate_is_missing() {
+        let mut chain = build_chain(4);
+        chain.remove(1);
+
+        CertificateExportCommand::check_certificate_chain_links(&chain)
+            .expect_err("Check should fail when a certificate is missing in the chain");
+    }
+}
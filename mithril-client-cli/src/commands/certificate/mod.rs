@@ -0,0 +1,70 @@
+//! Commands for the certificates
+mod export;
+mod list;
+mod show;
+mod verify_chain;
+
+pub use export::*;
+pub use list::*;
+pub use show::*;
+pub use verify_chain::*;
+
+use anyhow::Context;
+use clap::Subcommand;
+use mithril_client::{Client, MithrilResult};
+
+use crate::utils::ExpanderUtils;
+use crate::CommandContext;
+
+/// Certificate management (alias: cert)
+#[derive(Subcommand, Debug, Clone)]
+#[command(about = "Certificate management (alias: cert)")]
+pub enum CertificateCommands {
+    /// List certificates
+    #[clap(arg_required_else_help = false)]
+    List(CertificateListCommand),
+
+    /// Show detailed information about a certificate
+    #[clap(arg_required_else_help = true)]
+    Show(CertificateShowCommand),
+
+    /// Verify the certificate chain from the given certificate down to the genesis certificate
+    #[clap(arg_required_else_help = true)]
+    VerifyChain(CertificateVerifyChainCommand),
+
+    /// Verify the certificate chain from the given certificate and export it to a JSON file
+    #[clap(arg_required_else_help = true)]
+    Export(CertificateExportCommand),
+}
+
+impl CertificateCommands {
+    /// Execute certificate command
+    pub async fn execute(&self, config_builder: CommandContext) -> MithrilResult<()> {
+        match self {
+            Self::List(cmd) => cmd.execute(config_builder).await,
+            Self::Show(cmd) => cmd.execute(config_builder).await,
+            Self::VerifyChain(cmd) => cmd.execute(config_builder).await,
+            Self::Export(cmd) => cmd.execute(config_builder).await,
+        }
+    }
+}
+
+/// Expand the `latest` alias to the hash of the latest certificate.
+async fn expand_eventual_certificate_hash_alias(
+    client: &Client,
+    certificate_hash: &str,
+) -> MithrilResult<String> {
+    let get_list_of_certificate_hashes = || async {
+        let certificates = client.certificate().list().await.with_context(|| {
+            "Can not get the list of certificates while retrieving the latest certificate hash"
+        })?;
+
+        Ok(certificates
+            .iter()
+            .map(|certificate| certificate.hash.to_owned())
+            .collect::<Vec<String>>())
+    };
+
+    ExpanderUtils::expand_eventual_id_alias(certificate_hash, get_list_of_certificate_hashes())
+        .await
+}
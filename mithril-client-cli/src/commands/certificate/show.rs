@@ -0,0 +1,150 @@
+use anyhow::anyhow;
+use clap::Parser;
+use cli_table::{print_stdout, Cell, Table};
+
+use crate::{
+    commands::{
+        certificate::expand_eventual_certificate_hash_alias,
+        client_builder_with_fallback_genesis_key, SharedArgs,
+    },
+    CommandContext,
+};
+use mithril_client::{MithrilCertificate, MithrilResult};
+
+/// Clap command to show a given certificate
+#[derive(Parser, Debug, Clone)]
+pub struct CertificateShowCommand {
+    #[clap(flatten)]
+    shared_args: SharedArgs,
+
+    /// Certificate hash.
+    ///
+    /// If `latest` is specified as hash, the command will return the latest certificate.
+    hash: String,
+}
+
+impl CertificateShowCommand {
+    /// Is JSON output enabled
+    pub fn is_json_output_enabled(&self) -> bool {
+        self.shared_args.json
+    }
+
+    /// Certificate Show command
+    pub async fn execute(&self, context: CommandContext) -> MithrilResult<()> {
+        let params = context.config_parameters()?;
+        let client = client_builder_with_fallback_genesis_key(&params)?
+            .with_logger(context.logger().clone())
+            .build()?;
+
+        let certificate = client
+            .certificate()
+            .get(&expand_eventual_certificate_hash_alias(&client, &self.hash).await?)
+            .await?
+            .ok_or_else(|| anyhow!("Certificate not found for hash: '{}'", &self.hash))?;
+
+        if self.is_json_output_enabled() {
+            println!("{}", serde_json::to_string(&certificate)?);
+        } else {
+            let certificate_table = Self::certificate_details(&certificate)
+                .into_iter()
+                .map(|(label, value)| vec![label.cell(), value.cell()])
+                .collect::<Vec<_>>()
+                .table();
+
+            print_stdout(certificate_table)?
+        }
+
+        Ok(())
+    }
+
+    /// Returns the labelled details of a certificate, including the decoded parts of its
+    /// protocol message.
+    fn certificate_details(certificate: &MithrilCertificate) -> Vec<(String, String)> {
+        let mut details = vec![
+            ("Hash".to_string(), certificate.hash.clone()),
+            (
+                "Previous Hash".to_string(),
+                certificate.previous_hash.clone(),
+            ),
+            ("Epoch".to_string(), format!("{}", certificate.epoch)),
+            (
+                "Signed Entity Type".to_string(),
+                format!("{:?}", certificate.signed_entity_type),
+            ),
+            ("Network".to_string(), certificate.metadata.network.clone()),
+            (
+                "Protocol Version".to_string(),
+                certificate.metadata.protocol_version.clone(),
+            ),
+            (
+                "Protocol Parameters".to_string(),
+                format!("{:?}", certificate.metadata.protocol_parameters),
+            ),
+            (
+                "Initiated".to_string(),
+                certificate.metadata.initiated_at.to_string(),
+            ),
+            (
+                "Sealed".to_string(),
+                certificate.metadata.sealed_at.to_string(),
+            ),
+            (
+                "Total Signers".to_string(),
+                format!("{}", certificate.metadata.signers.len()),
+            ),
+        ];
+        details.extend(
+            certificate
+                .protocol_message
+                .message_parts
+                .iter()
+                .map(|(key, value)| (format!("Protocol Message: {key}"), value.clone())),
+        );
+        details.push((
+            "Signed Message".to_string(),
+            certificate.signed_message.clone(),
+        ));
+        details.push((
+            "Genesis".to_string(),
+            format!("{}", !certificate.genesis_signature.is_empty()),
+        ));
+
+        details
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_client::common::{ProtocolMessage, ProtocolMessagePartKey};
+
+    use super::*;
+
+    #[test]
+    fn certificate_details_include_the_decoded_protocol_message_parts() {
+        let mut protocol_message = ProtocolMessage::new();
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::SnapshotDigest,
+            "snapshot-digest-123".to_string(),
+        );
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::NextAggregateVerificationKey,
+            "next-avk-123".to_string(),
+        );
+        let certificate = MithrilCertificate {
+            protocol_message,
+            ..MithrilCertificate::dummy()
+        };
+
+        let details = CertificateShowCommand::certificate_details(&certificate);
+
+        assert!(details.contains(&(
+            "Protocol Message: snapshot_digest".to_string(),
+            "snapshot-digest-123".to_string()
+        )));
+        assert!(details.contains(&(
+            "Protocol Message: next_aggregate_verification_key".to_string(),
+            "next-avk-123".to_string()
+        )));
+        assert!(details.contains(&("Hash".to_string(), certificate.hash.clone())));
+    }
+}
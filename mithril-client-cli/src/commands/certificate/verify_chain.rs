@@ -0,0 +1,99 @@
+use anyhow::Context;
+use clap::Parser;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::{
+    commands::{certificate::expand_eventual_certificate_hash_alias, client_builder, SharedArgs},
+    configuration::{ConfigError, ConfigSource},
+    utils::{IndicatifFeedbackReceiver, ProgressOutputType, ProgressPrinter},
+    CommandContext,
+};
+use mithril_client::MithrilResult;
+
+/// Clap command to verify the certificate chain starting from a given certificate
+#[derive(Parser, Debug, Clone)]
+pub struct CertificateVerifyChainCommand {
+    #[clap(flatten)]
+    shared_args: SharedArgs,
+
+    /// Hash of the certificate to start the verification from.
+    ///
+    /// If `latest` is specified as hash, the verification will start from the latest certificate.
+    hash: String,
+
+    /// Genesis Verification Key to check the certificate chain.
+    #[clap(long, env = "GENESIS_VERIFICATION_KEY")]
+    genesis_verification_key: Option<String>,
+}
+
+impl CertificateVerifyChainCommand {
+    /// Is JSON output enabled
+    pub fn is_json_output_enabled(&self) -> bool {
+        self.shared_args.json
+    }
+
+    /// Main command execution
+    pub async fn execute(&self, context: CommandContext) -> MithrilResult<()> {
+        let params = context.config_parameters()?.add_source(self)?;
+        let logger = context.logger();
+
+        let progress_output_type = if self.is_json_output_enabled() {
+            ProgressOutputType::JsonReporter
+        } else {
+            ProgressOutputType::Tty
+        };
+        let progress_printer = ProgressPrinter::new(progress_output_type, 2);
+        let client = client_builder(&params)?
+            .add_feedback_receiver(Arc::new(IndicatifFeedbackReceiver::new(
+                progress_output_type,
+                logger.clone(),
+            )))
+            .with_logger(logger.clone())
+            .build()?;
+
+        progress_printer.report_step(1, &format!("Fetching certificate '{}' …", self.hash))?;
+        let certificate_hash = expand_eventual_certificate_hash_alias(&client, &self.hash).await?;
+
+        progress_printer.report_step(2, "Verifying the certificate chain…")?;
+        let certificate = client
+            .certificate()
+            .verify_chain(&certificate_hash)
+            .await
+            .with_context(|| {
+                format!(
+                    "Can not verify the certificate chain from certificate_hash: '{}'",
+                    certificate_hash
+                )
+            })?;
+
+        if self.is_json_output_enabled() {
+            println!(
+                r#"{{"certificate_hash": "{}", "epoch": {}, "verified": true}}"#,
+                certificate.hash, certificate.epoch
+            );
+        } else {
+            println!(
+                "The certificate chain starting from certificate '{}' (epoch {}) has been verified down to the genesis certificate.",
+                certificate.hash, certificate.epoch
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl ConfigSource for CertificateVerifyChainCommand {
+    fn collect(&self) -> Result<HashMap<String, String>, ConfigError> {
+        let mut map = HashMap::new();
+
+        if let Some(genesis_verification_key) = self.genesis_verification_key.clone() {
+            map.insert(
+                "genesis_verification_key".to_string(),
+                genesis_verification_key,
+            );
+        }
+
+        Ok(map)
+    }
+}
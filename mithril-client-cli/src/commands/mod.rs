@@ -7,13 +7,16 @@ pub mod cardano_db;
 pub mod cardano_db_v2;
 pub mod cardano_stake_distribution;
 pub mod cardano_transaction;
+pub mod certificate;
 mod deprecation;
 pub mod mithril_stake_distribution;
 
 pub use deprecation::{DeprecatedCommand, Deprecation};
 
+use chrono::TimeDelta;
 use clap::Args;
 use mithril_client::{ClientBuilder, MithrilResult};
+use std::path::Path;
 
 use crate::configuration::ConfigParameters;
 
@@ -25,11 +28,24 @@ pub struct SharedArgs {
     json: bool,
 }
 
+/// Delay after which a certificate validation result cached in the certificate chain cache file
+/// expires.
+const CERTIFICATE_CHAIN_CACHE_EXPIRATION_DELAY: TimeDelta = TimeDelta::weeks(1);
+
 pub(crate) fn client_builder(params: &ConfigParameters) -> MithrilResult<ClientBuilder> {
-    let builder = ClientBuilder::aggregator(
+    let mut builder = ClientBuilder::aggregator(
         &params.require("aggregator_endpoint")?,
         &params.require("genesis_verification_key")?,
     );
+    if let Some(genesis_verification_key_set) = params.get("genesis_verification_key_set") {
+        builder = builder.with_genesis_verification_key_set(&genesis_verification_key_set);
+    }
+    if let Some(certificate_chain_cache_file) = params.get("certificate_chain_cache_file") {
+        builder = builder.with_certificate_verifier_cache_file(
+            Path::new(&certificate_chain_cache_file),
+            CERTIFICATE_CHAIN_CACHE_EXPIRATION_DELAY,
+        );
+    }
 
     Ok(builder)
 }
@@ -15,7 +15,7 @@ use mithril_doc::{Documenter, GenerateDocCommands, StructDoc};
 use mithril_client_cli::commands::{
     cardano_db::CardanoDbCommands, cardano_db_v2::CardanoDbV2Commands,
     cardano_stake_distribution::CardanoStakeDistributionCommands,
-    cardano_transaction::CardanoTransactionCommands,
+    cardano_transaction::CardanoTransactionCommands, certificate::CertificateCommands,
     mithril_stake_distribution::MithrilStakeDistributionCommands, DeprecatedCommand, Deprecation,
 };
 use mithril_client_cli::{ClapError, CommandContext};
@@ -81,6 +81,19 @@ pub struct Args {
     /// Enable unstable commands
     #[clap(long)]
     unstable: bool,
+
+    /// Cache the certificate chain validation results in the given file (unstable).
+    ///
+    /// The already validated certificates are not verified again by later executions until
+    /// their cached validation expires.
+    #[clap(long, env = "CERTIFICATE_CHAIN_CACHE_FILE")]
+    #[example = "`./certificate-chain-cache.json`"]
+    certificate_chain_cache_file: Option<PathBuf>,
+
+    /// Genesis verification key set, encoded as json hex, used to verify the certificate chains
+    /// rooted in a threshold genesis certificate.
+    #[clap(long, env = "GENESIS_VERIFICATION_KEY_SET")]
+    genesis_verification_key_set: Option<String>,
 }
 
 impl Args {
@@ -97,6 +110,11 @@ impl Args {
             .add_source(config::File::with_name(&filename).required(false))
             .add_source(self.clone())
             .set_default("download_dir", "")?;
+        if self.certificate_chain_cache_file.is_some() && !self.unstable {
+            return Err(anyhow!(
+                "The \"--certificate-chain-cache-file\" option is only accepted using the --unstable flag."
+            ));
+        }
         let context = CommandContext::new(config, self.unstable, root_logger);
 
         self.command.execute(context).await
@@ -184,6 +202,36 @@ impl Source for Args {
             );
         }
 
+        if let Some(genesis_verification_key_set) = self.genesis_verification_key_set.clone() {
+            map.insert(
+                "genesis_verification_key_set".to_string(),
+                Value::new(
+                    Some(&namespace),
+                    ValueKind::from(genesis_verification_key_set),
+                ),
+            );
+        }
+
+        if let Some(certificate_chain_cache_file) = self.certificate_chain_cache_file.clone() {
+            map.insert(
+                "certificate_chain_cache_file".to_string(),
+                Value::new(
+                    Some(&namespace),
+                    ValueKind::from(
+                        certificate_chain_cache_file
+                            .to_str()
+                            .ok_or_else(|| {
+                                config::ConfigError::Message(format!(
+                                    "Could not read certificate chain cache file path: '{}'.",
+                                    certificate_chain_cache_file.display()
+                                ))
+                            })?
+                            .to_string(),
+                    ),
+                ),
+            );
+        }
+
         Ok(map)
     }
 }
@@ -205,6 +253,9 @@ enum ArtifactCommands {
     #[clap(subcommand, alias("cdbv2"))]
     CardanoDbV2(CardanoDbV2Commands),
 
+    #[clap(subcommand, alias("cert"))]
+    Certificate(CertificateCommands),
+
     #[clap(alias("doc"), hide(true))]
     GenerateDoc(GenerateDocCommands),
 }
@@ -226,6 +277,7 @@ impl ArtifactCommands {
                     cmd.execute(context).await
                 }
             }
+            Self::Certificate(cmd) => cmd.execute(context).await,
             Self::GenerateDoc(cmd) => cmd
                 .execute(&mut Args::command())
                 .map_err(|message| anyhow!(message)),
@@ -273,4 +325,25 @@ mod tests {
             .to_string()
             .contains("subcommand is only accepted using the --unstable flag."));
     }
+
+    #[tokio::test]
+    async fn fail_if_certificate_chain_cache_file_is_used_without_unstable_flag() {
+        let args = Args::try_parse_from([
+            "mithril-client",
+            "--certificate-chain-cache-file",
+            "cache.json",
+            "certificate",
+            "list",
+        ])
+        .unwrap();
+
+        let error = args
+            .execute(Logger::root(slog::Discard, slog::o!()))
+            .await
+            .expect_err("Should fail if unstable flag missing");
+
+        assert!(error
+            .to_string()
+            .contains("option is only accepted using the --unstable flag."));
+    }
 }
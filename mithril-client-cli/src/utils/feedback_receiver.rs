@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use indicatif::{ProgressBar, ProgressDrawTarget, ProgressState, ProgressStyle};
-use slog::Logger;
+use slog::{warn, Logger};
 use std::fmt::Write;
 use tokio::sync::RwLock;
 
@@ -12,6 +12,7 @@ use mithril_client::feedback::{FeedbackReceiver, MithrilEvent};
 /// by the `mithril-client` library
 pub struct IndicatifFeedbackReceiver {
     download_progress_reporter: RwLock<Option<DownloadProgressReporter>>,
+    cardano_database_download_pb: RwLock<Option<ProgressBar>>,
     certificate_validation_pb: RwLock<Option<ProgressBar>>,
     output_type: ProgressOutputType,
     logger: Logger,
@@ -22,6 +23,7 @@ impl IndicatifFeedbackReceiver {
     pub fn new(output_type: ProgressOutputType, logger: Logger) -> Self {
         Self {
             download_progress_reporter: RwLock::new(None),
+            cardano_database_download_pb: RwLock::new(None),
             certificate_validation_pb: RwLock::new(None),
             output_type,
             logger,
@@ -64,6 +66,16 @@ impl FeedbackReceiver for IndicatifFeedbackReceiver {
                     progress_reporter.report(downloaded_bytes);
                 }
             }
+            MithrilEvent::SnapshotDownloadResumed {
+                download_id: _,
+                resumed_offset,
+                size: _,
+            } => {
+                let download_progress_reporter = self.download_progress_reporter.read().await;
+                if let Some(progress_reporter) = download_progress_reporter.as_ref() {
+                    progress_reporter.report(resumed_offset);
+                }
+            }
             MithrilEvent::SnapshotDownloadCompleted { download_id: _ } => {
                 let mut download_progress_reporter = self.download_progress_reporter.write().await;
                 if let Some(progress_reporter) = download_progress_reporter.as_ref() {
@@ -71,6 +83,58 @@ impl FeedbackReceiver for IndicatifFeedbackReceiver {
                 }
                 *download_progress_reporter = None;
             }
+            MithrilEvent::SnapshotDownloadLocationSwitched {
+                download_id: _,
+                failed_location,
+                next_location,
+                reason,
+            }
+            | MithrilEvent::CardanoDatabaseDownloadLocationSwitched {
+                download_id: _,
+                failed_location,
+                next_location,
+                reason,
+            } => {
+                warn!(
+                    self.logger, "Download from '{failed_location}' failed, switching to '{next_location}'";
+                    "reason" => reason
+                );
+            }
+            MithrilEvent::CardanoDatabaseDownloadStarted {
+                hash: _,
+                download_id: _,
+                total_immutable_files,
+                include_ancillary,
+            } => {
+                let total_files = total_immutable_files + u64::from(include_ancillary);
+                let pb = if self.output_type == ProgressOutputType::Tty {
+                    ProgressBar::new(total_files)
+                } else {
+                    ProgressBar::with_draw_target(Some(total_files), ProgressDrawTarget::hidden())
+                };
+                pb.set_style(ProgressStyle::with_template("{spinner:.green} [{elapsed_precise}] [{wide_bar:.cyan/blue}] Files: {human_pos}/{human_len} ({eta})")
+                    .unwrap()
+                    .with_key("eta", |state : &ProgressState, w: &mut dyn Write| write!(w, "{:.1}s", state.eta().as_secs_f64()).unwrap())
+                    .progress_chars("#>-"));
+                let mut cardano_database_download_pb =
+                    self.cardano_database_download_pb.write().await;
+                *cardano_database_download_pb = Some(pb);
+            }
+            MithrilEvent::CardanoDatabaseImmutableDownloadCompleted { .. }
+            | MithrilEvent::CardanoDatabaseAncillaryDownloadCompleted { .. } => {
+                let cardano_database_download_pb = self.cardano_database_download_pb.read().await;
+                if let Some(progress_bar) = cardano_database_download_pb.as_ref() {
+                    progress_bar.inc(1);
+                }
+            }
+            MithrilEvent::CardanoDatabaseDownloadCompleted { download_id: _ } => {
+                let mut cardano_database_download_pb =
+                    self.cardano_database_download_pb.write().await;
+                if let Some(progress_bar) = cardano_database_download_pb.as_ref() {
+                    progress_bar.finish_with_message("Cardano database download completed");
+                }
+                *cardano_database_download_pb = None;
+            }
             MithrilEvent::CertificateChainValidationStarted {
                 certificate_chain_validation_id: _,
             } => {
@@ -1,12 +1,13 @@
 use async_trait::async_trait;
 use chrono::TimeDelta;
 use serde::Serialize;
+use std::str::FromStr;
 use std::sync::Arc;
 use wasm_bindgen::prelude::*;
 
 use mithril_client::{
     certificate_client::CertificateVerifierCache,
-    common::Epoch,
+    common::{BeaconFilter, Epoch, SignedEntityTypeDiscriminants},
     feedback::{FeedbackReceiver, MithrilEvent},
     CardanoTransactionsProofs, Client, ClientBuilder, ClientOptions, MessageBuilder,
     MithrilCertificate,
@@ -96,10 +97,16 @@ impl MithrilClient {
             None
         };
 
-        let client = ClientBuilder::aggregator(aggregator_endpoint, genesis_verification_key)
-            .add_feedback_receiver(feedback_receiver)
-            .with_options(client_options.clone())
-            .with_certificate_verifier_cache(certificate_verifier_cache.clone())
+        let mut client_builder =
+            ClientBuilder::aggregator(aggregator_endpoint, genesis_verification_key)
+                .add_feedback_receiver(feedback_receiver)
+                .with_options(client_options.clone())
+                .with_certificate_verifier_cache(certificate_verifier_cache.clone());
+        if let Some(genesis_verification_key_set) = &client_options.genesis_verification_key_set {
+            client_builder =
+                client_builder.with_genesis_verification_key_set(genesis_verification_key_set);
+        }
+        let client = client_builder
             .build()
             .map_err(|err| format!("{err:?}"))
             .unwrap();
@@ -255,6 +262,48 @@ impl MithrilClient {
         Ok(serde_wasm_bindgen::to_value(&result)?)
     }
 
+    /// Call the client for the list of the mithril certificates issued at the given epoch
+    #[wasm_bindgen]
+    pub async fn list_mithril_certificates_by_epoch(&self, epoch: u64) -> WasmResult {
+        let result = self
+            .client
+            .certificate()
+            .list_by_epoch(Epoch(epoch))
+            .await
+            .map_err(|err| format!("{err:?}"))?;
+
+        Ok(serde_wasm_bindgen::to_value(&result)?)
+    }
+
+    /// Call the client to get the most recent mithril certificate of a signed entity type
+    /// The optional beacon filter selects the certificate by the `epoch`, `immutable_file_number` or `block_number` of its beacon
+    #[wasm_bindgen]
+    pub async fn get_mithril_certificate_by_signed_entity_type(
+        &self,
+        signed_entity_type: &str,
+        beacon_filter: JsValue,
+    ) -> WasmResult {
+        let signed_entity_type = SignedEntityTypeDiscriminants::from_str(signed_entity_type)
+            .map_err(|err| format!("{err:?}"))?;
+        let beacon_filter: BeaconFilter = if beacon_filter.is_undefined() || beacon_filter.is_null()
+        {
+            BeaconFilter::default()
+        } else {
+            serde_wasm_bindgen::from_value(beacon_filter).map_err(|err| format!("{err:?}"))?
+        };
+        let result = self
+            .client
+            .certificate()
+            .get_by_signed_entity_type(signed_entity_type, &beacon_filter)
+            .await
+            .map_err(|err| format!("{err:?}"))?
+            .ok_or(JsValue::from_str(&format!(
+                "No certificate found for signed entity type: '{signed_entity_type}'"
+            )))?;
+
+        Ok(serde_wasm_bindgen::to_value(&result)?)
+    }
+
     /// Call the client to verify the certificate chain from a certificate hash
     #[wasm_bindgen]
     pub async fn verify_certificate_chain(&self, hash: &str) -> WasmResult {
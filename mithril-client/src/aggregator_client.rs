@@ -10,6 +10,7 @@
 use anyhow::{anyhow, Context};
 use async_recursion::async_recursion;
 use async_trait::async_trait;
+use futures::StreamExt;
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use reqwest::{Response, StatusCode, Url};
 use semver::Version;
@@ -19,11 +20,17 @@ use std::sync::Arc;
 use thiserror::Error;
 use tokio::sync::RwLock;
 
-use mithril_common::entities::{ClientError, ServerError};
+use mithril_common::entities::{
+    BeaconFilter, ClientError, ListFilter, ServerError, SignedEntityTypeDiscriminants,
+};
 use mithril_common::logging::LoggerExtensions;
+use mithril_common::messages::AggregatorEventMessage;
+#[cfg(feature = "unstable")]
+use mithril_common::messages::CardanoDatabaseImmutableFilesRestoredMessage;
 use mithril_common::MITHRIL_API_VERSION_HEADER;
 
 use crate::common::Epoch;
+use crate::utils::{ServerSentEvent, ServerSentEventDecoder};
 use crate::{MithrilError, MithrilResult};
 
 /// Error tied with the Aggregator client
@@ -55,9 +62,41 @@ pub enum AggregatorRequest {
         hash: String,
     },
 
+    /// Get a segment of the chain of [certificates][crate::MithrilCertificate], from the
+    /// certificate with the given hash to its ancestors
+    GetCertificateChain {
+        /// Hash of the first certificate of the chain segment
+        hash: String,
+
+        /// Maximum number of certificates to retrieve, the aggregator default if not set
+        depth: Option<u64>,
+    },
+
     /// Lists the aggregator [certificates][crate::MithrilCertificate]
     ListCertificates,
 
+    /// Lists the aggregator [certificates][crate::MithrilCertificate] matching the given cursor and filters
+    ListCertificatesWithFilter {
+        /// Cursor and filters of the list
+        filter: ListFilter,
+    },
+
+    /// Lists the aggregator [certificates][crate::MithrilCertificate] issued at the given epoch
+    ListCertificatesByEpoch {
+        /// Epoch of the certificates to list
+        epoch: Epoch,
+    },
+
+    /// Get the most recent [certificate][crate::MithrilCertificate] of a signed entity type whose
+    /// beacon matches the given filter
+    GetCertificateBySignedEntityType {
+        /// Signed entity type of the certificate to retrieve
+        signed_entity_type: SignedEntityTypeDiscriminants,
+
+        /// Filter on the fields of the signed entity beacon
+        beacon_filter: BeaconFilter,
+    },
+
     /// Get a specific [Mithril stake distribution][crate::MithrilStakeDistribution] from the aggregator
     GetMithrilStakeDistribution {
         /// Hash of the Mithril stake distribution to retrieve
@@ -67,6 +106,12 @@ pub enum AggregatorRequest {
     /// Lists the aggregator [Mithril stake distribution][crate::MithrilStakeDistribution]
     ListMithrilStakeDistributions,
 
+    /// Lists the aggregator [Mithril stake distribution][crate::MithrilStakeDistribution] matching the given cursor and filters
+    ListMithrilStakeDistributionsWithFilter {
+        /// Cursor and filters of the list
+        filter: ListFilter,
+    },
+
     /// Get a specific [snapshot][crate::Snapshot] from the aggregator
     GetSnapshot {
         /// Digest of the snapshot to retrieve
@@ -76,6 +121,12 @@ pub enum AggregatorRequest {
     /// Lists the aggregator [snapshots][crate::Snapshot]
     ListSnapshots,
 
+    /// Lists the aggregator [snapshots][crate::Snapshot] matching the given cursor and filters
+    ListSnapshotsWithFilter {
+        /// Cursor and filters of the list
+        filter: ListFilter,
+    },
+
     /// Increments the aggregator snapshot download statistics
     IncrementSnapshotStatistic {
         /// Snapshot as HTTP request body
@@ -93,12 +144,48 @@ pub enum AggregatorRequest {
     #[cfg(feature = "unstable")]
     ListCardanoDatabaseSnapshots,
 
+    /// Lists the aggregator [Cardano database snapshots][crate::CardanoDatabaseSnapshot] matching the given cursor and filters
+    #[cfg(feature = "unstable")]
+    ListCardanoDatabaseSnapshotsWithFilter {
+        /// Cursor and filters of the list
+        filter: ListFilter,
+    },
+
+    /// Lists the aggregator Cardano database immutable files digests
+    #[cfg(feature = "unstable")]
+    ListCardanoDatabaseDigests,
+
+    /// Increments the aggregator Cardano database snapshot immutable files restored statistics
+    #[cfg(feature = "unstable")]
+    IncrementCardanoDatabaseImmutablesRestoredStatistic {
+        /// Number of immutable files restored
+        number_of_immutables: u32,
+    },
+
+    /// Increments the aggregator Cardano database snapshot ancillary files restored statistics
+    #[cfg(feature = "unstable")]
+    IncrementCardanoDatabaseAncillaryStatistic,
+
+    /// Increments the aggregator Cardano database snapshot partial restoration statistics
+    #[cfg(feature = "unstable")]
+    IncrementCardanoDatabasePartialRestorationStatistic,
+
+    /// Increments the aggregator Cardano database snapshot complete restoration statistics
+    #[cfg(feature = "unstable")]
+    IncrementCardanoDatabaseCompleteRestorationStatistic,
+
     /// Get proofs that the given set of Cardano transactions is included in the global Cardano transactions set
     GetTransactionsProofs {
         /// Hashes of the transactions to get proofs for.
         transactions_hashes: Vec<String>,
     },
 
+    /// Get proofs that the given set of Cardano blocks is included in the global Cardano blocks set
+    GetBlocksProofs {
+        /// Hashes of the blocks to get proofs for.
+        block_hashes: Vec<String>,
+    },
+
     /// Get a specific [Cardano transaction snapshot][crate::CardanoTransactionSnapshot]
     GetCardanoTransactionSnapshot {
         /// Hash of the Cardano transaction snapshot to retrieve
@@ -108,6 +195,12 @@ pub enum AggregatorRequest {
     /// Lists the aggregator [Cardano transaction snapshot][crate::CardanoTransactionSnapshot]
     ListCardanoTransactionSnapshots,
 
+    /// Lists the aggregator [Cardano transaction snapshot][crate::CardanoTransactionSnapshot] matching the given cursor and filters
+    ListCardanoTransactionSnapshotsWithFilter {
+        /// Cursor and filters of the list
+        filter: ListFilter,
+    },
+
     /// Get a specific [Cardano stake distribution][crate::CardanoStakeDistribution] from the aggregator by hash
     GetCardanoStakeDistribution {
         /// Hash of the Cardano stake distribution to retrieve
@@ -122,6 +215,18 @@ pub enum AggregatorRequest {
 
     /// Lists the aggregator [Cardano stake distribution][crate::CardanoStakeDistribution]
     ListCardanoStakeDistributions,
+
+    /// Lists the aggregator [Cardano stake distribution][crate::CardanoStakeDistribution] matching the given cursor and filters
+    ListCardanoStakeDistributionsWithFilter {
+        /// Cursor and filters of the list
+        filter: ListFilter,
+    },
+
+    /// Subscribe to the aggregator [events][AggregatorEvent]
+    GetEvents {
+        /// Id of the last received event, the stream resumes after this event if given
+        last_event_id: Option<u64>,
+    },
 }
 
 impl AggregatorRequest {
@@ -131,17 +236,44 @@ impl AggregatorRequest {
             AggregatorRequest::GetCertificate { hash } => {
                 format!("certificate/{hash}")
             }
+            AggregatorRequest::GetCertificateChain { hash, depth } => match depth {
+                Some(depth) => format!("certificate/{hash}/chain?depth={depth}"),
+                None => format!("certificate/{hash}/chain"),
+            },
             AggregatorRequest::ListCertificates => "certificates".to_string(),
+            AggregatorRequest::ListCertificatesWithFilter { filter } => {
+                Self::route_with_filter("certificates", filter)
+            }
+            AggregatorRequest::ListCertificatesByEpoch { epoch } => {
+                format!("certificates/epoch/{epoch}")
+            }
+            AggregatorRequest::GetCertificateBySignedEntityType {
+                signed_entity_type,
+                beacon_filter,
+            } => {
+                let route = format!("certificate/signed-entity-type/{signed_entity_type}");
+                if beacon_filter.is_empty() {
+                    route
+                } else {
+                    format!("{route}?{}", beacon_filter.to_query_string())
+                }
+            }
             AggregatorRequest::GetMithrilStakeDistribution { hash } => {
                 format!("artifact/mithril-stake-distribution/{hash}")
             }
             AggregatorRequest::ListMithrilStakeDistributions => {
                 "artifact/mithril-stake-distributions".to_string()
             }
+            AggregatorRequest::ListMithrilStakeDistributionsWithFilter { filter } => {
+                Self::route_with_filter("artifact/mithril-stake-distributions", filter)
+            }
             AggregatorRequest::GetSnapshot { digest } => {
                 format!("artifact/snapshot/{}", digest)
             }
             AggregatorRequest::ListSnapshots => "artifact/snapshots".to_string(),
+            AggregatorRequest::ListSnapshotsWithFilter { filter } => {
+                Self::route_with_filter("artifact/snapshots", filter)
+            }
             AggregatorRequest::IncrementSnapshotStatistic { snapshot: _ } => {
                 "statistics/snapshot".to_string()
             }
@@ -153,18 +285,49 @@ impl AggregatorRequest {
             AggregatorRequest::ListCardanoDatabaseSnapshots => {
                 "artifact/cardano-database".to_string()
             }
+            #[cfg(feature = "unstable")]
+            AggregatorRequest::ListCardanoDatabaseSnapshotsWithFilter { filter } => {
+                Self::route_with_filter("artifact/cardano-database", filter)
+            }
+            #[cfg(feature = "unstable")]
+            AggregatorRequest::ListCardanoDatabaseDigests => {
+                "artifact/cardano-database/digests".to_string()
+            }
+            #[cfg(feature = "unstable")]
+            AggregatorRequest::IncrementCardanoDatabaseImmutablesRestoredStatistic {
+                number_of_immutables: _,
+            } => "statistics/cardano-database/immutable-files-restored".to_string(),
+            #[cfg(feature = "unstable")]
+            AggregatorRequest::IncrementCardanoDatabaseAncillaryStatistic => {
+                "statistics/cardano-database/ancillary-files-restored".to_string()
+            }
+            #[cfg(feature = "unstable")]
+            AggregatorRequest::IncrementCardanoDatabasePartialRestorationStatistic => {
+                "statistics/cardano-database/partial-restoration".to_string()
+            }
+            #[cfg(feature = "unstable")]
+            AggregatorRequest::IncrementCardanoDatabaseCompleteRestorationStatistic => {
+                "statistics/cardano-database/complete-restoration".to_string()
+            }
             AggregatorRequest::GetTransactionsProofs {
                 transactions_hashes,
             } => format!(
                 "proof/cardano-transaction?transaction_hashes={}",
                 transactions_hashes.join(",")
             ),
+            AggregatorRequest::GetBlocksProofs { block_hashes } => format!(
+                "proof/cardano-block?block_hashes={}",
+                block_hashes.join(",")
+            ),
             AggregatorRequest::GetCardanoTransactionSnapshot { hash } => {
                 format!("artifact/cardano-transaction/{hash}")
             }
             AggregatorRequest::ListCardanoTransactionSnapshots => {
                 "artifact/cardano-transactions".to_string()
             }
+            AggregatorRequest::ListCardanoTransactionSnapshotsWithFilter { filter } => {
+                Self::route_with_filter("artifact/cardano-transactions", filter)
+            }
             AggregatorRequest::GetCardanoStakeDistribution { hash } => {
                 format!("artifact/cardano-stake-distribution/{hash}")
             }
@@ -174,6 +337,21 @@ impl AggregatorRequest {
             AggregatorRequest::ListCardanoStakeDistributions => {
                 "artifact/cardano-stake-distributions".to_string()
             }
+            AggregatorRequest::ListCardanoStakeDistributionsWithFilter { filter } => {
+                Self::route_with_filter("artifact/cardano-stake-distributions", filter)
+            }
+            AggregatorRequest::GetEvents { last_event_id } => match last_event_id {
+                Some(last_event_id) => format!("events?last_event_id={last_event_id}"),
+                None => "events".to_string(),
+            },
+        }
+    }
+
+    fn route_with_filter(route: &str, filter: &ListFilter) -> String {
+        if filter.is_empty() {
+            route.to_string()
+        } else {
+            format!("{route}?{}", filter.to_query_string())
         }
     }
 
@@ -183,11 +361,38 @@ impl AggregatorRequest {
             AggregatorRequest::IncrementSnapshotStatistic { snapshot } => {
                 Some(snapshot.to_string())
             }
+            #[cfg(feature = "unstable")]
+            AggregatorRequest::IncrementCardanoDatabaseImmutablesRestoredStatistic {
+                number_of_immutables,
+            } => serde_json::to_string(&CardanoDatabaseImmutableFilesRestoredMessage {
+                nb_immutable_files: *number_of_immutables,
+            })
+            .ok(),
             _ => None,
         }
     }
 }
 
+/// An event pushed by the Aggregator on its events stream
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AggregatorEvent {
+    /// Id of the event, to give when subscribing again to resume the stream after this event
+    pub id: u64,
+
+    /// Content of the event
+    pub message: AggregatorEventMessage,
+}
+
+/// Stream of the events pushed by the Aggregator
+#[cfg(not(target_family = "wasm"))]
+pub type AggregatorEventStream =
+    futures::stream::BoxStream<'static, MithrilResult<AggregatorEvent>>;
+
+/// Stream of the events pushed by the Aggregator
+#[cfg(target_family = "wasm")]
+pub type AggregatorEventStream =
+    futures::stream::LocalBoxStream<'static, MithrilResult<AggregatorEvent>>;
+
 /// API that defines a client for the Aggregator
 #[cfg_attr(target_family = "wasm", async_trait(?Send))]
 #[cfg_attr(not(target_family = "wasm"), async_trait)]
@@ -203,6 +408,12 @@ pub trait AggregatorClient: Sync + Send {
         &self,
         request: AggregatorRequest,
     ) -> Result<String, AggregatorClientError>;
+
+    /// Get the stream of events pushed by the Aggregator
+    async fn get_event_stream(
+        &self,
+        request: AggregatorRequest,
+    ) -> Result<AggregatorEventStream, AggregatorClientError>;
 }
 
 /// Responsible for HTTP transport and API version check.
@@ -412,6 +623,19 @@ impl AggregatorHTTPClient {
         AggregatorClientError::RemoteServerLogical(anyhow!("{client_error}"))
     }
 
+    fn parse_event(event: ServerSentEvent) -> MithrilResult<AggregatorEvent> {
+        let id = event
+            .id
+            .ok_or(anyhow!("Received an aggregator event without id"))?;
+        let id = id
+            .parse::<u64>()
+            .with_context(|| format!("Invalid aggregator event id: '{id}'"))?;
+        let message = serde_json::from_str::<AggregatorEventMessage>(&event.data)
+            .with_context(|| format!("Could not deserialize aggregator event with id '{id}'"))?;
+
+        Ok(AggregatorEvent { id, message })
+    }
+
     async fn remote_technical_error(response: Response) -> AggregatorClientError {
         let status_code = response.status();
         let server_error = response
@@ -458,16 +682,51 @@ impl AggregatorClient for AggregatorHTTPClient {
             )
         })
     }
+
+    async fn get_event_stream(
+        &self,
+        request: AggregatorRequest,
+    ) -> Result<AggregatorEventStream, AggregatorClientError> {
+        let response = self.get(self.get_url_for_route(&request.route())?).await?;
+
+        let events = response
+            .bytes_stream()
+            .scan(ServerSentEventDecoder::new(), |decoder, chunk| {
+                let events: Vec<MithrilResult<AggregatorEvent>> = match chunk {
+                    Ok(bytes) => decoder
+                        .feed(&bytes)
+                        .into_iter()
+                        .map(Self::parse_event)
+                        .collect(),
+                    Err(e) => vec![Err(
+                        anyhow!(e).context("Error while reading the aggregator events stream")
+                    )],
+                };
+
+                futures::future::ready(Some(futures::stream::iter(events)))
+            })
+            .flatten();
+
+        #[cfg(not(target_family = "wasm"))]
+        let events = events.boxed();
+        #[cfg(target_family = "wasm")]
+        let events = events.boxed_local();
+
+        Ok(events)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use futures::TryStreamExt;
     use httpmock::MockServer;
     use reqwest::header::{HeaderName, HeaderValue};
     use std::collections::HashMap;
 
     use mithril_common::api_version::APIVersionProvider;
-    use mithril_common::entities::{ClientError, ServerError};
+    use mithril_common::entities::{
+        ClientError, ServerError, SignedEntityType, SignedEntityTypeDiscriminants,
+    };
 
     use super::*;
 
@@ -543,6 +802,25 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "unstable")]
+    #[test]
+    fn get_body_of_increment_cardano_database_immutables_restored_statistic_request() {
+        let body = AggregatorRequest::IncrementCardanoDatabaseImmutablesRestoredStatistic {
+            number_of_immutables: 58,
+        }
+        .get_body()
+        .expect("the request should have a body");
+
+        let message: CardanoDatabaseImmutableFilesRestoredMessage =
+            serde_json::from_str(&body).unwrap();
+        assert_eq!(
+            CardanoDatabaseImmutableFilesRestoredMessage {
+                nb_immutable_files: 58
+            },
+            message
+        );
+    }
+
     #[test]
     fn deduce_routes_from_request() {
         assert_eq!(
@@ -553,6 +831,24 @@ mod tests {
             .route()
         );
 
+        assert_eq!(
+            "certificate/abc/chain".to_string(),
+            AggregatorRequest::GetCertificateChain {
+                hash: "abc".to_string(),
+                depth: None,
+            }
+            .route()
+        );
+
+        assert_eq!(
+            "certificate/abc/chain?depth=50".to_string(),
+            AggregatorRequest::GetCertificateChain {
+                hash: "abc".to_string(),
+                depth: Some(50),
+            }
+            .route()
+        );
+
         assert_eq!(
             "artifact/mithril-stake-distribution/abc".to_string(),
             AggregatorRequest::GetMithrilStakeDistribution {
@@ -610,6 +906,39 @@ mod tests {
             AggregatorRequest::ListCardanoDatabaseSnapshots.route()
         );
 
+        #[cfg(feature = "unstable")]
+        assert_eq!(
+            "artifact/cardano-database/digests".to_string(),
+            AggregatorRequest::ListCardanoDatabaseDigests.route()
+        );
+
+        #[cfg(feature = "unstable")]
+        assert_eq!(
+            "statistics/cardano-database/immutable-files-restored".to_string(),
+            AggregatorRequest::IncrementCardanoDatabaseImmutablesRestoredStatistic {
+                number_of_immutables: 58
+            }
+            .route()
+        );
+
+        #[cfg(feature = "unstable")]
+        assert_eq!(
+            "statistics/cardano-database/ancillary-files-restored".to_string(),
+            AggregatorRequest::IncrementCardanoDatabaseAncillaryStatistic.route()
+        );
+
+        #[cfg(feature = "unstable")]
+        assert_eq!(
+            "statistics/cardano-database/partial-restoration".to_string(),
+            AggregatorRequest::IncrementCardanoDatabasePartialRestorationStatistic.route()
+        );
+
+        #[cfg(feature = "unstable")]
+        assert_eq!(
+            "statistics/cardano-database/complete-restoration".to_string(),
+            AggregatorRequest::IncrementCardanoDatabaseCompleteRestorationStatistic.route()
+        );
+
         assert_eq!(
             "proof/cardano-transaction?transaction_hashes=abc,def,ghi,jkl".to_string(),
             AggregatorRequest::GetTransactionsProofs {
@@ -623,6 +952,14 @@ mod tests {
             .route()
         );
 
+        assert_eq!(
+            "proof/cardano-block?block_hashes=abc,def".to_string(),
+            AggregatorRequest::GetBlocksProofs {
+                block_hashes: vec!["abc".to_string(), "def".to_string()]
+            }
+            .route()
+        );
+
         assert_eq!(
             "artifact/cardano-transaction/abc".to_string(),
             AggregatorRequest::GetCardanoTransactionSnapshot {
@@ -655,6 +992,189 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_list_with_filter_routes() {
+        let filter = ListFilter::default()
+            .before("hash-123")
+            .from_epoch(Epoch(4))
+            .to_epoch(Epoch(12));
+
+        assert_eq!(
+            "certificates".to_string(),
+            AggregatorRequest::ListCertificatesWithFilter {
+                filter: ListFilter::default()
+            }
+            .route()
+        );
+
+        assert_eq!(
+            "certificates?after=hash-456&signed_entity_type=CardanoTransactions".to_string(),
+            AggregatorRequest::ListCertificatesWithFilter {
+                filter: ListFilter::default()
+                    .after("hash-456")
+                    .signed_entity_type(SignedEntityTypeDiscriminants::CardanoTransactions)
+            }
+            .route()
+        );
+
+        assert_eq!(
+            "artifact/mithril-stake-distributions?before=hash-123&from_epoch=4&to_epoch=12"
+                .to_string(),
+            AggregatorRequest::ListMithrilStakeDistributionsWithFilter {
+                filter: filter.clone()
+            }
+            .route()
+        );
+
+        assert_eq!(
+            "artifact/snapshots?before=hash-123&from_epoch=4&to_epoch=12".to_string(),
+            AggregatorRequest::ListSnapshotsWithFilter {
+                filter: filter.clone()
+            }
+            .route()
+        );
+
+        #[cfg(feature = "unstable")]
+        assert_eq!(
+            "artifact/cardano-database?before=hash-123&from_epoch=4&to_epoch=12".to_string(),
+            AggregatorRequest::ListCardanoDatabaseSnapshotsWithFilter {
+                filter: filter.clone()
+            }
+            .route()
+        );
+
+        assert_eq!(
+            "artifact/cardano-transactions?before=hash-123&from_epoch=4&to_epoch=12".to_string(),
+            AggregatorRequest::ListCardanoTransactionSnapshotsWithFilter {
+                filter: filter.clone()
+            }
+            .route()
+        );
+
+        assert_eq!(
+            "artifact/cardano-stake-distributions?before=hash-123&from_epoch=4&to_epoch=12"
+                .to_string(),
+            AggregatorRequest::ListCardanoStakeDistributionsWithFilter { filter }.route()
+        );
+    }
+
+    #[test]
+    fn test_certificate_lookup_routes() {
+        assert_eq!(
+            "certificates/epoch/12".to_string(),
+            AggregatorRequest::ListCertificatesByEpoch { epoch: Epoch(12) }.route()
+        );
+
+        assert_eq!(
+            "certificate/signed-entity-type/CardanoTransactions".to_string(),
+            AggregatorRequest::GetCertificateBySignedEntityType {
+                signed_entity_type: SignedEntityTypeDiscriminants::CardanoTransactions,
+                beacon_filter: BeaconFilter::default(),
+            }
+            .route()
+        );
+
+        assert_eq!(
+            "certificate/signed-entity-type/CardanoImmutableFilesFull?epoch=4&immutable_file_number=1234"
+                .to_string(),
+            AggregatorRequest::GetCertificateBySignedEntityType {
+                signed_entity_type: SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
+                beacon_filter: BeaconFilter::default()
+                    .epoch(Epoch(4))
+                    .immutable_file_number(1234),
+            }
+            .route()
+        );
+    }
+
+    #[test]
+    fn test_get_events_routes() {
+        assert_eq!(
+            "events".to_string(),
+            AggregatorRequest::GetEvents {
+                last_event_id: None
+            }
+            .route()
+        );
+
+        assert_eq!(
+            "events?last_event_id=123".to_string(),
+            AggregatorRequest::GetEvents {
+                last_event_id: Some(123)
+            }
+            .route()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_client_decodes_events_stream() {
+        let (aggregator, client) = setup_server_and_client();
+        aggregator.mock(|when, then| {
+            when.path("/events").query_param("last_event_id", "11");
+            then.status(StatusCode::OK.as_u16())
+                .header("content-type", "text/event-stream")
+                .body(concat!(
+                    ":keep-alive\n\n",
+                    "event:epoch_changed\n",
+                    "data:{\"type\":\"epoch_changed\",\"data\":{\"epoch\":5}}\n",
+                    "id:12\n\n",
+                    "event:artifact_created\n",
+                    "data:{\"type\":\"artifact_created\",\"data\":{",
+                    "\"signed_entity_type\":{\"MithrilStakeDistribution\":5},",
+                    "\"certificate_hash\":\"hash-123\"}}\n",
+                    "id:13\n\n",
+                ));
+        });
+
+        let events: Vec<AggregatorEvent> = client
+            .get_event_stream(AggregatorRequest::GetEvents {
+                last_event_id: Some(11),
+            })
+            .await
+            .expect("GET request should succeed")
+            .try_collect()
+            .await
+            .expect("Events should be decoded");
+
+        assert_eq!(
+            vec![
+                AggregatorEvent {
+                    id: 12,
+                    message: AggregatorEventMessage::EpochChanged { epoch: Epoch(5) },
+                },
+                AggregatorEvent {
+                    id: 13,
+                    message: AggregatorEventMessage::ArtifactCreated {
+                        signed_entity_type: SignedEntityType::MithrilStakeDistribution(Epoch(5)),
+                        certificate_hash: "hash-123".to_string(),
+                    },
+                },
+            ],
+            events
+        );
+    }
+
+    #[tokio::test]
+    async fn test_client_events_stream_yields_an_error_for_an_event_without_id() {
+        let (aggregator, client) = setup_server_and_client();
+        aggregator.mock(|when, then| {
+            when.path("/events");
+            then.status(StatusCode::OK.as_u16())
+                .body("data:{\"type\":\"epoch_changed\",\"data\":{\"epoch\":5}}\n\n");
+        });
+
+        let result: MithrilResult<Vec<AggregatorEvent>> = client
+            .get_event_stream(AggregatorRequest::GetEvents {
+                last_event_id: None,
+            })
+            .await
+            .expect("GET request should succeed")
+            .try_collect()
+            .await;
+
+        result.expect_err("An event without id should be rejected");
+    }
+
     #[tokio::test]
     async fn test_client_handle_4xx_errors() {
         let client_error = ClientError::new("label", "message");
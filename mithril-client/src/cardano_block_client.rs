@@ -0,0 +1,141 @@
+//! A client to retrieve from an aggregator cryptographic proofs of membership for a subset of Cardano blocks.
+//!
+//! In order to do so it defines a [CardanoBlockClient] which exposes the following feature:
+//!  - [get_proofs][CardanoBlockClient::get_proofs]: get a [cryptographic proof][CardanoBlocksProofs]
+//!    that the blocks with given hash are included in the global Cardano blocks set, each certified
+//!    block coming with its block number and slot number.
+//!
+//!  **Important:** Verifying a proof **only** means that its cryptography is valid, in order to certify that a Cardano
+//! blocks subset is valid, the associated proof must be tied to a valid Mithril certificate (see the example below).
+//!
+//! # Get and verify Cardano block proof
+//!
+//! To get and verify a Cardano block proof using the [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::{ClientBuilder, MessageBuilder};
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//!
+//! // 1 - Get a proof from the aggregator and verify it
+//! let cardano_block_proof = client.cardano_block().get_proofs(&["block-hash-1", "block-hash-2"]).await?;
+//! println!("Mithril could not certify the following blocks : {:?}", &cardano_block_proof.non_certified_blocks);
+//!
+//! let verified_blocks = cardano_block_proof.verify()?;
+//!
+//! // 2 - Verify its associated certificate chain
+//! let certificate = client.certificate().verify_chain(&cardano_block_proof.certificate_hash).await?;
+//!
+//! // 3 - Ensure that the proof is indeed signed in the associated certificate
+//! let message = MessageBuilder::new().compute_cardano_blocks_proofs_message(&certificate, &verified_blocks);
+//! if certificate.match_message(&message) {
+//!     // All green, Mithril certifies that those blocks are part of the Cardano blocks set at the given heights.
+//!     for block in verified_blocks.certified_blocks() {
+//!         println!("Certified block hash={} at height={}", block.block_hash, block.block_number);
+//!     }
+//! }
+//! #    Ok(())
+//! # }
+//! ```
+
+use crate::aggregator_client::{AggregatorClient, AggregatorRequest};
+use crate::{CardanoBlocksProofs, MithrilResult};
+use anyhow::Context;
+use std::sync::Arc;
+
+/// HTTP client for CardanoBlocksAPI from the Aggregator
+pub struct CardanoBlockClient {
+    aggregator_client: Arc<dyn AggregatorClient>,
+}
+
+impl CardanoBlockClient {
+    /// Constructs a new `CardanoBlockClient`.
+    pub fn new(aggregator_client: Arc<dyn AggregatorClient>) -> Self {
+        Self { aggregator_client }
+    }
+
+    /// Get proofs that the given subset of blocks is included in the Cardano blocks set.
+    pub async fn get_proofs<T: ToString>(
+        &self,
+        block_hashes: &[T],
+    ) -> MithrilResult<CardanoBlocksProofs> {
+        let content = self
+            .aggregator_client
+            .get_content(AggregatorRequest::GetBlocksProofs {
+                block_hashes: block_hashes.iter().map(|h| h.to_string()).collect(),
+            })
+            .await?;
+        let blocks_proofs: CardanoBlocksProofs = serde_json::from_str(&content)
+            .with_context(|| "CardanoBlockProof Client can not deserialize blocks proofs")?;
+
+        Ok(blocks_proofs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use anyhow::anyhow;
+    use mockall::predicate::eq;
+
+    use crate::aggregator_client::{AggregatorClientError, MockAggregatorHTTPClient};
+    use crate::common::BlockNumber;
+    use crate::{CardanoBlocksProofs, CardanoBlocksSetProof};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_get_proof_ok() {
+        let mut aggregator_client = MockAggregatorHTTPClient::new();
+        let certificate_hash = "cert-hash-123".to_string();
+        let set_proof = CardanoBlocksSetProof::dummy();
+        let block_hashes: Vec<String> = set_proof
+            .blocks
+            .iter()
+            .map(|b| b.block_hash.clone())
+            .collect();
+        let blocks_proofs = CardanoBlocksProofs::new(
+            &certificate_hash,
+            vec![set_proof.clone()],
+            vec![],
+            BlockNumber(99999),
+        );
+        let expected_blocks_proofs = blocks_proofs.clone();
+        aggregator_client
+            .expect_get_content()
+            .with(eq(AggregatorRequest::GetBlocksProofs {
+                block_hashes: block_hashes.clone(),
+            }))
+            .return_once(move |_| Ok(serde_json::to_string(&blocks_proofs).unwrap()))
+            .times(1);
+
+        let cardano_block_client = CardanoBlockClient::new(Arc::new(aggregator_client));
+        let blocks_proofs = cardano_block_client
+            .get_proofs(&block_hashes)
+            .await
+            .unwrap();
+
+        assert_eq!(expected_blocks_proofs, blocks_proofs);
+    }
+
+    #[tokio::test]
+    async fn test_get_proof_ko() {
+        let mut aggregator_client = MockAggregatorHTTPClient::new();
+        aggregator_client
+            .expect_get_content()
+            .return_once(move |_| {
+                Err(AggregatorClientError::RemoteServerTechnical(anyhow!(
+                    "an error"
+                )))
+            })
+            .times(1);
+
+        let cardano_block_client = CardanoBlockClient::new(Arc::new(aggregator_client));
+        cardano_block_client
+            .get_proofs(&["block-hash-123"])
+            .await
+            .expect_err("The cardano block client should fail here.");
+    }
+}
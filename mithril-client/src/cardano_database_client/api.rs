@@ -0,0 +1,138 @@
+#[cfg(feature = "fs")]
+use slog::Logger;
+#[cfg(feature = "fs")]
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::aggregator_client::AggregatorClient;
+#[cfg(feature = "fs")]
+use crate::cardano_database_client::{
+    download_unpack::{self, DownloadUnpackOptions},
+    proving, ImmutableFileRange,
+};
+use crate::cardano_database_client::{fetch, statistics};
+use crate::common::ListFilter;
+#[cfg(feature = "fs")]
+use crate::feedback::FeedbackSender;
+#[cfg(feature = "fs")]
+use crate::snapshot_downloader::SnapshotDownloader;
+#[cfg(feature = "fs")]
+use crate::{common::MKProof, MithrilCertificate};
+use crate::{CardanoDatabaseSnapshot, CardanoDatabaseSnapshotListItem, MithrilResult};
+
+/// HTTP client for CardanoDatabase API from the Aggregator
+pub struct CardanoDatabaseClient {
+    pub(super) aggregator_client: Arc<dyn AggregatorClient>,
+    #[cfg(feature = "fs")]
+    pub(super) snapshot_downloader: Arc<dyn SnapshotDownloader>,
+    #[cfg(feature = "fs")]
+    pub(super) feedback_sender: FeedbackSender,
+    #[cfg(feature = "fs")]
+    pub(super) logger: Logger,
+}
+
+impl CardanoDatabaseClient {
+    /// Constructs a new `CardanoDatabase`.
+    pub fn new(
+        aggregator_client: Arc<dyn AggregatorClient>,
+        #[cfg(feature = "fs")] snapshot_downloader: Arc<dyn SnapshotDownloader>,
+        #[cfg(feature = "fs")] feedback_sender: FeedbackSender,
+        #[cfg(feature = "fs")] logger: Logger,
+    ) -> Self {
+        Self {
+            aggregator_client,
+            #[cfg(feature = "fs")]
+            snapshot_downloader,
+            #[cfg(feature = "fs")]
+            feedback_sender,
+            #[cfg(feature = "fs")]
+            logger: mithril_common::logging::LoggerExtensions::new_with_component_name::<Self>(
+                &logger,
+            ),
+        }
+    }
+
+    /// Fetch a list of signed CardanoDatabase
+    pub async fn list(&self) -> MithrilResult<Vec<CardanoDatabaseSnapshotListItem>> {
+        fetch::list(self).await
+    }
+
+    /// Fetch a list of signed CardanoDatabase matching the given cursor and filters
+    pub async fn list_with_filter(
+        &self,
+        filter: &ListFilter,
+    ) -> MithrilResult<Vec<CardanoDatabaseSnapshotListItem>> {
+        fetch::list_with_filter(self, filter).await
+    }
+
+    /// Get the given Cardano database data by hash.
+    pub async fn get(&self, hash: &str) -> MithrilResult<Option<CardanoDatabaseSnapshot>> {
+        fetch::get(self, hash).await
+    }
+
+    cfg_fs! {
+        /// Download and unpack the given range of immutable files and, optionally, the ancillary
+        /// files of the given Cardano database snapshot to the given directory.
+        ///
+        /// **NOTE**: The directory will be created if it doesn't exist, and the user running the
+        /// binary must have read/write access to it.
+        pub async fn download_unpack(
+            &self,
+            cardano_database_snapshot: &CardanoDatabaseSnapshot,
+            immutable_file_range: &ImmutableFileRange,
+            target_dir: &Path,
+            download_unpack_options: DownloadUnpackOptions,
+        ) -> MithrilResult<()> {
+            download_unpack::download_unpack(
+                self,
+                cardano_database_snapshot,
+                immutable_file_range,
+                target_dir,
+                download_unpack_options,
+            )
+            .await
+        }
+
+        /// Compute a Merkle proof of the given range of immutable files restored in the given
+        /// database directory.
+        ///
+        /// The immutable files are checked against the digests certified for the Cardano
+        /// database snapshot, the resulting proof can be used to compute the message signed by
+        /// the Mithril certificate with the [MessageBuilder][crate::MessageBuilder].
+        pub async fn compute_merkle_proof(
+            &self,
+            certificate: &MithrilCertificate,
+            cardano_database_snapshot: &CardanoDatabaseSnapshot,
+            immutable_file_range: &ImmutableFileRange,
+            database_dir: &Path,
+        ) -> MithrilResult<MKProof> {
+            proving::compute_merkle_proof(
+                self,
+                certificate,
+                cardano_database_snapshot,
+                immutable_file_range,
+                database_dir,
+            )
+            .await
+        }
+    }
+
+    /// Increments the aggregator Cardano database restoration statistics
+    ///
+    /// The `full_restoration` flag must be set if all the immutable files of the Cardano database
+    /// snapshot have been restored.
+    pub async fn add_statistics(
+        &self,
+        full_restoration: bool,
+        include_ancillary: bool,
+        number_of_immutable_files_restored: u64,
+    ) -> MithrilResult<()> {
+        statistics::add_statistics(
+            self,
+            full_restoration,
+            include_ancillary,
+            number_of_immutable_files_restored,
+        )
+        .await
+    }
+}
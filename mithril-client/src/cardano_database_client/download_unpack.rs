@@ -0,0 +1,1175 @@
+use anyhow::{anyhow, Context};
+use slog::warn;
+use std::fs;
+use std::path::Path;
+
+use mithril_common::digesters::{IMMUTABLE_DIR, LEDGER_DIR, VOLATILE_DIR};
+use mithril_common::entities::{AncillaryLocation, ImmutableFileNumber, ImmutablesLocation};
+
+use crate::cardano_database_client::{
+    CardanoDatabaseClient, CardanoDatabaseClientError, ImmutableFileRange,
+};
+use crate::common::CompressionAlgorithm;
+use crate::feedback::MithrilEvent;
+use crate::{CardanoDatabaseSnapshot, MithrilResult};
+
+/// Options for downloading and unpacking a Cardano database
+#[derive(Debug, Copy, Clone)]
+pub struct DownloadUnpackOptions {
+    /// Allow overriding the files already existing in the target directory
+    pub allow_override: bool,
+
+    /// Include ancillary files (ledger state, volatile and last immutable files) in the download
+    pub include_ancillary: bool,
+}
+
+impl Default for DownloadUnpackOptions {
+    fn default() -> Self {
+        Self {
+            allow_override: false,
+            include_ancillary: true,
+        }
+    }
+}
+
+#[inline]
+pub(super) async fn download_unpack(
+    client: &CardanoDatabaseClient,
+    cardano_database_snapshot: &CardanoDatabaseSnapshot,
+    immutable_file_range: &ImmutableFileRange,
+    target_dir: &Path,
+    download_unpack_options: DownloadUnpackOptions,
+) -> MithrilResult<()> {
+    let last_immutable_file_number = cardano_database_snapshot.beacon.immutable_file_number;
+    let immutable_file_number_range =
+        immutable_file_range.to_range_inclusive(last_immutable_file_number)?;
+    let total_immutable_files = immutable_file_range.length(last_immutable_file_number)?;
+    prepare_target_directory(target_dir, &download_unpack_options)?;
+
+    let download_id = MithrilEvent::new_cardano_database_download_id();
+    client
+        .feedback_sender
+        .send_event(MithrilEvent::CardanoDatabaseDownloadStarted {
+            hash: cardano_database_snapshot.hash.clone(),
+            download_id: download_id.clone(),
+            total_immutable_files,
+            include_ancillary: download_unpack_options.include_ancillary,
+        })
+        .await;
+
+    let mut immutables_locations_state =
+        ImmutablesLocationsState::new(cardano_database_snapshot.locations.immutables.len());
+    for immutable_file_number in immutable_file_number_range {
+        download_unpack_immutable_file(
+            client,
+            &cardano_database_snapshot.locations.immutables,
+            &mut immutables_locations_state,
+            immutable_file_number,
+            cardano_database_snapshot.compression_algorithm,
+            target_dir,
+            &download_id,
+        )
+        .await?;
+        client
+            .feedback_sender
+            .send_event(MithrilEvent::CardanoDatabaseImmutableDownloadCompleted {
+                download_id: download_id.clone(),
+                immutable_file_number,
+            })
+            .await;
+    }
+
+    if download_unpack_options.include_ancillary {
+        download_unpack_ancillary_file(
+            client,
+            &cardano_database_snapshot.locations.ancillary,
+            cardano_database_snapshot.compression_algorithm,
+            target_dir,
+            &download_id,
+        )
+        .await?;
+        client
+            .feedback_sender
+            .send_event(MithrilEvent::CardanoDatabaseAncillaryDownloadCompleted {
+                download_id: download_id.clone(),
+            })
+            .await;
+    }
+
+    client
+        .feedback_sender
+        .send_event(MithrilEvent::CardanoDatabaseDownloadCompleted { download_id })
+        .await;
+
+    Ok(())
+}
+
+/// Create the target directory if needed and check that its content can be written.
+fn prepare_target_directory(
+    target_dir: &Path,
+    download_unpack_options: &DownloadUnpackOptions,
+) -> MithrilResult<()> {
+    if !download_unpack_options.allow_override {
+        let mut directories_to_check = vec![target_dir.join(IMMUTABLE_DIR)];
+        if download_unpack_options.include_ancillary {
+            directories_to_check.push(target_dir.join(LEDGER_DIR));
+            directories_to_check.push(target_dir.join(VOLATILE_DIR));
+        }
+
+        for directory in directories_to_check {
+            if is_non_empty_directory(&directory)? {
+                return Err(CardanoDatabaseClientError::TargetDirectoryNotEmpty {
+                    target_dir: directory,
+                }
+                .into());
+            }
+        }
+    }
+
+    fs::create_dir_all(target_dir).with_context(|| {
+        format!(
+            "Could not create target directory: '{}'",
+            target_dir.display()
+        )
+    })?;
+
+    Ok(())
+}
+
+fn is_non_empty_directory(directory: &Path) -> MithrilResult<bool> {
+    if !directory.is_dir() {
+        return Ok(false);
+    }
+
+    let mut entries = fs::read_dir(directory)
+        .with_context(|| format!("Could not read directory: '{}'", directory.display()))?;
+
+    Ok(entries.next().is_some())
+}
+
+/// State of the immutable files locations shared by the downloads of the immutable files
+struct ImmutablesLocationsState {
+    /// Index of the location to try first, i.e. the location of the last successful download
+    preferred_index: usize,
+
+    /// Result of the probe of each location, `None` if the location was not probed yet
+    reachability: Vec<Option<Result<(), String>>>,
+}
+
+impl ImmutablesLocationsState {
+    fn new(total_locations: usize) -> Self {
+        Self {
+            preferred_index: 0,
+            reachability: vec![None; total_locations],
+        }
+    }
+}
+
+/// Download and unpack an immutable file, trying the locations in order starting with the
+/// preferred location of the given state.
+///
+/// Each location is probed only once, with the first immutable file downloaded from it. The
+/// location used to download the file becomes the preferred one, so the following files are
+/// downloaded from it first.
+async fn download_unpack_immutable_file(
+    client: &CardanoDatabaseClient,
+    locations: &[ImmutablesLocation],
+    locations_state: &mut ImmutablesLocationsState,
+    immutable_file_number: ImmutableFileNumber,
+    compression_algorithm: CompressionAlgorithm,
+    target_dir: &Path,
+    download_id: &str,
+) -> MithrilResult<()> {
+    let file_locations: Vec<(usize, String)> = (0..locations.len())
+        .map(|offset| (locations_state.preferred_index + offset) % locations.len())
+        .map(|index| {
+            let file_uri = match &locations[index] {
+                ImmutablesLocation::CloudStorage { uri } => {
+                    uri.expand_for_immutable_file_number(immutable_file_number)
+                }
+            };
+            (index, file_uri.into())
+        })
+        .collect();
+
+    let mut tried_locations = vec![];
+    for (position, (index, file_location)) in file_locations.iter().enumerate() {
+        let reachability = probe_immutables_location(
+            client,
+            &mut locations_state.reachability[*index],
+            file_location,
+        )
+        .await;
+        let failure_reason = match reachability {
+            Ok(()) => match client
+                .snapshot_downloader
+                .download_unpack(
+                    file_location,
+                    target_dir,
+                    compression_algorithm,
+                    download_id,
+                    0,
+                )
+                .await
+            {
+                Ok(()) => {
+                    locations_state.preferred_index = *index;
+                    return Ok(());
+                }
+                Err(e) => {
+                    warn!(
+                        client.logger, "Failed downloading immutable file from '{file_location}'";
+                        "immutable_file_number" => immutable_file_number,
+                        "error" => ?e
+                    );
+                    format!("download failed: {e:#}")
+                }
+            },
+            Err(failure_reason) => failure_reason,
+        };
+
+        if let Some((_, next_location)) = file_locations.get(position + 1) {
+            send_location_switched_event(
+                client,
+                download_id,
+                file_location,
+                next_location,
+                failure_reason,
+            )
+            .await;
+        }
+        tried_locations.push(file_location.clone());
+    }
+
+    Err(CardanoDatabaseClientError::NoWorkingImmutableLocation {
+        immutable_file_number,
+        locations: tried_locations.join(", "),
+    }
+    .into())
+}
+
+/// Probe an immutables location with the given file location if it was not probed yet.
+///
+/// Returns the failure reason if the location is unreachable.
+async fn probe_immutables_location(
+    client: &CardanoDatabaseClient,
+    location_reachability: &mut Option<Result<(), String>>,
+    file_location: &str,
+) -> Result<(), String> {
+    if location_reachability.is_none() {
+        let probe_result = client
+            .snapshot_downloader
+            .probe(file_location)
+            .await
+            .map_err(|e| {
+                warn!(
+                    client.logger, "Immutable files location of '{file_location}' is unreachable";
+                    "error" => ?e
+                );
+                format!("location unreachable: {e:#}")
+            });
+        *location_reachability = Some(probe_result);
+    }
+
+    location_reachability.clone().unwrap_or(Ok(()))
+}
+
+async fn download_unpack_ancillary_file(
+    client: &CardanoDatabaseClient,
+    locations: &[AncillaryLocation],
+    compression_algorithm: CompressionAlgorithm,
+    target_dir: &Path,
+    download_id: &str,
+) -> MithrilResult<()> {
+    if locations.is_empty() {
+        return Err(anyhow!(
+            "No ancillary location available to download the ancillary files"
+        ));
+    }
+
+    let file_locations: Vec<String> = locations
+        .iter()
+        .map(|location| match location {
+            AncillaryLocation::CloudStorage { uri } => uri.clone(),
+        })
+        .collect();
+
+    for (position, file_location) in file_locations.iter().enumerate() {
+        let failure_reason = match client.snapshot_downloader.probe(file_location).await {
+            Ok(()) => match client
+                .snapshot_downloader
+                .download_unpack(
+                    file_location,
+                    target_dir,
+                    compression_algorithm,
+                    download_id,
+                    0,
+                )
+                .await
+            {
+                Ok(()) => return Ok(()),
+                Err(e) => {
+                    warn!(
+                        client.logger, "Failed downloading ancillary files from '{file_location}'";
+                        "error" => ?e
+                    );
+                    format!("download failed: {e:#}")
+                }
+            },
+            Err(e) => {
+                warn!(
+                    client.logger, "Ancillary files location '{file_location}' is unreachable";
+                    "error" => ?e
+                );
+                format!("location unreachable: {e:#}")
+            }
+        };
+
+        if let Some(next_location) = file_locations.get(position + 1) {
+            send_location_switched_event(
+                client,
+                download_id,
+                file_location,
+                next_location,
+                failure_reason,
+            )
+            .await;
+        }
+    }
+
+    Err(CardanoDatabaseClientError::NoWorkingAncillaryLocation {
+        locations: file_locations.join(", "),
+    }
+    .into())
+}
+
+async fn send_location_switched_event(
+    client: &CardanoDatabaseClient,
+    download_id: &str,
+    failed_location: &str,
+    next_location: &str,
+    reason: String,
+) {
+    client
+        .feedback_sender
+        .send_event(MithrilEvent::CardanoDatabaseDownloadLocationSwitched {
+            download_id: download_id.to_string(),
+            failed_location: failed_location.to_string(),
+            next_location: next_location.to_string(),
+            reason,
+        })
+        .await;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::sync::Arc;
+
+    use mithril_common::entities::{CardanoDbBeacon, Epoch, MultiFilesUri, TemplateUri};
+    use mithril_common::messages::ArtifactsLocationsMessagePart;
+    use mithril_common::test_utils::TempDir;
+    use mockall::predicate::{always, eq};
+
+    use crate::cardano_database_client::test_dependency_injector::CardanoDatabaseClientDependencyInjector;
+    use crate::feedback::StackFeedbackReceiver;
+
+    use super::*;
+
+    fn cardano_database_snapshot_with_locations(
+        immutable_file_number: ImmutableFileNumber,
+        immutables: Vec<ImmutablesLocation>,
+        ancillary: Vec<AncillaryLocation>,
+    ) -> CardanoDatabaseSnapshot {
+        CardanoDatabaseSnapshot {
+            hash: "hash-123".to_string(),
+            beacon: CardanoDbBeacon {
+                epoch: Epoch(1),
+                immutable_file_number,
+            },
+            locations: ArtifactsLocationsMessagePart {
+                digests: vec![],
+                immutables,
+                ancillary,
+            },
+            ..CardanoDatabaseSnapshot::dummy()
+        }
+    }
+
+    fn immutables_location(template: &str) -> ImmutablesLocation {
+        ImmutablesLocation::CloudStorage {
+            uri: MultiFilesUri::Template(TemplateUri(template.to_string())),
+        }
+    }
+
+    fn ancillary_location(uri: &str) -> AncillaryLocation {
+        AncillaryLocation::CloudStorage {
+            uri: uri.to_string(),
+        }
+    }
+
+    mod download_unpack {
+        use super::*;
+
+        #[tokio::test]
+        async fn download_unpack_all_immutable_files_and_ancillary_from_expanded_locations() {
+            let target_dir = TempDir::create(
+                "cardano_database_client",
+                "download_unpack_all_immutable_files_and_ancillary_from_expanded_locations",
+            );
+            let snapshot = cardano_database_snapshot_with_locations(
+                2,
+                vec![immutables_location(
+                    "http://whatever/{immutable_file_number}.tar.gz",
+                )],
+                vec![ancillary_location("http://whatever/ancillary.tar.gz")],
+            );
+            let client = CardanoDatabaseClientDependencyInjector::new()
+                .with_snapshot_downloader_mock_config(|mock| {
+                    mock.expect_probe().returning(|_| Ok(()));
+                    for location in [
+                        "http://whatever/00001.tar.gz",
+                        "http://whatever/00002.tar.gz",
+                        "http://whatever/ancillary.tar.gz",
+                    ] {
+                        mock.expect_download_unpack()
+                            .with(eq(location), always(), always(), always(), always())
+                            .once()
+                            .returning(|_, _, _, _, _| Ok(()));
+                    }
+                })
+                .build_cardano_database_client();
+
+            client
+                .download_unpack(
+                    &snapshot,
+                    &ImmutableFileRange::Full,
+                    &target_dir,
+                    DownloadUnpackOptions::default(),
+                )
+                .await
+                .unwrap();
+        }
+
+        #[tokio::test]
+        async fn download_unpack_only_the_immutable_files_of_the_given_range() {
+            let target_dir = TempDir::create(
+                "cardano_database_client",
+                "download_unpack_only_the_immutable_files_of_the_given_range",
+            );
+            let snapshot = cardano_database_snapshot_with_locations(
+                10,
+                vec![immutables_location(
+                    "http://whatever/{immutable_file_number}.tar.gz",
+                )],
+                vec![],
+            );
+            let client = CardanoDatabaseClientDependencyInjector::new()
+                .with_snapshot_downloader_mock_config(|mock| {
+                    mock.expect_probe().returning(|_| Ok(()));
+                    for location in [
+                        "http://whatever/00004.tar.gz",
+                        "http://whatever/00005.tar.gz",
+                        "http://whatever/00006.tar.gz",
+                    ] {
+                        mock.expect_download_unpack()
+                            .with(eq(location), always(), always(), always(), always())
+                            .once()
+                            .returning(|_, _, _, _, _| Ok(()));
+                    }
+                })
+                .build_cardano_database_client();
+
+            client
+                .download_unpack(
+                    &snapshot,
+                    &ImmutableFileRange::Range(4, 6),
+                    &target_dir,
+                    DownloadUnpackOptions {
+                        include_ancillary: false,
+                        ..DownloadUnpackOptions::default()
+                    },
+                )
+                .await
+                .unwrap();
+        }
+
+        #[tokio::test]
+        async fn download_unpack_fails_without_downloading_if_the_range_is_invalid() {
+            let target_dir = TempDir::create(
+                "cardano_database_client",
+                "download_unpack_fails_without_downloading_if_the_range_is_invalid",
+            );
+            let snapshot = cardano_database_snapshot_with_locations(
+                10,
+                vec![immutables_location(
+                    "http://whatever/{immutable_file_number}.tar.gz",
+                )],
+                vec![],
+            );
+            let client = CardanoDatabaseClientDependencyInjector::new()
+                .with_snapshot_downloader_mock_config(|mock| {
+                    mock.expect_probe().returning(|_| Ok(()));
+                    mock.expect_download_unpack().never();
+                })
+                .build_cardano_database_client();
+
+            client
+                .download_unpack(
+                    &snapshot,
+                    &ImmutableFileRange::Range(8, 11),
+                    &target_dir,
+                    DownloadUnpackOptions {
+                        include_ancillary: false,
+                        ..DownloadUnpackOptions::default()
+                    },
+                )
+                .await
+                .expect_err("download_unpack should fail");
+        }
+
+        #[tokio::test]
+        async fn download_unpack_does_not_download_ancillary_if_not_included() {
+            let target_dir = TempDir::create(
+                "cardano_database_client",
+                "download_unpack_does_not_download_ancillary_if_not_included",
+            );
+            let snapshot = cardano_database_snapshot_with_locations(
+                1,
+                vec![immutables_location(
+                    "http://whatever/{immutable_file_number}.tar.gz",
+                )],
+                vec![ancillary_location("http://whatever/ancillary.tar.gz")],
+            );
+            let client = CardanoDatabaseClientDependencyInjector::new()
+                .with_snapshot_downloader_mock_config(|mock| {
+                    mock.expect_probe().returning(|_| Ok(()));
+                    mock.expect_download_unpack()
+                        .with(
+                            eq("http://whatever/00001.tar.gz"),
+                            always(),
+                            always(),
+                            always(),
+                            always(),
+                        )
+                        .once()
+                        .returning(|_, _, _, _, _| Ok(()));
+                })
+                .build_cardano_database_client();
+
+            client
+                .download_unpack(
+                    &snapshot,
+                    &ImmutableFileRange::Full,
+                    &target_dir,
+                    DownloadUnpackOptions {
+                        include_ancillary: false,
+                        ..DownloadUnpackOptions::default()
+                    },
+                )
+                .await
+                .unwrap();
+        }
+
+        #[tokio::test]
+        async fn download_unpack_fallback_to_next_immutables_location_on_failure() {
+            let target_dir = TempDir::create(
+                "cardano_database_client",
+                "download_unpack_fallback_to_next_immutables_location_on_failure",
+            );
+            let snapshot = cardano_database_snapshot_with_locations(
+                1,
+                vec![
+                    immutables_location("http://host-1/{immutable_file_number}.tar.gz"),
+                    immutables_location("http://host-2/{immutable_file_number}.tar.gz"),
+                ],
+                vec![],
+            );
+            let client = CardanoDatabaseClientDependencyInjector::new()
+                .with_snapshot_downloader_mock_config(|mock| {
+                    mock.expect_probe().returning(|_| Ok(()));
+                    mock.expect_download_unpack()
+                        .with(
+                            eq("http://host-1/00001.tar.gz"),
+                            always(),
+                            always(),
+                            always(),
+                            always(),
+                        )
+                        .once()
+                        .returning(|_, _, _, _, _| Err(anyhow!("download error")));
+                    mock.expect_download_unpack()
+                        .with(
+                            eq("http://host-2/00001.tar.gz"),
+                            always(),
+                            always(),
+                            always(),
+                            always(),
+                        )
+                        .once()
+                        .returning(|_, _, _, _, _| Ok(()));
+                })
+                .build_cardano_database_client();
+
+            client
+                .download_unpack(
+                    &snapshot,
+                    &ImmutableFileRange::Full,
+                    &target_dir,
+                    DownloadUnpackOptions {
+                        include_ancillary: false,
+                        ..DownloadUnpackOptions::default()
+                    },
+                )
+                .await
+                .unwrap();
+        }
+
+        #[tokio::test]
+        async fn download_unpack_keep_downloading_immutable_files_from_the_last_working_location() {
+            let target_dir = TempDir::create(
+                "cardano_database_client",
+                "download_unpack_keep_downloading_immutable_files_from_the_last_working_location",
+            );
+            let snapshot = cardano_database_snapshot_with_locations(
+                2,
+                vec![
+                    immutables_location("http://host-1/{immutable_file_number}.tar.gz"),
+                    immutables_location("http://host-2/{immutable_file_number}.tar.gz"),
+                ],
+                vec![],
+            );
+            let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+            let client = CardanoDatabaseClientDependencyInjector::new()
+                .with_snapshot_downloader_mock_config(|mock| {
+                    mock.expect_probe().returning(|_| Ok(()));
+                    mock.expect_download_unpack()
+                        .with(
+                            eq("http://host-1/00001.tar.gz"),
+                            always(),
+                            always(),
+                            always(),
+                            always(),
+                        )
+                        .once()
+                        .returning(|_, _, _, _, _| Err(anyhow!("download error")));
+                    mock.expect_download_unpack()
+                        .with(
+                            eq("http://host-1/00002.tar.gz"),
+                            always(),
+                            always(),
+                            always(),
+                            always(),
+                        )
+                        .never();
+                    for location in ["http://host-2/00001.tar.gz", "http://host-2/00002.tar.gz"] {
+                        mock.expect_download_unpack()
+                            .with(eq(location), always(), always(), always(), always())
+                            .once()
+                            .returning(|_, _, _, _, _| Ok(()));
+                    }
+                })
+                .add_feedback_receiver(feedback_receiver.clone())
+                .build_cardano_database_client();
+
+            client
+                .download_unpack(
+                    &snapshot,
+                    &ImmutableFileRange::Full,
+                    &target_dir,
+                    DownloadUnpackOptions {
+                        include_ancillary: false,
+                        ..DownloadUnpackOptions::default()
+                    },
+                )
+                .await
+                .unwrap();
+
+            let actual = feedback_receiver.stacked_events();
+            let id = actual[0].event_id();
+            assert_eq!(
+                actual[1],
+                MithrilEvent::CardanoDatabaseDownloadLocationSwitched {
+                    download_id: id.to_string(),
+                    failed_location: "http://host-1/00001.tar.gz".to_string(),
+                    next_location: "http://host-2/00001.tar.gz".to_string(),
+                    reason: "download failed: download error".to_string(),
+                }
+            );
+        }
+
+        #[tokio::test]
+        async fn download_unpack_probe_each_immutables_location_only_once() {
+            let target_dir = TempDir::create(
+                "cardano_database_client",
+                "download_unpack_probe_each_immutables_location_only_once",
+            );
+            let snapshot = cardano_database_snapshot_with_locations(
+                3,
+                vec![
+                    immutables_location("http://host-1/{immutable_file_number}.tar.gz"),
+                    immutables_location("http://host-2/{immutable_file_number}.tar.gz"),
+                ],
+                vec![],
+            );
+            let client = CardanoDatabaseClientDependencyInjector::new()
+                .with_snapshot_downloader_mock_config(|mock| {
+                    mock.expect_probe()
+                        .with(eq("http://host-1/00001.tar.gz"))
+                        .once()
+                        .returning(|_| Ok(()));
+                    mock.expect_probe()
+                        .with(eq("http://host-2/00001.tar.gz"))
+                        .once()
+                        .returning(|_| Ok(()));
+                    for location in ["http://host-1/00001.tar.gz", "http://host-2/00002.tar.gz"] {
+                        mock.expect_download_unpack()
+                            .with(eq(location), always(), always(), always(), always())
+                            .once()
+                            .returning(|_, _, _, _, _| Err(anyhow!("download error")));
+                    }
+                    for location in [
+                        "http://host-2/00001.tar.gz",
+                        "http://host-1/00002.tar.gz",
+                        "http://host-1/00003.tar.gz",
+                    ] {
+                        mock.expect_download_unpack()
+                            .with(eq(location), always(), always(), always(), always())
+                            .once()
+                            .returning(|_, _, _, _, _| Ok(()));
+                    }
+                })
+                .build_cardano_database_client();
+
+            client
+                .download_unpack(
+                    &snapshot,
+                    &ImmutableFileRange::Full,
+                    &target_dir,
+                    DownloadUnpackOptions {
+                        include_ancillary: false,
+                        ..DownloadUnpackOptions::default()
+                    },
+                )
+                .await
+                .unwrap();
+        }
+
+        #[tokio::test]
+        async fn download_unpack_skip_unreachable_immutables_locations_without_probing_them_again(
+        ) {
+            let target_dir = TempDir::create(
+                "cardano_database_client",
+                "download_unpack_skip_unreachable_immutables_locations_without_probing_them_again",
+            );
+            let snapshot = cardano_database_snapshot_with_locations(
+                2,
+                vec![
+                    immutables_location("http://host-1/{immutable_file_number}.tar.gz"),
+                    immutables_location("http://host-2/{immutable_file_number}.tar.gz"),
+                ],
+                vec![],
+            );
+            let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+            let client = CardanoDatabaseClientDependencyInjector::new()
+                .with_snapshot_downloader_mock_config(|mock| {
+                    mock.expect_probe()
+                        .with(eq("http://host-1/00001.tar.gz"))
+                        .once()
+                        .returning(|_| Err(anyhow!("unreachable")));
+                    mock.expect_probe()
+                        .with(eq("http://host-2/00001.tar.gz"))
+                        .once()
+                        .returning(|_| Ok(()));
+                    mock.expect_download_unpack()
+                        .with(
+                            eq("http://host-2/00001.tar.gz"),
+                            always(),
+                            always(),
+                            always(),
+                            always(),
+                        )
+                        .once()
+                        .returning(|_, _, _, _, _| Ok(()));
+                    mock.expect_download_unpack()
+                        .with(
+                            eq("http://host-2/00002.tar.gz"),
+                            always(),
+                            always(),
+                            always(),
+                            always(),
+                        )
+                        .once()
+                        .returning(|_, _, _, _, _| Err(anyhow!("download error")));
+                })
+                .add_feedback_receiver(feedback_receiver.clone())
+                .build_cardano_database_client();
+
+            let error = client
+                .download_unpack(
+                    &snapshot,
+                    &ImmutableFileRange::Full,
+                    &target_dir,
+                    DownloadUnpackOptions {
+                        include_ancillary: false,
+                        ..DownloadUnpackOptions::default()
+                    },
+                )
+                .await
+                .expect_err("download_unpack should fail");
+
+            assert!(
+                matches!(
+                    error.downcast_ref::<CardanoDatabaseClientError>(),
+                    Some(CardanoDatabaseClientError::NoWorkingImmutableLocation {
+                        immutable_file_number: 2,
+                        ..
+                    })
+                ),
+                "Unexpected error: {error:?}"
+            );
+            let actual = feedback_receiver.stacked_events();
+            let id = actual[0].event_id();
+            assert_eq!(
+                actual[1],
+                MithrilEvent::CardanoDatabaseDownloadLocationSwitched {
+                    download_id: id.to_string(),
+                    failed_location: "http://host-1/00001.tar.gz".to_string(),
+                    next_location: "http://host-2/00001.tar.gz".to_string(),
+                    reason: "location unreachable: unreachable".to_string(),
+                }
+            );
+        }
+
+        #[tokio::test]
+        async fn download_unpack_fails_if_all_immutables_locations_fail() {
+            let target_dir = TempDir::create(
+                "cardano_database_client",
+                "download_unpack_fails_if_all_immutables_locations_fail",
+            );
+            let snapshot = cardano_database_snapshot_with_locations(
+                1,
+                vec![
+                    immutables_location("http://host-1/{immutable_file_number}.tar.gz"),
+                    immutables_location("http://host-2/{immutable_file_number}.tar.gz"),
+                ],
+                vec![],
+            );
+            let client = CardanoDatabaseClientDependencyInjector::new()
+                .with_snapshot_downloader_mock_config(|mock| {
+                    mock.expect_probe().returning(|_| Ok(()));
+                    mock.expect_download_unpack()
+                        .times(2)
+                        .returning(|_, _, _, _, _| Err(anyhow!("download error")));
+                })
+                .build_cardano_database_client();
+
+            let error = client
+                .download_unpack(
+                    &snapshot,
+                    &ImmutableFileRange::Full,
+                    &target_dir,
+                    DownloadUnpackOptions {
+                        include_ancillary: false,
+                        ..DownloadUnpackOptions::default()
+                    },
+                )
+                .await
+                .expect_err("download_unpack should fail");
+
+            assert!(
+                matches!(
+                    error.downcast_ref::<CardanoDatabaseClientError>(),
+                    Some(CardanoDatabaseClientError::NoWorkingImmutableLocation {
+                        immutable_file_number: 1,
+                        ..
+                    })
+                ),
+                "Unexpected error: {error:?}"
+            );
+        }
+
+        #[tokio::test]
+        async fn download_unpack_skip_unreachable_ancillary_locations() {
+            let target_dir = TempDir::create(
+                "cardano_database_client",
+                "download_unpack_skip_unreachable_ancillary_locations",
+            );
+            let snapshot = cardano_database_snapshot_with_locations(
+                0,
+                vec![],
+                vec![
+                    ancillary_location("http://host-1/ancillary.tar.gz"),
+                    ancillary_location("http://host-2/ancillary.tar.gz"),
+                ],
+            );
+            let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+            let client = CardanoDatabaseClientDependencyInjector::new()
+                .with_snapshot_downloader_mock_config(|mock| {
+                    mock.expect_probe()
+                        .with(eq("http://host-1/ancillary.tar.gz"))
+                        .once()
+                        .returning(|_| Err(anyhow!("unreachable")));
+                    mock.expect_probe()
+                        .with(eq("http://host-2/ancillary.tar.gz"))
+                        .once()
+                        .returning(|_| Ok(()));
+                    mock.expect_download_unpack()
+                        .with(
+                            eq("http://host-2/ancillary.tar.gz"),
+                            always(),
+                            always(),
+                            always(),
+                            always(),
+                        )
+                        .once()
+                        .returning(|_, _, _, _, _| Ok(()));
+                })
+                .add_feedback_receiver(feedback_receiver.clone())
+                .build_cardano_database_client();
+
+            client
+                .download_unpack(
+                    &snapshot,
+                    &ImmutableFileRange::Full,
+                    &target_dir,
+                    DownloadUnpackOptions::default(),
+                )
+                .await
+                .unwrap();
+
+            let actual = feedback_receiver.stacked_events();
+            let id = actual[0].event_id();
+            assert_eq!(
+                actual[1],
+                MithrilEvent::CardanoDatabaseDownloadLocationSwitched {
+                    download_id: id.to_string(),
+                    failed_location: "http://host-1/ancillary.tar.gz".to_string(),
+                    next_location: "http://host-2/ancillary.tar.gz".to_string(),
+                    reason: "location unreachable: unreachable".to_string(),
+                }
+            );
+        }
+
+        #[tokio::test]
+        async fn download_unpack_fails_if_all_ancillary_locations_fail() {
+            let target_dir = TempDir::create(
+                "cardano_database_client",
+                "download_unpack_fails_if_all_ancillary_locations_fail",
+            );
+            let snapshot = cardano_database_snapshot_with_locations(
+                0,
+                vec![],
+                vec![
+                    ancillary_location("http://host-1/ancillary.tar.gz"),
+                    ancillary_location("http://host-2/ancillary.tar.gz"),
+                ],
+            );
+            let client = CardanoDatabaseClientDependencyInjector::new()
+                .with_snapshot_downloader_mock_config(|mock| {
+                    mock.expect_probe().returning(|_| Ok(()));
+                    mock.expect_download_unpack()
+                        .times(2)
+                        .returning(|_, _, _, _, _| Err(anyhow!("download error")));
+                })
+                .build_cardano_database_client();
+
+            let error = client
+                .download_unpack(
+                    &snapshot,
+                    &ImmutableFileRange::Full,
+                    &target_dir,
+                    DownloadUnpackOptions::default(),
+                )
+                .await
+                .expect_err("download_unpack should fail");
+
+            assert!(
+                matches!(
+                    error.downcast_ref::<CardanoDatabaseClientError>(),
+                    Some(CardanoDatabaseClientError::NoWorkingAncillaryLocation { .. })
+                ),
+                "Unexpected error: {error:?}"
+            );
+        }
+
+        #[tokio::test]
+        async fn download_unpack_fails_if_ancillary_is_included_without_location() {
+            let target_dir = TempDir::create(
+                "cardano_database_client",
+                "download_unpack_fails_if_ancillary_is_included_without_location",
+            );
+            let snapshot = cardano_database_snapshot_with_locations(0, vec![], vec![]);
+            let client =
+                CardanoDatabaseClientDependencyInjector::new().build_cardano_database_client();
+
+            client
+                .download_unpack(
+                    &snapshot,
+                    &ImmutableFileRange::Full,
+                    &target_dir,
+                    DownloadUnpackOptions::default(),
+                )
+                .await
+                .expect_err("download_unpack should fail");
+        }
+
+        #[tokio::test]
+        async fn download_unpack_fails_if_target_immutable_directory_is_not_empty_and_override_not_allowed(
+        ) {
+            let target_dir = TempDir::create(
+                "cardano_database_client",
+                "download_unpack_fails_if_target_immutable_directory_is_not_empty_and_override_not_allowed",
+            );
+            fs::create_dir_all(target_dir.join(IMMUTABLE_DIR)).unwrap();
+            File::create(target_dir.join(IMMUTABLE_DIR).join("00001.chunk")).unwrap();
+            let snapshot = cardano_database_snapshot_with_locations(1, vec![], vec![]);
+            let client =
+                CardanoDatabaseClientDependencyInjector::new().build_cardano_database_client();
+
+            let error = client
+                .download_unpack(
+                    &snapshot,
+                    &ImmutableFileRange::Full,
+                    &target_dir,
+                    DownloadUnpackOptions {
+                        allow_override: false,
+                        include_ancillary: false,
+                    },
+                )
+                .await
+                .expect_err("download_unpack should fail");
+
+            assert!(
+                matches!(
+                    error.downcast_ref::<CardanoDatabaseClientError>(),
+                    Some(CardanoDatabaseClientError::TargetDirectoryNotEmpty { .. })
+                ),
+                "Unexpected error: {error:?}"
+            );
+        }
+
+        #[tokio::test]
+        async fn download_unpack_succeeds_if_target_directory_is_not_empty_and_override_allowed() {
+            let target_dir = TempDir::create(
+                "cardano_database_client",
+                "download_unpack_succeeds_if_target_directory_is_not_empty_and_override_allowed",
+            );
+            fs::create_dir_all(target_dir.join(IMMUTABLE_DIR)).unwrap();
+            File::create(target_dir.join(IMMUTABLE_DIR).join("00001.chunk")).unwrap();
+            let snapshot = cardano_database_snapshot_with_locations(
+                1,
+                vec![immutables_location(
+                    "http://whatever/{immutable_file_number}.tar.gz",
+                )],
+                vec![],
+            );
+            let client = CardanoDatabaseClientDependencyInjector::new()
+                .with_snapshot_downloader_mock_config(|mock| {
+                    mock.expect_probe().returning(|_| Ok(()));
+                    mock.expect_download_unpack()
+                        .returning(|_, _, _, _, _| Ok(()));
+                })
+                .build_cardano_database_client();
+
+            client
+                .download_unpack(
+                    &snapshot,
+                    &ImmutableFileRange::Full,
+                    &target_dir,
+                    DownloadUnpackOptions {
+                        allow_override: true,
+                        include_ancillary: false,
+                    },
+                )
+                .await
+                .unwrap();
+        }
+
+        #[tokio::test]
+        async fn download_unpack_creates_target_directory_if_missing() {
+            let target_dir = TempDir::create(
+                "cardano_database_client",
+                "download_unpack_creates_target_directory_if_missing",
+            )
+            .join("missing");
+            let snapshot = cardano_database_snapshot_with_locations(0, vec![], vec![]);
+            let client =
+                CardanoDatabaseClientDependencyInjector::new().build_cardano_database_client();
+
+            client
+                .download_unpack(
+                    &snapshot,
+                    &ImmutableFileRange::Full,
+                    &target_dir,
+                    DownloadUnpackOptions {
+                        include_ancillary: false,
+                        ..DownloadUnpackOptions::default()
+                    },
+                )
+                .await
+                .unwrap();
+
+            assert!(target_dir.is_dir());
+        }
+
+        #[tokio::test]
+        async fn download_unpack_send_feedbacks() {
+            let target_dir =
+                TempDir::create("cardano_database_client", "download_unpack_send_feedbacks");
+            let snapshot = cardano_database_snapshot_with_locations(
+                2,
+                vec![immutables_location(
+                    "http://whatever/{immutable_file_number}.tar.gz",
+                )],
+                vec![ancillary_location("http://whatever/ancillary.tar.gz")],
+            );
+            let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+            let client = CardanoDatabaseClientDependencyInjector::new()
+                .with_snapshot_downloader_mock_config(|mock| {
+                    mock.expect_probe().returning(|_| Ok(()));
+                    mock.expect_download_unpack()
+                        .returning(|_, _, _, _, _| Ok(()));
+                })
+                .add_feedback_receiver(feedback_receiver.clone())
+                .build_cardano_database_client();
+
+            client
+                .download_unpack(
+                    &snapshot,
+                    &ImmutableFileRange::Full,
+                    &target_dir,
+                    DownloadUnpackOptions::default(),
+                )
+                .await
+                .unwrap();
+
+            let actual = feedback_receiver.stacked_events();
+            let id = actual[0].event_id();
+            let expected = vec![
+                MithrilEvent::CardanoDatabaseDownloadStarted {
+                    hash: snapshot.hash.clone(),
+                    download_id: id.to_string(),
+                    total_immutable_files: 2,
+                    include_ancillary: true,
+                },
+                MithrilEvent::CardanoDatabaseImmutableDownloadCompleted {
+                    download_id: id.to_string(),
+                    immutable_file_number: 1,
+                },
+                MithrilEvent::CardanoDatabaseImmutableDownloadCompleted {
+                    download_id: id.to_string(),
+                    immutable_file_number: 2,
+                },
+                MithrilEvent::CardanoDatabaseAncillaryDownloadCompleted {
+                    download_id: id.to_string(),
+                },
+                MithrilEvent::CardanoDatabaseDownloadCompleted {
+                    download_id: id.to_string(),
+                },
+            ];
+
+            assert_eq!(actual, expected);
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::path::PathBuf;
+use thiserror::Error;
+
+use mithril_common::entities::{ImmutableFileName, ImmutableFileNumber};
+
+/// Error for the Cardano database client
+#[derive(Error, Debug)]
+pub enum CardanoDatabaseClientError {
+    /// No working location was found to download an immutable file archive
+    #[error("Could not find a working download location for the immutable file number '{immutable_file_number}', tried location: {{'{locations}'}}.")]
+    NoWorkingImmutableLocation {
+        /// Number of the immutable file
+        immutable_file_number: ImmutableFileNumber,
+
+        /// list of locations tried
+        locations: String,
+    },
+
+    /// No working location was found to download the ancillary archive
+    #[error("Could not find a working download location for the ancillary files, tried location: {{'{locations}'}}.")]
+    NoWorkingAncillaryLocation {
+        /// list of locations tried
+        locations: String,
+    },
+
+    /// The target directory already contains files that would be overwritten
+    #[error("Target directory '{}' is not empty, enable the 'allow_override' option to overwrite its content.", target_dir.display())]
+    TargetDirectoryNotEmpty {
+        /// The non empty directory
+        target_dir: PathBuf,
+    },
+
+    /// The Merkle root computed from the digests published by the aggregator does not match the
+    /// Merkle root of the Cardano database snapshot
+    #[error("The Merkle root computed from the immutable files digests '{computed_merkle_root}' does not match the Merkle root of the Cardano database snapshot '{expected_merkle_root}'.")]
+    DigestsMerkleRootMismatch {
+        /// Merkle root of the Cardano database snapshot
+        expected_merkle_root: String,
+
+        /// Merkle root computed from the immutable files digests
+        computed_merkle_root: String,
+    },
+
+    /// Some certified immutable files are missing in the restored database
+    #[error("Some certified immutable files are missing in the database directory '{}': {immutable_files:?}.", database_dir.display())]
+    MissingImmutableFiles {
+        /// The database directory
+        database_dir: PathBuf,
+
+        /// Names of the missing immutable files
+        immutable_files: Vec<ImmutableFileName>,
+    },
+
+    /// Some restored immutable files do not match their certified digest
+    #[error("Some immutable files of the database directory '{}' do not match their certified digest: {immutable_files:?}.", database_dir.display())]
+    TamperedImmutableFiles {
+        /// The database directory
+        database_dir: PathBuf,
+
+        /// Names of the tampered immutable files
+        immutable_files: Vec<ImmutableFileName>,
+    },
+}
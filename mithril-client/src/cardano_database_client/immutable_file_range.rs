@@ -0,0 +1,176 @@
+use std::ops::RangeInclusive;
+
+use anyhow::anyhow;
+
+use crate::common::ImmutableFileNumber;
+use crate::MithrilResult;
+
+/// Immutable file range representation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImmutableFileRange {
+    /// From the first (included) to the last immutable file number (included)
+    Full,
+
+    /// From a specific immutable file number (included) to the last immutable file number (included)
+    From(ImmutableFileNumber),
+
+    /// From a specific immutable file number (included) to another specific immutable file number (included)
+    Range(ImmutableFileNumber, ImmutableFileNumber),
+
+    /// From the first immutable file number (included) up to a specific immutable file number (included)
+    UpTo(ImmutableFileNumber),
+}
+
+impl ImmutableFileRange {
+    /// Returns the range of immutable file numbers to restore, given the last immutable file number
+    /// of a Cardano database snapshot.
+    ///
+    /// Fails if the range is empty or if it is not included in the immutable files of the snapshot.
+    pub fn to_range_inclusive(
+        &self,
+        last_immutable_file_number: ImmutableFileNumber,
+    ) -> MithrilResult<RangeInclusive<ImmutableFileNumber>> {
+        const FIRST_IMMUTABLE_FILE_NUMBER: ImmutableFileNumber = 1;
+        let full_range = FIRST_IMMUTABLE_FILE_NUMBER..=last_immutable_file_number;
+
+        match self {
+            ImmutableFileRange::Full => Ok(full_range),
+            ImmutableFileRange::From(from) if full_range.contains(from) => {
+                Ok(*from..=last_immutable_file_number)
+            }
+            ImmutableFileRange::Range(from, to)
+                if full_range.contains(from) && full_range.contains(to) && from <= to =>
+            {
+                Ok(*from..=*to)
+            }
+            ImmutableFileRange::UpTo(to) if full_range.contains(to) => {
+                Ok(FIRST_IMMUTABLE_FILE_NUMBER..=*to)
+            }
+            _ => Err(anyhow!(
+                "Invalid immutable file range: {self:?}, the range must be included in the immutable files of the snapshot: {full_range:?}"
+            )),
+        }
+    }
+
+    /// Returns the number of immutable files in the range, given the last immutable file number
+    /// of a Cardano database snapshot.
+    pub fn length(&self, last_immutable_file_number: ImmutableFileNumber) -> MithrilResult<u64> {
+        let range = self.to_range_inclusive(last_immutable_file_number)?;
+        if range.is_empty() {
+            return Ok(0);
+        }
+
+        Ok(range.end() - range.start() + 1)
+    }
+
+    /// Returns `true` if the range restores all the immutable files of a Cardano database snapshot,
+    /// given its last immutable file number.
+    pub fn is_full(&self, last_immutable_file_number: ImmutableFileNumber) -> MithrilResult<bool> {
+        let range = self.to_range_inclusive(last_immutable_file_number)?;
+
+        Ok(*range.start() == 1 && *range.end() == last_immutable_file_number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_range_inclusive_with_full() {
+        let immutable_file_range = ImmutableFileRange::Full;
+        let last_immutable_file_number = 10;
+
+        let result = immutable_file_range
+            .to_range_inclusive(last_immutable_file_number)
+            .unwrap();
+        assert_eq!(1..=10, result);
+    }
+
+    #[test]
+    fn to_range_inclusive_with_from() {
+        let immutable_file_range = ImmutableFileRange::From(5);
+
+        let result = immutable_file_range.to_range_inclusive(10).unwrap();
+        assert_eq!(5..=10, result);
+
+        let result = immutable_file_range.to_range_inclusive(5).unwrap();
+        assert_eq!(5..=5, result);
+
+        immutable_file_range
+            .to_range_inclusive(4)
+            .expect_err("conversion to range inclusive should fail");
+
+        ImmutableFileRange::From(0)
+            .to_range_inclusive(10)
+            .expect_err("conversion to range inclusive should fail");
+    }
+
+    #[test]
+    fn to_range_inclusive_with_range() {
+        let immutable_file_range = ImmutableFileRange::Range(5, 8);
+
+        let result = immutable_file_range.to_range_inclusive(10).unwrap();
+        assert_eq!(5..=8, result);
+
+        let result = immutable_file_range.to_range_inclusive(8).unwrap();
+        assert_eq!(5..=8, result);
+
+        immutable_file_range
+            .to_range_inclusive(7)
+            .expect_err("conversion to range inclusive should fail");
+
+        ImmutableFileRange::Range(8, 5)
+            .to_range_inclusive(10)
+            .expect_err("conversion to range inclusive should fail");
+
+        ImmutableFileRange::Range(0, 5)
+            .to_range_inclusive(10)
+            .expect_err("conversion to range inclusive should fail");
+    }
+
+    #[test]
+    fn to_range_inclusive_with_up_to() {
+        let immutable_file_range = ImmutableFileRange::UpTo(8);
+
+        let result = immutable_file_range.to_range_inclusive(10).unwrap();
+        assert_eq!(1..=8, result);
+
+        let result = immutable_file_range.to_range_inclusive(8).unwrap();
+        assert_eq!(1..=8, result);
+
+        immutable_file_range
+            .to_range_inclusive(7)
+            .expect_err("conversion to range inclusive should fail");
+
+        ImmutableFileRange::UpTo(0)
+            .to_range_inclusive(10)
+            .expect_err("conversion to range inclusive should fail");
+    }
+
+    #[test]
+    fn length_of_the_range() {
+        assert_eq!(10, ImmutableFileRange::Full.length(10).unwrap());
+        assert_eq!(6, ImmutableFileRange::From(5).length(10).unwrap());
+        assert_eq!(4, ImmutableFileRange::Range(5, 8).length(10).unwrap());
+        assert_eq!(1, ImmutableFileRange::Range(5, 5).length(10).unwrap());
+        assert_eq!(8, ImmutableFileRange::UpTo(8).length(10).unwrap());
+        assert_eq!(0, ImmutableFileRange::Full.length(0).unwrap());
+
+        ImmutableFileRange::From(11)
+            .length(10)
+            .expect_err("length should fail");
+    }
+
+    #[test]
+    fn is_full_range() {
+        assert!(ImmutableFileRange::Full.is_full(10).unwrap());
+        assert!(ImmutableFileRange::From(1).is_full(10).unwrap());
+        assert!(ImmutableFileRange::Range(1, 10).is_full(10).unwrap());
+        assert!(ImmutableFileRange::UpTo(10).is_full(10).unwrap());
+
+        assert!(!ImmutableFileRange::From(2).is_full(10).unwrap());
+        assert!(!ImmutableFileRange::Range(1, 9).is_full(10).unwrap());
+        assert!(!ImmutableFileRange::UpTo(9).is_full(10).unwrap());
+    }
+}
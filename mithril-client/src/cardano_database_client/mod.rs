@@ -0,0 +1,212 @@
+//! A client to retrieve Cardano databases data from an Aggregator.
+//!
+//! In order to do so it defines a [CardanoDatabaseClient] which exposes the following features:
+//!  - [get][CardanoDatabaseClient::get]: get a Cardano database data from its hash
+//!  - [list][CardanoDatabaseClient::list]: get the list of available Cardano database
+//!  - [download_unpack][CardanoDatabaseClient::download_unpack]: download and unpack the immutable files, or a range of them, and the ancillary files of a Cardano database to a directory
+//!  - [compute_merkle_proof][CardanoDatabaseClient::compute_merkle_proof]: compute a Merkle proof of the restored immutable files of a Cardano database, after checking them against the certified digests
+//!  - [add_statistics][CardanoDatabaseClient::add_statistics]: notify the aggregator that a Cardano database has been restored
+//!
+//! # Get a Cardano database
+//!
+//! To get a Cardano database using the [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::ClientBuilder;
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//! let cardano_database = client.cardano_database().get("CARDANO_DATABASE_HASH").await?.unwrap();
+//!
+//! println!(
+//!     "Cardano database hash={}, merkle_root={}, immutable_file_number={:?}",
+//!     cardano_database.hash,
+//!     cardano_database.merkle_root,
+//!     cardano_database.beacon.immutable_file_number
+//! );
+//! #    Ok(())
+//! # }
+//! ```
+//!
+//! # List available Cardano databases
+//!
+//! To list available Cardano databases using the [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::ClientBuilder;
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//! let cardano_databases = client.cardano_database().list().await?;
+//!
+//! for cardano_database in cardano_databases {
+//!     println!("Cardano database hash={}, immutable_file_number={}", cardano_database.hash, cardano_database.beacon.immutable_file_number);
+//! }
+//! #    Ok(())
+//! # }
+//! ```
+//!
+//! # Download a Cardano database
+//! **Note:** _Available on crate feature_ **fs** _only._
+//!
+//! To download and unpack the immutable files and the ancillary files of a Cardano database using the [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! ```no_run
+//! # #[cfg(feature = "fs")]
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::{ClientBuilder, cardano_database_client::{DownloadUnpackOptions, ImmutableFileRange}};
+//! use std::path::Path;
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//! let cardano_database_snapshot = client.cardano_database().get("CARDANO_DATABASE_HASH").await?.unwrap();
+//!
+//! // Note: the directory will be created if it doesn't exist, and the user running the binary must have read/write access to it.
+//! let target_directory = Path::new("/home/user/download/");
+//! let immutable_file_range = ImmutableFileRange::Full;
+//! let download_unpack_options = DownloadUnpackOptions {
+//!     allow_override: true,
+//!     include_ancillary: true,
+//! };
+//! client
+//!    .cardano_database()
+//!    .download_unpack(&cardano_database_snapshot, &immutable_file_range, target_directory, download_unpack_options)
+//!    .await?;
+//!
+//! // The restoration can also be partial, for example with the immutable files 100 to 200 only
+//! let immutable_file_range = ImmutableFileRange::Range(100, 200);
+//! client
+//!    .cardano_database()
+//!    .download_unpack(&cardano_database_snapshot, &immutable_file_range, target_directory, DownloadUnpackOptions::default())
+//!    .await?;
+//! #
+//! #    Ok(())
+//! # }
+//! ```
+//!
+//! # Verify the restored immutable files of a Cardano database
+//! **Note:** _Available on crate feature_ **fs** _only._
+//!
+//! To check the restored immutable files of a Cardano database against the Mithril certificate using the [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! ```no_run
+//! # #[cfg(feature = "fs")]
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::{ClientBuilder, MessageBuilder, cardano_database_client::{DownloadUnpackOptions, ImmutableFileRange}};
+//! use std::path::Path;
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//! let cardano_database_snapshot = client.cardano_database().get("CARDANO_DATABASE_HASH").await?.unwrap();
+//! let certificate = client.certificate().verify_chain(&cardano_database_snapshot.certificate_hash).await?;
+//!
+//! let target_directory = Path::new("/home/user/download/");
+//! let immutable_file_range = ImmutableFileRange::Range(100, 200);
+//! client
+//!    .cardano_database()
+//!    .download_unpack(&cardano_database_snapshot, &immutable_file_range, target_directory, DownloadUnpackOptions::default())
+//!    .await?;
+//!
+//! let merkle_proof = client
+//!    .cardano_database()
+//!    .compute_merkle_proof(&certificate, &cardano_database_snapshot, &immutable_file_range, target_directory)
+//!    .await?;
+//! let message = MessageBuilder::new().compute_cardano_database_message(&certificate, &merkle_proof)?;
+//! assert!(certificate.match_message(&message));
+//! #
+//! #    Ok(())
+//! # }
+//! ```
+
+mod api;
+#[cfg(feature = "fs")]
+mod download_unpack;
+#[cfg(feature = "fs")]
+mod error;
+mod fetch;
+mod immutable_file_range;
+#[cfg(feature = "fs")]
+mod proving;
+mod statistics;
+
+pub use api::*;
+#[cfg(feature = "fs")]
+pub use download_unpack::*;
+#[cfg(feature = "fs")]
+pub use error::*;
+pub use immutable_file_range::*;
+
+#[cfg(test)]
+pub(crate) mod test_dependency_injector {
+    use std::sync::Arc;
+
+    use crate::aggregator_client::MockAggregatorHTTPClient;
+    #[cfg(feature = "fs")]
+    use crate::feedback::{FeedbackReceiver, FeedbackSender};
+    #[cfg(feature = "fs")]
+    use crate::snapshot_downloader::MockHttpSnapshotDownloader;
+    #[cfg(feature = "fs")]
+    use crate::test_utils;
+
+    use super::*;
+
+    /// Dependency injector for `CardanoDatabaseClient` for testing purposes.
+    pub(crate) struct CardanoDatabaseClientDependencyInjector {
+        http_client: MockAggregatorHTTPClient,
+        #[cfg(feature = "fs")]
+        snapshot_downloader: MockHttpSnapshotDownloader,
+        #[cfg(feature = "fs")]
+        feedback_receivers: Vec<Arc<dyn FeedbackReceiver>>,
+    }
+
+    impl CardanoDatabaseClientDependencyInjector {
+        pub(crate) fn new() -> Self {
+            Self {
+                http_client: MockAggregatorHTTPClient::new(),
+                #[cfg(feature = "fs")]
+                snapshot_downloader: MockHttpSnapshotDownloader::new(),
+                #[cfg(feature = "fs")]
+                feedback_receivers: vec![],
+            }
+        }
+
+        pub(crate) fn with_http_client_mock_config<F>(mut self, config: F) -> Self
+        where
+            F: FnOnce(&mut MockAggregatorHTTPClient),
+        {
+            config(&mut self.http_client);
+
+            self
+        }
+
+        #[cfg(feature = "fs")]
+        pub(crate) fn with_snapshot_downloader_mock_config<F>(mut self, config: F) -> Self
+        where
+            F: FnOnce(&mut MockHttpSnapshotDownloader),
+        {
+            config(&mut self.snapshot_downloader);
+
+            self
+        }
+
+        #[cfg(feature = "fs")]
+        pub(crate) fn add_feedback_receiver(
+            mut self,
+            feedback_receiver: Arc<dyn FeedbackReceiver>,
+        ) -> Self {
+            self.feedback_receivers.push(feedback_receiver);
+
+            self
+        }
+
+        pub(crate) fn build_cardano_database_client(self) -> CardanoDatabaseClient {
+            CardanoDatabaseClient::new(
+                Arc::new(self.http_client),
+                #[cfg(feature = "fs")]
+                Arc::new(self.snapshot_downloader),
+                #[cfg(feature = "fs")]
+                FeedbackSender::new(&self.feedback_receivers),
+                #[cfg(feature = "fs")]
+                test_utils::test_logger(),
+            )
+        }
+    }
+}
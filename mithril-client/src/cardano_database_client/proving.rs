@@ -0,0 +1,376 @@
+use anyhow::Context;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use mithril_common::crypto_helper::{MKProof, MKTree, MKTreeNode, MKTreeStoreInMemory};
+use mithril_common::digesters::{CardanoImmutableDigester, ImmutableDigester, ImmutableFile};
+use mithril_common::entities::{HexEncodedDigest, ImmutableFileName, ImmutableFileNumber};
+use mithril_common::messages::CardanoDatabaseDigestListMessage;
+
+use crate::aggregator_client::AggregatorRequest;
+use crate::cardano_database_client::{
+    CardanoDatabaseClient, CardanoDatabaseClientError, ImmutableFileRange,
+};
+use crate::{CardanoDatabaseSnapshot, MithrilCertificate, MithrilResult};
+
+#[inline]
+pub(super) async fn compute_merkle_proof(
+    client: &CardanoDatabaseClient,
+    certificate: &MithrilCertificate,
+    cardano_database_snapshot: &CardanoDatabaseSnapshot,
+    immutable_file_range: &ImmutableFileRange,
+    database_dir: &Path,
+) -> MithrilResult<MKProof> {
+    let last_immutable_file_number = cardano_database_snapshot.beacon.immutable_file_number;
+    let immutable_file_number_range =
+        immutable_file_range.to_range_inclusive(last_immutable_file_number)?;
+
+    let certified_digests = fetch_certified_digests(client, last_immutable_file_number).await?;
+    let merkle_tree = MKTree::<MKTreeStoreInMemory>::new(
+        &certified_digests.values().cloned().collect::<Vec<_>>(),
+    )
+    .with_context(|| {
+        "Could not compute the Merkle tree of the certified immutable files digests"
+    })?;
+    let computed_merkle_root = merkle_tree.compute_root()?.to_hex();
+    if computed_merkle_root != cardano_database_snapshot.merkle_root {
+        return Err(CardanoDatabaseClientError::DigestsMerkleRootMismatch {
+            expected_merkle_root: cardano_database_snapshot.merkle_root.clone(),
+            computed_merkle_root,
+        }
+        .into());
+    }
+
+    let digester = CardanoImmutableDigester::new(
+        certificate.metadata.network.clone(),
+        None,
+        client.logger.clone(),
+    );
+    let computed_digests = digester
+        .compute_digests_for_range(database_dir, &immutable_file_number_range)
+        .await
+        .with_context(|| {
+            format!(
+                "Could not compute the digests of the immutable files of the database directory: '{}'",
+                database_dir.display()
+            )
+        })?;
+    let computed_digests_by_name: BTreeMap<ImmutableFileName, HexEncodedDigest> = computed_digests
+        .into_iter()
+        .map(|(immutable_file, digest)| (immutable_file.filename, digest))
+        .collect();
+
+    let mut missing_immutable_files = vec![];
+    let mut tampered_immutable_files = vec![];
+    let mut leaves_to_prove = vec![];
+    for (immutable_file, certified_digest) in certified_digests
+        .iter()
+        .filter(|(immutable_file, _)| immutable_file_number_range.contains(&immutable_file.number))
+    {
+        match computed_digests_by_name.get(&immutable_file.filename) {
+            None => missing_immutable_files.push(immutable_file.filename.clone()),
+            Some(computed_digest) if computed_digest != certified_digest => {
+                tampered_immutable_files.push(immutable_file.filename.clone())
+            }
+            Some(computed_digest) => {
+                leaves_to_prove.push(MKTreeNode::from(computed_digest.clone()))
+            }
+        }
+    }
+    if !missing_immutable_files.is_empty() {
+        return Err(CardanoDatabaseClientError::MissingImmutableFiles {
+            database_dir: database_dir.to_path_buf(),
+            immutable_files: missing_immutable_files,
+        }
+        .into());
+    }
+    if !tampered_immutable_files.is_empty() {
+        return Err(CardanoDatabaseClientError::TamperedImmutableFiles {
+            database_dir: database_dir.to_path_buf(),
+            immutable_files: tampered_immutable_files,
+        }
+        .into());
+    }
+
+    merkle_tree
+        .compute_proof(&leaves_to_prove)
+        .with_context(|| "Could not compute the Merkle proof of the restored immutable files")
+}
+
+/// Fetch the digests of the immutable files certified by a Cardano database snapshot, ordered
+/// as the leaves of its Merkle tree.
+async fn fetch_certified_digests(
+    client: &CardanoDatabaseClient,
+    last_immutable_file_number: ImmutableFileNumber,
+) -> MithrilResult<BTreeMap<ImmutableFile, HexEncodedDigest>> {
+    let content = client
+        .aggregator_client
+        .get_content(AggregatorRequest::ListCardanoDatabaseDigests)
+        .await
+        .with_context(|| "Could not fetch the immutable files digests from the aggregator")?;
+    let digests: CardanoDatabaseDigestListMessage = serde_json::from_str(&content)
+        .with_context(|| "CardanoDatabase client can not deserialize the digests list")?;
+
+    let mut certified_digests = BTreeMap::new();
+    for digest in digests {
+        let immutable_file = ImmutableFile::new(PathBuf::from(&digest.immutable_file_name))
+            .with_context(|| {
+                format!(
+                    "Invalid immutable file name in the digests list: '{}'",
+                    digest.immutable_file_name
+                )
+            })?;
+        if immutable_file.number <= last_immutable_file_number {
+            certified_digests.insert(immutable_file, digest.digest);
+        }
+    }
+
+    Ok(certified_digests)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, File};
+    use std::io::Write;
+
+    use mithril_common::digesters::{DummyCardanoDb, DummyCardanoDbBuilder};
+    use mithril_common::entities::{CardanoDbBeacon, Epoch};
+    use mithril_common::messages::CardanoDatabaseDigestListItemMessage;
+    use mockall::predicate::eq;
+
+    use crate::cardano_database_client::test_dependency_injector::CardanoDatabaseClientDependencyInjector;
+    use crate::test_utils;
+
+    use super::*;
+
+    struct TestContext {
+        cardano_db: DummyCardanoDb,
+        digests: CardanoDatabaseDigestListMessage,
+        merkle_root: String,
+    }
+
+    async fn prepare_cardano_db(dir_name: &str, immutables: &[ImmutableFileNumber]) -> TestContext {
+        let cardano_db = DummyCardanoDbBuilder::new(&format!("cardano_database_client/{dir_name}"))
+            .with_immutables(immutables)
+            .build();
+        let digester =
+            CardanoImmutableDigester::new("devnet".to_string(), None, test_utils::test_logger());
+        let computed_digests = digester
+            .compute_digests_for_range(cardano_db.get_dir(), &(1..=*immutables.last().unwrap()))
+            .await
+            .unwrap();
+        let merkle_root = MKTree::<MKTreeStoreInMemory>::new(
+            &computed_digests.values().cloned().collect::<Vec<_>>(),
+        )
+        .unwrap()
+        .compute_root()
+        .unwrap()
+        .to_hex();
+        let digests = computed_digests
+            .into_iter()
+            .map(
+                |(immutable_file, digest)| CardanoDatabaseDigestListItemMessage {
+                    immutable_file_name: immutable_file.filename,
+                    digest,
+                },
+            )
+            .collect();
+
+        TestContext {
+            cardano_db,
+            digests,
+            merkle_root,
+        }
+    }
+
+    fn snapshot(
+        merkle_root: &str,
+        immutable_file_number: ImmutableFileNumber,
+    ) -> CardanoDatabaseSnapshot {
+        CardanoDatabaseSnapshot {
+            merkle_root: merkle_root.to_string(),
+            beacon: CardanoDbBeacon {
+                epoch: Epoch(1),
+                immutable_file_number,
+            },
+            ..CardanoDatabaseSnapshot::dummy()
+        }
+    }
+
+    fn client_returning_digests(
+        digests: &CardanoDatabaseDigestListMessage,
+    ) -> CardanoDatabaseClient {
+        let content = serde_json::to_string(digests).unwrap();
+
+        CardanoDatabaseClientDependencyInjector::new()
+            .with_http_client_mock_config(|http_client| {
+                http_client
+                    .expect_get_content()
+                    .with(eq(AggregatorRequest::ListCardanoDatabaseDigests))
+                    .return_once(move |_| Ok(content));
+            })
+            .build_cardano_database_client()
+    }
+
+    #[tokio::test]
+    async fn compute_merkle_proof_for_the_full_range() {
+        let context =
+            prepare_cardano_db("compute_merkle_proof_for_the_full_range", &[1, 2, 3]).await;
+        let client = client_returning_digests(&context.digests);
+
+        let proof = client
+            .compute_merkle_proof(
+                &MithrilCertificate::dummy(),
+                &snapshot(&context.merkle_root, 3),
+                &ImmutableFileRange::Full,
+                context.cardano_db.get_dir(),
+            )
+            .await
+            .unwrap();
+
+        proof.verify().unwrap();
+        assert_eq!(context.merkle_root, proof.root().to_hex());
+        assert_eq!(9, proof.leaves().len());
+    }
+
+    #[tokio::test]
+    async fn compute_merkle_proof_for_a_partial_range() {
+        let context =
+            prepare_cardano_db("compute_merkle_proof_for_a_partial_range", &[1, 2, 3, 4]).await;
+        for immutable_file in context.cardano_db.get_immutable_files() {
+            if immutable_file.number == 1 || immutable_file.number == 4 {
+                fs::remove_file(&immutable_file.path).unwrap();
+            }
+        }
+        let client = client_returning_digests(&context.digests);
+
+        let proof = client
+            .compute_merkle_proof(
+                &MithrilCertificate::dummy(),
+                &snapshot(&context.merkle_root, 4),
+                &ImmutableFileRange::Range(2, 3),
+                context.cardano_db.get_dir(),
+            )
+            .await
+            .unwrap();
+
+        proof.verify().unwrap();
+        assert_eq!(context.merkle_root, proof.root().to_hex());
+        assert_eq!(6, proof.leaves().len());
+    }
+
+    #[tokio::test]
+    async fn compute_merkle_proof_ignores_digests_after_the_snapshot_beacon() {
+        let context = prepare_cardano_db(
+            "compute_merkle_proof_ignores_digests_after_the_snapshot_beacon",
+            &[1, 2],
+        )
+        .await;
+        let mut digests = context.digests.clone();
+        digests.push(CardanoDatabaseDigestListItemMessage {
+            immutable_file_name: "00003.chunk".to_string(),
+            digest: "not-yet-certified".to_string(),
+        });
+        let client = client_returning_digests(&digests);
+
+        let proof = client
+            .compute_merkle_proof(
+                &MithrilCertificate::dummy(),
+                &snapshot(&context.merkle_root, 2),
+                &ImmutableFileRange::Full,
+                context.cardano_db.get_dir(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(context.merkle_root, proof.root().to_hex());
+    }
+
+    #[tokio::test]
+    async fn compute_merkle_proof_fails_if_the_digests_do_not_match_the_merkle_root() {
+        let context = prepare_cardano_db(
+            "compute_merkle_proof_fails_if_the_digests_do_not_match_the_merkle_root",
+            &[1, 2],
+        )
+        .await;
+        let client = client_returning_digests(&context.digests);
+
+        let error = client
+            .compute_merkle_proof(
+                &MithrilCertificate::dummy(),
+                &snapshot("another-merkle-root", 2),
+                &ImmutableFileRange::Full,
+                context.cardano_db.get_dir(),
+            )
+            .await
+            .expect_err("compute_merkle_proof should fail");
+
+        assert!(
+            matches!(
+                error.downcast_ref::<CardanoDatabaseClientError>(),
+                Some(CardanoDatabaseClientError::DigestsMerkleRootMismatch { .. })
+            ),
+            "Unexpected error: {error:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn compute_merkle_proof_fails_if_an_immutable_file_is_missing() {
+        let context = prepare_cardano_db(
+            "compute_merkle_proof_fails_if_an_immutable_file_is_missing",
+            &[1, 2, 3],
+        )
+        .await;
+        fs::remove_file(context.cardano_db.get_immutable_dir().join("00002.primary")).unwrap();
+        let client = client_returning_digests(&context.digests);
+
+        let error = client
+            .compute_merkle_proof(
+                &MithrilCertificate::dummy(),
+                &snapshot(&context.merkle_root, 3),
+                &ImmutableFileRange::From(2),
+                context.cardano_db.get_dir(),
+            )
+            .await
+            .expect_err("compute_merkle_proof should fail");
+
+        match error.downcast_ref::<CardanoDatabaseClientError>() {
+            Some(CardanoDatabaseClientError::MissingImmutableFiles {
+                immutable_files, ..
+            }) => assert_eq!(&vec!["00002.primary".to_string()], immutable_files),
+            _ => panic!("Unexpected error: {error:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn compute_merkle_proof_fails_if_an_immutable_file_is_tampered() {
+        let context = prepare_cardano_db(
+            "compute_merkle_proof_fails_if_an_immutable_file_is_tampered",
+            &[1, 2, 3],
+        )
+        .await;
+        let mut tampered_file = File::options()
+            .append(true)
+            .open(context.cardano_db.get_immutable_dir().join("00003.chunk"))
+            .unwrap();
+        writeln!(tampered_file, "tampered").unwrap();
+        let client = client_returning_digests(&context.digests);
+
+        let error = client
+            .compute_merkle_proof(
+                &MithrilCertificate::dummy(),
+                &snapshot(&context.merkle_root, 3),
+                &ImmutableFileRange::Full,
+                context.cardano_db.get_dir(),
+            )
+            .await
+            .expect_err("compute_merkle_proof should fail");
+
+        match error.downcast_ref::<CardanoDatabaseClientError>() {
+            Some(CardanoDatabaseClientError::TamperedImmutableFiles {
+                immutable_files, ..
+            }) => assert_eq!(&vec!["00003.chunk".to_string()], immutable_files),
+            _ => panic!("Unexpected error: {error:?}"),
+        }
+    }
+}
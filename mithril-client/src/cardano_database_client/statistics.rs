@@ -0,0 +1,134 @@
+use anyhow::Context;
+
+use crate::aggregator_client::AggregatorRequest;
+use crate::cardano_database_client::CardanoDatabaseClient;
+use crate::MithrilResult;
+
+#[inline]
+pub(super) async fn add_statistics(
+    client: &CardanoDatabaseClient,
+    full_restoration: bool,
+    include_ancillary: bool,
+    number_of_immutable_files_restored: u64,
+) -> MithrilResult<()> {
+    let number_of_immutables = u32::try_from(number_of_immutable_files_restored)
+        .with_context(|| {
+            format!(
+                "Number of immutable files restored does not fit in a u32: {number_of_immutable_files_restored}"
+            )
+        })?;
+    client
+        .aggregator_client
+        .post_content(
+            AggregatorRequest::IncrementCardanoDatabaseImmutablesRestoredStatistic {
+                number_of_immutables,
+            },
+        )
+        .await?;
+
+    if include_ancillary {
+        client
+            .aggregator_client
+            .post_content(AggregatorRequest::IncrementCardanoDatabaseAncillaryStatistic)
+            .await?;
+    }
+
+    let restoration_statistic_request = if full_restoration {
+        AggregatorRequest::IncrementCardanoDatabaseCompleteRestorationStatistic
+    } else {
+        AggregatorRequest::IncrementCardanoDatabasePartialRestorationStatistic
+    };
+    client
+        .aggregator_client
+        .post_content(restoration_statistic_request)
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+
+    use crate::aggregator_client::AggregatorClientError;
+    use crate::cardano_database_client::test_dependency_injector::CardanoDatabaseClientDependencyInjector;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn add_statistics_for_complete_restoration_with_ancillary() {
+        let client = CardanoDatabaseClientDependencyInjector::new()
+            .with_http_client_mock_config(|http_client| {
+                http_client
+                    .expect_post_content()
+                    .with(eq(
+                        AggregatorRequest::IncrementCardanoDatabaseImmutablesRestoredStatistic {
+                            number_of_immutables: 42,
+                        },
+                    ))
+                    .once()
+                    .returning(|_| Ok(String::new()));
+                http_client
+                    .expect_post_content()
+                    .with(eq(
+                        AggregatorRequest::IncrementCardanoDatabaseAncillaryStatistic,
+                    ))
+                    .once()
+                    .returning(|_| Ok(String::new()));
+                http_client
+                    .expect_post_content()
+                    .with(eq(
+                        AggregatorRequest::IncrementCardanoDatabaseCompleteRestorationStatistic,
+                    ))
+                    .once()
+                    .returning(|_| Ok(String::new()));
+            })
+            .build_cardano_database_client();
+
+        client.add_statistics(true, true, 42).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn add_statistics_for_partial_restoration_without_ancillary() {
+        let client = CardanoDatabaseClientDependencyInjector::new()
+            .with_http_client_mock_config(|http_client| {
+                http_client
+                    .expect_post_content()
+                    .with(eq(
+                        AggregatorRequest::IncrementCardanoDatabaseImmutablesRestoredStatistic {
+                            number_of_immutables: 12,
+                        },
+                    ))
+                    .once()
+                    .returning(|_| Ok(String::new()));
+                http_client
+                    .expect_post_content()
+                    .with(eq(
+                        AggregatorRequest::IncrementCardanoDatabasePartialRestorationStatistic,
+                    ))
+                    .once()
+                    .returning(|_| Ok(String::new()));
+            })
+            .build_cardano_database_client();
+
+        client.add_statistics(false, false, 12).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn add_statistics_fails_if_the_aggregator_request_fails() {
+        let client = CardanoDatabaseClientDependencyInjector::new()
+            .with_http_client_mock_config(|http_client| {
+                http_client.expect_post_content().returning(|_| {
+                    Err(AggregatorClientError::RemoteServerTechnical(
+                        anyhow::anyhow!("an error"),
+                    ))
+                });
+            })
+            .build_cardano_database_client();
+
+        client
+            .add_statistics(true, true, 12)
+            .await
+            .expect_err("add_statistics should fail");
+    }
+}
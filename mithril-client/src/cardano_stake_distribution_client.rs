@@ -72,6 +72,7 @@ use std::sync::Arc;
 
 use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
 use crate::common::Epoch;
+use crate::common::ListFilter;
 use crate::{CardanoStakeDistribution, CardanoStakeDistributionListItem, MithrilResult};
 
 /// HTTP client for CardanoStakeDistribution API from the Aggregator
@@ -87,9 +88,30 @@ impl CardanoStakeDistributionClient {
 
     /// Fetch a list of signed CardanoStakeDistribution
     pub async fn list(&self) -> MithrilResult<Vec<CardanoStakeDistributionListItem>> {
+        self.fetch_list_with_aggregator_request(AggregatorRequest::ListCardanoStakeDistributions)
+            .await
+    }
+
+    /// Fetch a list of signed CardanoStakeDistribution matching the given cursor and filters.
+    pub async fn list_with_filter(
+        &self,
+        filter: &ListFilter,
+    ) -> MithrilResult<Vec<CardanoStakeDistributionListItem>> {
+        self.fetch_list_with_aggregator_request(
+            AggregatorRequest::ListCardanoStakeDistributionsWithFilter {
+                filter: filter.clone(),
+            },
+        )
+        .await
+    }
+
+    async fn fetch_list_with_aggregator_request(
+        &self,
+        request: AggregatorRequest,
+    ) -> MithrilResult<Vec<CardanoStakeDistributionListItem>> {
         let response = self
             .aggregator_client
-            .get_content(AggregatorRequest::ListCardanoStakeDistributions)
+            .get_content(request)
             .await
             .with_context(|| "CardanoStakeDistribution client can not get the artifact list")?;
         let items = serde_json::from_str::<Vec<CardanoStakeDistributionListItem>>(&response)
@@ -187,6 +209,27 @@ mod tests {
         assert_eq!("hash-456".to_string(), messages[1].hash);
     }
 
+    #[tokio::test]
+    async fn list_cardano_stake_distributions_with_filter_returns_messages() {
+        let message = fake_messages();
+        let filter = ListFilter::default().from_epoch(Epoch(4));
+        let mut http_client = MockAggregatorHTTPClient::new();
+        http_client
+            .expect_get_content()
+            .with(eq(
+                AggregatorRequest::ListCardanoStakeDistributionsWithFilter {
+                    filter: filter.clone(),
+                },
+            ))
+            .return_once(move |_| Ok(serde_json::to_string(&message).unwrap()));
+        let client = CardanoStakeDistributionClient::new(Arc::new(http_client));
+
+        let messages = client.list_with_filter(&filter).await.unwrap();
+
+        assert_eq!(2, messages.len());
+        assert_eq!("hash-123".to_string(), messages[0].hash);
+    }
+
     #[tokio::test]
     async fn list_cardano_stake_distributions_returns_error_when_invalid_json_structure_in_response(
     ) {
@@ -75,6 +75,7 @@
 //! ```
 
 use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
+use crate::common::ListFilter;
 use crate::{
     CardanoTransactionSnapshot, CardanoTransactionSnapshotListItem, CardanoTransactionsProofs,
     MithrilResult,
@@ -119,9 +120,30 @@ impl CardanoTransactionClient {
 
     /// Fetch a list of signed Cardano transaction snapshots.
     pub async fn list_snapshots(&self) -> MithrilResult<Vec<CardanoTransactionSnapshotListItem>> {
+        self.fetch_list_with_aggregator_request(AggregatorRequest::ListCardanoTransactionSnapshots)
+            .await
+    }
+
+    /// Fetch a list of signed Cardano transaction snapshots matching the given cursor and filters.
+    pub async fn list_snapshots_with_filter(
+        &self,
+        filter: &ListFilter,
+    ) -> MithrilResult<Vec<CardanoTransactionSnapshotListItem>> {
+        self.fetch_list_with_aggregator_request(
+            AggregatorRequest::ListCardanoTransactionSnapshotsWithFilter {
+                filter: filter.clone(),
+            },
+        )
+        .await
+    }
+
+    async fn fetch_list_with_aggregator_request(
+        &self,
+        request: AggregatorRequest,
+    ) -> MithrilResult<Vec<CardanoTransactionSnapshotListItem>> {
         let response = self
             .aggregator_client
-            .get_content(AggregatorRequest::ListCardanoTransactionSnapshots)
+            .get_content(request)
             .await
             .with_context(|| "CardanoTransactionClient Client can not get the artifact list")?;
         let items = serde_json::from_str::<Vec<CardanoTransactionSnapshotListItem>>(&response)
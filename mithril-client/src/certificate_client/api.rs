@@ -1,10 +1,11 @@
 use async_trait::async_trait;
+use mithril_common::entities::{BeaconFilter, Epoch, ListFilter, SignedEntityTypeDiscriminants};
 use mithril_common::logging::LoggerExtensions;
 use std::sync::Arc;
 
 use crate::aggregator_client::AggregatorClient;
 use crate::certificate_client::fetch::InternalCertificateRetriever;
-use crate::certificate_client::{fetch, verify};
+use crate::certificate_client::{fetch, subscribe, verify};
 use crate::{MithrilCertificate, MithrilCertificateListItem, MithrilResult};
 
 /// Aggregator client for the Certificate
@@ -39,11 +40,41 @@ impl CertificateClient {
         fetch::list(self).await
     }
 
+    /// Fetch a list of certificates matching the given cursor and filters
+    pub async fn list_with_filter(
+        &self,
+        filter: &ListFilter,
+    ) -> MithrilResult<Vec<MithrilCertificateListItem>> {
+        fetch::list_with_filter(self, filter).await
+    }
+
     /// Get a single certificate full information from the aggregator.
     pub async fn get(&self, certificate_hash: &str) -> MithrilResult<Option<MithrilCertificate>> {
         fetch::get(self, certificate_hash).await
     }
 
+    /// Fetch the list of the certificates issued at the given epoch
+    pub async fn list_by_epoch(
+        &self,
+        epoch: Epoch,
+    ) -> MithrilResult<Vec<MithrilCertificateListItem>> {
+        fetch::list_by_epoch(self, epoch).await
+    }
+
+    /// Get the most recent certificate of the given signed entity type whose beacon matches the
+    /// given filter.
+    ///
+    /// Use an empty filter to get the latest certificate of the signed entity type, or a filter
+    /// built from a [SignedEntityType][crate::common::SignedEntityType] to get the
+    /// certificate of this exact beacon.
+    pub async fn get_by_signed_entity_type(
+        &self,
+        signed_entity_type: SignedEntityTypeDiscriminants,
+        beacon_filter: &BeaconFilter,
+    ) -> MithrilResult<Option<MithrilCertificate>> {
+        fetch::get_by_signed_entity_type(self, signed_entity_type, beacon_filter).await
+    }
+
     /// Validate the chain starting with the certificate with given `certificate_hash`, return the certificate if
     /// the chain is valid.
     ///
@@ -51,8 +82,57 @@ impl CertificateClient {
     pub async fn verify_chain(&self, certificate_hash: &str) -> MithrilResult<MithrilCertificate> {
         verify::verify_chain(self, certificate_hash).await
     }
+
+    /// Validate the chain starting with the certificate with given `certificate_hash`, return
+    /// all the validated certificates of the chain, from the certificate with the given hash to
+    /// the genesis certificate, if the chain is valid.
+    ///
+    /// Unlike [verify_chain][Self::verify_chain], no certificate is skipped using the
+    /// verifier cache.
+    ///
+    /// This method will fail if no certificate exists for the given `certificate_hash`.
+    pub async fn verify_chain_and_collect(
+        &self,
+        certificate_hash: &str,
+    ) -> MithrilResult<Vec<MithrilCertificate>> {
+        verify::verify_chain_and_collect(self, certificate_hash).await
+    }
+
+    /// Subscribe to the certificates created by the aggregator, the chain of each certificate is
+    /// validated before it is yielded by the returned stream.
+    ///
+    /// If a `last_event_id` is given, the stream resumes after this event, as long as it is still
+    /// kept in the aggregator recent events history.
+    pub async fn subscribe(
+        &self,
+        last_event_id: Option<u64>,
+    ) -> MithrilResult<VerifiedCertificateEventStream> {
+        subscribe::subscribe(self, last_event_id).await
+    }
+}
+
+/// A certificate created by the aggregator, received from its events stream, whose chain has
+/// been validated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifiedCertificateEvent {
+    /// Id of the aggregator event, to give to [CertificateClient::subscribe] to resume the stream
+    /// after this certificate
+    pub event_id: u64,
+
+    /// The certificate with a validated chain
+    pub certificate: MithrilCertificate,
 }
 
+/// Stream of the certificates created by the aggregator
+#[cfg(not(target_family = "wasm"))]
+pub type VerifiedCertificateEventStream =
+    futures::stream::BoxStream<'static, MithrilResult<VerifiedCertificateEvent>>;
+
+/// Stream of the certificates created by the aggregator
+#[cfg(target_family = "wasm")]
+pub type VerifiedCertificateEventStream =
+    futures::stream::LocalBoxStream<'static, MithrilResult<VerifiedCertificateEvent>>;
+
 /// API that defines how to validate certificates.
 #[cfg_attr(test, mockall::automock)]
 #[cfg_attr(target_family = "wasm", async_trait(?Send))]
@@ -60,6 +140,17 @@ impl CertificateClient {
 pub trait CertificateVerifier: Sync + Send {
     /// Validate the chain starting with the given certificate.
     async fn verify_chain(&self, certificate: &MithrilCertificate) -> MithrilResult<()>;
+
+    /// Validate the whole chain starting with the given certificate and return the validated
+    /// certificates, from the given certificate to the genesis certificate.
+    async fn verify_chain_and_collect(
+        &self,
+        _certificate: &MithrilCertificate,
+    ) -> MithrilResult<Vec<MithrilCertificate>> {
+        Err(anyhow::anyhow!(
+            "This certificate verifier can not collect the certificates of a validated chain"
+        ))
+    }
 }
 
 #[cfg(feature = "unstable")]
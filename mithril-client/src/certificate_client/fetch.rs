@@ -1,11 +1,15 @@
 use anyhow::{anyhow, Context};
 use async_trait::async_trait;
-use slog::{crit, Logger};
-use std::sync::Arc;
+use slog::{crit, debug, Logger};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 
 use mithril_common::certificate_chain::{CertificateRetriever, CertificateRetrieverError};
-use mithril_common::entities::Certificate;
-use mithril_common::messages::CertificateMessage;
+use mithril_common::entities::{
+    BeaconFilter, Certificate, Epoch, ListFilter, SignedEntityTypeDiscriminants,
+};
+use mithril_common::messages::{CertificateChainMessage, CertificateMessage};
 
 use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
 use crate::certificate_client::CertificateClient;
@@ -14,10 +18,39 @@ use crate::{MithrilCertificate, MithrilCertificateListItem, MithrilResult};
 #[inline]
 pub(super) async fn list(
     client: &CertificateClient,
+) -> MithrilResult<Vec<MithrilCertificateListItem>> {
+    list_with_request(client, AggregatorRequest::ListCertificates).await
+}
+
+#[inline]
+pub(super) async fn list_with_filter(
+    client: &CertificateClient,
+    filter: &ListFilter,
+) -> MithrilResult<Vec<MithrilCertificateListItem>> {
+    list_with_request(
+        client,
+        AggregatorRequest::ListCertificatesWithFilter {
+            filter: filter.clone(),
+        },
+    )
+    .await
+}
+
+#[inline]
+pub(super) async fn list_by_epoch(
+    client: &CertificateClient,
+    epoch: Epoch,
+) -> MithrilResult<Vec<MithrilCertificateListItem>> {
+    list_with_request(client, AggregatorRequest::ListCertificatesByEpoch { epoch }).await
+}
+
+async fn list_with_request(
+    client: &CertificateClient,
+    request: AggregatorRequest,
 ) -> MithrilResult<Vec<MithrilCertificateListItem>> {
     let response = client
         .aggregator_client
-        .get_content(AggregatorRequest::ListCertificates)
+        .get_content(request)
         .await
         .with_context(|| "CertificateClient can not get the certificate list")?;
     let items = serde_json::from_str::<Vec<MithrilCertificateListItem>>(&response)
@@ -34,14 +67,69 @@ pub(super) async fn get(
     client.retriever.get(certificate_hash).await
 }
 
+pub(super) async fn get_by_signed_entity_type(
+    client: &CertificateClient,
+    signed_entity_type: SignedEntityTypeDiscriminants,
+    beacon_filter: &BeaconFilter,
+) -> MithrilResult<Option<MithrilCertificate>> {
+    beacon_filter.validate_for(signed_entity_type)?;
+
+    client
+        .retriever
+        .get_with_request(AggregatorRequest::GetCertificateBySignedEntityType {
+            signed_entity_type,
+            beacon_filter: beacon_filter.clone(),
+        })
+        .await
+}
+
 /// Internal type to implement the [InternalCertificateRetriever] trait and avoid a circular
 /// dependency between the [CertificateClient] and the [CommonMithrilCertificateVerifier] that need
 /// a [CertificateRetriever] as a dependency.
 pub(super) struct InternalCertificateRetriever {
     aggregator_client: Arc<dyn AggregatorClient>,
+    chain_prefetch: Option<CertificateChainPrefetch>,
     logger: Logger,
 }
 
+/// Segment of the certificate chain downloaded ahead of the verification of its certificates.
+struct CertificateChainPrefetch {
+    depth: u64,
+    certificates: Mutex<HashMap<String, Certificate>>,
+    is_supported_by_aggregator: AtomicBool,
+}
+
+impl CertificateChainPrefetch {
+    fn new(depth: u64) -> Self {
+        Self {
+            depth,
+            certificates: Mutex::new(HashMap::new()),
+            is_supported_by_aggregator: AtomicBool::new(true),
+        }
+    }
+
+    fn is_supported_by_aggregator(&self) -> bool {
+        self.is_supported_by_aggregator.load(Ordering::Relaxed)
+    }
+
+    fn disable(&self) {
+        self.is_supported_by_aggregator
+            .store(false, Ordering::Relaxed);
+    }
+
+    fn take(&self, certificate_hash: &str) -> Option<Certificate> {
+        self.certificates.lock().unwrap().remove(certificate_hash)
+    }
+
+    fn replace_with(&self, certificates: impl IntoIterator<Item = Certificate>) {
+        let mut prefetched_certificates = self.certificates.lock().unwrap();
+        *prefetched_certificates = certificates
+            .into_iter()
+            .map(|certificate| (certificate.hash.clone(), certificate))
+            .collect();
+    }
+}
+
 impl InternalCertificateRetriever {
     pub(super) fn new(
         aggregator_client: Arc<dyn AggregatorClient>,
@@ -49,20 +137,35 @@ impl InternalCertificateRetriever {
     ) -> InternalCertificateRetriever {
         InternalCertificateRetriever {
             aggregator_client,
+            chain_prefetch: None,
             logger,
         }
     }
 
+    /// Download the certificate chain by segments of `depth` certificates when retrieving the
+    /// certificates details, instead of one certificate at a time.
+    ///
+    /// Fallback to downloading one certificate at a time if the aggregator doesn't support it.
+    pub(super) fn with_chain_prefetch(mut self, depth: u64) -> Self {
+        self.chain_prefetch = Some(CertificateChainPrefetch::new(depth));
+        self
+    }
+
     pub(super) async fn get(
         &self,
         certificate_hash: &str,
     ) -> MithrilResult<Option<MithrilCertificate>> {
-        let response = self
-            .aggregator_client
-            .get_content(AggregatorRequest::GetCertificate {
-                hash: certificate_hash.to_string(),
-            })
-            .await;
+        self.get_with_request(AggregatorRequest::GetCertificate {
+            hash: certificate_hash.to_string(),
+        })
+        .await
+    }
+
+    async fn get_with_request(
+        &self,
+        request: AggregatorRequest,
+    ) -> MithrilResult<Option<MithrilCertificate>> {
+        let response = self.aggregator_client.get_content(request).await;
 
         match response {
             Err(AggregatorClientError::RemoteServerLogical(_)) => Ok(None),
@@ -81,6 +184,77 @@ impl InternalCertificateRetriever {
             }
         }
     }
+
+    async fn get_certificate(&self, certificate_hash: &str) -> MithrilResult<Option<Certificate>> {
+        match &self.chain_prefetch {
+            Some(prefetch) if prefetch.is_supported_by_aggregator() => {
+                self.get_certificate_with_chain_prefetch(prefetch, certificate_hash)
+                    .await
+            }
+            _ => self
+                .get(certificate_hash)
+                .await?
+                .map(|message| message.try_into())
+                .transpose(),
+        }
+    }
+
+    async fn get_certificate_with_chain_prefetch(
+        &self,
+        prefetch: &CertificateChainPrefetch,
+        certificate_hash: &str,
+    ) -> MithrilResult<Option<Certificate>> {
+        if let Some(certificate) = prefetch.take(certificate_hash) {
+            return Ok(Some(certificate));
+        }
+
+        let response = self
+            .aggregator_client
+            .get_content(AggregatorRequest::GetCertificateChain {
+                hash: certificate_hash.to_string(),
+                depth: Some(prefetch.depth),
+            })
+            .await;
+
+        match response {
+            Err(AggregatorClientError::RemoteServerLogical(_)) => {
+                // Either the certificate does not exist or the aggregator does not support the
+                // certificate chain route, the later is checked by retrieving the certificate alone
+                let certificate = self.get(certificate_hash).await?;
+                if certificate.is_some() {
+                    debug!(
+                        self.logger,
+                        "Certificate chain prefetch not supported by the aggregator, disabling it"
+                    );
+                    prefetch.disable();
+                }
+
+                certificate.map(|message| message.try_into()).transpose()
+            }
+            Err(e) => Err(e.into()),
+            Ok(response) => {
+                let message = serde_json::from_str::<CertificateChainMessage>(&response)
+                    .with_context(|| "CertificateClient can not deserialize certificate chain")?;
+                let mut certificates = message
+                    .into_iter()
+                    .map(|message| message.try_into())
+                    .collect::<MithrilResult<Vec<Certificate>>>()?
+                    .into_iter();
+
+                match certificates.next() {
+                    Some(certificate) if certificate.hash == certificate_hash => {
+                        prefetch.replace_with(certificates);
+                        Ok(Some(certificate))
+                    }
+                    Some(certificate) => Err(anyhow!(
+                        "Certificate chain should start with certificate '{certificate_hash}', got '{}'",
+                        certificate.hash
+                    )),
+                    None => Ok(None),
+                }
+            }
+        }
+    }
 }
 
 #[cfg_attr(target_family = "wasm", async_trait(?Send))]
@@ -90,12 +264,9 @@ impl CertificateRetriever for InternalCertificateRetriever {
         &self,
         certificate_hash: &str,
     ) -> Result<Certificate, CertificateRetrieverError> {
-        self.get(certificate_hash)
+        self.get_certificate(certificate_hash)
             .await
             .map_err(CertificateRetrieverError)?
-            .map(|message| message.try_into())
-            .transpose()
-            .map_err(CertificateRetrieverError)?
             .ok_or(CertificateRetrieverError(anyhow!(format!(
                 "Certificate does not exist: '{}'",
                 certificate_hash
@@ -105,6 +276,7 @@ impl CertificateRetriever for InternalCertificateRetriever {
 
 #[cfg(test)]
 mod tests {
+    use mithril_common::entities::BlockNumber;
     use mithril_common::test_utils::fake_data;
 
     use crate::certificate_client::tests_utils::CertificateClientTestBuilder;
@@ -135,6 +307,103 @@ mod tests {
         assert_eq!(expected, items);
     }
 
+    #[tokio::test]
+    async fn get_certificate_list_with_filter() {
+        let expected = vec![MithrilCertificateListItem {
+            hash: "cert-hash-123".to_string(),
+            ..MithrilCertificateListItem::dummy()
+        }];
+        let message = expected.clone();
+        let filter = ListFilter::default().before("cert-hash-456");
+        let expected_request = AggregatorRequest::ListCertificatesWithFilter {
+            filter: filter.clone(),
+        };
+        let certificate_client = CertificateClientTestBuilder::default()
+            .config_aggregator_client_mock(|mock| {
+                mock.expect_get_content()
+                    .withf(move |request| request == &expected_request)
+                    .return_once(move |_| Ok(serde_json::to_string(&message).unwrap()));
+            })
+            .build();
+        let items = certificate_client.list_with_filter(&filter).await.unwrap();
+
+        assert_eq!(expected, items);
+    }
+
+    #[tokio::test]
+    async fn get_certificate_list_by_epoch() {
+        let expected = vec![MithrilCertificateListItem {
+            hash: "cert-hash-123".to_string(),
+            ..MithrilCertificateListItem::dummy()
+        }];
+        let message = expected.clone();
+        let certificate_client = CertificateClientTestBuilder::default()
+            .config_aggregator_client_mock(|mock| {
+                mock.expect_get_content()
+                    .withf(|request| {
+                        request == &AggregatorRequest::ListCertificatesByEpoch { epoch: Epoch(8) }
+                    })
+                    .return_once(move |_| Ok(serde_json::to_string(&message).unwrap()));
+            })
+            .build();
+        let items = certificate_client.list_by_epoch(Epoch(8)).await.unwrap();
+
+        assert_eq!(expected, items);
+    }
+
+    #[tokio::test]
+    async fn get_certificate_by_signed_entity_type() {
+        let certificate = fake_data::certificate("cert-hash-123");
+        let expected_certificate = certificate.clone();
+        let beacon_filter = BeaconFilter::default().immutable_file_number(1234);
+        let expected_request = AggregatorRequest::GetCertificateBySignedEntityType {
+            signed_entity_type: SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
+            beacon_filter: beacon_filter.clone(),
+        };
+
+        let certificate_client = CertificateClientTestBuilder::default()
+            .config_aggregator_client_mock(|mock| {
+                mock.expect_get_content()
+                    .withf(move |request| request == &expected_request)
+                    .return_once(move |_| {
+                        let message: CertificateMessage = certificate.try_into().unwrap();
+                        Ok(serde_json::to_string(&message).unwrap())
+                    })
+                    .times(1);
+            })
+            .build();
+
+        let cert: Certificate = certificate_client
+            .get_by_signed_entity_type(
+                SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
+                &beacon_filter,
+            )
+            .await
+            .unwrap()
+            .expect("The certificate should be found")
+            .try_into()
+            .unwrap();
+
+        assert_eq!(expected_certificate, cert);
+    }
+
+    #[tokio::test]
+    async fn get_certificate_by_signed_entity_type_fails_if_the_beacon_filter_does_not_apply() {
+        let certificate_client = CertificateClientTestBuilder::default()
+            .config_aggregator_client_mock(|mock| {
+                mock.expect_get_content().never();
+            })
+            .build();
+
+        certificate_client
+            .get_by_signed_entity_type(
+                SignedEntityTypeDiscriminants::MithrilStakeDistribution,
+                &BeaconFilter::default().block_number(BlockNumber(12)),
+            )
+            .await
+            .expect_err("The beacon of a MithrilStakeDistribution has no block number");
+    }
+
     #[tokio::test]
     async fn get_certificate_empty_list() {
         let certificate_client = CertificateClientTestBuilder::default()
@@ -217,4 +486,155 @@ mod tests {
             .await
             .expect_err("The certificate client should fail here.");
     }
+
+    mod chain_prefetch {
+        use mithril_common::test_utils::CertificateChainBuilder;
+        use mockall::predicate::eq;
+
+        use crate::aggregator_client::MockAggregatorHTTPClient;
+        use crate::test_utils;
+
+        use super::*;
+
+        fn build_retriever_with_chain_prefetch(
+            aggregator_client_mock_config: impl FnOnce(&mut MockAggregatorHTTPClient),
+            depth: u64,
+        ) -> InternalCertificateRetriever {
+            let mut aggregator_client = MockAggregatorHTTPClient::new();
+            aggregator_client_mock_config(&mut aggregator_client);
+
+            InternalCertificateRetriever::new(
+                Arc::new(aggregator_client),
+                test_utils::test_logger(),
+            )
+            .with_chain_prefetch(depth)
+        }
+
+        fn certificate_message_json(certificate: Certificate) -> String {
+            let message: CertificateMessage = certificate.try_into().unwrap();
+            serde_json::to_string(&message).unwrap()
+        }
+
+        #[tokio::test]
+        async fn retrieve_certificate_chain_by_segments() {
+            let (chain, _) = CertificateChainBuilder::new()
+                .with_total_certificates(6)
+                .with_certificates_per_epoch(1)
+                .build();
+            let retriever = build_retriever_with_chain_prefetch(
+                |mock| mock.expect_certificate_chain_prefetch(chain.clone(), 2),
+                2,
+            );
+
+            retriever.get(&chain[0].hash).await.unwrap();
+            for certificate in &chain[1..] {
+                let retrieved_certificate = retriever
+                    .get_certificate_details(&certificate.hash)
+                    .await
+                    .unwrap();
+                assert_eq!(certificate, &retrieved_certificate);
+            }
+        }
+
+        #[tokio::test]
+        async fn fallback_to_retrieve_certificates_one_by_one_if_aggregator_does_not_support_chain_route(
+        ) {
+            let certificate_1 = fake_data::certificate("cert-1");
+            let certificate_2 = fake_data::certificate("cert-2");
+            let retriever = build_retriever_with_chain_prefetch(
+                |mock| {
+                    mock.expect_get_content()
+                        .with(eq(AggregatorRequest::GetCertificateChain {
+                            hash: "cert-1".to_string(),
+                            depth: Some(10),
+                        }))
+                        .return_once(|_| {
+                            Err(AggregatorClientError::RemoteServerLogical(anyhow!(
+                                "not found"
+                            )))
+                        })
+                        .once();
+                    mock.expect_certificate_chain(vec![
+                        certificate_1.clone(),
+                        certificate_2.clone(),
+                    ]);
+                },
+                10,
+            );
+
+            let retrieved_certificate = retriever.get_certificate_details("cert-1").await.unwrap();
+            assert_eq!(certificate_1, retrieved_certificate);
+
+            let retrieved_certificate = retriever.get_certificate_details("cert-2").await.unwrap();
+            assert_eq!(certificate_2, retrieved_certificate);
+        }
+
+        #[tokio::test]
+        async fn keep_prefetching_after_failing_to_retrieve_an_unknown_certificate() {
+            let certificate = fake_data::certificate("cert-1");
+            let retriever = build_retriever_with_chain_prefetch(
+                |mock| {
+                    mock.expect_get_content()
+                        .with(eq(AggregatorRequest::GetCertificateChain {
+                            hash: "unknown".to_string(),
+                            depth: Some(10),
+                        }))
+                        .return_once(|_| {
+                            Err(AggregatorClientError::RemoteServerLogical(anyhow!(
+                                "not found"
+                            )))
+                        })
+                        .once();
+                    mock.expect_get_content()
+                        .with(eq(AggregatorRequest::GetCertificate {
+                            hash: "unknown".to_string(),
+                        }))
+                        .return_once(|_| {
+                            Err(AggregatorClientError::RemoteServerLogical(anyhow!(
+                                "not found"
+                            )))
+                        })
+                        .once();
+                    let message = format!("[{}]", certificate_message_json(certificate.clone()));
+                    mock.expect_get_content()
+                        .with(eq(AggregatorRequest::GetCertificateChain {
+                            hash: "cert-1".to_string(),
+                            depth: Some(10),
+                        }))
+                        .return_once(move |_| Ok(message))
+                        .once();
+                },
+                10,
+            );
+
+            retriever
+                .get_certificate_details("unknown")
+                .await
+                .expect_err("Retrieving an unknown certificate should fail");
+
+            let retrieved_certificate = retriever.get_certificate_details("cert-1").await.unwrap();
+            assert_eq!(certificate, retrieved_certificate);
+        }
+
+        #[tokio::test]
+        async fn fail_if_the_certificate_chain_does_not_start_with_the_requested_certificate() {
+            let retriever = build_retriever_with_chain_prefetch(
+                |mock| {
+                    let message = format!(
+                        "[{}]",
+                        certificate_message_json(fake_data::certificate("cert-2"))
+                    );
+                    mock.expect_get_content()
+                        .return_once(move |_| Ok(message))
+                        .once();
+                },
+                10,
+            );
+
+            retriever
+                .get_certificate_details("cert-1")
+                .await
+                .expect_err("A chain starting with another certificate should be rejected");
+        }
+    }
 }
@@ -3,6 +3,7 @@
 //! In order to do so it defines a [CertificateClient] exposes the following features:
 //!  - [get][CertificateClient::get]: get a certificate data from its hash
 //!  - [list][CertificateClient::list]: get the list of available certificates
+//!  - [get_by_signed_entity_type][CertificateClient::get_by_signed_entity_type]: get the most recent certificate of a signed entity type, optionally at a given beacon
 //!  - [verify_chain][CertificateClient::verify_chain]: verify a certificate chain
 //!
 //! # Get a certificate
@@ -39,6 +40,31 @@
 //! # }
 //! ```
 //!
+//! # Get the certificate of a signed entity type
+//!
+//! To get the certificate that signed the Cardano immutable files at a given immutable file number
+//! using the [ClientBuilder][crate::client::ClientBuilder].
+//!
+//! ```no_run
+//! # async fn run() -> mithril_client::MithrilResult<()> {
+//! use mithril_client::ClientBuilder;
+//! use mithril_client::common::{BeaconFilter, SignedEntityTypeDiscriminants};
+//!
+//! let client = ClientBuilder::aggregator("YOUR_AGGREGATOR_ENDPOINT", "YOUR_GENESIS_VERIFICATION_KEY").build()?;
+//! let certificate = client
+//!     .certificate()
+//!     .get_by_signed_entity_type(
+//!         SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
+//!         &BeaconFilter::default().immutable_file_number(1234),
+//!     )
+//!     .await?
+//!     .unwrap();
+//!
+//! println!("Certificate hash={}, signed_message={}", certificate.hash, certificate.signed_message);
+//! #    Ok(())
+//! # }
+//! ```
+//!
 //! # Validate a certificate chain
 //!
 //! To validate a certificate using the [ClientBuilder][crate::client::ClientBuilder].
@@ -57,20 +83,25 @@
 
 mod api;
 mod fetch;
+mod subscribe;
 mod verify;
 #[cfg(feature = "unstable")]
 mod verify_cache;
 
 pub use api::*;
 pub use verify::MithrilCertificateVerifier;
+#[cfg(all(feature = "fs", feature = "unstable"))]
+pub use verify_cache::FileCertificateVerifierCache;
 #[cfg(feature = "unstable")]
 pub use verify_cache::MemoryCertificateVerifierCache;
 
 #[cfg(test)]
 pub(crate) mod tests_utils {
-    use mithril_common::crypto_helper::ProtocolGenesisVerificationKey;
+    use mithril_common::crypto_helper::{
+        ProtocolGenesisVerificationKey, ProtocolGenesisVerificationKeySet,
+    };
     use mithril_common::entities::Certificate;
-    use mithril_common::messages::CertificateMessage;
+    use mithril_common::messages::{CertificateChainMessage, CertificateMessage};
     use mockall::predicate::eq;
     use std::sync::Arc;
 
@@ -84,6 +115,7 @@ pub(crate) mod tests_utils {
     pub(crate) struct CertificateClientTestBuilder {
         aggregator_client: MockAggregatorHTTPClient,
         genesis_verification_key: Option<String>,
+        genesis_verification_key_set: Option<String>,
         feedback_receivers: Vec<Arc<dyn FeedbackReceiver>>,
         #[cfg(feature = "unstable")]
         verifier_cache: Option<Arc<dyn CertificateVerifierCache>>,
@@ -106,6 +138,15 @@ pub(crate) mod tests_utils {
             self
         }
 
+        pub fn with_genesis_verification_key_set(
+            mut self,
+            genesis_verification_key_set: ProtocolGenesisVerificationKeySet,
+        ) -> Self {
+            self.genesis_verification_key_set =
+                Some(genesis_verification_key_set.to_json_hex().unwrap());
+            self
+        }
+
         pub fn add_feedback_receiver(
             mut self,
             feedback_receiver: Arc<dyn FeedbackReceiver>,
@@ -131,21 +172,31 @@ pub(crate) mod tests_utils {
             let logger = test_utils::test_logger();
             let aggregator_client = Arc::new(self.aggregator_client);
 
-            let certificate_verifier: Arc<dyn CertificateVerifier> =
-                match self.genesis_verification_key {
-                    None => Arc::new(MockCertificateVerifier::new()),
-                    Some(genesis_verification_key) => Arc::new(
-                        MithrilCertificateVerifier::new(
-                            aggregator_client.clone(),
-                            &genesis_verification_key,
-                            FeedbackSender::new(&self.feedback_receivers),
-                            #[cfg(feature = "unstable")]
-                            self.verifier_cache,
-                            logger.clone(),
-                        )
-                        .unwrap(),
-                    ),
-                };
+            let certificate_verifier: Arc<dyn CertificateVerifier> = match self
+                .genesis_verification_key
+            {
+                None => Arc::new(MockCertificateVerifier::new()),
+                Some(genesis_verification_key) => {
+                    let verifier = MithrilCertificateVerifier::new(
+                        aggregator_client.clone(),
+                        &genesis_verification_key,
+                        FeedbackSender::new(&self.feedback_receivers),
+                        #[cfg(feature = "unstable")]
+                        self.verifier_cache,
+                        logger.clone(),
+                    )
+                    .unwrap();
+
+                    match self.genesis_verification_key_set {
+                        None => Arc::new(verifier),
+                        Some(genesis_verification_key_set) => Arc::new(
+                            verifier
+                                .with_genesis_verification_key_set(&genesis_verification_key_set)
+                                .unwrap(),
+                        ),
+                    }
+                }
+            };
 
             CertificateClient::new(aggregator_client.clone(), certificate_verifier, logger)
         }
@@ -165,5 +216,34 @@ pub(crate) mod tests_utils {
                     .returning(move |_| Ok(message.to_owned()));
             }
         }
+
+        /// Expect the first certificate of the chain to be retrieved alone and its ancestors
+        /// to be retrieved by segments, as done when verifying the chain.
+        pub(crate) fn expect_certificate_chain_prefetch(
+            &mut self,
+            certificate_chain: Vec<Certificate>,
+            depth: u64,
+        ) {
+            let (first_certificate, ancestors) = certificate_chain.split_first().unwrap();
+            self.expect_certificate_chain(vec![first_certificate.clone()]);
+
+            for segment in ancestors.chunks(depth as usize) {
+                let hash = segment[0].hash.clone();
+                let message = serde_json::to_string(
+                    &segment
+                        .iter()
+                        .map(|certificate| certificate.clone().try_into().unwrap())
+                        .collect::<CertificateChainMessage>(),
+                )
+                .unwrap();
+                self.expect_get_content()
+                    .with(eq(AggregatorRequest::GetCertificateChain {
+                        hash,
+                        depth: Some(depth),
+                    }))
+                    .once()
+                    .returning(move |_| Ok(message.to_owned()));
+            }
+        }
     }
 }
@@ -0,0 +1,151 @@
+use anyhow::Context;
+use futures::{StreamExt, TryStreamExt};
+
+use mithril_common::messages::AggregatorEventMessage;
+
+use crate::aggregator_client::AggregatorRequest;
+use crate::certificate_client::{
+    verify, CertificateClient, VerifiedCertificateEvent, VerifiedCertificateEventStream,
+};
+use crate::MithrilResult;
+
+pub(super) async fn subscribe(
+    client: &CertificateClient,
+    last_event_id: Option<u64>,
+) -> MithrilResult<VerifiedCertificateEventStream> {
+    let events = client
+        .aggregator_client
+        .get_event_stream(AggregatorRequest::GetEvents { last_event_id })
+        .await
+        .with_context(|| "CertificateClient can not subscribe to the aggregator events")?;
+    let retriever = client.retriever.clone();
+    let verifier = client.verifier.clone();
+
+    let certificates = events.try_filter_map(move |event| {
+        let retriever = retriever.clone();
+        let verifier = verifier.clone();
+
+        async move {
+            match event.message {
+                AggregatorEventMessage::CertificateCreated {
+                    certificate_hash, ..
+                } => {
+                    let certificate =
+                        verify::verify_chain_with(&retriever, verifier.as_ref(), &certificate_hash)
+                            .await?;
+
+                    Ok(Some(VerifiedCertificateEvent {
+                        event_id: event.id,
+                        certificate,
+                    }))
+                }
+                _ => Ok(None),
+            }
+        }
+    });
+
+    #[cfg(not(target_family = "wasm"))]
+    let certificates = certificates.boxed();
+    #[cfg(target_family = "wasm")]
+    let certificates = certificates.boxed_local();
+
+    Ok(certificates)
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use futures::stream;
+    use mockall::predicate::eq;
+
+    use mithril_common::entities::{Epoch, SignedEntityType};
+    use mithril_common::test_utils::CertificateChainBuilder;
+
+    use crate::aggregator_client::{AggregatorClientError, AggregatorEvent};
+    use crate::certificate_client::tests_utils::CertificateClientTestBuilder;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn subscribe_yields_verified_certificates_of_certificate_created_events() {
+        let (chain, verifier) = CertificateChainBuilder::new()
+            .with_total_certificates(3)
+            .with_certificates_per_epoch(1)
+            .build();
+        let last_certificate = chain.first().unwrap().clone();
+        let events = vec![
+            Ok(AggregatorEvent {
+                id: 10,
+                message: AggregatorEventMessage::EpochChanged { epoch: Epoch(3) },
+            }),
+            Ok(AggregatorEvent {
+                id: 11,
+                message: AggregatorEventMessage::CertificateCreated {
+                    certificate_hash: last_certificate.hash.clone(),
+                    epoch: last_certificate.epoch,
+                    signed_entity_type: last_certificate.signed_entity_type(),
+                },
+            }),
+        ];
+
+        let certificate_client = CertificateClientTestBuilder::default()
+            .config_aggregator_client_mock(|mock| {
+                mock.expect_get_event_stream()
+                    .with(eq(AggregatorRequest::GetEvents {
+                        last_event_id: Some(9),
+                    }))
+                    .return_once(move |_| Ok(stream::iter(events).boxed()));
+                mock.expect_certificate_chain_prefetch(
+                    chain.clone(),
+                    verify::CERTIFICATE_CHAIN_PREFETCH_DEPTH,
+                );
+            })
+            .with_genesis_verification_key(verifier.to_verification_key())
+            .build();
+
+        let certificates: Vec<VerifiedCertificateEvent> = certificate_client
+            .subscribe(Some(9))
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .expect("Certificate chain validation should succeed");
+
+        assert_eq!(1, certificates.len());
+        assert_eq!(11, certificates[0].event_id);
+        assert_eq!(last_certificate.hash, certificates[0].certificate.hash);
+    }
+
+    #[tokio::test]
+    async fn subscribe_yields_an_error_if_a_certificate_can_not_be_retrieved() {
+        let events = vec![Ok(AggregatorEvent {
+            id: 1,
+            message: AggregatorEventMessage::CertificateCreated {
+                certificate_hash: "unknown-hash".to_string(),
+                epoch: Epoch(1),
+                signed_entity_type: SignedEntityType::MithrilStakeDistribution(Epoch(1)),
+            },
+        })];
+
+        let certificate_client = CertificateClientTestBuilder::default()
+            .config_aggregator_client_mock(|mock| {
+                mock.expect_get_event_stream()
+                    .return_once(move |_| Ok(stream::iter(events).boxed()));
+                mock.expect_get_content().returning(|_| {
+                    Err(AggregatorClientError::RemoteServerLogical(anyhow!(
+                        "not found"
+                    )))
+                });
+            })
+            .build();
+
+        let result: MithrilResult<Vec<VerifiedCertificateEvent>> = certificate_client
+            .subscribe(None)
+            .await
+            .unwrap()
+            .try_collect()
+            .await;
+
+        result.expect_err("Subscribe should fail when a certificate can not be retrieved");
+    }
+}
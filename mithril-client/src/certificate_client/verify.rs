@@ -1,16 +1,17 @@
 use anyhow::{anyhow, Context};
 use async_trait::async_trait;
 use slog::{trace, Logger};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use mithril_common::{
     certificate_chain::{
-        CertificateRetriever, CertificateVerifier as CommonCertificateVerifier,
+        CertificateChainVerificationObserver, CertificateVerifier as _,
         MithrilCertificateVerifier as CommonMithrilCertificateVerifier,
     },
-    crypto_helper::ProtocolGenesisVerificationKey,
+    crypto_helper::{ProtocolGenesisVerificationKey, ProtocolGenesisVerificationKeySet},
     entities::Certificate,
     logging::LoggerExtensions,
+    StdResult,
 };
 
 use crate::aggregator_client::AggregatorClient;
@@ -21,11 +22,26 @@ use crate::certificate_client::{CertificateClient, CertificateVerifier};
 use crate::feedback::{FeedbackSender, MithrilEvent};
 use crate::{MithrilCertificate, MithrilResult};
 
+/// Number of certificates downloaded at once when verifying a certificate chain
+pub(super) const CERTIFICATE_CHAIN_PREFETCH_DEPTH: u64 = 100;
+
 #[inline]
 pub(super) async fn verify_chain(
     client: &CertificateClient,
     certificate_hash: &str,
 ) -> MithrilResult<MithrilCertificate> {
+    verify_chain_with(
+        &client.retriever,
+        client.verifier.as_ref(),
+        certificate_hash,
+    )
+    .await
+}
+
+pub(super) async fn verify_chain_and_collect(
+    client: &CertificateClient,
+    certificate_hash: &str,
+) -> MithrilResult<Vec<MithrilCertificate>> {
     let certificate = client
         .retriever
         .get(certificate_hash)
@@ -36,11 +52,25 @@ pub(super) async fn verify_chain(
 
     client
         .verifier
-        .verify_chain(&certificate)
+        .verify_chain_and_collect(&certificate)
         .await
         .with_context(|| {
             format!("Certificate chain of certificate '{certificate_hash}' is invalid")
-        })?;
+        })
+}
+
+pub(super) async fn verify_chain_with(
+    retriever: &InternalCertificateRetriever,
+    verifier: &dyn CertificateVerifier,
+    certificate_hash: &str,
+) -> MithrilResult<MithrilCertificate> {
+    let certificate = retriever.get(certificate_hash).await?.ok_or(anyhow!(
+        "No certificate exist for hash '{certificate_hash}'"
+    ))?;
+
+    verifier.verify_chain(&certificate).await.with_context(|| {
+        format!("Certificate chain of certificate '{certificate_hash}' is invalid")
+    })?;
 
     Ok(certificate)
 }
@@ -48,8 +78,7 @@ pub(super) async fn verify_chain(
 /// Implementation of a [CertificateVerifier] that can send feedbacks using
 /// the [feedback][crate::feedback] mechanism.
 pub struct MithrilCertificateVerifier {
-    retriever: Arc<InternalCertificateRetriever>,
-    internal_verifier: Arc<dyn CommonCertificateVerifier>,
+    internal_verifier: CommonMithrilCertificateVerifier,
     genesis_verification_key: ProtocolGenesisVerificationKey,
     feedback_sender: FeedbackSender,
     #[cfg(feature = "unstable")]
@@ -67,20 +96,25 @@ impl MithrilCertificateVerifier {
         logger: Logger,
     ) -> MithrilResult<MithrilCertificateVerifier> {
         let logger = logger.new_with_component_name::<Self>();
-        let retriever = Arc::new(InternalCertificateRetriever::new(
-            aggregator_client,
-            logger.clone(),
-        ));
-        let internal_verifier = Arc::new(CommonMithrilCertificateVerifier::new(
-            logger.clone(),
-            retriever.clone(),
-        ));
+        let retriever = InternalCertificateRetriever::new(aggregator_client, logger.clone());
+        // The certificates validated in the cache are not downloaded, prefetching the chain
+        // would download them for nothing
+        #[cfg(feature = "unstable")]
+        let retriever = if verifier_cache.is_some() {
+            retriever
+        } else {
+            retriever.with_chain_prefetch(CERTIFICATE_CHAIN_PREFETCH_DEPTH)
+        };
+        #[cfg(not(feature = "unstable"))]
+        let retriever = retriever.with_chain_prefetch(CERTIFICATE_CHAIN_PREFETCH_DEPTH);
+        let internal_verifier =
+            CommonMithrilCertificateVerifier::new(logger.clone(), Arc::new(retriever))
+                .with_batch_multi_signature_verification();
         let genesis_verification_key =
             ProtocolGenesisVerificationKey::try_from(genesis_verification_key)
                 .with_context(|| "Invalid genesis verification key")?;
 
         Ok(Self {
-            retriever,
             internal_verifier,
             genesis_verification_key,
             feedback_sender,
@@ -90,158 +124,138 @@ impl MithrilCertificateVerifier {
         })
     }
 
-    #[cfg(feature = "unstable")]
-    async fn fetch_cached_previous_hash(&self, hash: &str) -> MithrilResult<Option<String>> {
-        if let Some(cache) = self.verifier_cache.as_ref() {
-            Ok(cache.get_previous_hash(hash).await?)
-        } else {
-            Ok(None)
-        }
-    }
+    /// Set the genesis verification key set, encoded as json hex, used to verify the threshold
+    /// genesis certificates.
+    pub fn with_genesis_verification_key_set(
+        mut self,
+        genesis_verification_key_set: &str,
+    ) -> MithrilResult<Self> {
+        let genesis_verification_key_set =
+            ProtocolGenesisVerificationKeySet::from_json_hex(genesis_verification_key_set)
+                .with_context(|| "Invalid genesis verification key set")?;
+        self.internal_verifier = self
+            .internal_verifier
+            .with_genesis_verification_key_set(genesis_verification_key_set);
 
-    #[cfg(not(feature = "unstable"))]
-    async fn fetch_cached_previous_hash(&self, _hash: &str) -> MithrilResult<Option<String>> {
-        Ok(None)
+        Ok(self)
     }
 
-    async fn verify_with_cache_enabled(
+    async fn verify_chain_with_observer(
         &self,
-        certificate_chain_validation_id: &str,
-        certificate: CertificateToVerify,
-    ) -> MithrilResult<Option<CertificateToVerify>> {
-        trace!(self.logger, "Validating certificate"; "hash" => certificate.hash(), "previous_hash" => certificate.hash());
-        if let Some(previous_hash) = self.fetch_cached_previous_hash(certificate.hash()).await? {
-            trace!(self.logger, "Certificate fetched from cache"; "hash" => certificate.hash(), "previous_hash" => &previous_hash);
-            self.feedback_sender
-                .send_event(MithrilEvent::CertificateFetchedFromCache {
-                    certificate_hash: certificate.hash().to_owned(),
-                    certificate_chain_validation_id: certificate_chain_validation_id.to_string(),
-                })
-                .await;
-
-            Ok(Some(CertificateToVerify::ToDownload {
-                hash: previous_hash,
-            }))
-        } else {
-            let certificate = match certificate {
-                CertificateToVerify::Downloaded { certificate } => certificate,
-                CertificateToVerify::ToDownload { hash } => {
-                    self.retriever.get_certificate_details(&hash).await?
-                }
-            };
-
-            let previous_certificate = self
-                .verify_without_cache(certificate_chain_validation_id, certificate)
-                .await?;
-            Ok(previous_certificate.map(Into::into))
-        }
-    }
+        certificate: &MithrilCertificate,
+        observer: Arc<CertificateChainValidationReporter>,
+    ) -> MithrilResult<()> {
+        self.feedback_sender
+            .send_event(MithrilEvent::CertificateChainValidationStarted {
+                certificate_chain_validation_id: observer.certificate_chain_validation_id.clone(),
+            })
+            .await;
 
-    async fn verify_without_cache(
-        &self,
-        certificate_chain_validation_id: &str,
-        certificate: Certificate,
-    ) -> MithrilResult<Option<Certificate>> {
-        let previous_certificate = self
-            .internal_verifier
-            .verify_certificate(&certificate, &self.genesis_verification_key)
+        self.internal_verifier
+            .verify_certificate_chain_with_observer(
+                certificate.clone().try_into()?,
+                &self.genesis_verification_key,
+                observer.clone(),
+            )
             .await?;
 
-        #[cfg(feature = "unstable")]
-        if let Some(cache) = self.verifier_cache.as_ref() {
-            if !certificate.is_genesis() {
-                cache
-                    .store_validated_certificate(&certificate.hash, &certificate.previous_hash)
-                    .await?;
-            }
-        }
-
-        trace!(self.logger, "Certificate validated"; "hash" => &certificate.hash, "previous_hash" => &certificate.previous_hash);
         self.feedback_sender
-            .send_event(MithrilEvent::CertificateValidated {
-                certificate_hash: certificate.hash,
-                certificate_chain_validation_id: certificate_chain_validation_id.to_string(),
+            .send_event(MithrilEvent::CertificateChainValidated {
+                certificate_chain_validation_id: observer.certificate_chain_validation_id.clone(),
             })
             .await;
 
-        Ok(previous_certificate)
+        Ok(())
     }
 }
 
-enum CertificateToVerify {
-    /// The certificate is already downloaded.
-    Downloaded { certificate: Certificate },
-    /// The certificate is not downloaded yet (since its parent was cached).
-    ToDownload { hash: String },
+/// Observer of a certificate chain validation that sends feedbacks, stores the validated
+/// certificates in the cache and can collect them.
+struct CertificateChainValidationReporter {
+    certificate_chain_validation_id: String,
+    feedback_sender: FeedbackSender,
+    #[cfg(feature = "unstable")]
+    verifier_cache: Option<Arc<dyn CertificateVerifierCache>>,
+    validated_certificates: Option<Mutex<Vec<Certificate>>>,
+    logger: Logger,
 }
 
-impl CertificateToVerify {
-    fn hash(&self) -> &str {
-        match self {
-            CertificateToVerify::Downloaded { certificate } => &certificate.hash,
-            CertificateToVerify::ToDownload { hash } => hash,
+impl CertificateChainValidationReporter {
+    fn new(verifier: &MithrilCertificateVerifier) -> Self {
+        Self {
+            certificate_chain_validation_id: MithrilEvent::new_certificate_chain_validation_id(),
+            feedback_sender: verifier.feedback_sender.clone(),
+            #[cfg(feature = "unstable")]
+            verifier_cache: verifier.verifier_cache.clone(),
+            validated_certificates: None,
+            logger: verifier.logger.clone(),
         }
     }
-}
 
-impl From<Certificate> for CertificateToVerify {
-    fn from(value: Certificate) -> Self {
-        Self::Downloaded { certificate: value }
+    /// Collect the validated certificates, the cache being bypassed so that none is skipped.
+    fn collecting_validated_certificates(mut self) -> Self {
+        #[cfg(feature = "unstable")]
+        {
+            self.verifier_cache = None;
+        }
+        self.validated_certificates = Some(Mutex::new(vec![]));
+        self
+    }
+
+    fn take_validated_certificates(&self) -> Vec<Certificate> {
+        self.validated_certificates
+            .as_ref()
+            .map(|certificates| std::mem::take(&mut *certificates.lock().unwrap()))
+            .unwrap_or_default()
     }
 }
 
 #[cfg_attr(target_family = "wasm", async_trait(?Send))]
 #[cfg_attr(not(target_family = "wasm"), async_trait)]
-impl CertificateVerifier for MithrilCertificateVerifier {
-    async fn verify_chain(&self, certificate: &MithrilCertificate) -> MithrilResult<()> {
-        // Todo: move most of this code in the `mithril_common` verifier by defining
-        // a new `verify_chain` method that take a callback called when a certificate is
-        // validated.
-        let certificate_chain_validation_id = MithrilEvent::new_certificate_chain_validation_id();
+impl CertificateChainVerificationObserver for CertificateChainValidationReporter {
+    #[cfg(feature = "unstable")]
+    async fn get_validated_previous_hash(&self, hash: &str) -> StdResult<Option<String>> {
+        if let Some(cache) = self.verifier_cache.as_ref() {
+            Ok(cache.get_previous_hash(hash).await?)
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn on_certificate_skipped(&self, certificate_hash: &str) -> StdResult<()> {
+        trace!(self.logger, "Certificate fetched from cache"; "hash" => certificate_hash);
         self.feedback_sender
-            .send_event(MithrilEvent::CertificateChainValidationStarted {
-                certificate_chain_validation_id: certificate_chain_validation_id.clone(),
+            .send_event(MithrilEvent::CertificateFetchedFromCache {
+                certificate_hash: certificate_hash.to_owned(),
+                certificate_chain_validation_id: self.certificate_chain_validation_id.clone(),
             })
             .await;
 
-        // Validate certificates without cache until we cross an epoch boundary
-        // This is necessary to ensure that the AVK chaining is correct
-        let start_epoch = certificate.epoch;
-        let mut current_certificate: Option<Certificate> = Some(certificate.clone().try_into()?);
-        loop {
-            match current_certificate {
-                None => break,
-                Some(next) => {
-                    current_certificate = self
-                        .verify_without_cache(&certificate_chain_validation_id, next)
-                        .await?;
-
-                    let has_crossed_epoch_boundary = current_certificate
-                        .as_ref()
-                        .is_some_and(|c| c.epoch != start_epoch);
-                    if has_crossed_epoch_boundary {
-                        break;
-                    }
-                }
+        Ok(())
+    }
+
+    async fn on_certificate_validated(&self, certificate: &Certificate) -> StdResult<()> {
+        #[cfg(feature = "unstable")]
+        if let Some(cache) = self.verifier_cache.as_ref() {
+            if !certificate.is_genesis() {
+                cache
+                    .store_validated_certificate(&certificate.hash, &certificate.previous_hash)
+                    .await?;
             }
         }
 
-        let mut current_certificate: Option<CertificateToVerify> =
-            current_certificate.map(Into::into);
-        loop {
-            match current_certificate {
-                None => break,
-                Some(next) => {
-                    current_certificate = self
-                        .verify_with_cache_enabled(&certificate_chain_validation_id, next)
-                        .await?
-                }
-            }
+        if let Some(validated_certificates) = self.validated_certificates.as_ref() {
+            validated_certificates
+                .lock()
+                .unwrap()
+                .push(certificate.clone());
         }
 
+        trace!(self.logger, "Certificate validated"; "hash" => &certificate.hash, "previous_hash" => &certificate.previous_hash);
         self.feedback_sender
-            .send_event(MithrilEvent::CertificateChainValidated {
-                certificate_chain_validation_id,
+            .send_event(MithrilEvent::CertificateValidated {
+                certificate_hash: certificate.hash.clone(),
+                certificate_chain_validation_id: self.certificate_chain_validation_id.clone(),
             })
             .await;
 
@@ -249,8 +263,36 @@ impl CertificateVerifier for MithrilCertificateVerifier {
     }
 }
 
+#[cfg_attr(target_family = "wasm", async_trait(?Send))]
+#[cfg_attr(not(target_family = "wasm"), async_trait)]
+impl CertificateVerifier for MithrilCertificateVerifier {
+    async fn verify_chain_and_collect(
+        &self,
+        certificate: &MithrilCertificate,
+    ) -> MithrilResult<Vec<MithrilCertificate>> {
+        let observer = Arc::new(
+            CertificateChainValidationReporter::new(self).collecting_validated_certificates(),
+        );
+        self.verify_chain_with_observer(certificate, observer.clone())
+            .await?;
+
+        observer
+            .take_validated_certificates()
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect()
+    }
+
+    async fn verify_chain(&self, certificate: &MithrilCertificate) -> MithrilResult<()> {
+        let observer = Arc::new(CertificateChainValidationReporter::new(self));
+        self.verify_chain_with_observer(certificate, observer).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use mithril_common::crypto_helper::{ProtocolGenesisSignatureBundle, ProtocolGenesisSigner};
+    use mithril_common::entities::CertificateSignature;
     use mithril_common::test_utils::CertificateChainBuilder;
 
     use crate::certificate_client::tests_utils::CertificateClientTestBuilder;
@@ -268,7 +310,12 @@ mod tests {
 
         let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
         let certificate_client = CertificateClientTestBuilder::default()
-            .config_aggregator_client_mock(|mock| mock.expect_certificate_chain(chain.clone()))
+            .config_aggregator_client_mock(|mock| {
+                mock.expect_certificate_chain_prefetch(
+                    chain.clone(),
+                    CERTIFICATE_CHAIN_PREFETCH_DEPTH,
+                )
+            })
             .with_genesis_verification_key(verifier.to_verification_key())
             .add_feedback_receiver(feedback_receiver.clone())
             .build();
@@ -311,7 +358,12 @@ mod tests {
         let last_certificate_hash = chain.first().unwrap().hash.clone();
 
         let certificate_client = CertificateClientTestBuilder::default()
-            .config_aggregator_client_mock(|mock| mock.expect_certificate_chain(chain.clone()))
+            .config_aggregator_client_mock(|mock| {
+                mock.expect_certificate_chain_prefetch(
+                    chain.clone(),
+                    CERTIFICATE_CHAIN_PREFETCH_DEPTH,
+                )
+            })
             .with_genesis_verification_key(verifier.to_verification_key())
             .build();
 
@@ -323,6 +375,138 @@ mod tests {
         assert_eq!(certificate.hash, last_certificate_hash);
     }
 
+    #[tokio::test]
+    async fn verify_chain_rooted_in_a_threshold_genesis_certificate() {
+        let (mut chain, verifier) = CertificateChainBuilder::new()
+            .with_total_certificates(5)
+            .with_certificates_per_epoch(2)
+            .build();
+        let genesis_signers: Vec<_> = (0..3)
+            .map(|_| ProtocolGenesisSigner::create_non_deterministic_genesis_signer())
+            .collect();
+        let genesis_verification_key_set = ProtocolGenesisVerificationKeySet::new(
+            2,
+            genesis_signers
+                .iter()
+                .map(|signer| signer.verification_key())
+                .collect(),
+        )
+        .unwrap();
+        let genesis_certificate = chain.last_mut().unwrap();
+        genesis_certificate.signature =
+            CertificateSignature::GenesisThresholdSignature(ProtocolGenesisSignatureBundle::new(
+                genesis_signers[0..2]
+                    .iter()
+                    .map(|signer| signer.sign_share(genesis_certificate.signed_message.as_bytes()))
+                    .collect(),
+            ));
+        genesis_certificate.hash = genesis_certificate.compute_hash();
+        for index in (0..chain.len() - 1).rev() {
+            chain[index].previous_hash = chain[index + 1].hash.clone();
+            chain[index].hash = chain[index].compute_hash();
+        }
+        let last_certificate_hash = chain[0].hash.clone();
+
+        let certificate_client = CertificateClientTestBuilder::default()
+            .config_aggregator_client_mock(|mock| {
+                mock.expect_certificate_chain_prefetch(
+                    chain.clone(),
+                    CERTIFICATE_CHAIN_PREFETCH_DEPTH,
+                )
+            })
+            .with_genesis_verification_key(verifier.to_verification_key())
+            .with_genesis_verification_key_set(genesis_verification_key_set)
+            .build();
+
+        certificate_client
+            .verify_chain(&last_certificate_hash)
+            .await
+            .expect("Chain validation should succeed");
+    }
+
+    #[tokio::test]
+    async fn verify_chain_fails_and_pinpoints_certificate_with_invalid_multi_signature() {
+        let (mut chain, verifier) = CertificateChainBuilder::new()
+            .with_total_certificates(7)
+            .with_certificates_per_epoch(3)
+            .build();
+        assert_eq!(chain[0].epoch, chain[1].epoch);
+        chain[1].signature = chain[0].signature.clone();
+        chain[1].hash = chain[1].compute_hash();
+        chain[0].previous_hash = chain[1].hash.clone();
+        chain[0].hash = chain[0].compute_hash();
+        let last_certificate_hash = chain[0].hash.clone();
+        let invalid_certificate_hash = chain[1].hash.clone();
+
+        let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+        let certificate_client = CertificateClientTestBuilder::default()
+            .config_aggregator_client_mock(|mock| {
+                mock.expect_certificate_chain_prefetch(
+                    chain.clone(),
+                    CERTIFICATE_CHAIN_PREFETCH_DEPTH,
+                )
+            })
+            .with_genesis_verification_key(verifier.to_verification_key())
+            .add_feedback_receiver(feedback_receiver.clone())
+            .build();
+
+        let error = certificate_client
+            .verify_chain(&last_certificate_hash)
+            .await
+            .expect_err("Chain validation should fail");
+
+        assert!(
+            format!("{error:?}").contains(&invalid_certificate_hash),
+            "error should mention the certificate with the invalid multi signature: {error:?}"
+        );
+        let validated_certificates_hashes = feedback_receiver
+            .stacked_events()
+            .into_iter()
+            .filter_map(|event| match event {
+                MithrilEvent::CertificateValidated {
+                    certificate_hash, ..
+                } => Some(certificate_hash),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert!(
+            validated_certificates_hashes.is_empty(),
+            "no certificate of a batch with an invalid multi signature should be reported as validated, got: {validated_certificates_hashes:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn verify_chain_and_collect_return_all_validated_certificates_of_the_chain() {
+        let (chain, verifier) = CertificateChainBuilder::new()
+            .with_total_certificates(5)
+            .with_certificates_per_epoch(2)
+            .build();
+        let last_certificate_hash = chain.first().unwrap().hash.clone();
+
+        let certificate_client = CertificateClientTestBuilder::default()
+            .config_aggregator_client_mock(|mock| {
+                mock.expect_certificate_chain_prefetch(
+                    chain.clone(),
+                    CERTIFICATE_CHAIN_PREFETCH_DEPTH,
+                )
+            })
+            .with_genesis_verification_key(verifier.to_verification_key())
+            .build();
+
+        let certificates = certificate_client
+            .verify_chain_and_collect(&last_certificate_hash)
+            .await
+            .expect("Chain validation should succeed");
+
+        let mut expected: Vec<MithrilCertificate> = vec![];
+        let mut next_hash = last_certificate_hash;
+        while let Some(certificate) = chain.iter().find(|c| c.hash == next_hash) {
+            next_hash = certificate.previous_hash.clone();
+            expected.push(certificate.clone().try_into().unwrap());
+        }
+        assert_eq!(expected, certificates);
+    }
+
     #[cfg(feature = "unstable")]
     mod cache {
         use chrono::TimeDelta;
@@ -372,12 +556,7 @@ mod tests {
             );
 
             verifier
-                .verify_with_cache_enabled(
-                    "certificate_chain_validation_id",
-                    CertificateToVerify::Downloaded {
-                        certificate: genesis_certificate.clone(),
-                    },
-                )
+                .verify_chain(&genesis_certificate.clone().try_into().unwrap())
                 .await
                 .unwrap();
 
@@ -408,12 +587,7 @@ mod tests {
             );
 
             verifier
-                .verify_with_cache_enabled(
-                    "certificate_chain_validation_id",
-                    CertificateToVerify::Downloaded {
-                        certificate: certificate.clone(),
-                    },
-                )
+                .verify_chain(&certificate.clone().try_into().unwrap())
                 .await
                 .unwrap();
 
@@ -0,0 +1,579 @@
+use anyhow::Context;
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use sha2::{Digest, Sha256};
+use slog::{warn, Logger};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::ops::Add;
+use std::path::{Path, PathBuf};
+use tokio::sync::RwLock;
+
+use mithril_common::logging::LoggerExtensions;
+
+use crate::certificate_client::verify_cache::{CertificateHash, PreviousCertificateHash};
+use crate::certificate_client::CertificateVerifierCache;
+use crate::MithrilResult;
+
+/// A file based cache for the certificate verifier.
+///
+/// The cache content is persisted as a JSON lines file so it can be reused by later executions:
+/// each validated certificate is appended to the file, and expired or unreadable entries are
+/// discarded when the file is loaded.
+///
+/// The file is only rewritten through a temporary file atomically moved over it, an entry
+/// appended by a concurrent client while the file is compacted may be lost, which only means
+/// that its certificate will be validated again.
+///
+/// Entries are scoped by the aggregator endpoint and the genesis verification key used to
+/// validate them, so a file shared by clients targeting different aggregators or genesis keys
+/// never lets a certificate validated by one of them be trusted by another.
+pub struct FileCertificateVerifierCache {
+    file_path: PathBuf,
+    scope: String,
+    expiration_delay: TimeDelta,
+    cache: RwLock<HashMap<String, CachedCertificate>>,
+    logger: Logger,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct CachedCertificate {
+    previous_hash: String,
+    expire_at: DateTime<Utc>,
+}
+
+impl CachedCertificate {
+    fn new<TPreviousHash: Into<String>>(
+        previous_hash: TPreviousHash,
+        expire_at: DateTime<Utc>,
+    ) -> Self {
+        CachedCertificate {
+            previous_hash: previous_hash.into(),
+            expire_at,
+        }
+    }
+}
+
+/// A line of the cache file
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+struct CacheFileEntry {
+    scope: String,
+    hash: String,
+    previous_hash: String,
+    expire_at: DateTime<Utc>,
+}
+
+impl FileCertificateVerifierCache {
+    /// `FileCertificateVerifierCache` factory
+    ///
+    /// The cache is loaded from the given file if it exists, else it starts empty and the file
+    /// will be created on the first write.
+    ///
+    /// Only the entries stored for the same `aggregator_endpoint` and `genesis_verification_key`
+    /// are available to the cache.
+    pub fn new(
+        file_path: &Path,
+        expiration_delay: TimeDelta,
+        aggregator_endpoint: &str,
+        genesis_verification_key: &str,
+        logger: Logger,
+    ) -> MithrilResult<Self> {
+        let logger = logger.new_with_component_name::<Self>();
+        let scope = Self::compute_scope(aggregator_endpoint, genesis_verification_key);
+        let cache = if file_path.exists() {
+            Self::load(file_path, &logger)?
+                .into_iter()
+                .filter(|entry| entry.scope == scope)
+                .map(|entry| {
+                    (
+                        entry.hash,
+                        CachedCertificate::new(entry.previous_hash, entry.expire_at),
+                    )
+                })
+                .collect()
+        } else {
+            HashMap::new()
+        };
+
+        Ok(FileCertificateVerifierCache {
+            file_path: file_path.to_path_buf(),
+            scope,
+            expiration_delay,
+            cache: RwLock::new(cache),
+            logger,
+        })
+    }
+
+    /// Get the number of elements in the cache
+    pub async fn len(&self) -> usize {
+        self.cache.read().await.len()
+    }
+
+    /// Return true if the cache is empty
+    pub async fn is_empty(&self) -> bool {
+        self.cache.read().await.is_empty()
+    }
+
+    fn compute_scope(aggregator_endpoint: &str, genesis_verification_key: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(aggregator_endpoint.as_bytes());
+        hasher.update([0]);
+        hasher.update(genesis_verification_key.as_bytes());
+
+        hex::encode(hasher.finalize())
+    }
+
+    /// Read the not expired entries of the file, compacting it if some entries expired or
+    /// could not be read.
+    fn load(file_path: &Path, logger: &Logger) -> MithrilResult<Vec<CacheFileEntry>> {
+        let (entries, total_skipped_lines) = Self::read_entries(file_path, logger)?;
+        let total_entries = entries.len();
+        let now = Utc::now();
+        let entries: Vec<CacheFileEntry> = entries
+            .into_iter()
+            .filter(|entry| entry.expire_at >= now)
+            .collect();
+
+        if entries.len() != total_entries || total_skipped_lines > 0 {
+            Self::rewrite(file_path, &entries)?;
+        }
+
+        Ok(entries)
+    }
+
+    /// Read the entries of the file, returning them with the number of lines that could not
+    /// be deserialized.
+    ///
+    /// An invalid line, such as a line truncated by a client that crashed while appending it, is
+    /// skipped so a corrupted file never prevents the cache from being used.
+    fn read_entries(
+        file_path: &Path,
+        logger: &Logger,
+    ) -> MithrilResult<(Vec<CacheFileEntry>, usize)> {
+        let file = File::open(file_path).with_context(|| {
+            format!(
+                "Could not read certificate verifier cache file: '{}'",
+                file_path.display()
+            )
+        })?;
+
+        let mut entries = vec![];
+        let mut total_skipped_lines = 0;
+        for line in BufReader::new(file).lines() {
+            let line = line.with_context(|| {
+                format!(
+                    "Could not read certificate verifier cache file: '{}'",
+                    file_path.display()
+                )
+            })?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str::<CacheFileEntry>(&line) {
+                Ok(entry) => entries.push(entry),
+                Err(error) => {
+                    warn!(
+                        logger, "Skipping invalid line of certificate verifier cache file";
+                        "file" => file_path.display(), "error" => ?error
+                    );
+                    total_skipped_lines += 1;
+                }
+            }
+        }
+
+        Ok((entries, total_skipped_lines))
+    }
+
+    /// Replace the content of the file with the given entries.
+    ///
+    /// The entries are written to a uniquely named temporary file that is then moved over the
+    /// cache file, so a concurrent reader never sees a partial file.
+    fn rewrite(file_path: &Path, entries: &[CacheFileEntry]) -> MithrilResult<()> {
+        let parent_dir = Self::create_parent_dir(file_path)?;
+        let mut temp_file = tempfile::NamedTempFile::new_in(parent_dir).with_context(|| {
+            format!(
+                "Could not create certificate verifier cache temporary file in: '{}'",
+                parent_dir.display()
+            )
+        })?;
+        for entry in entries {
+            Self::write_entry(temp_file.as_file_mut(), entry)?;
+        }
+        temp_file.persist(file_path).with_context(|| {
+            format!(
+                "Could not write certificate verifier cache file: '{}'",
+                file_path.display()
+            )
+        })?;
+
+        Ok(())
+    }
+
+    fn append(file_path: &Path, entry: &CacheFileEntry) -> MithrilResult<()> {
+        Self::create_parent_dir(file_path)?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(file_path)
+            .with_context(|| {
+                format!(
+                    "Could not open certificate verifier cache file: '{}'",
+                    file_path.display()
+                )
+            })?;
+
+        Self::write_entry(&mut file, entry).with_context(|| {
+            format!(
+                "Could not write certificate verifier cache file: '{}'",
+                file_path.display()
+            )
+        })
+    }
+
+    /// Remove the entries of the given scope from the file, keeping the entries of the other
+    /// scopes.
+    fn remove_scope_entries(file_path: &Path, scope: &str, logger: &Logger) -> MithrilResult<()> {
+        if file_path.exists() {
+            let (entries, _) = Self::read_entries(file_path, logger)?;
+            let other_scopes_entries: Vec<CacheFileEntry> = entries
+                .into_iter()
+                .filter(|entry| entry.scope != scope)
+                .collect();
+            Self::rewrite(file_path, &other_scopes_entries)?;
+        }
+
+        Ok(())
+    }
+
+    /// Run a blocking file operation on a thread where blocking is acceptable.
+    async fn run_file_operation<F>(operation: F) -> MithrilResult<()>
+    where
+        F: FnOnce() -> MithrilResult<()> + Send + 'static,
+    {
+        tokio::task::spawn_blocking(operation)
+            .await
+            .with_context(|| "Certificate verifier cache file operation crashed")?
+    }
+
+    fn write_entry(file: &mut File, entry: &CacheFileEntry) -> MithrilResult<()> {
+        let mut line = serde_json::to_string(entry)
+            .with_context(|| "Could not serialize certificate verifier cache entry")?;
+        line.push('\n');
+        // A single write of the whole line so concurrent appends are not interleaved
+        file.write_all(line.as_bytes())?;
+
+        Ok(())
+    }
+
+    fn create_parent_dir(file_path: &Path) -> MithrilResult<&Path> {
+        let parent_dir = file_path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        std::fs::create_dir_all(parent_dir).with_context(|| {
+            format!(
+                "Could not create certificate verifier cache directory: '{}'",
+                parent_dir.display()
+            )
+        })?;
+
+        Ok(parent_dir)
+    }
+}
+
+#[cfg_attr(target_family = "wasm", async_trait(?Send))]
+#[cfg_attr(not(target_family = "wasm"), async_trait)]
+impl CertificateVerifierCache for FileCertificateVerifierCache {
+    async fn store_validated_certificate(
+        &self,
+        certificate_hash: &CertificateHash,
+        previous_certificate_hash: &PreviousCertificateHash,
+    ) -> MithrilResult<()> {
+        let mut cache = self.cache.write().await;
+        let expire_at = Utc::now().add(self.expiration_delay);
+        let file_path = self.file_path.clone();
+        let entry = CacheFileEntry {
+            scope: self.scope.clone(),
+            hash: certificate_hash.to_string(),
+            previous_hash: previous_certificate_hash.to_string(),
+            expire_at,
+        };
+        Self::run_file_operation(move || Self::append(&file_path, &entry)).await?;
+        cache.insert(
+            certificate_hash.to_string(),
+            CachedCertificate::new(previous_certificate_hash, expire_at),
+        );
+
+        Ok(())
+    }
+
+    async fn get_previous_hash(
+        &self,
+        certificate_hash: &CertificateHash,
+    ) -> MithrilResult<Option<String>> {
+        let cache = self.cache.read().await;
+        Ok(cache
+            .get(certificate_hash)
+            .filter(|cached| cached.expire_at >= Utc::now())
+            .map(|cached| cached.previous_hash.clone()))
+    }
+
+    async fn reset(&self) -> MithrilResult<()> {
+        let mut cache = self.cache.write().await;
+        let file_path = self.file_path.clone();
+        let scope = self.scope.clone();
+        let logger = self.logger.clone();
+        Self::run_file_operation(move || Self::remove_scope_entries(&file_path, &scope, &logger))
+            .await?;
+        cache.clear();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mithril_common::test_utils::TempDir;
+
+    use crate::test_utils::test_logger;
+
+    use super::*;
+
+    const AGGREGATOR_ENDPOINT: &str = "http://aggregator.test";
+    const GENESIS_VERIFICATION_KEY: &str = "genesis-verification-key";
+
+    fn cache_file_path(test_name: &str) -> PathBuf {
+        TempDir::create("file_certificate_verifier_cache", test_name).join("cache.json")
+    }
+
+    fn build_cache(file_path: &Path, expiration_delay: TimeDelta) -> FileCertificateVerifierCache {
+        FileCertificateVerifierCache::new(
+            file_path,
+            expiration_delay,
+            AGGREGATOR_ENDPOINT,
+            GENESIS_VERIFICATION_KEY,
+            test_logger(),
+        )
+        .unwrap()
+    }
+
+    fn write_cache_file(file_path: &Path, entries: &[(&str, &str, DateTime<Utc>)]) {
+        let scope = FileCertificateVerifierCache::compute_scope(
+            AGGREGATOR_ENDPOINT,
+            GENESIS_VERIFICATION_KEY,
+        );
+        let content: String = entries
+            .iter()
+            .map(|(hash, previous_hash, expire_at)| {
+                let entry = CacheFileEntry {
+                    scope: scope.clone(),
+                    hash: hash.to_string(),
+                    previous_hash: previous_hash.to_string(),
+                    expire_at: *expire_at,
+                };
+                format!("{}\n", serde_json::to_string(&entry).unwrap())
+            })
+            .collect();
+        std::fs::write(file_path, content).unwrap();
+    }
+
+    fn count_file_lines(file_path: &Path) -> usize {
+        std::fs::read_to_string(file_path).unwrap().lines().count()
+    }
+
+    #[tokio::test]
+    async fn new_cache_is_empty_if_the_file_does_not_exist() {
+        let file_path = cache_file_path("new_cache_is_empty_if_the_file_does_not_exist");
+
+        let cache = build_cache(&file_path, TimeDelta::hours(1));
+
+        assert!(cache.is_empty().await);
+        assert!(!file_path.exists());
+    }
+
+    #[tokio::test]
+    async fn new_cache_loads_the_not_expired_entries_of_the_file_and_compact_it() {
+        let file_path =
+            cache_file_path("new_cache_loads_the_not_expired_entries_of_the_file_and_compact_it");
+        write_cache_file(
+            &file_path,
+            &[
+                ("hash", "parent", Utc::now() + TimeDelta::hours(1)),
+                ("expired_hash", "parent", Utc::now() - TimeDelta::hours(1)),
+            ],
+        );
+
+        let cache = build_cache(&file_path, TimeDelta::hours(1));
+
+        assert_eq!(1, cache.len().await);
+        assert_eq!(
+            Some("parent".to_string()),
+            cache.get_previous_hash("hash").await.unwrap()
+        );
+        assert_eq!(None, cache.get_previous_hash("expired_hash").await.unwrap());
+        assert_eq!(1, count_file_lines(&file_path));
+    }
+
+    #[tokio::test]
+    async fn new_cache_skips_the_corrupted_lines_of_the_file_and_compact_it() {
+        let file_path =
+            cache_file_path("new_cache_skips_the_corrupted_lines_of_the_file_and_compact_it");
+        write_cache_file(
+            &file_path,
+            &[("hash", "parent", Utc::now() + TimeDelta::hours(1))],
+        );
+        let mut file = OpenOptions::new().append(true).open(&file_path).unwrap();
+        file.write_all(b"not a json\n{\"scope\":\"truncated")
+            .unwrap();
+
+        let cache = build_cache(&file_path, TimeDelta::hours(1));
+
+        assert_eq!(1, cache.len().await);
+        assert_eq!(
+            Some("parent".to_string()),
+            cache.get_previous_hash("hash").await.unwrap()
+        );
+        assert_eq!(1, count_file_lines(&file_path));
+    }
+
+    #[tokio::test]
+    async fn stored_certificates_are_persisted_and_available_to_a_new_cache() {
+        let file_path =
+            cache_file_path("stored_certificates_are_persisted_and_available_to_a_new_cache");
+        let cache = build_cache(&file_path, TimeDelta::hours(1));
+        cache
+            .store_validated_certificate("hash", "parent")
+            .await
+            .unwrap();
+        cache
+            .store_validated_certificate("another_hash", "another_parent")
+            .await
+            .unwrap();
+
+        let reloaded_cache = build_cache(&file_path, TimeDelta::hours(1));
+
+        assert_eq!(2, reloaded_cache.len().await);
+        assert_eq!(
+            Some("parent".to_string()),
+            reloaded_cache.get_previous_hash("hash").await.unwrap()
+        );
+        assert_eq!(
+            Some("another_parent".to_string()),
+            reloaded_cache
+                .get_previous_hash("another_hash")
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn store_append_to_the_file_without_losing_the_entries_of_concurrent_caches() {
+        let file_path = cache_file_path(
+            "store_append_to_the_file_without_losing_the_entries_of_concurrent_caches",
+        );
+        let cache = build_cache(&file_path, TimeDelta::hours(1));
+        let concurrent_cache = build_cache(&file_path, TimeDelta::hours(1));
+
+        cache
+            .store_validated_certificate("hash", "parent")
+            .await
+            .unwrap();
+        concurrent_cache
+            .store_validated_certificate("another_hash", "another_parent")
+            .await
+            .unwrap();
+
+        assert_eq!(2, count_file_lines(&file_path));
+        let reloaded_cache = build_cache(&file_path, TimeDelta::hours(1));
+        assert_eq!(2, reloaded_cache.len().await);
+    }
+
+    #[tokio::test]
+    async fn entries_are_only_available_to_caches_with_the_same_aggregator_and_genesis_key() {
+        let file_path = cache_file_path(
+            "entries_are_only_available_to_caches_with_the_same_aggregator_and_genesis_key",
+        );
+        let cache = build_cache(&file_path, TimeDelta::hours(1));
+        cache
+            .store_validated_certificate("hash", "parent")
+            .await
+            .unwrap();
+
+        let other_aggregator_cache = FileCertificateVerifierCache::new(
+            &file_path,
+            TimeDelta::hours(1),
+            "http://another-aggregator.test",
+            GENESIS_VERIFICATION_KEY,
+            test_logger(),
+        )
+        .unwrap();
+        let other_genesis_key_cache = FileCertificateVerifierCache::new(
+            &file_path,
+            TimeDelta::hours(1),
+            AGGREGATOR_ENDPOINT,
+            "another-genesis-verification-key",
+            test_logger(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            None,
+            other_aggregator_cache
+                .get_previous_hash("hash")
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            None,
+            other_genesis_key_cache
+                .get_previous_hash("hash")
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn get_previous_hash_return_none_if_not_found_or_expired() {
+        let file_path = cache_file_path("get_previous_hash_return_none_if_not_found_or_expired");
+        let cache = build_cache(&file_path, TimeDelta::hours(-1));
+        cache
+            .store_validated_certificate("hash", "parent")
+            .await
+            .unwrap();
+
+        assert_eq!(None, cache.get_previous_hash("hash").await.unwrap());
+        assert_eq!(None, cache.get_previous_hash("not_found").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn reset_clears_the_persisted_cache_but_keep_the_entries_of_other_scopes() {
+        let file_path = cache_file_path(
+            "reset_clears_the_persisted_cache_but_keep_the_entries_of_other_scopes",
+        );
+        let cache = build_cache(&file_path, TimeDelta::hours(1));
+        cache
+            .store_validated_certificate("hash", "parent")
+            .await
+            .unwrap();
+        let other_scope_cache = FileCertificateVerifierCache::new(
+            &file_path,
+            TimeDelta::hours(1),
+            "http://another-aggregator.test",
+            GENESIS_VERIFICATION_KEY,
+            test_logger(),
+        )
+        .unwrap();
+        other_scope_cache
+            .store_validated_certificate("other_hash", "other_parent")
+            .await
+            .unwrap();
+
+        cache.reset().await.unwrap();
+
+        let reloaded_cache = build_cache(&file_path, TimeDelta::hours(1));
+        assert!(cache.is_empty().await);
+        assert!(reloaded_cache.is_empty().await);
+        assert_eq!(1, count_file_lines(&file_path));
+    }
+}
@@ -3,17 +3,22 @@ use reqwest::Url;
 use serde::{Deserialize, Serialize};
 use slog::{o, Logger};
 use std::collections::HashMap;
+#[cfg(feature = "fs")]
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use mithril_common::api_version::APIVersionProvider;
 
 use crate::aggregator_client::{AggregatorClient, AggregatorHTTPClient};
+use crate::cardano_block_client::CardanoBlockClient;
 #[cfg(feature = "unstable")]
 use crate::cardano_database_client::CardanoDatabaseClient;
 use crate::cardano_stake_distribution_client::CardanoStakeDistributionClient;
 use crate::cardano_transaction_client::CardanoTransactionClient;
 #[cfg(feature = "unstable")]
 use crate::certificate_client::CertificateVerifierCache;
+#[cfg(all(feature = "fs", feature = "unstable"))]
+use crate::certificate_client::FileCertificateVerifierCache;
 use crate::certificate_client::{
     CertificateClient, CertificateVerifier, MithrilCertificateVerifier,
 };
@@ -58,6 +63,12 @@ pub struct ClientOptions {
     #[cfg(target_family = "wasm")]
     #[cfg_attr(target_family = "wasm", serde(default = "one_week_in_seconds"))]
     pub certificate_chain_verification_cache_duration_in_seconds: u32,
+
+    /// Genesis verification key set, encoded as json hex, used by the WASM client to verify
+    /// the certificate chains rooted in a threshold genesis certificate.
+    #[cfg(target_family = "wasm")]
+    #[cfg_attr(target_family = "wasm", serde(default))]
+    pub genesis_verification_key_set: Option<String>,
 }
 
 impl ClientOptions {
@@ -71,6 +82,8 @@ impl ClientOptions {
             enable_certificate_chain_verification_cache: false,
             #[cfg(target_family = "wasm")]
             certificate_chain_verification_cache_duration_in_seconds: one_week_in_seconds(),
+            #[cfg(target_family = "wasm")]
+            genesis_verification_key_set: None,
         }
     }
 
@@ -92,6 +105,7 @@ pub struct Client {
     #[cfg(feature = "unstable")]
     cardano_database_client: Arc<CardanoDatabaseClient>,
     cardano_transaction_client: Arc<CardanoTransactionClient>,
+    cardano_block_client: Arc<CardanoBlockClient>,
     cardano_stake_distribution_client: Arc<CardanoStakeDistributionClient>,
 }
 
@@ -122,6 +136,11 @@ impl Client {
         self.cardano_transaction_client.clone()
     }
 
+    /// Get the client that fetches and verifies Mithril Cardano block proof.
+    pub fn cardano_block(&self) -> Arc<CardanoBlockClient> {
+        self.cardano_block_client.clone()
+    }
+
     /// Get the client that fetches Cardano stake distributions.
     pub fn cardano_stake_distribution(&self) -> Arc<CardanoStakeDistributionClient> {
         self.cardano_stake_distribution_client.clone()
@@ -132,12 +151,17 @@ impl Client {
 pub struct ClientBuilder {
     aggregator_endpoint: Option<String>,
     genesis_verification_key: String,
+    genesis_verification_key_set: Option<String>,
     aggregator_client: Option<Arc<dyn AggregatorClient>>,
     certificate_verifier: Option<Arc<dyn CertificateVerifier>>,
     #[cfg(feature = "unstable")]
     certificate_verifier_cache: Option<Arc<dyn CertificateVerifierCache>>,
+    #[cfg(all(feature = "fs", feature = "unstable"))]
+    certificate_verifier_cache_file: Option<(PathBuf, chrono::TimeDelta)>,
     #[cfg(feature = "fs")]
     snapshot_downloader: Option<Arc<dyn SnapshotDownloader>>,
+    #[cfg(feature = "fs")]
+    snapshot_partial_downloads_dir: Option<PathBuf>,
     logger: Option<Logger>,
     feedback_receivers: Vec<Arc<dyn FeedbackReceiver>>,
     options: ClientOptions,
@@ -150,12 +174,17 @@ impl ClientBuilder {
         Self {
             aggregator_endpoint: Some(endpoint.to_string()),
             genesis_verification_key: genesis_verification_key.to_string(),
+            genesis_verification_key_set: None,
             aggregator_client: None,
             certificate_verifier: None,
             #[cfg(feature = "unstable")]
             certificate_verifier_cache: None,
+            #[cfg(all(feature = "fs", feature = "unstable"))]
+            certificate_verifier_cache_file: None,
             #[cfg(feature = "fs")]
             snapshot_downloader: None,
+            #[cfg(feature = "fs")]
+            snapshot_partial_downloads_dir: None,
             logger: None,
             feedback_receivers: vec![],
             options: ClientOptions::default(),
@@ -170,12 +199,17 @@ impl ClientBuilder {
         Self {
             aggregator_endpoint: None,
             genesis_verification_key: genesis_verification_key.to_string(),
+            genesis_verification_key_set: None,
             aggregator_client: None,
             certificate_verifier: None,
             #[cfg(feature = "unstable")]
             certificate_verifier_cache: None,
+            #[cfg(all(feature = "fs", feature = "unstable"))]
+            certificate_verifier_cache_file: None,
             #[cfg(feature = "fs")]
             snapshot_downloader: None,
+            #[cfg(feature = "fs")]
+            snapshot_partial_downloads_dir: None,
             logger: None,
             feedback_receivers: vec![],
             options: ClientOptions::default(),
@@ -193,6 +227,24 @@ impl ClientBuilder {
 
         let feedback_sender = FeedbackSender::new(&self.feedback_receivers);
 
+        #[cfg(all(feature = "fs", feature = "unstable"))]
+        let certificate_verifier_cache = match self.certificate_verifier_cache_file {
+            Some((file_path, expiration_delay)) => Some(Arc::new(
+                FileCertificateVerifierCache::new(
+                    &file_path,
+                    expiration_delay,
+                    self.aggregator_endpoint.as_deref().unwrap_or_default(),
+                    &self.genesis_verification_key,
+                    logger.clone(),
+                )
+                .with_context(|| "Building certificate verifier cache failed")?,
+            )
+                as Arc<dyn CertificateVerifierCache>),
+            None => self.certificate_verifier_cache,
+        };
+        #[cfg(all(not(feature = "fs"), feature = "unstable"))]
+        let certificate_verifier_cache = self.certificate_verifier_cache;
+
         let aggregator_client = match self.aggregator_client {
             None => {
                 let endpoint = self
@@ -217,17 +269,25 @@ impl ClientBuilder {
         };
 
         let certificate_verifier = match self.certificate_verifier {
-            None => Arc::new(
-                MithrilCertificateVerifier::new(
+            None => {
+                let verifier = MithrilCertificateVerifier::new(
                     aggregator_client.clone(),
                     &self.genesis_verification_key,
                     feedback_sender.clone(),
                     #[cfg(feature = "unstable")]
-                    self.certificate_verifier_cache,
+                    certificate_verifier_cache,
                     logger.clone(),
                 )
-                .with_context(|| "Building certificate verifier failed")?,
-            ),
+                .with_context(|| "Building certificate verifier failed")?;
+                let verifier = match &self.genesis_verification_key_set {
+                    None => verifier,
+                    Some(genesis_verification_key_set) => verifier
+                        .with_genesis_verification_key_set(genesis_verification_key_set)
+                        .with_context(|| "Building certificate verifier failed")?,
+                };
+
+                Arc::new(verifier)
+            }
             Some(verifier) => verifier,
         };
         let certificate_client = Arc::new(CertificateClient::new(
@@ -242,14 +302,32 @@ impl ClientBuilder {
 
         #[cfg(feature = "fs")]
         let snapshot_downloader = match self.snapshot_downloader {
-            None => Arc::new(
-                HttpSnapshotDownloader::new(feedback_sender.clone(), logger.clone())
-                    .with_context(|| "Building snapshot downloader failed")?,
-            ),
+            None => {
+                let http_snapshot_downloader =
+                    HttpSnapshotDownloader::new(feedback_sender.clone(), logger.clone())
+                        .with_context(|| "Building snapshot downloader failed")?;
+                Arc::new(match &self.snapshot_partial_downloads_dir {
+                    Some(partial_downloads_dir) => {
+                        http_snapshot_downloader.with_resumable_downloads(partial_downloads_dir)
+                    }
+                    None => http_snapshot_downloader,
+                })
+            }
             Some(snapshot_downloader) => snapshot_downloader,
         };
 
         let snapshot_client = Arc::new(SnapshotClient::new(
+            aggregator_client.clone(),
+            #[cfg(feature = "fs")]
+            snapshot_downloader.clone(),
+            #[cfg(feature = "fs")]
+            feedback_sender.clone(),
+            #[cfg(feature = "fs")]
+            logger.clone(),
+        ));
+
+        #[cfg(feature = "unstable")]
+        let cardano_database_client = Arc::new(CardanoDatabaseClient::new(
             aggregator_client.clone(),
             #[cfg(feature = "fs")]
             snapshot_downloader,
@@ -259,13 +337,11 @@ impl ClientBuilder {
             logger,
         ));
 
-        #[cfg(feature = "unstable")]
-        let cardano_database_client =
-            Arc::new(CardanoDatabaseClient::new(aggregator_client.clone()));
-
         let cardano_transaction_client =
             Arc::new(CardanoTransactionClient::new(aggregator_client.clone()));
 
+        let cardano_block_client = Arc::new(CardanoBlockClient::new(aggregator_client.clone()));
+
         let cardano_stake_distribution_client =
             Arc::new(CardanoStakeDistributionClient::new(aggregator_client));
 
@@ -276,6 +352,7 @@ impl ClientBuilder {
             #[cfg(feature = "unstable")]
             cardano_database_client,
             cardano_transaction_client,
+            cardano_block_client,
             cardano_stake_distribution_client,
         })
     }
@@ -289,6 +366,18 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the genesis verification key set, encoded as json hex, used to verify the certificate
+    /// chains rooted in a threshold genesis certificate.
+    ///
+    /// It is not used if a custom [CertificateVerifier] is set.
+    pub fn with_genesis_verification_key_set(
+        mut self,
+        genesis_verification_key_set: &str,
+    ) -> ClientBuilder {
+        self.genesis_verification_key_set = Some(genesis_verification_key_set.to_string());
+        self
+    }
+
     /// Set the [CertificateVerifier] that will be used to validate certificates.
     pub fn with_certificate_verifier(
         mut self,
@@ -307,10 +396,30 @@ impl ClientBuilder {
         certificate_verifier_cache: Option<Arc<dyn CertificateVerifierCache>>,
     ) -> ClientBuilder {
         self.certificate_verifier_cache = certificate_verifier_cache;
+        #[cfg(feature = "fs")]
+        {
+            self.certificate_verifier_cache_file = None;
+        }
         self
     }
     }
 
+    /// Cache the certificate validation results in the given file, so they can be reused by later
+    /// clients, each validation result expiring after the given delay.
+    ///
+    /// This replaces any [CertificateVerifierCache] previously set.
+    #[cfg(all(feature = "fs", feature = "unstable"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "fs", feature = "unstable"))))]
+    pub fn with_certificate_verifier_cache_file(
+        mut self,
+        file_path: &Path,
+        expiration_delay: chrono::TimeDelta,
+    ) -> ClientBuilder {
+        self.certificate_verifier_cache = None;
+        self.certificate_verifier_cache_file = Some((file_path.to_path_buf(), expiration_delay));
+        self
+    }
+
     cfg_fs! {
     /// Set the [SnapshotDownloader] that will be used to download snapshots.
     pub fn with_snapshot_downloader(
@@ -320,6 +429,15 @@ impl ClientBuilder {
         self.snapshot_downloader = Some(snapshot_downloader);
         self
     }
+
+    /// Persist the snapshot archives in the given directory while they are downloaded, so an
+    /// interrupted download can be resumed from where it stopped.
+    ///
+    /// This has no effect if a custom [SnapshotDownloader] is set.
+    pub fn with_resumable_snapshot_downloads(mut self, partial_downloads_dir: &Path) -> ClientBuilder {
+        self.snapshot_partial_downloads_dir = Some(partial_downloads_dir.to_path_buf());
+        self
+    }
     }
 
     /// Set the [Logger] to use.
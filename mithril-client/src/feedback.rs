@@ -53,11 +53,13 @@
 
 use async_trait::async_trait;
 use serde::Serialize;
-use slog::{info, Logger};
+use slog::{info, warn, Logger};
 use std::sync::{Arc, RwLock};
 use strum::Display;
 use uuid::Uuid;
 
+use crate::common::ImmutableFileNumber;
+
 /// Event that can be reported by a [FeedbackReceiver].
 #[derive(Debug, Clone, Eq, PartialEq, Display, Serialize)]
 #[strum(serialize_all = "PascalCase")]
@@ -81,11 +83,71 @@ pub enum MithrilEvent {
         /// Size of the downloaded archive
         size: u64,
     },
+    /// A snapshot download has been resumed from a partially downloaded archive
+    SnapshotDownloadResumed {
+        /// Unique identifier used to track this specific snapshot download
+        download_id: String,
+        /// Number of bytes that were already downloaded
+        resumed_offset: u64,
+        /// Size of the downloaded archive
+        size: u64,
+    },
     /// A snapshot download has completed
     SnapshotDownloadCompleted {
         /// Unique identifier used to track this specific snapshot download
         download_id: String,
     },
+    /// A snapshot download switched to the next location after a failure of the current one
+    SnapshotDownloadLocationSwitched {
+        /// Unique identifier used to track this specific snapshot download
+        download_id: String,
+        /// Location that is unreachable or from which the download failed
+        failed_location: String,
+        /// Location from which the download is retried
+        next_location: String,
+        /// Reason of the failure of the previous location
+        reason: String,
+    },
+    /// A Cardano database download has started
+    CardanoDatabaseDownloadStarted {
+        /// Hash of the downloaded Cardano database snapshot
+        hash: String,
+        /// Unique identifier used to track this specific Cardano database download
+        download_id: String,
+        /// Number of immutable files to download
+        total_immutable_files: u64,
+        /// Whether the ancillary files are downloaded
+        include_ancillary: bool,
+    },
+    /// An immutable file archive of a Cardano database has been downloaded and unpacked
+    CardanoDatabaseImmutableDownloadCompleted {
+        /// Unique identifier used to track this specific Cardano database download
+        download_id: String,
+        /// Number of the downloaded immutable file
+        immutable_file_number: ImmutableFileNumber,
+    },
+    /// The ancillary archive of a Cardano database has been downloaded and unpacked
+    CardanoDatabaseAncillaryDownloadCompleted {
+        /// Unique identifier used to track this specific Cardano database download
+        download_id: String,
+    },
+    /// A Cardano database download has completed
+    CardanoDatabaseDownloadCompleted {
+        /// Unique identifier used to track this specific Cardano database download
+        download_id: String,
+    },
+    /// A file download of a Cardano database switched to the next location after a failure of
+    /// the current one
+    CardanoDatabaseDownloadLocationSwitched {
+        /// Unique identifier used to track this specific Cardano database download
+        download_id: String,
+        /// Location that is unreachable or from which the download failed
+        failed_location: String,
+        /// Location from which the download is retried
+        next_location: String,
+        /// Reason of the failure of the previous location
+        reason: String,
+    },
     /// A certificate chain validation has started
     CertificateChainValidationStarted {
         /// Unique identifier used to track this specific certificate chain validation
@@ -118,6 +180,11 @@ impl MithrilEvent {
         Uuid::new_v4().to_string()
     }
 
+    /// Generate a random unique identifier to identify a Cardano database download
+    pub fn new_cardano_database_download_id() -> String {
+        Uuid::new_v4().to_string()
+    }
+
     /// Generate a random unique identifier to identify a certificate chain validation
     pub fn new_certificate_chain_validation_id() -> String {
         Uuid::new_v4().to_string()
@@ -128,7 +195,18 @@ impl MithrilEvent {
         match self {
             MithrilEvent::SnapshotDownloadStarted { download_id, .. } => download_id,
             MithrilEvent::SnapshotDownloadProgress { download_id, .. } => download_id,
+            MithrilEvent::SnapshotDownloadResumed { download_id, .. } => download_id,
             MithrilEvent::SnapshotDownloadCompleted { download_id } => download_id,
+            MithrilEvent::SnapshotDownloadLocationSwitched { download_id, .. } => download_id,
+            MithrilEvent::CardanoDatabaseDownloadStarted { download_id, .. } => download_id,
+            MithrilEvent::CardanoDatabaseImmutableDownloadCompleted { download_id, .. } => {
+                download_id
+            }
+            MithrilEvent::CardanoDatabaseAncillaryDownloadCompleted { download_id } => download_id,
+            MithrilEvent::CardanoDatabaseDownloadCompleted { download_id } => download_id,
+            MithrilEvent::CardanoDatabaseDownloadLocationSwitched { download_id, .. } => {
+                download_id
+            }
             MithrilEvent::CertificateChainValidationStarted {
                 certificate_chain_validation_id,
             } => certificate_chain_validation_id,
@@ -216,9 +294,70 @@ impl FeedbackReceiver for SlogFeedbackReceiver {
                     "downloaded_bytes" => downloaded_bytes, "size" => size, "download_id" => download_id,
                 );
             }
+            MithrilEvent::SnapshotDownloadResumed {
+                download_id,
+                resumed_offset,
+                size,
+            } => {
+                info!(
+                    self.logger, "Snapshot download resumed";
+                    "resumed_offset" => resumed_offset, "size" => size, "download_id" => download_id,
+                );
+            }
             MithrilEvent::SnapshotDownloadCompleted { download_id } => {
                 info!(self.logger, "Snapshot download completed"; "download_id" => download_id);
             }
+            MithrilEvent::SnapshotDownloadLocationSwitched {
+                download_id,
+                failed_location,
+                next_location,
+                reason,
+            } => {
+                warn!(
+                    self.logger, "Snapshot download switched to the next location";
+                    "failed_location" => failed_location, "next_location" => next_location,
+                    "reason" => reason, "download_id" => download_id,
+                );
+            }
+            MithrilEvent::CardanoDatabaseDownloadStarted {
+                hash,
+                download_id,
+                total_immutable_files,
+                include_ancillary,
+            } => {
+                info!(
+                    self.logger, "Cardano database download started";
+                    "hash" => hash, "download_id" => download_id,
+                    "total_immutable_files" => total_immutable_files, "include_ancillary" => include_ancillary,
+                );
+            }
+            MithrilEvent::CardanoDatabaseImmutableDownloadCompleted {
+                download_id,
+                immutable_file_number,
+            } => {
+                info!(
+                    self.logger, "Cardano database immutable file downloaded";
+                    "immutable_file_number" => immutable_file_number, "download_id" => download_id,
+                );
+            }
+            MithrilEvent::CardanoDatabaseAncillaryDownloadCompleted { download_id } => {
+                info!(self.logger, "Cardano database ancillary files downloaded"; "download_id" => download_id);
+            }
+            MithrilEvent::CardanoDatabaseDownloadCompleted { download_id } => {
+                info!(self.logger, "Cardano database download completed"; "download_id" => download_id);
+            }
+            MithrilEvent::CardanoDatabaseDownloadLocationSwitched {
+                download_id,
+                failed_location,
+                next_location,
+                reason,
+            } => {
+                warn!(
+                    self.logger, "Cardano database download switched to the next location";
+                    "failed_location" => failed_location, "next_location" => next_location,
+                    "reason" => reason, "download_id" => download_id,
+                );
+            }
             MithrilEvent::CertificateChainValidationStarted {
                 certificate_chain_validation_id,
             } => {
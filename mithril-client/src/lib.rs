@@ -10,6 +10,7 @@
 //! - [Cardano Database v2][cardano_database_client] list, get, download archive and record statistics.
 //! - [Mithril stake distribution][mithril_stake_distribution_client] list and get.
 //! - [Cardano transactions][cardano_transaction_client] list & get snapshot, get proofs.
+//! - [Cardano blocks][cardano_block_client] get proofs.
 //! - [Cardano stake distribution][cardano_stake_distribution_client] list, get and get by epoch.
 //! - [Certificates][certificate_client] list, get, and chain validation.
 //!
@@ -85,6 +86,7 @@ macro_rules! cfg_unstable {
 }
 
 pub mod aggregator_client;
+pub mod cardano_block_client;
 cfg_unstable! {
     pub mod cardano_database_client;
 }
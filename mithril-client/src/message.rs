@@ -14,10 +14,12 @@ use mithril_common::{
     messages::SignedEntityTypeMessagePart,
 };
 
+#[cfg(feature = "unstable")]
+use crate::common::MKProof;
 use crate::{
     common::{ProtocolMessage, ProtocolMessagePartKey},
     CardanoStakeDistribution, MithrilCertificate, MithrilResult, MithrilSigner,
-    MithrilStakeDistribution, VerifiedCardanoTransactions,
+    MithrilStakeDistribution, VerifiedCardanoBlocks, VerifiedCardanoTransactions,
 };
 
 /// A [MessageBuilder] can be used to compute the message of Mithril artifacts.
@@ -141,6 +143,17 @@ impl MessageBuilder {
         message
     }
 
+    /// Compute message for a Cardano Blocks Proofs.
+    pub fn compute_cardano_blocks_proofs_message(
+        &self,
+        blocks_proofs_certificate: &MithrilCertificate,
+        verified_blocks: &VerifiedCardanoBlocks,
+    ) -> ProtocolMessage {
+        let mut message = blocks_proofs_certificate.protocol_message.clone();
+        verified_blocks.fill_protocol_message(&mut message);
+        message
+    }
+
     /// Compute message for a Cardano stake distribution.
     pub fn compute_cardano_stake_distribution_message(
         &self,
@@ -164,6 +177,28 @@ impl MessageBuilder {
 
         Ok(message)
     }
+
+    cfg_unstable! {
+        /// Compute message for a Cardano database (based on the Merkle proof of its restored
+        /// immutable files).
+        pub fn compute_cardano_database_message(
+            &self,
+            certificate: &MithrilCertificate,
+            merkle_proof: &MKProof,
+        ) -> MithrilResult<ProtocolMessage> {
+            merkle_proof
+                .verify()
+                .with_context(|| "Could not compute message: invalid Merkle proof")?;
+
+            let mut message = certificate.protocol_message.clone();
+            message.set_message_part(
+                ProtocolMessagePartKey::CardanoDatabaseMerkleRoot,
+                merkle_proof.root().to_hex(),
+            );
+
+            Ok(message)
+        }
+    }
 }
 
 impl Default for MessageBuilder {
@@ -171,3 +206,29 @@ impl Default for MessageBuilder {
         Self::new()
     }
 }
+
+#[cfg(all(test, feature = "unstable"))]
+mod tests {
+    use mithril_common::crypto_helper::{MKTree, MKTreeStoreInMemory};
+
+    use super::*;
+
+    #[test]
+    fn compute_cardano_database_message_sets_the_merkle_root_of_the_proof() {
+        let leaves = vec!["digest-1", "digest-2", "digest-3"];
+        let merkle_tree = MKTree::<MKTreeStoreInMemory>::new(&leaves).unwrap();
+        let merkle_proof = merkle_tree.compute_proof(&[leaves[1].into()]).unwrap();
+        let certificate = MithrilCertificate::dummy();
+
+        let message = MessageBuilder::new()
+            .compute_cardano_database_message(&certificate, &merkle_proof)
+            .unwrap();
+
+        let mut expected_message = certificate.protocol_message.clone();
+        expected_message.set_message_part(
+            ProtocolMessagePartKey::CardanoDatabaseMerkleRoot,
+            merkle_tree.compute_root().unwrap().to_hex(),
+        );
+        assert_eq!(expected_message, message);
+    }
+}
@@ -41,6 +41,7 @@
 use std::sync::Arc;
 
 use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
+use crate::common::ListFilter;
 use anyhow::Context;
 
 use crate::{MithrilResult, MithrilStakeDistribution, MithrilStakeDistributionListItem};
@@ -58,9 +59,30 @@ impl MithrilStakeDistributionClient {
 
     /// Fetch a list of signed MithrilStakeDistribution
     pub async fn list(&self) -> MithrilResult<Vec<MithrilStakeDistributionListItem>> {
+        self.fetch_list_with_aggregator_request(AggregatorRequest::ListMithrilStakeDistributions)
+            .await
+    }
+
+    /// Fetch a list of Mithril stake distributions matching the given cursor and filters.
+    pub async fn list_with_filter(
+        &self,
+        filter: &ListFilter,
+    ) -> MithrilResult<Vec<MithrilStakeDistributionListItem>> {
+        self.fetch_list_with_aggregator_request(
+            AggregatorRequest::ListMithrilStakeDistributionsWithFilter {
+                filter: filter.clone(),
+            },
+        )
+        .await
+    }
+
+    async fn fetch_list_with_aggregator_request(
+        &self,
+        request: AggregatorRequest,
+    ) -> MithrilResult<Vec<MithrilStakeDistributionListItem>> {
         let response = self
             .aggregator_client
-            .get_content(AggregatorRequest::ListMithrilStakeDistributions)
+            .get_content(request)
             .await
             .with_context(|| "MithrilStakeDistribution Client can not get the artifact list")?;
         let items = serde_json::from_str::<Vec<MithrilStakeDistributionListItem>>(&response)
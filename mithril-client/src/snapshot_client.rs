@@ -98,6 +98,7 @@ use std::sync::Arc;
 use thiserror::Error;
 
 use crate::aggregator_client::{AggregatorClient, AggregatorClientError, AggregatorRequest};
+use crate::common::ListFilter;
 #[cfg(feature = "fs")]
 use crate::feedback::FeedbackSender;
 #[cfg(feature = "fs")]
@@ -152,9 +153,28 @@ impl SnapshotClient {
 
     /// Return a list of available snapshots
     pub async fn list(&self) -> MithrilResult<Vec<SnapshotListItem>> {
+        self.fetch_list_with_aggregator_request(AggregatorRequest::ListSnapshots)
+            .await
+    }
+
+    /// Fetch a list of snapshots matching the given cursor and filters.
+    pub async fn list_with_filter(
+        &self,
+        filter: &ListFilter,
+    ) -> MithrilResult<Vec<SnapshotListItem>> {
+        self.fetch_list_with_aggregator_request(AggregatorRequest::ListSnapshotsWithFilter {
+            filter: filter.clone(),
+        })
+        .await
+    }
+
+    async fn fetch_list_with_aggregator_request(
+        &self,
+        request: AggregatorRequest,
+    ) -> MithrilResult<Vec<SnapshotListItem>> {
         let response = self
             .aggregator_client
-            .get_content(AggregatorRequest::ListSnapshots)
+            .get_content(request)
             .await
             .with_context(|| "Snapshot Client can not get the artifact list")?;
         let items = serde_json::from_str::<Vec<SnapshotListItem>>(&response)
@@ -186,6 +206,9 @@ impl SnapshotClient {
     cfg_fs! {
         /// Download and unpack the given snapshot to the given directory
         ///
+        /// The locations of the snapshot are tried in order: unreachable locations are skipped and
+        /// if a download fails the next location is used.
+        ///
         /// **NOTE**: The directory should already exist, and the user running the binary
         /// must have read/write access to it.
         pub async fn download_unpack(
@@ -195,17 +218,19 @@ impl SnapshotClient {
         ) -> MithrilResult<()> {
             use crate::feedback::MithrilEvent;
 
-            for location in snapshot.locations.as_slice() {
-                if self.snapshot_downloader.probe(location).await.is_ok() {
-                    let download_id = MithrilEvent::new_snapshot_download_id();
-                    self.feedback_sender
-                        .send_event(MithrilEvent::SnapshotDownloadStarted {
-                            digest: snapshot.digest.clone(),
-                            download_id: download_id.clone(),
-                            size: snapshot.size,
-                        })
-                        .await;
-                    return match self
+            let download_id = MithrilEvent::new_snapshot_download_id();
+            self.feedback_sender
+                .send_event(MithrilEvent::SnapshotDownloadStarted {
+                    digest: snapshot.digest.clone(),
+                    download_id: download_id.clone(),
+                    size: snapshot.size,
+                })
+                .await;
+
+            let mut locations = snapshot.locations.iter().peekable();
+            while let Some(location) = locations.next() {
+                let failure_reason = match self.snapshot_downloader.probe(location).await {
+                    Ok(()) => match self
                         .snapshot_downloader
                         .download_unpack(
                             location,
@@ -220,16 +245,34 @@ impl SnapshotClient {
                             self.feedback_sender
                                 .send_event(MithrilEvent::SnapshotDownloadCompleted { download_id })
                                 .await;
-                            Ok(())
+                            return Ok(());
                         }
                         Err(e) => {
                             slog::warn!(
                                 self.logger, "Failed downloading snapshot from '{location}'";
                                 "error" => ?e
                             );
-                            Err(e)
+                            format!("download failed: {e:#}")
                         }
-                    };
+                    },
+                    Err(e) => {
+                        slog::warn!(
+                            self.logger, "Snapshot location '{location}' is unreachable";
+                            "error" => ?e
+                        );
+                        format!("location unreachable: {e:#}")
+                    }
+                };
+
+                if let Some(next_location) = locations.peek() {
+                    self.feedback_sender
+                        .send_event(MithrilEvent::SnapshotDownloadLocationSwitched {
+                            download_id: download_id.clone(),
+                            failed_location: location.clone(),
+                            next_location: next_location.to_string(),
+                            reason: failure_reason,
+                        })
+                        .await;
                 }
             }
 
@@ -264,10 +307,31 @@ mod tests_download {
         snapshot_downloader::MockHttpSnapshotDownloader,
         test_utils,
     };
+    use anyhow::anyhow;
+    use mockall::predicate::{always, eq};
     use std::path::Path;
 
     use super::*;
 
+    fn snapshot_with_locations(locations: &[&str]) -> Snapshot {
+        Snapshot {
+            locations: locations.iter().map(|l| l.to_string()).collect(),
+            ..Snapshot::dummy()
+        }
+    }
+
+    fn build_client(
+        snapshot_downloader: MockHttpSnapshotDownloader,
+        feedback_receiver: Arc<StackFeedbackReceiver>,
+    ) -> SnapshotClient {
+        SnapshotClient::new(
+            Arc::new(MockAggregatorHTTPClient::new()),
+            Arc::new(snapshot_downloader),
+            FeedbackSender::new(&[feedback_receiver]),
+            test_utils::test_logger(),
+        )
+    }
+
     #[tokio::test]
     async fn download_unpack_send_feedbacks() {
         let mut snapshot_downloader = MockHttpSnapshotDownloader::new();
@@ -276,12 +340,7 @@ mod tests_download {
             .expect_download_unpack()
             .returning(|_, _, _, _, _| Ok(()));
         let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
-        let client = SnapshotClient::new(
-            Arc::new(MockAggregatorHTTPClient::new()),
-            Arc::new(snapshot_downloader),
-            FeedbackSender::new(&[feedback_receiver.clone()]),
-            test_utils::test_logger(),
-        );
+        let client = build_client(snapshot_downloader, feedback_receiver.clone());
         let snapshot = Snapshot::dummy();
 
         client
@@ -304,4 +363,159 @@ mod tests_download {
 
         assert_eq!(actual, expected);
     }
+
+    #[tokio::test]
+    async fn download_unpack_skip_unreachable_locations() {
+        let mut snapshot_downloader = MockHttpSnapshotDownloader::new();
+        snapshot_downloader
+            .expect_probe()
+            .with(eq("http://host-1/snapshot.tar.gz"))
+            .once()
+            .returning(|_| Err(anyhow!("unreachable")));
+        snapshot_downloader
+            .expect_probe()
+            .with(eq("http://host-2/snapshot.tar.gz"))
+            .once()
+            .returning(|_| Ok(()));
+        snapshot_downloader
+            .expect_download_unpack()
+            .with(
+                eq("http://host-2/snapshot.tar.gz"),
+                always(),
+                always(),
+                always(),
+                always(),
+            )
+            .once()
+            .returning(|_, _, _, _, _| Ok(()));
+        let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+        let client = build_client(snapshot_downloader, feedback_receiver.clone());
+        let snapshot = snapshot_with_locations(&[
+            "http://host-1/snapshot.tar.gz",
+            "http://host-2/snapshot.tar.gz",
+        ]);
+
+        client
+            .download_unpack(&snapshot, Path::new(""))
+            .await
+            .expect("download should succeed");
+
+        let actual = feedback_receiver.stacked_events();
+        let id = actual[0].event_id();
+        let expected = vec![
+            MithrilEvent::SnapshotDownloadStarted {
+                digest: snapshot.digest,
+                download_id: id.to_string(),
+                size: snapshot.size,
+            },
+            MithrilEvent::SnapshotDownloadLocationSwitched {
+                download_id: id.to_string(),
+                failed_location: "http://host-1/snapshot.tar.gz".to_string(),
+                next_location: "http://host-2/snapshot.tar.gz".to_string(),
+                reason: "location unreachable: unreachable".to_string(),
+            },
+            MithrilEvent::SnapshotDownloadCompleted {
+                download_id: id.to_string(),
+            },
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[tokio::test]
+    async fn download_unpack_fallback_to_next_location_after_a_download_failure() {
+        let mut snapshot_downloader = MockHttpSnapshotDownloader::new();
+        snapshot_downloader.expect_probe().returning(|_| Ok(()));
+        snapshot_downloader
+            .expect_download_unpack()
+            .with(
+                eq("http://host-1/snapshot.tar.gz"),
+                always(),
+                always(),
+                always(),
+                always(),
+            )
+            .once()
+            .returning(|_, _, _, _, _| Err(anyhow!("connection reset")));
+        snapshot_downloader
+            .expect_download_unpack()
+            .with(
+                eq("http://host-2/snapshot.tar.gz"),
+                always(),
+                always(),
+                always(),
+                always(),
+            )
+            .once()
+            .returning(|_, _, _, _, _| Ok(()));
+        let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+        let client = build_client(snapshot_downloader, feedback_receiver.clone());
+        let snapshot = snapshot_with_locations(&[
+            "http://host-1/snapshot.tar.gz",
+            "http://host-2/snapshot.tar.gz",
+        ]);
+
+        client
+            .download_unpack(&snapshot, Path::new(""))
+            .await
+            .expect("download should succeed");
+
+        let actual = feedback_receiver.stacked_events();
+        let id = actual[0].event_id();
+        assert_eq!(
+            actual[1],
+            MithrilEvent::SnapshotDownloadLocationSwitched {
+                download_id: id.to_string(),
+                failed_location: "http://host-1/snapshot.tar.gz".to_string(),
+                next_location: "http://host-2/snapshot.tar.gz".to_string(),
+                reason: "download failed: connection reset".to_string(),
+            }
+        );
+        assert_eq!(
+            actual.last(),
+            Some(&MithrilEvent::SnapshotDownloadCompleted {
+                download_id: id.to_string(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn download_unpack_fails_if_all_locations_fail() {
+        let mut snapshot_downloader = MockHttpSnapshotDownloader::new();
+        snapshot_downloader
+            .expect_probe()
+            .with(eq("http://host-1/snapshot.tar.gz"))
+            .returning(|_| Err(anyhow!("unreachable")));
+        snapshot_downloader
+            .expect_probe()
+            .with(eq("http://host-2/snapshot.tar.gz"))
+            .returning(|_| Ok(()));
+        snapshot_downloader
+            .expect_download_unpack()
+            .once()
+            .returning(|_, _, _, _, _| Err(anyhow!("connection reset")));
+        let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+        let client = build_client(snapshot_downloader, feedback_receiver.clone());
+        let snapshot = snapshot_with_locations(&[
+            "http://host-1/snapshot.tar.gz",
+            "http://host-2/snapshot.tar.gz",
+        ]);
+
+        let error = client
+            .download_unpack(&snapshot, Path::new(""))
+            .await
+            .expect_err("download should fail");
+
+        assert!(
+            matches!(
+                error.downcast_ref::<SnapshotClientError>(),
+                Some(SnapshotClientError::NoWorkingLocation { .. })
+            ),
+            "Unexpected error: {error:?}"
+        );
+        assert!(!feedback_receiver
+            .stacked_events()
+            .iter()
+            .any(|event| matches!(event, MithrilEvent::SnapshotDownloadCompleted { .. })));
+    }
 }
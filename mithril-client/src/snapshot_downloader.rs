@@ -10,13 +10,14 @@
 use anyhow::{anyhow, Context};
 use async_trait::async_trait;
 use futures::StreamExt;
+use reqwest::header::RANGE;
 use reqwest::Url;
 use reqwest::{Response, StatusCode};
-use slog::{debug, Logger};
+use slog::{debug, warn, Logger};
 use std::fs;
-use std::path::Path;
-use tokio::fs::File;
-use tokio::io::AsyncReadExt;
+use std::path::{Path, PathBuf};
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
 use mithril_common::logging::LoggerExtensions;
 
@@ -48,9 +49,14 @@ pub trait SnapshotDownloader: Sync + Send {
 }
 
 /// A snapshot downloader that only handles download through HTTP.
+///
+/// By default the downloaded archives are streamed directly to the unpacker. With
+/// [resumable downloads][HttpSnapshotDownloader::with_resumable_downloads], the archives are first
+/// persisted to disk so an interrupted download can be resumed using HTTP range requests.
 pub struct HttpSnapshotDownloader {
     http_client: reqwest::Client,
     feedback_sender: FeedbackSender,
+    partial_downloads_dir: Option<PathBuf>,
     logger: Logger,
 }
 
@@ -64,10 +70,24 @@ impl HttpSnapshotDownloader {
         Ok(Self {
             http_client,
             feedback_sender,
+            partial_downloads_dir: None,
             logger: logger.new_with_component_name::<Self>(),
         })
     }
 
+    /// Persist the remote archives in the given directory before unpacking them, so an
+    /// interrupted download can be resumed from where it stopped.
+    ///
+    /// The partial archives are named after the download id and the last segment of their
+    /// location, which allows to resume a download from another location of the same archive.
+    ///
+    /// Only the archives with a known size are downloaded this way, since the size is required
+    /// to check that a resumed archive is complete; the others are streamed directly.
+    pub fn with_resumable_downloads(mut self, partial_downloads_dir: &Path) -> Self {
+        self.partial_downloads_dir = Some(partial_downloads_dir.to_path_buf());
+        self
+    }
+
     async fn get(&self, location: &str) -> MithrilResult<Response> {
         debug!(self.logger, "GET Snapshot location='{location}'.");
         let request_builder = self.http_client.get(location);
@@ -82,6 +102,50 @@ impl HttpSnapshotDownloader {
         }
     }
 
+    /// GET the given location starting from the given offset.
+    ///
+    /// The response status is `PARTIAL_CONTENT` if the server honored the range, `OK` if it sent
+    /// the whole content and `RANGE_NOT_SATISFIABLE` if there is nothing left to download.
+    async fn get_from_offset(&self, location: &str, offset: u64) -> MithrilResult<Response> {
+        debug!(
+            self.logger,
+            "GET Snapshot location='{location}' from offset {offset}."
+        );
+        let response = self
+            .http_client
+            .get(location)
+            .header(RANGE, format!("bytes={offset}-"))
+            .send()
+            .await
+            .with_context(|| {
+                format!("Cannot perform a GET for the snapshot (location='{location}')")
+            })?;
+
+        match response.status() {
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT | StatusCode::RANGE_NOT_SATISFIABLE => {
+                Ok(response)
+            }
+            StatusCode::NOT_FOUND => Err(anyhow!("Location='{location} not found")),
+            status_code => Err(anyhow!("Unhandled error {status_code}")),
+        }
+    }
+
+    fn partial_archive_path(
+        partial_downloads_dir: &Path,
+        location: &str,
+        download_id: &str,
+    ) -> MithrilResult<PathBuf> {
+        let url = Url::parse(location)
+            .with_context(|| format!("Invalid snapshot location='{location}'"))?;
+        let archive_name = url
+            .path_segments()
+            .and_then(|mut segments| segments.next_back())
+            .filter(|segment| !segment.is_empty())
+            .ok_or_else(|| anyhow!("Snapshot location='{location}' has no file name"))?;
+
+        Ok(partial_downloads_dir.join(format!("{download_id}-{archive_name}.part")))
+    }
+
     fn file_scheme_to_local_path(file_url: &str) -> Option<String> {
         Url::parse(file_url)
             .ok()
@@ -149,6 +213,124 @@ impl HttpSnapshotDownloader {
         }
         Ok(())
     }
+
+    /// Download a remote archive to a partial archive file, resuming the download if the file
+    /// already exists, and return the path of the complete archive.
+    ///
+    /// The size of the downloaded archive is checked against the `snapshot_size`.
+    async fn download_remote_file_resumable<F, Fut>(
+        &self,
+        location: &str,
+        partial_downloads_dir: &Path,
+        download_id: &str,
+        snapshot_size: u64,
+        report_progress: F,
+    ) -> MithrilResult<PathBuf>
+    where
+        F: Fn(u64) -> Fut,
+        Fut: std::future::Future<Output = ()>,
+    {
+        fs::create_dir_all(partial_downloads_dir).with_context(|| {
+            format!(
+                "Could not create partial downloads directory: '{}'",
+                partial_downloads_dir.display()
+            )
+        })?;
+        let archive_path =
+            Self::partial_archive_path(partial_downloads_dir, location, download_id)?;
+        let mut offset = fs::metadata(&archive_path).map(|m| m.len()).unwrap_or(0);
+        if offset > snapshot_size {
+            warn!(
+                self.logger, "Partial archive is bigger than the snapshot, restarting the download";
+                "archive_path" => archive_path.display(), "offset" => offset, "size" => snapshot_size
+            );
+            offset = 0;
+        }
+
+        let is_complete = offset == snapshot_size;
+        if !is_complete {
+            let response = self.get_from_offset(location, offset).await?;
+            let is_resumed = match response.status() {
+                StatusCode::PARTIAL_CONTENT => true,
+                StatusCode::RANGE_NOT_SATISFIABLE if offset > 0 => {
+                    debug!(self.logger, "Partial archive already complete"; "archive_path" => archive_path.display());
+                    return self.check_archive_size(&archive_path, snapshot_size);
+                }
+                StatusCode::RANGE_NOT_SATISFIABLE => {
+                    return Err(anyhow!("Range not satisfiable for location='{location}'"));
+                }
+                _ => false,
+            };
+            if !is_resumed {
+                offset = 0;
+            }
+
+            let mut archive_file = OpenOptions::new()
+                .create(true)
+                .write(true)
+                .append(is_resumed)
+                .truncate(!is_resumed)
+                .open(&archive_path)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Could not open partial archive: '{}'",
+                        archive_path.display()
+                    )
+                })?;
+
+            if offset > 0 {
+                self.feedback_sender
+                    .send_event(MithrilEvent::SnapshotDownloadResumed {
+                        download_id: download_id.to_owned(),
+                        resumed_offset: offset,
+                        size: snapshot_size,
+                    })
+                    .await;
+            }
+
+            let mut downloaded_bytes = offset;
+            let mut remote_stream = response.bytes_stream();
+            while let Some(item) = remote_stream.next().await {
+                let chunk = item.with_context(|| "Download: Could not read from byte stream")?;
+                archive_file.write_all(&chunk).await.with_context(|| {
+                    format!("Download: could not write {} bytes to file.", chunk.len())
+                })?;
+
+                downloaded_bytes += chunk.len() as u64;
+                report_progress(downloaded_bytes).await
+            }
+            archive_file
+                .flush()
+                .await
+                .with_context(|| "Download: could not flush the partial archive")?;
+        }
+
+        self.check_archive_size(&archive_path, snapshot_size)
+    }
+
+    fn check_archive_size(
+        &self,
+        archive_path: &Path,
+        snapshot_size: u64,
+    ) -> MithrilResult<PathBuf> {
+        let archive_size = fs::metadata(archive_path)
+            .with_context(|| format!("Could not read metadata of '{}'", archive_path.display()))?
+            .len();
+        if archive_size != snapshot_size {
+            fs::remove_file(archive_path).with_context(|| {
+                format!(
+                    "Could not remove invalid archive '{}'",
+                    archive_path.display()
+                )
+            })?;
+            return Err(anyhow!(
+                "Downloaded archive size mismatch: expected {snapshot_size} bytes, got {archive_size} bytes"
+            ));
+        }
+
+        Ok(archive_path.to_path_buf())
+    }
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -186,17 +368,60 @@ impl SnapshotDownloader for HttpSnapshotDownloader {
                 .await
         };
 
-        if let Some(local_path) = Self::file_scheme_to_local_path(location) {
+        let mut downloaded_archive = None;
+        let download_result = if let Some(local_path) = Self::file_scheme_to_local_path(location) {
             self.download_local_file(&local_path, &sender, report_progress)
-                .await?;
+                .await
+        } else if let Some(partial_downloads_dir) = self
+            .partial_downloads_dir
+            .as_ref()
+            .filter(|_| snapshot_size > 0)
+        {
+            match self
+                .download_remote_file_resumable(
+                    location,
+                    partial_downloads_dir,
+                    download_id,
+                    snapshot_size,
+                    report_progress,
+                )
+                .await
+            {
+                Ok(archive_path) => {
+                    let result = self
+                        .download_local_file(&archive_path.to_string_lossy(), &sender, |_| async {})
+                        .await;
+                    downloaded_archive = Some(archive_path);
+                    result
+                }
+                Err(error) => Err(error),
+            }
         } else {
+            if self.partial_downloads_dir.is_some() {
+                debug!(
+                    self.logger, "Snapshot size unknown, the download can not be resumed";
+                    "location" => location, "download_id" => download_id
+                );
+            }
             self.download_remote_file(location, &sender, report_progress)
-                .await?;
-        }
+                .await
+        };
 
         drop(sender); // Signal EOF
-        unpack_thread
-            .await
+        let unpack_result = unpack_thread.await;
+
+        // A complete archive is removed even if its unpack failed since it would fail again
+        if let Some(archive_path) = downloaded_archive {
+            if let Err(error) = fs::remove_file(&archive_path) {
+                warn!(
+                    self.logger, "Could not remove downloaded archive";
+                    "archive_path" => archive_path.display(), "error" => ?error
+                );
+            }
+        }
+
+        download_result?;
+        unpack_result
             .with_context(|| {
                 format!(
                     "Unpack: panic while unpacking to dir '{}'",
@@ -231,3 +456,227 @@ impl SnapshotDownloader for HttpSnapshotDownloader {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use httpmock::MockServer;
+    use std::sync::Arc;
+
+    use mithril_common::test_utils::TempDir;
+
+    use crate::feedback::StackFeedbackReceiver;
+    use crate::test_utils;
+
+    use super::*;
+
+    const ARCHIVE_PATH: &str = "/snapshot.tar.gz";
+
+    fn build_archive() -> Vec<u8> {
+        let content = "file content ".repeat(100);
+        let encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        let mut builder = tar::Builder::new(encoder);
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, "unpacked_file", content.as_bytes())
+            .unwrap();
+
+        builder.into_inner().unwrap().finish().unwrap()
+    }
+
+    fn build_downloader(
+        partial_downloads_dir: &Path,
+        feedback_receiver: Arc<StackFeedbackReceiver>,
+    ) -> HttpSnapshotDownloader {
+        HttpSnapshotDownloader::new(
+            FeedbackSender::new(&[feedback_receiver]),
+            test_utils::test_logger(),
+        )
+        .unwrap()
+        .with_resumable_downloads(partial_downloads_dir)
+    }
+
+    #[tokio::test]
+    async fn resume_download_of_a_partial_archive_with_a_range_request() {
+        let test_dir = TempDir::create(
+            "snapshot_downloader",
+            "resume_download_of_a_partial_archive_with_a_range_request",
+        );
+        let (partial_downloads_dir, target_dir) =
+            (test_dir.join("partial"), test_dir.join("target"));
+        fs::create_dir_all(&partial_downloads_dir).unwrap();
+        fs::create_dir_all(&target_dir).unwrap();
+        let archive = build_archive();
+        let offset = archive.len() / 2;
+        fs::write(
+            partial_downloads_dir.join("download_id-snapshot.tar.gz.part"),
+            &archive[..offset],
+        )
+        .unwrap();
+        let server = MockServer::start();
+        let range_mock = server.mock(|when, then| {
+            when.path(ARCHIVE_PATH)
+                .header("range", format!("bytes={offset}-"));
+            then.status(StatusCode::PARTIAL_CONTENT.as_u16())
+                .body(&archive[offset..]);
+        });
+        let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+        let downloader = build_downloader(&partial_downloads_dir, feedback_receiver.clone());
+
+        downloader
+            .download_unpack(
+                &server.url(ARCHIVE_PATH),
+                &target_dir,
+                CompressionAlgorithm::Gzip,
+                "download_id",
+                archive.len() as u64,
+            )
+            .await
+            .unwrap();
+
+        range_mock.assert();
+        assert!(target_dir.join("unpacked_file").is_file());
+        assert!(!partial_downloads_dir.join("download_id-snapshot.tar.gz.part").exists());
+        assert_eq!(
+            feedback_receiver.stacked_events()[0],
+            MithrilEvent::SnapshotDownloadResumed {
+                download_id: "download_id".to_string(),
+                resumed_offset: offset as u64,
+                size: archive.len() as u64,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn restart_download_from_the_beginning_if_the_server_ignores_the_range() {
+        let test_dir = TempDir::create(
+            "snapshot_downloader",
+            "restart_download_from_the_beginning_if_the_server_ignores_the_range",
+        );
+        let (partial_downloads_dir, target_dir) =
+            (test_dir.join("partial"), test_dir.join("target"));
+        fs::create_dir_all(&partial_downloads_dir).unwrap();
+        fs::create_dir_all(&target_dir).unwrap();
+        let archive = build_archive();
+        fs::write(
+            partial_downloads_dir.join("download_id-snapshot.tar.gz.part"),
+            b"corrupted partial archive",
+        )
+        .unwrap();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.path(ARCHIVE_PATH);
+            then.status(StatusCode::OK.as_u16()).body(&archive);
+        });
+        let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+        let downloader = build_downloader(&partial_downloads_dir, feedback_receiver.clone());
+
+        downloader
+            .download_unpack(
+                &server.url(ARCHIVE_PATH),
+                &target_dir,
+                CompressionAlgorithm::Gzip,
+                "download_id",
+                archive.len() as u64,
+            )
+            .await
+            .unwrap();
+
+        assert!(target_dir.join("unpacked_file").is_file());
+        assert!(!feedback_receiver
+            .stacked_events()
+            .iter()
+            .any(|event| matches!(event, MithrilEvent::SnapshotDownloadResumed { .. })));
+    }
+
+    #[tokio::test]
+    async fn fails_and_remove_the_archive_if_its_size_does_not_match_the_snapshot_size() {
+        let test_dir = TempDir::create(
+            "snapshot_downloader",
+            "fails_and_remove_the_archive_if_its_size_does_not_match_the_snapshot_size",
+        );
+        let (partial_downloads_dir, target_dir) =
+            (test_dir.join("partial"), test_dir.join("target"));
+        fs::create_dir_all(&target_dir).unwrap();
+        let archive = build_archive();
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.path(ARCHIVE_PATH);
+            then.status(StatusCode::OK.as_u16()).body(&archive);
+        });
+        let downloader = build_downloader(
+            &partial_downloads_dir,
+            Arc::new(StackFeedbackReceiver::new()),
+        );
+
+        let error = downloader
+            .download_unpack(
+                &server.url(ARCHIVE_PATH),
+                &target_dir,
+                CompressionAlgorithm::Gzip,
+                "download_id",
+                archive.len() as u64 + 10,
+            )
+            .await
+            .expect_err("download_unpack should fail");
+
+        assert!(
+            error.to_string().contains("size mismatch"),
+            "Unexpected error: {error:?}"
+        );
+        assert!(!partial_downloads_dir.join("download_id-snapshot.tar.gz.part").exists());
+        assert!(!target_dir.join("unpacked_file").exists());
+    }
+
+    #[tokio::test]
+    async fn do_not_resume_the_download_of_an_archive_of_unknown_size() {
+        let test_dir = TempDir::create(
+            "snapshot_downloader",
+            "do_not_resume_the_download_of_an_archive_of_unknown_size",
+        );
+        let (partial_downloads_dir, target_dir) =
+            (test_dir.join("partial"), test_dir.join("target"));
+        fs::create_dir_all(&partial_downloads_dir).unwrap();
+        fs::create_dir_all(&target_dir).unwrap();
+        let archive = build_archive();
+        let partial_archive_path = partial_downloads_dir.join("download_id-snapshot.tar.gz.part");
+        fs::write(&partial_archive_path, &archive[..archive.len() / 2]).unwrap();
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.path(ARCHIVE_PATH).matches(|req| {
+                !req.headers
+                    .as_ref()
+                    .is_some_and(|headers| headers
+                        .iter()
+                        .any(|(name, _)| name.eq_ignore_ascii_case("range")))
+            });
+            then.status(StatusCode::OK.as_u16()).body(&archive);
+        });
+        let feedback_receiver = Arc::new(StackFeedbackReceiver::new());
+        let downloader = build_downloader(&partial_downloads_dir, feedback_receiver.clone());
+
+        downloader
+            .download_unpack(
+                &server.url(ARCHIVE_PATH),
+                &target_dir,
+                CompressionAlgorithm::Gzip,
+                "download_id",
+                0,
+            )
+            .await
+            .unwrap();
+
+        mock.assert();
+        assert!(target_dir.join("unpacked_file").is_file());
+        assert_eq!(
+            archive.len() / 2,
+            fs::metadata(&partial_archive_path).unwrap().len() as usize
+        );
+        assert!(!feedback_receiver
+            .stacked_events()
+            .iter()
+            .any(|event| matches!(event, MithrilEvent::SnapshotDownloadResumed { .. })));
+    }
+}
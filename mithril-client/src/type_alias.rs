@@ -45,6 +45,14 @@ pub use mithril_common::messages::CertificateListItemMessageMetadata as MithrilC
 ///
 pub use mithril_common::messages::SignerWithStakeMessagePart as MithrilSigner;
 
+pub use mithril_common::messages::CardanoBlocksProofsMessage as CardanoBlocksProofs;
+
+pub use mithril_common::messages::CardanoBlocksSetProofMessagePart as CardanoBlocksSetProof;
+
+pub use mithril_common::messages::VerifiedCardanoBlocks;
+
+pub use mithril_common::messages::VerifyCardanoBlocksProofsError;
+
 pub use mithril_common::messages::CardanoTransactionsProofsMessage as CardanoTransactionsProofs;
 
 pub use mithril_common::messages::CardanoTransactionsSetProofMessagePart as CardanoTransactionsSetProof;
@@ -68,11 +76,13 @@ pub use mithril_common::messages::CardanoStakeDistributionListItemMessage as Car
 /// `mithril-common` re-exports
 pub mod common {
     pub use mithril_common::entities::{
-        BlockHash, BlockNumber, CardanoDbBeacon, ChainPoint, CompressionAlgorithm, Epoch,
-        ImmutableFileNumber, ProtocolMessage, ProtocolMessagePartKey, ProtocolParameters,
-        SlotNumber, StakeDistribution, TransactionHash,
+        BeaconFilter, BlockHash, BlockNumber, CardanoBlock, CardanoDbBeacon, ChainPoint,
+        CompressionAlgorithm, Epoch, ImmutableFileNumber, ListFilter, ProtocolMessage,
+        ProtocolMessagePartKey, ProtocolParameters, SignedEntityType,
+        SignedEntityTypeDiscriminants, SlotNumber, StakeDistribution, TransactionHash,
     };
     cfg_unstable! {
+        pub use mithril_common::crypto_helper::MKProof;
         pub use mithril_common::entities::{
             AncillaryLocation, DigestLocation, ImmutablesLocation, MultiFilesUri, TemplateUri,
         };
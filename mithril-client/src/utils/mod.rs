@@ -1,5 +1,10 @@
 //! Utilities module
-//! This module contains tools needed mostly for the snapshot download and unpack.
+//! This module contains tools needed mostly for the snapshot download and unpack, and to decode
+//! the aggregator events stream.
+
+mod sse_decoder;
+
+pub use sse_decoder::*;
 
 cfg_fs! {
     mod stream_reader;
@@ -0,0 +1,146 @@
+/// An event received on a server-sent events stream.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ServerSentEvent {
+    /// Id of the event, if any
+    pub id: Option<String>,
+    /// Name of the event, if any
+    pub event: Option<String>,
+    /// Data of the event, multiple `data` lines are joined with a line feed
+    pub data: String,
+}
+
+/// Incremental decoder of a server-sent events stream.
+///
+/// Bytes can be fed in chunks of any size, the events are returned as soon as they are complete.
+/// See the [specification](https://html.spec.whatwg.org/multipage/server-sent-events.html#event-stream-interpretation).
+#[derive(Debug, Default)]
+pub struct ServerSentEventDecoder {
+    buffer: Vec<u8>,
+    id: Option<String>,
+    event: Option<String>,
+    data_lines: Vec<String>,
+}
+
+impl ServerSentEventDecoder {
+    /// Constructs a new `ServerSentEventDecoder`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed a chunk of the stream and return the events completed by this chunk.
+    pub fn feed(&mut self, chunk: &[u8]) -> Vec<ServerSentEvent> {
+        self.buffer.extend_from_slice(chunk);
+
+        let mut events = vec![];
+        while let Some(line_end) = self.buffer.iter().position(|byte| *byte == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=line_end).collect();
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim_end_matches(['\n', '\r']);
+
+            if let Some(event) = self.process_line(line) {
+                events.push(event);
+            }
+        }
+
+        events
+    }
+
+    fn process_line(&mut self, line: &str) -> Option<ServerSentEvent> {
+        if line.is_empty() {
+            return self.dispatch_event();
+        }
+        if line.starts_with(':') {
+            // Comment line, used by the servers as keep-alive
+            return None;
+        }
+
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+        match field {
+            "id" => self.id = Some(value.to_string()),
+            "event" => self.event = Some(value.to_string()),
+            "data" => self.data_lines.push(value.to_string()),
+            _ => {}
+        }
+
+        None
+    }
+
+    fn dispatch_event(&mut self) -> Option<ServerSentEvent> {
+        let event = self.event.take();
+        if self.data_lines.is_empty() {
+            return None;
+        }
+
+        Some(ServerSentEvent {
+            // The last event id is kept for the next events of the stream
+            id: self.id.clone(),
+            event,
+            data: std::mem::take(&mut self.data_lines).join("\n"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_complete_events() {
+        let mut decoder = ServerSentEventDecoder::new();
+
+        let events = decoder.feed(
+            b"event:epoch_changed\ndata:{\"epoch\":5}\nid:12\n\nid: 13\ndata: first\ndata: second\n\n",
+        );
+
+        assert_eq!(
+            vec![
+                ServerSentEvent {
+                    id: Some("12".to_string()),
+                    event: Some("epoch_changed".to_string()),
+                    data: "{\"epoch\":5}".to_string(),
+                },
+                ServerSentEvent {
+                    id: Some("13".to_string()),
+                    event: None,
+                    data: "first\nsecond".to_string(),
+                },
+            ],
+            events
+        );
+    }
+
+    #[test]
+    fn decode_events_split_across_chunks() {
+        let mut decoder = ServerSentEventDecoder::new();
+
+        assert_eq!(Vec::<ServerSentEvent>::new(), decoder.feed(b"id:1\r\nda"));
+        assert_eq!(Vec::<ServerSentEvent>::new(), decoder.feed(b"ta:hello\r\n"));
+        assert_eq!(
+            vec![ServerSentEvent {
+                id: Some("1".to_string()),
+                event: None,
+                data: "hello".to_string(),
+            }],
+            decoder.feed(b"\r\n")
+        );
+    }
+
+    #[test]
+    fn ignore_comments_and_events_without_data() {
+        let mut decoder = ServerSentEventDecoder::new();
+
+        let events = decoder.feed(b":keep-alive\n\nevent:ping\n\nunknown:field\ndata:value\n\n");
+
+        assert_eq!(
+            vec![ServerSentEvent {
+                id: None,
+                event: None,
+                data: "value".to_string(),
+            }],
+            events
+        );
+    }
+}
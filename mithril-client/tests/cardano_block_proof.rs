@@ -0,0 +1,63 @@
+mod extensions;
+
+use crate::extensions::fake::{FakeAggregator, FakeCertificateVerifier};
+use mithril_client::common::{BlockNumber, CardanoBlock, SlotNumber};
+use mithril_client::{aggregator_client::AggregatorRequest, ClientBuilder, MessageBuilder};
+
+#[tokio::test]
+async fn cardano_block_proof_get_validate() {
+    let genesis_verification_key =
+        mithril_common::test_utils::fake_keys::genesis_verification_key()[0];
+
+    let blocks = [
+        CardanoBlock::new("block-hash-1", BlockNumber(10), SlotNumber(100)),
+        CardanoBlock::new("block-hash-2", BlockNumber(11), SlotNumber(110)),
+    ];
+    let block_hashes: Vec<String> = blocks.iter().map(|b| b.block_hash.clone()).collect();
+    let certificate_hash = "certificate_hash";
+    let fake_aggregator = FakeAggregator::new();
+    let test_http_server = fake_aggregator.spawn_with_blocks_proofs(&blocks, certificate_hash);
+    let client = ClientBuilder::aggregator(&test_http_server.url(), genesis_verification_key)
+        .with_certificate_verifier(FakeCertificateVerifier::build_that_validate_any_certificate())
+        .build()
+        .expect("Should be able to create a Client");
+    let cardano_block_client = client.cardano_block();
+
+    // 1 - get list of set proofs for wanted blocks & associated certificate hash
+    let proofs = cardano_block_client
+        .get_proofs(&block_hashes)
+        .await
+        .expect("Getting proof for the blocks should not fail");
+    assert_eq!(
+        fake_aggregator.get_last_call().await,
+        Some(format!(
+            "/{}",
+            AggregatorRequest::GetBlocksProofs {
+                block_hashes: block_hashes.clone(),
+            }
+            .route()
+        ))
+    );
+
+    // 2 - verify the proofs
+    let verified_blocks = proofs.verify().expect("Proofs should be valid");
+    assert_eq!(blocks.as_slice(), verified_blocks.certified_blocks());
+
+    // 3 - validate certificate chain
+    let certificate = client
+        .certificate()
+        .verify_chain(&proofs.certificate_hash)
+        .await
+        .expect("Validating the chain should not fail");
+
+    // 4 - validate that the verified blocks proof is signed by the certificate
+    let message =
+        MessageBuilder::new().compute_cardano_blocks_proofs_message(&certificate, &verified_blocks);
+
+    assert!(
+        certificate.match_message(&message),
+        "Certificate and message did not match:\ncertificate_message: '{}'\n computed_message: '{}'",
+        certificate.signed_message,
+        message.compute_hash()
+    );
+}
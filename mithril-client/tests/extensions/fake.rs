@@ -141,9 +141,12 @@ impl FakeAggregator {
 }
 
 mod proof {
-    use mithril_client::common::{BlockNumber, ProtocolMessagePartKey};
-    use mithril_client::{CardanoTransactionsProofs, CardanoTransactionsSetProof};
-    use mithril_common::crypto_helper::{MKProof, ProtocolMkProof};
+    use mithril_client::common::{BlockNumber, CardanoBlock, ProtocolMessagePartKey};
+    use mithril_client::{
+        CardanoBlocksProofs, CardanoTransactionsProofs, CardanoTransactionsSetProof,
+    };
+    use mithril_common::crypto_helper::{MKProof, MKTreeStoreInMemory, ProtocolMkProof};
+    use mithril_common::entities::CardanoBlocksSetProof;
 
     use super::*;
 
@@ -188,6 +191,42 @@ mod proof {
                 routes::certificate::routes(self.calls.clone(), None, certificate_json),
             ))
         }
+
+        pub fn spawn_with_blocks_proofs(
+            &self,
+            blocks: &[CardanoBlock],
+            certificate_hash: &str,
+        ) -> TestHttpServer {
+            let set_proof =
+                CardanoBlocksSetProof::from_leaves::<MKTreeStoreInMemory>(blocks).unwrap();
+            let merkle_root = set_proof.merkle_root();
+
+            let proofs_json = serde_json::to_string(&CardanoBlocksProofs::new(
+                certificate_hash,
+                vec![set_proof.try_into().unwrap()],
+                vec![],
+                BlockNumber(9999),
+            ))
+            .unwrap();
+
+            let certificate = {
+                let mut cert = MithrilCertificate {
+                    hash: certificate_hash.to_string(),
+                    ..MithrilCertificate::dummy()
+                };
+                cert.protocol_message
+                    .set_message_part(ProtocolMessagePartKey::CardanoBlocksMerkleRoot, merkle_root);
+                cert.protocol_message
+                    .set_message_part(ProtocolMessagePartKey::LatestBlockNumber, 9999.to_string());
+                cert.signed_message = cert.protocol_message.compute_hash();
+                cert
+            };
+            let certificate_json = serde_json::to_string(&certificate).unwrap();
+
+            test_http_server(routes::proof::routes(self.calls.clone(), proofs_json).or(
+                routes::certificate::routes(self.calls.clone(), None, certificate_json),
+            ))
+        }
     }
 }
 
@@ -6,7 +6,8 @@ pub fn routes(
     calls: FakeAggregatorCalls,
     returned_value: String,
 ) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
-    proof_cardano_transaction(calls.clone(), returned_value)
+    proof_cardano_transaction(calls.clone(), returned_value.clone())
+        .or(proof_cardano_block(calls, returned_value))
 }
 
 /// Route: /proof/cardano-transaction
@@ -28,3 +29,23 @@ fn proof_cardano_transaction(
             )
         })
 }
+
+/// Route: /proof/cardano-block
+fn proof_cardano_block(
+    calls: FakeAggregatorCalls,
+    returned_value: String,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path!("proof" / "cardano-block")
+        .and(warp::get())
+        .and(warp::path::full().map(move |p| p))
+        .and(warp::query::raw())
+        .and(with_calls_middleware(calls.clone()))
+        .and_then(move |fullpath, query, calls| {
+            FakeAggregator::store_call_with_query_and_return_value(
+                fullpath,
+                query,
+                calls,
+                returned_value.clone(),
+            )
+        })
+}
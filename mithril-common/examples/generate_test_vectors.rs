@@ -0,0 +1,12 @@
+//! Generate the corpus of deterministic test vectors of the Mithril protocol.
+//!
+//! Usage: `make test-vectors`, which writes the corpus to `test_data/test_vectors_v{version}.json`.
+use mithril_common::test_utils::TestVectorsCorpus;
+use mithril_common::StdResult;
+
+fn main() -> StdResult<()> {
+    let corpus = TestVectorsCorpus::generate()?;
+    print!("{}", corpus.to_json()?);
+
+    Ok(())
+}
@@ -1,7 +1,9 @@
 use pallas_traverse::MultiEraBlock;
 use std::fmt::{Debug, Formatter};
 
-use crate::entities::{BlockNumber, CardanoTransaction, ChainPoint, SlotNumber, TransactionHash};
+use crate::entities::{
+    BlockNumber, CardanoBlock, CardanoTransaction, ChainPoint, SlotNumber, TransactionHash,
+};
 
 /// A block scanned from a Cardano database
 #[derive(Clone, PartialEq)]
@@ -68,6 +70,15 @@ impl ScannedBlock {
             })
             .collect::<Vec<_>>()
     }
+
+    /// Convert the scanned block into a [CardanoBlock], without its transactions.
+    pub fn to_cardano_block(&self) -> CardanoBlock {
+        CardanoBlock::new(
+            hex::encode(&self.block_hash),
+            self.block_number,
+            self.slot_number,
+        )
+    }
 }
 
 impl Debug for ScannedBlock {
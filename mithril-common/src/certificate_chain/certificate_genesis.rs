@@ -7,8 +7,8 @@ use thiserror::Error;
 
 use crate::{
     crypto_helper::{
-        ProtocolAggregateVerificationKey, ProtocolGenesisSignature, ProtocolGenesisSigner,
-        PROTOCOL_VERSION,
+        ProtocolAggregateVerificationKey, ProtocolGenesisSignature, ProtocolGenesisSignatureBundle,
+        ProtocolGenesisSigner, PROTOCOL_VERSION,
     },
     entities::{
         Certificate, CertificateMetadata, CertificateSignature, Epoch, ProtocolMessage,
@@ -80,6 +80,39 @@ impl CertificateGenesisProducer {
         epoch: Epoch,
         genesis_avk: ProtocolAggregateVerificationKey,
         genesis_signature: ProtocolGenesisSignature,
+    ) -> StdResult<Certificate> {
+        Self::create_genesis_certificate_with_signature(
+            protocol_parameters,
+            network,
+            epoch,
+            genesis_avk,
+            CertificateSignature::GenesisSignature(genesis_signature),
+        )
+    }
+
+    /// Create a Genesis Certificate signed with a bundle of threshold genesis signatures
+    pub fn create_threshold_genesis_certificate<T: Into<String>>(
+        protocol_parameters: ProtocolParameters,
+        network: T,
+        epoch: Epoch,
+        genesis_avk: ProtocolAggregateVerificationKey,
+        genesis_signature_bundle: ProtocolGenesisSignatureBundle,
+    ) -> StdResult<Certificate> {
+        Self::create_genesis_certificate_with_signature(
+            protocol_parameters,
+            network,
+            epoch,
+            genesis_avk,
+            CertificateSignature::GenesisThresholdSignature(genesis_signature_bundle),
+        )
+    }
+
+    fn create_genesis_certificate_with_signature<T: Into<String>>(
+        protocol_parameters: ProtocolParameters,
+        network: T,
+        epoch: Epoch,
+        genesis_avk: ProtocolAggregateVerificationKey,
+        genesis_signature: CertificateSignature,
     ) -> StdResult<Certificate> {
         let protocol_version = PROTOCOL_VERSION.to_string();
         let initiated_at = Utc::now();
@@ -102,7 +135,7 @@ impl CertificateGenesisProducer {
             metadata,
             genesis_protocol_message,
             genesis_avk,
-            CertificateSignature::GenesisSignature(genesis_signature),
+            genesis_signature,
         ))
     }
 }
@@ -3,6 +3,10 @@
 use anyhow::{anyhow, Context};
 use async_trait::async_trait;
 use hex::ToHex;
+#[cfg(not(target_arch = "wasm32"))]
+use mithril_stm::stm::StmAggrSig;
+#[cfg(not(target_arch = "wasm32"))]
+use slog::warn;
 use slog::{debug, Logger};
 use std::sync::Arc;
 use thiserror::Error;
@@ -10,7 +14,7 @@ use thiserror::Error;
 use super::CertificateRetriever;
 use crate::crypto_helper::{
     ProtocolAggregateVerificationKey, ProtocolGenesisError, ProtocolGenesisVerificationKey,
-    ProtocolMultiSignature,
+    ProtocolGenesisVerificationKeySet, ProtocolMultiSignature,
 };
 use crate::entities::{
     Certificate, CertificateSignature, ProtocolMessagePartKey, ProtocolParameters,
@@ -79,6 +83,13 @@ pub enum CertificateVerifierError {
     #[error("can't validate genesis certificate: given certificate isn't a genesis certificate")]
     InvalidGenesisCertificateProvided,
 
+    /// Error raised when [CertificateVerifier::verify_genesis_certificate] was called with a
+    /// threshold genesis certificate but no genesis verification key set was provided.
+    #[error(
+        "can't validate threshold genesis certificate: no genesis verification key set provided"
+    )]
+    MissingGenesisVerificationKeySet,
+
     /// Error raised when [CertificateVerifier::verify_standard_certificate] was called with a
     /// certificate that's not a standard certificate.
     #[error("can't validate standard certificate: given certificate isn't a standard certificate")]
@@ -128,12 +139,94 @@ pub trait CertificateVerifier: Send + Sync {
 
         Ok(())
     }
+
+    /// Verify that the Certificate Chain associated to a Certificate is valid, the multi
+    /// signatures of consecutive certificates sharing the same aggregate verification key being
+    /// verified at once.
+    ///
+    /// The given observer is notified of each validated certificate, in the order of the chain,
+    /// and can skip the verification of the certificates it already validated once the chain
+    /// crosses the epoch of its first certificate.
+    ///
+    /// The default implementation verifies the certificates one by one with
+    /// [verify_certificate][CertificateVerifier::verify_certificate] and notifies the observer of
+    /// each of them, without skipping any certificate.
+    async fn verify_certificate_chain_with_observer(
+        &self,
+        certificate: Certificate,
+        genesis_verification_key: &ProtocolGenesisVerificationKey,
+        observer: Arc<dyn CertificateChainVerificationObserver>,
+    ) -> StdResult<()> {
+        let mut certificate = certificate;
+        loop {
+            let previous_certificate = self
+                .verify_certificate(&certificate, genesis_verification_key)
+                .await?;
+            observer.on_certificate_validated(&certificate).await?;
+
+            match previous_certificate {
+                Some(previous_certificate) => certificate = previous_certificate,
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+/// Observer of a certificate chain verification done with
+/// [CertificateVerifier::verify_certificate_chain_with_observer].
+#[cfg_attr(target_family = "wasm", async_trait(?Send))]
+#[cfg_attr(not(target_family = "wasm"), async_trait)]
+pub trait CertificateChainVerificationObserver: Send + Sync {
+    /// Get the previous hash of the given certificate if it was already validated, its
+    /// verification being then skipped.
+    async fn get_validated_previous_hash(
+        &self,
+        _certificate_hash: &str,
+    ) -> StdResult<Option<String>> {
+        Ok(None)
+    }
+
+    /// Called when the verification of a certificate is skipped because it was already
+    /// validated.
+    async fn on_certificate_skipped(&self, _certificate_hash: &str) -> StdResult<()> {
+        Ok(())
+    }
+
+    /// Called when a certificate is validated.
+    async fn on_certificate_validated(&self, _certificate: &Certificate) -> StdResult<()> {
+        Ok(())
+    }
+}
+
+/// Observer that does not skip any certificate.
+struct NoCertificateChainVerificationObserver;
+
+#[cfg_attr(target_family = "wasm", async_trait(?Send))]
+#[cfg_attr(not(target_family = "wasm"), async_trait)]
+impl CertificateChainVerificationObserver for NoCertificateChainVerificationObserver {}
+
+enum CertificateToVerify {
+    /// The certificate is already downloaded.
+    Downloaded { certificate: Box<Certificate> },
+    /// The certificate is not downloaded yet (since its child was skipped).
+    ToDownload { hash: String },
+}
+
+impl CertificateToVerify {
+    fn hash(&self) -> &str {
+        match self {
+            CertificateToVerify::Downloaded { certificate } => &certificate.hash,
+            CertificateToVerify::ToDownload { hash } => hash,
+        }
+    }
 }
 
 /// MithrilCertificateVerifier is an implementation of the CertificateVerifier
 pub struct MithrilCertificateVerifier {
     logger: Logger,
     certificate_retriever: Arc<dyn CertificateRetriever>,
+    batch_multi_signature_verification: bool,
+    genesis_verification_key_set: Option<ProtocolGenesisVerificationKeySet>,
 }
 
 impl MithrilCertificateVerifier {
@@ -143,9 +236,31 @@ impl MithrilCertificateVerifier {
         Self {
             logger: logger.new_with_component_name::<Self>(),
             certificate_retriever,
+            batch_multi_signature_verification: false,
+            genesis_verification_key_set: None,
         }
     }
 
+    /// Set the genesis verification key set used to verify threshold genesis certificates.
+    pub fn with_genesis_verification_key_set(
+        mut self,
+        genesis_verification_key_set: ProtocolGenesisVerificationKeySet,
+    ) -> Self {
+        self.genesis_verification_key_set = Some(genesis_verification_key_set);
+        self
+    }
+
+    /// Enable the batch verification of the multi signatures when verifying a certificate chain
+    /// with [CertificateVerifier::verify_certificate_chain].
+    ///
+    /// The multi signatures of consecutive certificates sharing the same aggregate verification
+    /// key are verified at once. If a batch fails, its multi signatures are verified one by one
+    /// to find the invalid certificate.
+    pub fn with_batch_multi_signature_verification(mut self) -> Self {
+        self.batch_multi_signature_verification = true;
+        self
+    }
+
     async fn fetch_previous_certificate(
         &self,
         certificate: &Certificate,
@@ -179,6 +294,154 @@ impl MithrilCertificateVerifier {
             .map_err(|e| CertificateVerifierError::VerifyMultiSignature(e.to_string()))
     }
 
+    fn verify_certificate_multi_signature(&self, certificate: &Certificate) -> StdResult<()> {
+        let multi_signature = match &certificate.signature {
+            CertificateSignature::MultiSignature(_, signature) => Ok(signature),
+            _ => Err(CertificateVerifierError::InvalidStandardCertificateProvided),
+        }?;
+        self.verify_multi_signature(
+            certificate.signed_message.as_bytes(),
+            multi_signature,
+            &certificate.aggregate_verification_key,
+            &certificate.metadata.protocol_parameters,
+        )?;
+
+        Ok(())
+    }
+
+    /// Verify the multi signatures of the given standard certificates, at once when possible.
+    ///
+    /// If the batch verification fails, the multi signatures are verified one by one to find the
+    /// invalid certificate.
+    fn verify_certificates_multi_signatures(&self, certificates: &[Certificate]) -> StdResult<()> {
+        #[cfg(not(target_arch = "wasm32"))]
+        if certificates.len() > 1 {
+            match self.batch_verify_multi_signatures(certificates) {
+                Ok(()) => return Ok(()),
+                Err(error) => warn!(
+                    self.logger, "Batch verification of multi signatures failed, verifying them one by one";
+                    "error" => ?error,
+                    "certificates_hashes" => ?certificates.iter().map(|c| &c.hash).collect::<Vec<_>>(),
+                ),
+            }
+        }
+
+        for certificate in certificates {
+            self.verify_certificate_multi_signature(certificate)
+                .with_context(|| {
+                    format!(
+                        "Invalid multi signature for certificate: '{}'",
+                        certificate.hash
+                    )
+                })?;
+        }
+
+        Ok(())
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn batch_verify_multi_signatures(&self, certificates: &[Certificate]) -> StdResult<()> {
+        debug!(
+            self.logger,
+            "Batch verify {} multi signatures",
+            certificates.len()
+        );
+        let mut multi_signatures = Vec::with_capacity(certificates.len());
+        let mut messages = Vec::with_capacity(certificates.len());
+        let mut aggregate_verification_keys = Vec::with_capacity(certificates.len());
+        let mut protocol_parameters = Vec::with_capacity(certificates.len());
+        for certificate in certificates {
+            match &certificate.signature {
+                CertificateSignature::MultiSignature(_, signature) => {
+                    multi_signatures.push((**signature).clone());
+                }
+                _ => {
+                    return Err(anyhow!(
+                        CertificateVerifierError::InvalidStandardCertificateProvided
+                    ))
+                }
+            }
+            messages.push(certificate.signed_message.as_bytes().to_vec());
+            aggregate_verification_keys.push((*certificate.aggregate_verification_key).clone());
+            protocol_parameters.push(certificate.metadata.protocol_parameters.to_owned().into());
+        }
+
+        StmAggrSig::batch_verify(
+            &multi_signatures,
+            &messages,
+            &aggregate_verification_keys,
+            &protocol_parameters,
+        )
+        .map_err(|e| {
+            anyhow!(CertificateVerifierError::VerifyMultiSignature(
+                e.to_string()
+            ))
+        })
+    }
+
+    /// Verify a certificate, except for its multi signature, and return its previous certificate
+    /// if it is not a genesis certificate.
+    async fn verify_certificate_without_multi_signature(
+        &self,
+        certificate: &Certificate,
+        genesis_verification_key: &ProtocolGenesisVerificationKey,
+    ) -> StdResult<Option<Certificate>> {
+        debug!(
+            self.logger, "Verifying certificate without its multi signature";
+            "certificate_hash" => &certificate.hash,
+            "certificate_previous_hash" => &certificate.previous_hash,
+            "certificate_epoch" => ?certificate.epoch,
+            "certificate_signed_entity_type" => ?certificate.signed_entity_type(),
+        );
+
+        if certificate.is_genesis() {
+            self.verify_genesis_certificate(certificate, genesis_verification_key)
+                .await?;
+
+            return Ok(None);
+        }
+
+        let previous_certificate = self.fetch_previous_certificate(certificate).await?;
+        self.verify_is_not_in_infinite_loop(certificate)?;
+        self.verify_hash_matches_content(certificate)?;
+        self.verify_signed_message_matches_hashed_protocol_message(certificate)?;
+        self.verify_standard_certificate_chaining(certificate, &previous_certificate)?;
+
+        Ok(Some(previous_certificate))
+    }
+
+    /// Verify the multi signatures of the pending certificates and notify the observer that
+    /// they are validated.
+    async fn verify_pending_multi_signatures(
+        &self,
+        pending_certificates: &mut Vec<Certificate>,
+        observer: &dyn CertificateChainVerificationObserver,
+    ) -> StdResult<()> {
+        self.verify_certificates_multi_signatures(pending_certificates)?;
+        for certificate in pending_certificates.drain(..) {
+            observer.on_certificate_validated(&certificate).await?;
+        }
+
+        Ok(())
+    }
+
+    fn verify_standard_certificate_chaining(
+        &self,
+        certificate: &Certificate,
+        previous_certificate: &Certificate,
+    ) -> StdResult<()> {
+        self.verify_epoch_matches_protocol_message(certificate)?;
+        self.verify_epoch_chaining(certificate, previous_certificate)?;
+        self.verify_previous_hash_matches_previous_certificate_hash(
+            certificate,
+            previous_certificate,
+        )?;
+        self.verify_aggregate_verification_key_chaining(certificate, previous_certificate)?;
+        self.verify_protocol_parameters_chaining(certificate, previous_certificate)?;
+
+        Ok(())
+    }
+
     fn verify_is_not_in_infinite_loop(&self, certificate: &Certificate) -> StdResult<()> {
         if certificate.is_chaining_to_itself() {
             return Err(anyhow!(
@@ -337,18 +600,35 @@ impl CertificateVerifier for MithrilCertificateVerifier {
         genesis_certificate: &Certificate,
         genesis_verification_key: &ProtocolGenesisVerificationKey,
     ) -> StdResult<()> {
-        let genesis_signature = match &genesis_certificate.signature {
-            CertificateSignature::GenesisSignature(signature) => Ok(signature),
-            _ => Err(CertificateVerifierError::InvalidGenesisCertificateProvided),
-        }?;
+        if !genesis_certificate.is_genesis() {
+            return Err(anyhow!(
+                CertificateVerifierError::InvalidGenesisCertificateProvided
+            ));
+        }
         self.verify_hash_matches_content(genesis_certificate)?;
         self.verify_signed_message_matches_hashed_protocol_message(genesis_certificate)?;
-        genesis_verification_key
-            .verify(
-                genesis_certificate.signed_message.as_bytes(),
-                genesis_signature,
-            )
-            .with_context(|| "Certificate verifier failed verifying a genesis certificate")?;
+        match &genesis_certificate.signature {
+            CertificateSignature::GenesisSignature(genesis_signature) => genesis_verification_key
+                .verify(
+                    genesis_certificate.signed_message.as_bytes(),
+                    genesis_signature,
+                )
+                .with_context(|| "Certificate verifier failed verifying a genesis certificate")?,
+            CertificateSignature::GenesisThresholdSignature(genesis_signature_bundle) => self
+                .genesis_verification_key_set
+                .as_ref()
+                .ok_or(CertificateVerifierError::MissingGenesisVerificationKeySet)?
+                .verify(
+                    genesis_certificate.signed_message.as_bytes(),
+                    genesis_signature_bundle,
+                )
+                .with_context(|| {
+                    "Certificate verifier failed verifying a threshold genesis certificate"
+                })?,
+            CertificateSignature::MultiSignature(..) => {
+                unreachable!("a genesis certificate can not have a multi signature")
+            }
+        }
         self.verify_epoch_matches_protocol_message(genesis_certificate)?;
 
         Ok(())
@@ -359,27 +639,19 @@ impl CertificateVerifier for MithrilCertificateVerifier {
         certificate: &Certificate,
         previous_certificate: &Certificate,
     ) -> StdResult<()> {
-        let multi_signature = match &certificate.signature {
-            CertificateSignature::MultiSignature(_, signature) => Ok(signature),
-            _ => Err(CertificateVerifierError::InvalidStandardCertificateProvided),
-        }?;
+        if !matches!(
+            &certificate.signature,
+            CertificateSignature::MultiSignature(_, _)
+        ) {
+            return Err(anyhow!(
+                CertificateVerifierError::InvalidStandardCertificateProvided
+            ));
+        }
         self.verify_is_not_in_infinite_loop(certificate)?;
         self.verify_hash_matches_content(certificate)?;
         self.verify_signed_message_matches_hashed_protocol_message(certificate)?;
-        self.verify_multi_signature(
-            certificate.signed_message.as_bytes(),
-            multi_signature,
-            &certificate.aggregate_verification_key,
-            &certificate.metadata.protocol_parameters,
-        )?;
-        self.verify_epoch_matches_protocol_message(certificate)?;
-        self.verify_epoch_chaining(certificate, previous_certificate)?;
-        self.verify_previous_hash_matches_previous_certificate_hash(
-            certificate,
-            previous_certificate,
-        )?;
-        self.verify_aggregate_verification_key_chaining(certificate, previous_certificate)?;
-        self.verify_protocol_parameters_chaining(certificate, previous_certificate)?;
+        self.verify_certificate_multi_signature(certificate)?;
+        self.verify_standard_certificate_chaining(certificate, previous_certificate)?;
 
         Ok(())
     }
@@ -399,7 +671,8 @@ impl CertificateVerifier for MithrilCertificateVerifier {
         );
 
         match &certificate.signature {
-            CertificateSignature::GenesisSignature(_) => {
+            CertificateSignature::GenesisSignature(_)
+            | CertificateSignature::GenesisThresholdSignature(_) => {
                 self.verify_genesis_certificate(certificate, genesis_verification_key)
                     .await?;
 
@@ -414,6 +687,120 @@ impl CertificateVerifier for MithrilCertificateVerifier {
             }
         }
     }
+
+    async fn verify_certificate_chain(
+        &self,
+        certificate: Certificate,
+        genesis_verification_key: &ProtocolGenesisVerificationKey,
+    ) -> StdResult<()> {
+        if self.batch_multi_signature_verification {
+            return self
+                .verify_certificate_chain_with_observer(
+                    certificate,
+                    genesis_verification_key,
+                    Arc::new(NoCertificateChainVerificationObserver),
+                )
+                .await;
+        }
+
+        let mut certificate = certificate;
+        while let Some(previous_certificate) = self
+            .verify_certificate(&certificate, genesis_verification_key)
+            .await?
+        {
+            certificate = previous_certificate;
+        }
+
+        Ok(())
+    }
+
+    /// Pending multi signatures are verified before returning any other error, so that an
+    /// invalid multi signature of a more recent certificate of the chain is reported first.
+    async fn verify_certificate_chain_with_observer(
+        &self,
+        certificate: Certificate,
+        genesis_verification_key: &ProtocolGenesisVerificationKey,
+        observer: Arc<dyn CertificateChainVerificationObserver>,
+    ) -> StdResult<()> {
+        // Validated certificates are not skipped until the chain crosses an epoch boundary,
+        // this is necessary to ensure that the AVK chaining is correct
+        let start_epoch = certificate.epoch;
+        let mut can_skip_validated_certificates = false;
+        let mut pending_certificates: Vec<Certificate> = Vec::new();
+        let mut next_certificate = Some(CertificateToVerify::Downloaded {
+            certificate: Box::new(certificate),
+        });
+        while let Some(certificate_to_verify) = next_certificate {
+            if can_skip_validated_certificates {
+                if let Some(previous_hash) = observer
+                    .get_validated_previous_hash(certificate_to_verify.hash())
+                    .await?
+                {
+                    self.verify_pending_multi_signatures(
+                        &mut pending_certificates,
+                        observer.as_ref(),
+                    )
+                    .await?;
+                    observer
+                        .on_certificate_skipped(certificate_to_verify.hash())
+                        .await?;
+                    next_certificate = Some(CertificateToVerify::ToDownload {
+                        hash: previous_hash,
+                    });
+                    continue;
+                }
+            }
+
+            let certificate = match certificate_to_verify {
+                CertificateToVerify::Downloaded { certificate } => *certificate,
+                CertificateToVerify::ToDownload { hash } => self
+                    .certificate_retriever
+                    .get_certificate_details(&hash)
+                    .await
+                    .map_err(|e| anyhow!(e))
+                    .with_context(|| "Can not retrieve certificate during verification")?,
+            };
+
+            if pending_certificates.last().is_some_and(|last| {
+                last.aggregate_verification_key != certificate.aggregate_verification_key
+            }) {
+                self.verify_pending_multi_signatures(&mut pending_certificates, observer.as_ref())
+                    .await?;
+            }
+
+            match self
+                .verify_certificate_without_multi_signature(&certificate, genesis_verification_key)
+                .await
+            {
+                Ok(Some(previous_certificate)) => {
+                    can_skip_validated_certificates |= previous_certificate.epoch != start_epoch;
+                    pending_certificates.push(certificate);
+                    next_certificate = Some(CertificateToVerify::Downloaded {
+                        certificate: Box::new(previous_certificate),
+                    });
+                }
+                Ok(None) => {
+                    self.verify_pending_multi_signatures(
+                        &mut pending_certificates,
+                        observer.as_ref(),
+                    )
+                    .await?;
+                    observer.on_certificate_validated(&certificate).await?;
+                    next_certificate = None;
+                }
+                Err(error) => {
+                    self.verify_pending_multi_signatures(
+                        &mut pending_certificates,
+                        observer.as_ref(),
+                    )
+                    .await?;
+                    return Err(error);
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -428,7 +815,9 @@ mod tests {
     use super::*;
 
     use crate::certificate_chain::{CertificateRetrieverError, FakeCertificaterRetriever};
-    use crate::crypto_helper::{tests_setup::*, ProtocolClerk};
+    use crate::crypto_helper::{
+        tests_setup::*, ProtocolClerk, ProtocolGenesisSignatureBundle, ProtocolGenesisSigner,
+    };
     use crate::test_utils::{
         CertificateChainBuilder, CertificateChainBuilderContext, MithrilFixtureBuilder, TestLogger,
     };
@@ -546,6 +935,94 @@ mod tests {
         verify.expect("verify_genesis_certificate should not fail");
     }
 
+    fn sign_threshold_genesis_certificate(
+        genesis_certificate: &Certificate,
+        total_genesis_signers: usize,
+        threshold: usize,
+        total_signatures: usize,
+    ) -> (Certificate, ProtocolGenesisVerificationKeySet) {
+        let genesis_signers: Vec<_> = (0..total_genesis_signers)
+            .map(|_| ProtocolGenesisSigner::create_non_deterministic_genesis_signer())
+            .collect();
+        let genesis_verification_key_set = ProtocolGenesisVerificationKeySet::new(
+            threshold,
+            genesis_signers
+                .iter()
+                .map(|signer| signer.verification_key())
+                .collect(),
+        )
+        .unwrap();
+        let genesis_signature_bundle = ProtocolGenesisSignatureBundle::new(
+            genesis_signers[0..total_signatures]
+                .iter()
+                .map(|signer| signer.sign_share(genesis_certificate.signed_message.as_bytes()))
+                .collect(),
+        );
+        let mut threshold_genesis_certificate = genesis_certificate.clone();
+        threshold_genesis_certificate.signature =
+            CertificateSignature::GenesisThresholdSignature(genesis_signature_bundle);
+        threshold_genesis_certificate.hash = threshold_genesis_certificate.compute_hash();
+
+        (threshold_genesis_certificate, genesis_verification_key_set)
+    }
+
+    #[tokio::test]
+    async fn verify_threshold_genesis_certificate_success() {
+        let (fake_certificates, genesis_verifier) = setup_certificate_chain(5, 1);
+        let (genesis_certificate, genesis_verification_key_set) =
+            sign_threshold_genesis_certificate(fake_certificates.last().unwrap(), 3, 2, 2);
+        let verifier = MockDependencyInjector::new()
+            .build_certificate_verifier()
+            .with_genesis_verification_key_set(genesis_verification_key_set);
+
+        verifier
+            .verify_genesis_certificate(
+                &genesis_certificate,
+                &genesis_verifier.to_verification_key(),
+            )
+            .await
+            .expect("verify_genesis_certificate should not fail");
+    }
+
+    #[tokio::test]
+    async fn verify_threshold_genesis_certificate_fails_if_threshold_is_not_reached() {
+        let (fake_certificates, genesis_verifier) = setup_certificate_chain(5, 1);
+        let (genesis_certificate, genesis_verification_key_set) =
+            sign_threshold_genesis_certificate(fake_certificates.last().unwrap(), 3, 2, 1);
+        let verifier = MockDependencyInjector::new()
+            .build_certificate_verifier()
+            .with_genesis_verification_key_set(genesis_verification_key_set);
+
+        verifier
+            .verify_genesis_certificate(
+                &genesis_certificate,
+                &genesis_verifier.to_verification_key(),
+            )
+            .await
+            .expect_err("verify_genesis_certificate should fail");
+    }
+
+    #[tokio::test]
+    async fn verify_threshold_genesis_certificate_fails_without_genesis_verification_key_set() {
+        let (fake_certificates, genesis_verifier) = setup_certificate_chain(5, 1);
+        let (genesis_certificate, _) =
+            sign_threshold_genesis_certificate(fake_certificates.last().unwrap(), 3, 2, 2);
+        let verifier = MockDependencyInjector::new().build_certificate_verifier();
+
+        let error = verifier
+            .verify_genesis_certificate(
+                &genesis_certificate,
+                &genesis_verifier.to_verification_key(),
+            )
+            .await
+            .expect_err("verify_genesis_certificate should fail");
+
+        assert_error_matches!(
+            CertificateVerifierError::MissingGenesisVerificationKeySet,
+            error
+        )
+    }
+
     #[tokio::test]
     async fn verify_genesis_certificate_fails_if_is_not_genesis() {
         let (total_certificates, certificates_per_epoch) = (5, 1);
@@ -1030,6 +1507,92 @@ mod tests {
         assert!(!verifier.has_unverified_certificates().await);
     }
 
+    #[tokio::test]
+    async fn default_verify_certificate_chain_with_observer_verifies_and_notifies_all_chained_certificates(
+    ) {
+        struct SequentialCertificateVerifier(MithrilCertificateVerifier);
+
+        #[async_trait]
+        impl CertificateVerifier for SequentialCertificateVerifier {
+            async fn verify_genesis_certificate(
+                &self,
+                genesis_certificate: &Certificate,
+                genesis_verification_key: &ProtocolGenesisVerificationKey,
+            ) -> StdResult<()> {
+                self.0
+                    .verify_genesis_certificate(genesis_certificate, genesis_verification_key)
+                    .await
+            }
+
+            async fn verify_standard_certificate(
+                &self,
+                certificate: &Certificate,
+                previous_certificate: &Certificate,
+            ) -> StdResult<()> {
+                self.0
+                    .verify_standard_certificate(certificate, previous_certificate)
+                    .await
+            }
+
+            async fn verify_certificate(
+                &self,
+                certificate: &Certificate,
+                genesis_verification_key: &ProtocolGenesisVerificationKey,
+            ) -> StdResult<Option<Certificate>> {
+                self.0
+                    .verify_certificate(certificate, genesis_verification_key)
+                    .await
+            }
+        }
+
+        #[derive(Default)]
+        struct ValidatedCertificatesObserver {
+            validated_certificates_hashes: Mutex<Vec<String>>,
+        }
+
+        #[async_trait]
+        impl CertificateChainVerificationObserver for ValidatedCertificatesObserver {
+            async fn on_certificate_validated(&self, certificate: &Certificate) -> StdResult<()> {
+                self.validated_certificates_hashes
+                    .lock()
+                    .await
+                    .push(certificate.hash.clone());
+                Ok(())
+            }
+        }
+
+        let (total_certificates, certificates_per_epoch) = (5, 2);
+        let (fake_certificates, genesis_verifier) =
+            setup_certificate_chain(total_certificates, certificates_per_epoch);
+        let verifier = SequentialCertificateVerifier(MithrilCertificateVerifier::new(
+            TestLogger::stdout(),
+            Arc::new(FakeCertificaterRetriever::from_certificates(
+                &fake_certificates,
+            )),
+        ));
+        let observer = Arc::new(ValidatedCertificatesObserver::default());
+
+        verifier
+            .verify_certificate_chain_with_observer(
+                fake_certificates[0].clone(),
+                &genesis_verifier.to_verification_key(),
+                observer.clone(),
+            )
+            .await
+            .expect("verify_certificate_chain_with_observer should not fail");
+
+        let mut expected_hashes = vec![];
+        let mut next_hash = fake_certificates[0].hash.clone();
+        while let Some(certificate) = fake_certificates.iter().find(|c| c.hash == next_hash) {
+            expected_hashes.push(certificate.hash.clone());
+            next_hash = certificate.previous_hash.clone();
+        }
+        assert_eq!(
+            expected_hashes,
+            *observer.validated_certificates_hashes.lock().await
+        );
+    }
+
     #[tokio::test]
     async fn verify_certificate_chain_success_when_chain_is_valid() {
         let (total_certificates, certificates_per_epoch) = (7, 2);
@@ -1157,4 +1720,239 @@ mod tests {
             error
         )
     }
+
+    mod batch_multi_signature_verification {
+        use super::*;
+
+        fn build_batch_certificate_verifier(
+            certificates: &[Certificate],
+        ) -> MithrilCertificateVerifier {
+            let certificate_retriever = FakeCertificaterRetriever::from_certificates(certificates);
+            MithrilCertificateVerifier::new(TestLogger::stdout(), Arc::new(certificate_retriever))
+                .with_batch_multi_signature_verification()
+        }
+
+        #[tokio::test]
+        async fn verify_certificate_chain_success_when_chain_is_valid() {
+            let (total_certificates, certificates_per_epoch) = (7, 2);
+            let (fake_certificates, genesis_verifier) =
+                setup_certificate_chain(total_certificates, certificates_per_epoch);
+            let verifier = build_batch_certificate_verifier(&fake_certificates);
+
+            verifier
+                .verify_certificate_chain(
+                    fake_certificates[0].clone(),
+                    &genesis_verifier.to_verification_key(),
+                )
+                .await
+                .expect("verify_certificate_chain should not fail");
+        }
+
+        #[tokio::test]
+        async fn verify_certificate_chain_fails_when_chain_is_tampered() {
+            let (total_certificates, certificates_per_epoch) = (7, 2);
+            let (mut fake_certificates, genesis_verifier) =
+                setup_certificate_chain(total_certificates, certificates_per_epoch);
+            let index_certificate_fail = (total_certificates / 2) as usize;
+            fake_certificates[index_certificate_fail].signed_message =
+                "tampered-message".to_string();
+            let verifier = build_batch_certificate_verifier(&fake_certificates);
+
+            let error = verifier
+                .verify_certificate_chain(
+                    fake_certificates[0].clone(),
+                    &genesis_verifier.to_verification_key(),
+                )
+                .await
+                .expect_err("verify_certificate_chain should fail");
+
+            assert_error_matches!(CertificateVerifierError::CertificateHashUnmatch, error)
+        }
+
+        #[tokio::test]
+        async fn verify_certificate_chain_fails_and_pinpoints_certificate_with_invalid_multi_signature(
+        ) {
+            let (total_certificates, certificates_per_epoch) = (7, 3);
+            let (mut fake_certificates, genesis_verifier) =
+                setup_certificate_chain(total_certificates, certificates_per_epoch);
+            assert_eq!(fake_certificates[0].epoch, fake_certificates[1].epoch);
+            fake_certificates[1].signature = fake_certificates[0].signature.clone();
+            fake_certificates[1].hash = fake_certificates[1].compute_hash();
+            fake_certificates[0].previous_hash = fake_certificates[1].hash.clone();
+            fake_certificates[0].hash = fake_certificates[0].compute_hash();
+            let verifier = build_batch_certificate_verifier(&fake_certificates);
+
+            let error = verifier
+                .verify_certificate_chain(
+                    fake_certificates[0].clone(),
+                    &genesis_verifier.to_verification_key(),
+                )
+                .await
+                .expect_err("verify_certificate_chain should fail");
+
+            assert!(
+                matches!(
+                    error.downcast_ref::<CertificateVerifierError>(),
+                    Some(CertificateVerifierError::VerifyMultiSignature(_))
+                ),
+                "unexpected error type: got {error:?}"
+            );
+            assert!(
+                format!("{error:?}").contains(&fake_certificates[1].hash),
+                "error should mention the certificate with the invalid multi signature: {error:?}"
+            );
+        }
+
+        #[tokio::test]
+        async fn verify_certificate_chain_reports_invalid_multi_signature_before_later_chaining_error(
+        ) {
+            let (total_certificates, certificates_per_epoch) = (7, 3);
+            let (mut fake_certificates, genesis_verifier) =
+                setup_certificate_chain(total_certificates, certificates_per_epoch);
+            fake_certificates[0].signature = fake_certificates[1].signature.clone();
+            fake_certificates[0].hash = fake_certificates[0].compute_hash();
+            let index_certificate_fail = (total_certificates / 2) as usize;
+            fake_certificates[index_certificate_fail].signed_message =
+                "tampered-message".to_string();
+            let verifier = build_batch_certificate_verifier(&fake_certificates);
+
+            let error = verifier
+                .verify_certificate_chain(
+                    fake_certificates[0].clone(),
+                    &genesis_verifier.to_verification_key(),
+                )
+                .await
+                .expect_err("verify_certificate_chain should fail");
+
+            assert!(
+                matches!(
+                    error.downcast_ref::<CertificateVerifierError>(),
+                    Some(CertificateVerifierError::VerifyMultiSignature(_))
+                ),
+                "unexpected error type: got {error:?}"
+            );
+        }
+
+        #[derive(Default)]
+        struct CertificateChainVerificationObserverTest {
+            validated_previous_hashes: HashMap<String, String>,
+            skipped_certificates_hashes: Mutex<Vec<String>>,
+            validated_certificates_hashes: Mutex<Vec<String>>,
+        }
+
+        #[async_trait]
+        impl CertificateChainVerificationObserver for CertificateChainVerificationObserverTest {
+            async fn get_validated_previous_hash(
+                &self,
+                certificate_hash: &str,
+            ) -> StdResult<Option<String>> {
+                Ok(self
+                    .validated_previous_hashes
+                    .get(certificate_hash)
+                    .cloned())
+            }
+
+            async fn on_certificate_skipped(&self, certificate_hash: &str) -> StdResult<()> {
+                self.skipped_certificates_hashes
+                    .lock()
+                    .await
+                    .push(certificate_hash.to_string());
+                Ok(())
+            }
+
+            async fn on_certificate_validated(&self, certificate: &Certificate) -> StdResult<()> {
+                self.validated_certificates_hashes
+                    .lock()
+                    .await
+                    .push(certificate.hash.clone());
+                Ok(())
+            }
+        }
+
+        fn chain_hashes_from(certificates: &[Certificate], hash: &str) -> Vec<String> {
+            let mut hashes = vec![];
+            let mut next_hash = hash.to_string();
+            while let Some(certificate) = certificates.iter().find(|c| c.hash == next_hash) {
+                hashes.push(certificate.hash.clone());
+                next_hash = certificate.previous_hash.clone();
+            }
+            hashes
+        }
+
+        #[tokio::test]
+        async fn verify_certificate_chain_with_observer_notifies_validated_certificates_in_chain_order(
+        ) {
+            let (total_certificates, certificates_per_epoch) = (7, 2);
+            let (fake_certificates, genesis_verifier) =
+                setup_certificate_chain(total_certificates, certificates_per_epoch);
+            let verifier = build_batch_certificate_verifier(&fake_certificates);
+            let observer = Arc::new(CertificateChainVerificationObserverTest::default());
+
+            verifier
+                .verify_certificate_chain_with_observer(
+                    fake_certificates[0].clone(),
+                    &genesis_verifier.to_verification_key(),
+                    observer.clone(),
+                )
+                .await
+                .expect("verify_certificate_chain_with_observer should not fail");
+
+            assert_eq!(
+                chain_hashes_from(&fake_certificates, &fake_certificates[0].hash),
+                *observer.validated_certificates_hashes.lock().await
+            );
+            assert!(observer.skipped_certificates_hashes.lock().await.is_empty());
+        }
+
+        #[tokio::test]
+        async fn verify_certificate_chain_with_observer_skips_validated_certificates_after_crossing_an_epoch_boundary(
+        ) {
+            let (total_certificates, certificates_per_epoch) = (7, 2);
+            let (fake_certificates, genesis_verifier) =
+                setup_certificate_chain(total_certificates, certificates_per_epoch);
+            let chain_hashes = chain_hashes_from(&fake_certificates, &fake_certificates[0].hash);
+            let genesis_hash = chain_hashes.last().unwrap().clone();
+            let verifier = build_batch_certificate_verifier(&fake_certificates);
+            // Every certificate but the genesis is known as already validated
+            let observer = Arc::new(CertificateChainVerificationObserverTest {
+                validated_previous_hashes: chain_hashes
+                    .windows(2)
+                    .map(|pair| (pair[0].clone(), pair[1].clone()))
+                    .collect(),
+                ..Default::default()
+            });
+
+            verifier
+                .verify_certificate_chain_with_observer(
+                    fake_certificates[0].clone(),
+                    &genesis_verifier.to_verification_key(),
+                    observer.clone(),
+                )
+                .await
+                .expect("verify_certificate_chain_with_observer should not fail");
+
+            let first_skipped_index = chain_hashes
+                .iter()
+                .position(|hash| {
+                    fake_certificates
+                        .iter()
+                        .find(|c| &c.hash == hash)
+                        .is_some_and(|c| c.epoch != fake_certificates[0].epoch)
+                })
+                .unwrap();
+            let last_index = chain_hashes.len() - 1;
+            assert_eq!(
+                chain_hashes[first_skipped_index..last_index].to_vec(),
+                *observer.skipped_certificates_hashes.lock().await
+            );
+            assert_eq!(
+                [
+                    chain_hashes[..first_skipped_index].to_vec(),
+                    vec![genesis_hash]
+                ]
+                .concat(),
+                *observer.validated_certificates_hashes.lock().await
+            );
+        }
+    }
 }
@@ -10,7 +10,8 @@ cfg_test_tools! {
 pub use certificate_genesis::CertificateGenesisProducer;
 pub use certificate_retriever::{CertificateRetriever, CertificateRetrieverError};
 pub use certificate_verifier::{
-    CertificateVerifier, CertificateVerifierError, MithrilCertificateVerifier,
+    CertificateChainVerificationObserver, CertificateVerifier, CertificateVerifierError,
+    MithrilCertificateVerifier,
 };
 
 cfg_test_tools! {
@@ -447,6 +447,27 @@ impl CardanoCliChainObserver {
 
         Ok(Some(stake_distribution))
     }
+
+    async fn get_kes_period_info(&self, opcert: &OpCert) -> Result<Value, ChainObserverError> {
+        let dir = std::env::temp_dir().join("mithril_kes_period");
+        fs::create_dir_all(&dir).map_err(|e| ChainObserverError::General(e.into()))?;
+        let opcert_file = dir.join(format!("opcert_kes_period-{}", opcert.compute_hash()));
+        opcert
+            .to_file(&opcert_file)
+            .map_err(|e| ChainObserverError::General(e.into()))?;
+        let output = self
+            .cli_runner
+            .launch_kes_period(opcert_file.to_str().unwrap())
+            .await
+            .map_err(ChainObserverError::General)?;
+        let first_left_curly_bracket_index = output.find('{').unwrap_or_default();
+        let output_cleaned = output.split_at(first_left_curly_bracket_index).1;
+        let v: Value = serde_json::from_str(output_cleaned)
+            .with_context(|| format!("output was = '{output}'"))
+            .map_err(ChainObserverError::InvalidContent)?;
+
+        Ok(v)
+    }
 }
 
 #[async_trait]
@@ -542,22 +563,7 @@ impl ChainObserver for CardanoCliChainObserver {
         &self,
         opcert: &OpCert,
     ) -> Result<Option<KESPeriod>, ChainObserverError> {
-        let dir = std::env::temp_dir().join("mithril_kes_period");
-        fs::create_dir_all(&dir).map_err(|e| ChainObserverError::General(e.into()))?;
-        let opcert_file = dir.join(format!("opcert_kes_period-{}", opcert.compute_hash()));
-        opcert
-            .to_file(&opcert_file)
-            .map_err(|e| ChainObserverError::General(e.into()))?;
-        let output = self
-            .cli_runner
-            .launch_kes_period(opcert_file.to_str().unwrap())
-            .await
-            .map_err(ChainObserverError::General)?;
-        let first_left_curly_bracket_index = output.find('{').unwrap_or_default();
-        let output_cleaned = output.split_at(first_left_curly_bracket_index).1;
-        let v: Value = serde_json::from_str(output_cleaned)
-            .with_context(|| format!("output was = '{output}'"))
-            .map_err(ChainObserverError::InvalidContent)?;
+        let v = self.get_kes_period_info(opcert).await?;
 
         if let Value::Number(kes_period) = &v["qKesCurrentKesPeriod"] {
             Ok(kes_period.as_u64().map(|p| p as KESPeriod))
@@ -565,6 +571,19 @@ impl ChainObserver for CardanoCliChainObserver {
             Ok(None)
         }
     }
+
+    async fn get_current_operational_certificate_counter(
+        &self,
+        opcert: &OpCert,
+    ) -> Result<Option<u64>, ChainObserverError> {
+        let v = self.get_kes_period_info(opcert).await?;
+
+        if let Value::Number(counter) = &v["qKesNodeStateOperationalCertificateNumber"] {
+            Ok(counter.as_u64())
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -825,4 +844,23 @@ mod tests {
             .unwrap();
         assert_eq!(test_expected::launch_kes_period::KES_PERIOD, kes_period);
     }
+
+    #[tokio::test]
+    async fn test_get_current_operational_certificate_counter() {
+        let keypair = ColdKeyGenerator::create_deterministic_keypair([0u8; 32]);
+        let mut dummy_key_buffer = [0u8; Sum6Kes::SIZE + 4];
+        let mut dummy_seed = [0u8; 32];
+        let (_, kes_verification_key) = Sum6Kes::keygen(&mut dummy_key_buffer, &mut dummy_seed);
+        let operational_certificate = OpCert::new(kes_verification_key, 0, 0, keypair);
+        let observer = CardanoCliChainObserver::new(Box::<TestCliRunner>::default());
+        let counter = observer
+            .get_current_operational_certificate_counter(&operational_certificate)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            test_expected::launch_kes_period::NODE_STATE_OPERATIONAL_CERTIFICATE_NUMBER,
+            counter
+        );
+    }
 }
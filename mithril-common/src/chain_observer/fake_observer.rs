@@ -27,6 +27,11 @@ pub struct FakeObserver {
     ///
     /// [get_current_era]: ChainObserver::get_current_era
     pub current_era: RwLock<String>,
+
+    /// An operational certificate counter, used by [get_current_operational_certificate_counter]
+    ///
+    /// [get_current_operational_certificate_counter]: ChainObserver::get_current_operational_certificate_counter
+    pub operational_certificate_counter: RwLock<Option<u64>>,
 }
 
 impl FakeObserver {
@@ -37,6 +42,7 @@ impl FakeObserver {
             current_time_point: RwLock::new(current_time_point.clone()),
             datums: RwLock::new(vec![]),
             current_era: RwLock::new(String::new()),
+            operational_certificate_counter: RwLock::new(None),
         }
     }
 
@@ -143,6 +149,13 @@ impl FakeObserver {
         let mut current_era = self.current_era.write().await;
         *current_era = new_current_era;
     }
+
+    /// Set the operational certificate counter of the node protocol state
+    /// [get_current_operational_certificate_counter][ChainObserver::get_current_operational_certificate_counter].
+    pub async fn set_operational_certificate_counter(&self, new_counter: Option<u64>) {
+        let mut operational_certificate_counter = self.operational_certificate_counter.write().await;
+        *operational_certificate_counter = new_counter;
+    }
 }
 
 impl Default for FakeObserver {
@@ -205,6 +218,13 @@ impl ChainObserver for FakeObserver {
     ) -> Result<Option<KESPeriod>, ChainObserverError> {
         Ok(Some(0))
     }
+
+    async fn get_current_operational_certificate_counter(
+        &self,
+        _opcert: &OpCert,
+    ) -> Result<Option<u64>, ChainObserverError> {
+        Ok(*self.operational_certificate_counter.read().await)
+    }
 }
 
 #[cfg(test)]
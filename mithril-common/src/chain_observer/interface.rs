@@ -54,4 +54,13 @@ pub trait ChainObserver: Sync + Send {
     ) -> Result<Option<KESPeriod>, ChainObserverError> {
         Ok(None)
     }
+
+    /// Retrieve the operational certificate counter of the pool of an operational certificate
+    /// as known by the node protocol state
+    async fn get_current_operational_certificate_counter(
+        &self,
+        _opcert: &OpCert,
+    ) -> Result<Option<u64>, ChainObserverError> {
+        Ok(None)
+    }
 }
@@ -4,13 +4,16 @@ use std::path::{Path, PathBuf};
 use anyhow::{anyhow, Context};
 use async_trait::async_trait;
 use pallas_addresses::Address;
-use pallas_codec::utils::{Bytes, CborWrap, TagWrap};
+use pallas_codec::{
+    minicbor,
+    utils::{Bytes, CborWrap, TagWrap},
+};
 use pallas_network::{
     facades::NodeClient,
     miniprotocols::{
         localstate::{
             queries_v16::{
-                self, Addr, Addrs, ChainBlockNumber, GenesisConfig, PostAlonsoTransactionOutput,
+                self, Addr, Addrs, BlockQuery, ChainBlockNumber, GenesisConfig, PostAlonsoTransactionOutput,
                 StakeSnapshot, Stakes, TransactionOutput, UTxOByAddress,
             },
             Client,
@@ -385,6 +388,60 @@ impl PallasChainObserver {
         Ok(Some(current_kes_period))
     }
 
+    /// Fetches the protocol state (`DebugChainDepState`) through the local statequery and
+    /// extracts the operational certificate counter of the pool that issued the given
+    /// operational certificate.
+    async fn get_operational_certificate_counter(
+        &self,
+        client: &mut NodeClient,
+        opcert: &OpCert,
+    ) -> Result<Option<u64>, ChainObserverError> {
+        let statequery = client.statequery();
+        let era = self.get_era(statequery).await?;
+
+        let protocol_state = queries_v16::get_cbor(statequery, era, BlockQuery::DebugChainDepState)
+            .await
+            .map_err(|err| anyhow!(err))
+            .with_context(|| "PallasChainObserver failed to get the protocol state")?;
+        let protocol_state = protocol_state
+            .first()
+            .with_context(|| "PallasChainObserver failed to extract the protocol state")?;
+
+        let pool_key_hash = hex::decode(opcert.compute_protocol_party_id_as_hash())
+            .with_context(|| "PallasChainObserver failed to decode the pool key hash")?;
+
+        let counter = Self::decode_operational_certificate_counter(&protocol_state.0, &pool_key_hash)
+            .with_context(|| "PallasChainObserver failed to decode the protocol state")?;
+
+        Ok(counter)
+    }
+
+    /// Decodes the operational certificate counter of a pool from a CBOR encoded Praos protocol
+    /// state: `[version, [last slot, {pool key hash => counter}, nonces...]]`.
+    fn decode_operational_certificate_counter(
+        protocol_state: &[u8],
+        pool_key_hash: &[u8],
+    ) -> StdResult<Option<u64>> {
+        let mut decoder = minicbor::Decoder::new(protocol_state);
+        decoder.array()?;
+        decoder.u8()?;
+        decoder.array()?;
+        decoder.skip()?;
+
+        let total_counters = decoder
+            .map()?
+            .with_context(|| "Indefinite length operational certificate counters map")?;
+        for _ in 0..total_counters {
+            let key_hash = decoder.bytes()?;
+            let counter = decoder.u64()?;
+            if key_hash == pool_key_hash {
+                return Ok(Some(counter));
+            }
+        }
+
+        Ok(None)
+    }
+
     /// Processes a state query with the `NodeClient`, releasing the state query.
     async fn process_statequery(&self, client: &mut NodeClient) -> StdResult<()> {
         let statequery = client.statequery();
@@ -507,6 +564,23 @@ impl ChainObserver for PallasChainObserver {
 
         Ok(current_kes_period)
     }
+
+    async fn get_current_operational_certificate_counter(
+        &self,
+        opcert: &OpCert,
+    ) -> Result<Option<u64>, ChainObserverError> {
+        let mut client = self.get_client().await?;
+
+        let counter = self
+            .get_operational_certificate_counter(&mut client, opcert)
+            .await?;
+
+        self.post_process_statequery(&mut client).await?;
+
+        client.abort().await;
+
+        Ok(counter)
+    }
 }
 
 #[cfg(test)]
@@ -645,6 +719,37 @@ mod tests {
         vec![genesis]
     }
 
+    fn get_fake_operational_certificate() -> OpCert {
+        let keypair = ColdKeyGenerator::create_deterministic_keypair([0u8; 32]);
+        let mut dummy_key_buffer = [0u8; Sum6Kes::SIZE + 4];
+        let mut dummy_seed = [0u8; 32];
+        let (_, kes_verification_key) = Sum6Kes::keygen(&mut dummy_key_buffer, &mut dummy_seed);
+
+        OpCert::new(kes_verification_key, 0, 0, keypair)
+    }
+
+    fn get_fake_protocol_state() -> Vec<u8> {
+        let pool_key_hash =
+            hex::decode(get_fake_operational_certificate().compute_protocol_party_id_as_hash())
+                .unwrap();
+        let other_pool_key_hash =
+            hex::decode("00000036d515e12e18cd3c88c74f09a67984c2c279a5296aa96efe89").unwrap();
+
+        let mut protocol_state = vec![];
+        let mut encoder = minicbor::Encoder::new(&mut protocol_state);
+        encoder.array(2).unwrap().u8(0).unwrap();
+        encoder.array(7).unwrap();
+        encoder.array(2).unwrap().u8(1).unwrap().u64(52851885).unwrap();
+        encoder.map(2).unwrap();
+        encoder.bytes(&other_pool_key_hash).unwrap().u64(12).unwrap();
+        encoder.bytes(&pool_key_hash).unwrap().u64(3).unwrap();
+        for _ in 0..5 {
+            encoder.array(2).unwrap().u8(1).unwrap().bytes(&[0u8; 32]).unwrap();
+        }
+
+        protocol_state
+    }
+
     /// pallas responses mock server.
     async fn mock_server(server: &mut pallas_network::facades::NodeServer) -> AnyCbor {
         let query: queries_v16::Request =
@@ -676,6 +781,11 @@ mod tests {
             Request::LedgerQuery(LedgerQuery::BlockQuery(_, BlockQuery::GetStakeSnapshots(_))) => {
                 AnyCbor::from_encode(get_fake_stake_snapshot())
             }
+            Request::LedgerQuery(LedgerQuery::BlockQuery(_, BlockQuery::GetCBOR(_))) => {
+                AnyCbor::from_encode(vec![TagWrap::<Bytes, 24>::new(Bytes::from(
+                    get_fake_protocol_state(),
+                ))])
+            }
             _ => panic!("unexpected query from client: {query:?}"),
         }
     }
@@ -799,6 +909,36 @@ mod tests {
         assert_eq!(407, kes_period);
     }
 
+    #[tokio::test]
+    async fn get_current_operational_certificate_counter() {
+        let socket_path =
+            create_temp_dir("get_current_operational_certificate_counter").join("node.socket");
+        let server = setup_server(socket_path.clone(), 2).await;
+        let client = tokio::spawn(async move {
+            let observer =
+                PallasChainObserver::new(socket_path.as_path(), CardanoNetwork::TestNet(10));
+            observer
+                .get_current_operational_certificate_counter(&get_fake_operational_certificate())
+                .await
+                .unwrap()
+        });
+
+        let (_, client_res) = tokio::join!(server, client);
+        let counter = client_res.unwrap();
+        assert_eq!(Some(3), counter);
+    }
+
+    #[test]
+    fn decode_operational_certificate_counter_of_an_unknown_pool() {
+        let counter = PallasChainObserver::decode_operational_certificate_counter(
+            &get_fake_protocol_state(),
+            &[1u8; 28],
+        )
+        .unwrap();
+
+        assert_eq!(None, counter);
+    }
+
     #[tokio::test]
     async fn calculate_kes_period() {
         let socket_path = create_temp_dir("get_current_kes_period").join("node.socket");
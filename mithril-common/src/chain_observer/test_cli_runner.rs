@@ -28,6 +28,7 @@ pub(crate) mod test_expected {
     }
     pub(crate) mod launch_kes_period {
         pub(crate) const KES_PERIOD: u32 = 404;
+        pub(crate) const NODE_STATE_OPERATIONAL_CERTIFICATE_NUMBER: u64 = 6;
     }
     pub(crate) mod launch_stake_snapshot {
         pub(crate) const DEFAULT_POOL_STAKE_MARK: u64 = 3_000_000;
@@ -260,7 +261,7 @@ pool1qz2vzszautc2c8mljnqre2857dpmheq7kgt6vav0s38tvvhxm6w   1.051e-6
 ✓ The operational certificate counter agrees with the node protocol state counter
 ✓ Operational certificate's kes period is within the correct KES period interval
 {{
-    "qKesNodeStateOperationalCertificateNumber": 6,
+    "qKesNodeStateOperationalCertificateNumber": {},
     "qKesCurrentKesPeriod": {},
     "qKesOnDiskOperationalCertificateNumber": 6,
     "qKesRemainingSlotsInKesPeriod": 3760228,
@@ -270,6 +271,7 @@ pool1qz2vzszautc2c8mljnqre2857dpmheq7kgt6vav0s38tvvhxm6w   1.051e-6
     "qKesStartKesInterval": 372,
     "qKesSlotsPerKesPeriod": 129600
 }}"#,
+            test_expected::launch_kes_period::NODE_STATE_OPERATIONAL_CERTIFICATE_NUMBER,
             test_expected::launch_kes_period::KES_PERIOD
         );
 
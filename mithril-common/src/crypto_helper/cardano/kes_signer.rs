@@ -0,0 +1,411 @@
+//! KES signing backends used to certify the Mithril verification key of a signer.
+//!
+//! The [KesSigner] trait abstracts where the KES secret key lives:
+//! * [FileKesSigner] reads the KES secret key from a file on disk,
+//! * [SocketKesSigner] delegates the signature to an external process (for instance a
+//!   hardware backed key agent) reachable through a Unix domain socket.
+
+use anyhow::{anyhow, Context};
+use kes_summed_ed25519::kes::{Sum6Kes, Sum6KesSig};
+use kes_summed_ed25519::traits::KesSk;
+use serde::{Deserialize, Serialize};
+#[cfg(unix)]
+use std::io::{BufRead, BufReader, Read, Write};
+#[cfg(unix)]
+use std::os::unix::{fs::FileTypeExt, fs::PermissionsExt, net::UnixStream};
+use std::path::{Path, PathBuf};
+#[cfg(unix)]
+use std::time::Duration;
+
+use crate::crypto_helper::cardano::{
+    KESPeriod, ProtocolInitializerErrorWrapper, SerDeShelleyFileFormat, Sum6KesBytes,
+};
+use crate::crypto_helper::ProtocolSignerVerificationKeySignature;
+use crate::StdResult;
+
+/// A KES signer is able to sign a message with a KES secret key evolved to a given period.
+#[cfg_attr(test, mockall::automock)]
+pub trait KesSigner: Send + Sync {
+    /// Sign the given message with the KES secret key evolved to the given KES period.
+    fn sign(&self, message: &[u8], kes_period: KESPeriod) -> StdResult<Sum6KesSig>;
+}
+
+/// A [KesSigner] that reads the KES secret key from a file.
+pub struct FileKesSigner {
+    kes_sk_path: PathBuf,
+}
+
+impl FileKesSigner {
+    /// [FileKesSigner] factory
+    pub fn new<P: AsRef<Path>>(kes_sk_path: P) -> Self {
+        Self {
+            kes_sk_path: kes_sk_path.as_ref().to_path_buf(),
+        }
+    }
+}
+
+impl KesSigner for FileKesSigner {
+    fn sign(&self, message: &[u8], kes_period: KESPeriod) -> StdResult<Sum6KesSig> {
+        let mut kes_sk_bytes = Sum6KesBytes::from_file(&self.kes_sk_path)
+            .map_err(|e| anyhow!(e))
+            .with_context(|| "FileKesSigner can not read KES secret key from file")?;
+        let mut kes_sk = Sum6Kes::try_from(&mut kes_sk_bytes)
+            .map_err(|e| ProtocolInitializerErrorWrapper::ProtocolInitializer(anyhow!(e)))
+            .with_context(|| "FileKesSigner can not use KES secret key")?;
+        let kes_sk_period = kes_sk.get_period();
+        if kes_sk_period > kes_period {
+            return Err(anyhow!(ProtocolInitializerErrorWrapper::KesMismatch(
+                kes_sk_period,
+                kes_period,
+            )));
+        }
+
+        // We need to perform the evolutions
+        for period in kes_sk_period..kes_period {
+            kes_sk
+                .update()
+                .map_err(|_| ProtocolInitializerErrorWrapper::KesUpdate(period))?;
+        }
+
+        Ok(kes_sk.sign(message))
+    }
+}
+
+/// Request sent by a [SocketKesSigner] to the external KES signing process.
+///
+/// It is sent as a single line of JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KesSignRequest {
+    /// Hex encoded message to sign
+    pub message: String,
+
+    /// KES period the KES secret key must be evolved to before signing
+    pub kes_period: KESPeriod,
+}
+
+/// Response sent back by the external KES signing process to a [SocketKesSigner].
+///
+/// It is sent as a single line of JSON, either the signature or the error is set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KesSignResponse {
+    /// The KES signature of the message
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<ProtocolSignerVerificationKeySignature>,
+
+    /// The error raised by the external KES signing process
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// A [KesSigner] that delegates the signature to an external process listening on a Unix
+/// domain socket.
+///
+/// The KES secret key never leaves the external process: each request is a [KesSignRequest]
+/// and each response a [KesSignResponse], both encoded as a single line of JSON.
+///
+/// Access to the external process is restricted by the permissions of the socket file, which
+/// must not be accessible to other users than its owner and group.
+///
+/// Signing is blocking: callers running in an async runtime should use a blocking task.
+#[cfg(unix)]
+pub struct SocketKesSigner {
+    socket_path: PathBuf,
+    timeout: Duration,
+}
+
+#[cfg(unix)]
+impl SocketKesSigner {
+    /// Default timeout of a signature request
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+    /// Maximum size in bytes of a response of the external process
+    pub const MAX_RESPONSE_SIZE: u64 = 16 * 1024;
+
+    /// [SocketKesSigner] factory
+    pub fn new<P: AsRef<Path>>(socket_path: P) -> Self {
+        Self {
+            socket_path: socket_path.as_ref().to_path_buf(),
+            timeout: Self::DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Set the timeout of a signature request
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    fn check_socket_permissions(&self) -> StdResult<()> {
+        let metadata = std::fs::metadata(&self.socket_path)?;
+        if !metadata.file_type().is_socket() {
+            return Err(anyhow!("'{}' is not a socket", self.socket_path.display()));
+        }
+        let mode = metadata.permissions().mode();
+        if mode & 0o007 != 0 {
+            return Err(anyhow!(
+                "socket '{}' must not be accessible to other users (mode {:o})",
+                self.socket_path.display(),
+                mode & 0o777
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn send_request(&self, request: &KesSignRequest) -> StdResult<KesSignResponse> {
+        self.check_socket_permissions()?;
+        let stream = UnixStream::connect(&self.socket_path)?;
+        stream.set_read_timeout(Some(self.timeout))?;
+        stream.set_write_timeout(Some(self.timeout))?;
+
+        let mut writer = stream.try_clone()?;
+        writeln!(writer, "{}", serde_json::to_string(request)?)?;
+        writer.flush()?;
+
+        let mut line = String::new();
+        BufReader::new(stream.take(Self::MAX_RESPONSE_SIZE)).read_line(&mut line)?;
+        if !line.ends_with('\n') {
+            return Err(anyhow!(
+                "response is incomplete or larger than {} bytes",
+                Self::MAX_RESPONSE_SIZE
+            ));
+        }
+
+        Ok(serde_json::from_str(&line)?)
+    }
+}
+
+#[cfg(unix)]
+impl KesSigner for SocketKesSigner {
+    fn sign(&self, message: &[u8], kes_period: KESPeriod) -> StdResult<Sum6KesSig> {
+        let request = KesSignRequest {
+            message: hex::encode(message),
+            kes_period,
+        };
+        let response = self.send_request(&request).with_context(|| {
+            format!(
+                "SocketKesSigner can not get a KES signature from '{}'",
+                self.socket_path.display()
+            )
+        })?;
+
+        match (response.signature, response.error) {
+            (_, Some(error)) => Err(anyhow!(error)).with_context(|| {
+                format!(
+                    "KES signing process at '{}' failed to sign",
+                    self.socket_path.display()
+                )
+            }),
+            (Some(signature), None) => Ok(signature.into()),
+            (None, None) => Err(anyhow!(
+                "KES signing process at '{}' returned neither a signature nor an error",
+                self.socket_path.display()
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use kes_summed_ed25519::kes::Sum6KesSig;
+    use kes_summed_ed25519::traits::KesSig;
+    use kes_summed_ed25519::PublicKey;
+
+    use crate::test_utils::TempDir;
+
+    use super::*;
+
+    fn create_kes_secret_key_file(test_name: &str) -> (PathBuf, PublicKey) {
+        let temp_dir = TempDir::create("mithril_cardano_kes_signer", test_name);
+        let mut dummy_buffer = [0u8; Sum6Kes::SIZE + 4];
+        let mut dummy_seed = [1u8; 32];
+        let (kes_secret_key, kes_verification_key) =
+            Sum6Kes::keygen(&mut dummy_buffer, &mut dummy_seed);
+        let mut kes_bytes = Sum6KesBytes([0u8; Sum6Kes::SIZE + 4]);
+        kes_bytes.0.copy_from_slice(&kes_secret_key.clone_sk());
+        let kes_secret_key_file = temp_dir.join("kes.skey");
+        kes_bytes
+            .to_file(&kes_secret_key_file)
+            .expect("KES secret key file export should not fail");
+
+        (kes_secret_key_file, kes_verification_key)
+    }
+
+    #[test]
+    fn file_kes_signer_signs_with_key_evolved_to_the_given_period() {
+        let (kes_secret_key_file, kes_verification_key) =
+            create_kes_secret_key_file("file_kes_signer_signs_with_evolved_key");
+        let kes_signer = FileKesSigner::new(kes_secret_key_file);
+
+        let signature = kes_signer.sign(b"message", 3).unwrap();
+
+        signature
+            .verify(3, &kes_verification_key, b"message")
+            .expect("signature should be valid for the requested period");
+        signature
+            .verify(0, &kes_verification_key, b"message")
+            .expect_err("signature should not be valid for another period");
+    }
+
+    #[test]
+    fn file_kes_signer_fails_if_key_file_does_not_exist() {
+        let kes_signer = FileKesSigner::new("/this/kes/key/does/not/exist");
+
+        kes_signer
+            .sign(b"message", 0)
+            .expect_err("signing without a KES secret key file should fail");
+    }
+
+    #[cfg(unix)]
+    mod socket_kes_signer {
+        use std::fs::Permissions;
+        use std::os::unix::net::UnixListener;
+        use std::sync::Arc;
+
+        use crate::test_utils::SocketKesSignerServer;
+
+        use super::*;
+
+        fn spawn_socket_kes_signer_server(
+            kes_signer: Arc<dyn KesSigner>,
+            test_name: &str,
+        ) -> PathBuf {
+            // Kept short since a Unix domain socket path is limited to around a hundred bytes
+            let socket_path = TempDir::create("kes_sock", test_name).join("kes.sock");
+            let server = SocketKesSignerServer::bind(kes_signer, &socket_path).unwrap();
+            std::thread::spawn(move || server.serve());
+
+            socket_path
+        }
+
+        #[test]
+        fn delegates_signature_to_the_external_process() {
+            let (kes_secret_key_file, kes_verification_key) =
+                create_kes_secret_key_file("socket_kes_signer_delegates_signature");
+            let socket_path = spawn_socket_kes_signer_server(
+                Arc::new(FileKesSigner::new(kes_secret_key_file)),
+                "delegates",
+            );
+            let kes_signer = SocketKesSigner::new(socket_path);
+
+            let signature = kes_signer.sign(b"message", 2).unwrap();
+
+            signature
+                .verify(2, &kes_verification_key, b"message")
+                .expect("signature should be valid for the requested period");
+        }
+
+        #[test]
+        fn forwards_message_and_period_to_the_external_process() {
+            let (kes_secret_key_file, _) =
+                create_kes_secret_key_file("socket_kes_signer_forwards_message_and_period");
+            let expected_signature = FileKesSigner::new(&kes_secret_key_file)
+                .sign(b"message", 5)
+                .unwrap();
+            let mut mock_kes_signer = MockKesSigner::new();
+            mock_kes_signer
+                .expect_sign()
+                .withf(|message, kes_period| message == b"message" && *kes_period == 5)
+                .return_once(move |_, _| Ok(expected_signature))
+                .once();
+            let socket_path = spawn_socket_kes_signer_server(Arc::new(mock_kes_signer), "forwards");
+            let kes_signer = SocketKesSigner::new(socket_path);
+
+            let signature: Sum6KesSig = kes_signer.sign(b"message", 5).unwrap();
+
+            assert_eq!(expected_signature, signature);
+        }
+
+        #[test]
+        fn returns_error_of_the_external_process() {
+            let mut mock_kes_signer = MockKesSigner::new();
+            mock_kes_signer
+                .expect_sign()
+                .returning(|_, kes_period| Err(anyhow!("period {kes_period} is out of reach")));
+            let socket_path =
+                spawn_socket_kes_signer_server(Arc::new(mock_kes_signer), "returns_error");
+            let kes_signer = SocketKesSigner::new(socket_path);
+
+            let error = kes_signer
+                .sign(b"message", 7)
+                .expect_err("signing should fail when the external process fails");
+
+            assert!(
+                format!("{error:?}").contains("period 7 is out of reach"),
+                "unexpected error: {error:?}"
+            );
+        }
+
+        #[test]
+        fn fails_if_external_process_is_unreachable() {
+            let socket_path = TempDir::create(
+                "mithril_cardano_kes_signer",
+                "socket_kes_signer_fails_if_external_process_is_unreachable",
+            )
+            .join("kes-signer.sock");
+            let kes_signer = SocketKesSigner::new(socket_path);
+
+            kes_signer
+                .sign(b"message", 0)
+                .expect_err("signing should fail when the external process is unreachable");
+        }
+
+        #[test]
+        fn fails_if_socket_is_accessible_to_other_users() {
+            let mut mock_kes_signer = MockKesSigner::new();
+            mock_kes_signer.expect_sign().never();
+            let socket_path =
+                spawn_socket_kes_signer_server(Arc::new(mock_kes_signer), "other_users");
+            std::fs::set_permissions(&socket_path, Permissions::from_mode(0o666)).unwrap();
+            let kes_signer = SocketKesSigner::new(socket_path);
+
+            let error = kes_signer
+                .sign(b"message", 0)
+                .expect_err("signing should fail when the socket is accessible to other users");
+
+            assert!(
+                format!("{error:?}").contains("must not be accessible to other users"),
+                "unexpected error: {error:?}"
+            );
+        }
+
+        #[test]
+        fn fails_if_response_is_too_large() {
+            let socket_path = TempDir::create("kes_sock", "too_large").join("kes.sock");
+            let listener = UnixListener::bind(&socket_path).unwrap();
+            std::fs::set_permissions(&socket_path, Permissions::from_mode(0o600)).unwrap();
+            std::thread::spawn(move || {
+                let (mut stream, _) = listener.accept().unwrap();
+                let oversized_response =
+                    vec![b'a'; SocketKesSigner::MAX_RESPONSE_SIZE as usize + 1];
+                let _ = stream.write_all(&oversized_response);
+            });
+            let kes_signer = SocketKesSigner::new(socket_path);
+
+            let error = kes_signer
+                .sign(b"message", 0)
+                .expect_err("signing should fail when the response is too large");
+
+            assert!(
+                format!("{error:?}").contains("larger than"),
+                "unexpected error: {error:?}"
+            );
+        }
+
+        #[test]
+        fn fails_if_path_is_not_a_socket() {
+            let (kes_secret_key_file, _) =
+                create_kes_secret_key_file("socket_kes_signer_fails_if_path_is_not_a_socket");
+            let kes_signer = SocketKesSigner::new(kes_secret_key_file);
+
+            let error = kes_signer
+                .sign(b"message", 0)
+                .expect_err("signing should fail when the path is not a socket");
+
+            assert!(
+                format!("{error:?}").contains("is not a socket"),
+                "unexpected error: {error:?}"
+            );
+        }
+    }
+}
@@ -1,9 +1,11 @@
 mod codec;
 mod cold_key;
+mod kes_signer;
 mod key_certification;
 mod opcert;
 
 pub use codec::*;
+pub use kes_signer::*;
 pub use key_certification::*;
 pub use opcert::*;
 
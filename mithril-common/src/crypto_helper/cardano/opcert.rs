@@ -90,6 +90,11 @@ impl OpCert {
         msg
     }
 
+    /// Get the issue number (counter) of the certificate
+    pub fn issue_number(&self) -> u64 {
+        self.issue_number
+    }
+
     /// Validate a certificate
     pub fn validate(&self) -> Result<(), ProtocolRegistrationErrorWrapper> {
         if self
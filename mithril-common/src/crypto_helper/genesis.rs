@@ -1,9 +1,11 @@
+use crate::crypto_helper::{key_decode_hex, key_encode_hex};
 use crate::{StdError, StdResult};
 use anyhow::anyhow;
 use ed25519_dalek::{Signer, SigningKey};
 use rand_chacha::rand_core::{CryptoRng, RngCore, SeedableRng};
 use rand_chacha::ChaCha20Rng;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use thiserror::Error;
 
 use super::{ProtocolGenesisSecretKey, ProtocolGenesisSignature, ProtocolGenesisVerificationKey};
@@ -67,6 +69,138 @@ impl ProtocolGenesisSigner {
     pub fn sign(&self, message: &[u8]) -> ProtocolGenesisSignature {
         self.secret_key.sign(message).into()
     }
+
+    /// Signs a message and returns a [ProtocolGenesisSignatureShare] to be gathered in a
+    /// [ProtocolGenesisSignatureBundle]
+    pub fn sign_share(&self, message: &[u8]) -> ProtocolGenesisSignatureShare {
+        ProtocolGenesisSignatureShare {
+            verification_key: self.verification_key(),
+            signature: self.sign(message),
+        }
+    }
+}
+
+/// A genesis signature created by one of the holders of the genesis keys of a threshold genesis
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProtocolGenesisSignatureShare {
+    /// Verification key of the holder of the genesis key
+    pub verification_key: ProtocolGenesisVerificationKey,
+
+    /// Genesis signature of the holder of the genesis key
+    pub signature: ProtocolGenesisSignature,
+}
+
+/// A bundle of the genesis signatures created by the holders of the genesis keys of a threshold
+/// genesis
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProtocolGenesisSignatureBundle {
+    /// Genesis signatures of the holders of the genesis keys
+    pub signatures: Vec<ProtocolGenesisSignatureShare>,
+}
+
+impl ProtocolGenesisSignatureBundle {
+    /// [ProtocolGenesisSignatureBundle] factory
+    pub fn new(signatures: Vec<ProtocolGenesisSignatureShare>) -> Self {
+        Self { signatures }
+    }
+
+    /// Create an instance from a JSON hex representation
+    pub fn from_json_hex(hex_string: &str) -> StdResult<Self> {
+        Ok(key_decode_hex(hex_string)?)
+    }
+
+    /// Create a JSON hex representation of the bundle
+    pub fn to_json_hex(&self) -> StdResult<String> {
+        Ok(key_encode_hex(self)?)
+    }
+}
+
+/// The published set of the verification keys of the holders of the genesis keys of a threshold
+/// genesis, alongside the minimum number of signatures needed to sign a genesis certificate
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProtocolGenesisVerificationKeySet {
+    /// Minimum number of genesis signatures needed (the `m` of a `m-of-n` threshold)
+    pub threshold: usize,
+
+    /// Verification keys of the holders of the genesis keys (the `n` of a `m-of-n` threshold)
+    pub verification_keys: Vec<ProtocolGenesisVerificationKey>,
+}
+
+impl ProtocolGenesisVerificationKeySet {
+    /// [ProtocolGenesisVerificationKeySet] factory
+    ///
+    /// Fails if the threshold is zero or greater than the number of distinct verification keys.
+    pub fn new(
+        threshold: usize,
+        verification_keys: Vec<ProtocolGenesisVerificationKey>,
+    ) -> StdResult<Self> {
+        let distinct_keys = verification_keys
+            .iter()
+            .map(|key| key.to_bytes())
+            .collect::<HashSet<_>>();
+        if distinct_keys.len() != verification_keys.len() {
+            return Err(anyhow!(
+                "Genesis verification key set can not contain duplicated verification keys"
+            ));
+        }
+        if threshold == 0 || threshold > verification_keys.len() {
+            return Err(anyhow!(
+                "Genesis verification key set threshold must be between 1 and {}, got {threshold}",
+                verification_keys.len()
+            ));
+        }
+
+        Ok(Self {
+            threshold,
+            verification_keys,
+        })
+    }
+
+    /// Create an instance from a JSON hex representation
+    pub fn from_json_hex(hex_string: &str) -> StdResult<Self> {
+        let key_set: Self = key_decode_hex(hex_string)?;
+
+        Self::new(key_set.threshold, key_set.verification_keys)
+    }
+
+    /// Create a JSON hex representation of the key set
+    pub fn to_json_hex(&self) -> StdResult<String> {
+        Ok(key_encode_hex(self)?)
+    }
+
+    /// Verifies that a bundle contains at least `threshold` valid genesis signatures of a message
+    /// from distinct verification keys of the set
+    pub fn verify(&self, message: &[u8], bundle: &ProtocolGenesisSignatureBundle) -> StdResult<()> {
+        let mut signers = HashSet::new();
+        for share in &bundle.signatures {
+            if !self.verification_keys.contains(&share.verification_key) {
+                return Err(ProtocolGenesisError(anyhow!(
+                    "genesis signature from verification key '{}' which is not in the genesis verification key set",
+                    share.verification_key.to_json_hex()?
+                ))
+                .into());
+            }
+            if !signers.insert(share.verification_key.to_bytes()) {
+                return Err(ProtocolGenesisError(anyhow!(
+                    "duplicated genesis signature from verification key '{}'",
+                    share.verification_key.to_json_hex()?
+                ))
+                .into());
+            }
+            share.verification_key.verify(message, &share.signature)?;
+        }
+
+        if signers.len() < self.threshold {
+            return Err(ProtocolGenesisError(anyhow!(
+                "not enough genesis signatures: got {}, need {}",
+                signers.len(),
+                self.threshold
+            ))
+            .into());
+        }
+
+        Ok(())
+    }
 }
 
 /// A protocol Genesis Verifier that is responsible for verifying the
@@ -74,12 +208,32 @@ impl ProtocolGenesisSigner {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ProtocolGenesisVerifier {
     pub(crate) verification_key: ProtocolGenesisVerificationKey,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) verification_key_set: Option<ProtocolGenesisVerificationKeySet>,
 }
 
 impl ProtocolGenesisVerifier {
     /// [ProtocolGenesisVerifier] from [ProtocolGenesisVerificationKey]
     pub fn from_verification_key(verification_key: ProtocolGenesisVerificationKey) -> Self {
-        Self { verification_key }
+        Self {
+            verification_key,
+            verification_key_set: None,
+        }
+    }
+
+    /// Set the [ProtocolGenesisVerificationKeySet] used to verify threshold genesis signatures
+    pub fn with_verification_key_set(
+        mut self,
+        verification_key_set: ProtocolGenesisVerificationKeySet,
+    ) -> Self {
+        self.verification_key_set = Some(verification_key_set);
+        self
+    }
+
+    /// Get the [ProtocolGenesisVerificationKeySet] used to verify threshold genesis signatures
+    pub fn verification_key_set(&self) -> Option<&ProtocolGenesisVerificationKeySet> {
+        self.verification_key_set.as_ref()
     }
 
     /// [ProtocolGenesisVerifier] to [ProtocolGenesisVerificationKey]
@@ -91,6 +245,21 @@ impl ProtocolGenesisVerifier {
     pub fn verify(&self, message: &[u8], signature: &ProtocolGenesisSignature) -> StdResult<()> {
         self.verification_key.verify(message, signature)
     }
+
+    /// Verifies a bundle of threshold genesis signatures of a message against the
+    /// [ProtocolGenesisVerificationKeySet] of the verifier
+    pub fn verify_signature_bundle(
+        &self,
+        message: &[u8],
+        bundle: &ProtocolGenesisSignatureBundle,
+    ) -> StdResult<()> {
+        self.verification_key_set
+            .as_ref()
+            .ok_or_else(|| {
+                anyhow!("genesis verifier has no verification key set to verify a signature bundle")
+            })?
+            .verify(message, bundle)
+    }
 }
 
 impl ProtocolGenesisVerificationKey {
@@ -167,4 +336,135 @@ mod tests {
             "genesis signature verification should not fail"
         );
     }
+
+    fn create_genesis_signers(total: u8) -> Vec<ProtocolGenesisSigner> {
+        (0..total)
+            .map(|i| {
+                ProtocolGenesisSigner::create_test_genesis_signer(ChaCha20Rng::from_seed([i; 32]))
+            })
+            .collect()
+    }
+
+    fn create_verification_key_set(
+        threshold: usize,
+        signers: &[ProtocolGenesisSigner],
+    ) -> ProtocolGenesisVerificationKeySet {
+        ProtocolGenesisVerificationKeySet::new(
+            threshold,
+            signers.iter().map(|s| s.verification_key()).collect(),
+        )
+        .unwrap()
+    }
+
+    fn sign_bundle(
+        signers: &[ProtocolGenesisSigner],
+        message: &[u8],
+    ) -> ProtocolGenesisSignatureBundle {
+        ProtocolGenesisSignatureBundle::new(signers.iter().map(|s| s.sign_share(message)).collect())
+    }
+
+    #[test]
+    fn create_verification_key_set_fails_with_invalid_threshold_or_duplicated_keys() {
+        let signers = create_genesis_signers(3);
+        let verification_keys: Vec<_> = signers.iter().map(|s| s.verification_key()).collect();
+
+        ProtocolGenesisVerificationKeySet::new(0, verification_keys.clone())
+            .expect_err("a zero threshold should fail");
+        ProtocolGenesisVerificationKeySet::new(4, verification_keys.clone())
+            .expect_err("a threshold greater than the number of keys should fail");
+        ProtocolGenesisVerificationKeySet::new(
+            2,
+            vec![
+                verification_keys[0],
+                verification_keys[0],
+                verification_keys[1],
+            ],
+        )
+        .expect_err("duplicated verification keys should fail");
+        ProtocolGenesisVerificationKeySet::new(3, verification_keys)
+            .expect("a valid key set should not fail");
+    }
+
+    #[test]
+    fn test_codec_genesis_verification_key_set_and_signature_bundle() {
+        let signers = create_genesis_signers(3);
+        let key_set = create_verification_key_set(2, &signers);
+        let bundle = sign_bundle(&signers[0..2], b"some message.");
+
+        let key_set_decoded =
+            ProtocolGenesisVerificationKeySet::from_json_hex(&key_set.to_json_hex().unwrap())
+                .unwrap();
+        let bundle_decoded =
+            ProtocolGenesisSignatureBundle::from_json_hex(&bundle.to_json_hex().unwrap()).unwrap();
+
+        assert_eq!(key_set, key_set_decoded);
+        assert_eq!(bundle, bundle_decoded);
+    }
+
+    #[test]
+    fn verify_signature_bundle_succeeds_if_threshold_is_reached() {
+        let message: &[u8] = b"some message.";
+        let signers = create_genesis_signers(5);
+        let genesis_verifier = signers[0]
+            .create_genesis_verifier()
+            .with_verification_key_set(create_verification_key_set(3, &signers));
+
+        for signing_signers in [&signers[0..3], &signers[2..5], &signers[..]] {
+            genesis_verifier
+                .verify_signature_bundle(message, &sign_bundle(signing_signers, message))
+                .expect("signature bundle verification should not fail");
+        }
+    }
+
+    #[test]
+    fn verify_signature_bundle_fails_if_threshold_is_not_reached() {
+        let message: &[u8] = b"some message.";
+        let signers = create_genesis_signers(5);
+        let key_set = create_verification_key_set(3, &signers);
+
+        key_set
+            .verify(message, &sign_bundle(&signers[0..2], message))
+            .expect_err("signature bundle verification should fail");
+
+        let mut bundle = sign_bundle(&signers[0..2], message);
+        bundle.signatures.push(signers[0].sign_share(message));
+        key_set
+            .verify(message, &bundle)
+            .expect_err("duplicated signatures should not count toward the threshold");
+    }
+
+    #[test]
+    fn verify_signature_bundle_fails_with_an_invalid_signature() {
+        let message: &[u8] = b"some message.";
+        let signers = create_genesis_signers(3);
+        let key_set = create_verification_key_set(2, &signers);
+
+        let mut bundle = sign_bundle(&signers, message);
+        bundle.signatures[1].signature = signers[1].sign(b"another message.");
+        key_set
+            .verify(message, &bundle)
+            .expect_err("an invalid signature should fail");
+    }
+
+    #[test]
+    fn verify_signature_bundle_fails_with_a_signature_from_an_unknown_key() {
+        let message: &[u8] = b"some message.";
+        let signers = create_genesis_signers(4);
+        let key_set = create_verification_key_set(2, &signers[0..3]);
+
+        key_set
+            .verify(message, &sign_bundle(&signers[2..4], message))
+            .expect_err("a signature from a key not in the set should fail");
+    }
+
+    #[test]
+    fn verify_signature_bundle_fails_without_verification_key_set() {
+        let message: &[u8] = b"some message.";
+        let signers = create_genesis_signers(2);
+        let genesis_verifier = signers[0].create_genesis_verifier();
+
+        genesis_verifier
+            .verify_signature_bundle(message, &sign_bundle(&signers, message))
+            .expect_err("a verifier without verification key set should fail");
+    }
 }
@@ -15,16 +15,22 @@ cfg_test_tools! {
 
 pub use cardano::ColdKeyGenerator;
 
+#[cfg(unix)]
+pub use cardano::SocketKesSigner;
 pub use cardano::{
-    KESPeriod, OpCert, ProtocolInitializerErrorWrapper, ProtocolRegistrationErrorWrapper,
-    SerDeShelleyFileFormat, Sum6KesBytes,
+    FileKesSigner, KESPeriod, KesSignRequest, KesSignResponse, KesSigner, OpCert,
+    ProtocolInitializerErrorWrapper, ProtocolRegistrationErrorWrapper, SerDeShelleyFileFormat,
+    Sum6KesBytes,
 };
 pub use codec::*;
 pub use era::{
     EraMarkersSigner, EraMarkersVerifier, EraMarkersVerifierError, EraMarkersVerifierSecretKey,
     EraMarkersVerifierSignature, EraMarkersVerifierVerificationKey,
 };
-pub use genesis::{ProtocolGenesisError, ProtocolGenesisSigner, ProtocolGenesisVerifier};
+pub use genesis::{
+    ProtocolGenesisError, ProtocolGenesisSignatureBundle, ProtocolGenesisSignatureShare,
+    ProtocolGenesisSigner, ProtocolGenesisVerificationKeySet, ProtocolGenesisVerifier,
+};
 pub use merkle_map::{MKMap, MKMapKey, MKMapNode, MKMapProof, MKMapValue};
 pub use merkle_tree::{
     Bytes, MKProof, MKTree, MKTreeLeafIndexer, MKTreeLeafPosition, MKTreeNode, MKTreeStoreInMemory,
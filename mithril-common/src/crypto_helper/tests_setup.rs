@@ -1,5 +1,5 @@
 //! Test data builders for Mithril STM types, for testing purpose.
-use super::{genesis::*, types::*, OpCert, SerDeShelleyFileFormat};
+use super::{genesis::*, types::*, FileKesSigner, KesSigner, OpCert, SerDeShelleyFileFormat};
 use crate::{
     entities::{Certificate, ProtocolMessage, ProtocolMessagePartKey, SignerWithStake, Stake},
     test_utils::{CertificateChainBuilder, SignerFixture, TempDir},
@@ -7,7 +7,7 @@ use crate::{
 
 use rand_chacha::ChaCha20Rng;
 use rand_core::SeedableRng;
-use std::{fs, path::PathBuf};
+use std::{fs, path::PathBuf, sync::Arc};
 
 /// Create or retrieve a temporary directory for storing cryptographic material for a signer, use this for tests only.
 pub fn setup_temp_directory_for_signer(
@@ -58,9 +58,11 @@ fn setup_protocol_initializer(
         .unwrap();
     let mut protocol_initializer_rng = ChaCha20Rng::from_seed(protocol_initializer_seed);
     let kes_period = kes_secret_key_path.as_ref().map(|_| 0);
+    let kes_signer =
+        kes_secret_key_path.map(|path| Arc::new(FileKesSigner::new(path)) as Arc<dyn KesSigner>);
     let protocol_initializer: ProtocolInitializer = ProtocolInitializer::setup(
         *protocol_parameters,
-        kes_secret_key_path,
+        kes_signer,
         kes_period,
         stake,
         &mut protocol_initializer_rng,
@@ -5,7 +5,7 @@ use crate::crypto_helper::cardano::{
 
 use mithril_stm::{
     key_reg::ClosedKeyReg,
-    stm::{Index, Stake, StmClerk, StmParameters, StmSigner},
+    stm::{Index, Stake, StmClerk, StmLotteryPreview, StmParameters, StmSigner},
     AggregationError,
 };
 
@@ -32,6 +32,9 @@ pub type ProtocolParameters = StmParameters;
 /// Alias of [MithrilStm::Index](type@mithril_stm::stm::Index).
 pub type ProtocolLotteryIndex = Index;
 
+/// Alias of [MithrilStm::StmLotteryPreview](struct@mithril_stm::stm::StmLotteryPreview).
+pub type ProtocolLotteryPreview = StmLotteryPreview;
+
 /// Alias of [MithrilStm:StmSigner](struct@mithril_stm::stm::StmSigner).
 pub type ProtocolSigner = StmSigner<D>;
 
@@ -10,7 +10,7 @@ use crate::{
 use async_trait::async_trait;
 use sha2::{Digest, Sha256};
 use slog::{debug, info, warn, Logger};
-use std::{collections::BTreeMap, io, path::Path, sync::Arc};
+use std::{collections::BTreeMap, io, ops::RangeInclusive, path::Path, sync::Arc};
 
 struct ComputedImmutablesDigests {
     entries: BTreeMap<ImmutableFile, HexEncodedDigest>,
@@ -155,6 +155,32 @@ impl ImmutableDigester for CardanoImmutableDigester {
 
         Ok(mktree)
     }
+
+    async fn compute_digests_for_range(
+        &self,
+        dirpath: &Path,
+        range: &RangeInclusive<ImmutableFileNumber>,
+    ) -> Result<BTreeMap<ImmutableFile, HexEncodedDigest>, ImmutableDigesterError> {
+        let immutables_to_process = list_immutable_files_to_process_for_range(dirpath, range)?;
+        info!(self.logger, ">> compute_digests_for_range"; "range" => #?range, "nb_of_immutables" => immutables_to_process.len());
+        let computed_immutables_digests = self.process_immutables(immutables_to_process).await?;
+
+        self.update_cache(&computed_immutables_digests).await;
+
+        Ok(computed_immutables_digests.entries)
+    }
+}
+
+fn list_immutable_files_to_process_for_range(
+    dirpath: &Path,
+    range: &RangeInclusive<ImmutableFileNumber>,
+) -> Result<Vec<ImmutableFile>, ImmutableDigesterError> {
+    let immutables: Vec<ImmutableFile> = ImmutableFile::list_all_in_dir(dirpath)?
+        .into_iter()
+        .filter(|f| range.contains(&f.number))
+        .collect();
+
+    Ok(immutables)
 }
 
 fn list_immutable_files_to_process(
@@ -521,6 +547,60 @@ mod tests {
         assert_eq!(expected, cached_entries);
     }
 
+    #[tokio::test]
+    async fn compute_digests_for_range_includes_the_last_immutable_files_trio() {
+        let cardano_db =
+            db_builder("compute_digests_for_range_includes_the_last_immutable_files_trio")
+                .with_immutables(&[1, 2, 3])
+                .build();
+        let digester =
+            CardanoImmutableDigester::new("devnet".to_string(), None, TestLogger::stdout());
+
+        let result = digester
+            .compute_digests_for_range(cardano_db.get_immutable_dir(), &(2..=3))
+            .await
+            .expect("compute_digests_for_range must not fail");
+
+        let expected: BTreeMap<_, _> = cardano_db
+            .get_immutable_files()
+            .iter()
+            .filter(|i| i.number >= 2)
+            .map(|i| {
+                let digest = hex::encode(i.compute_raw_hash::<Sha256>().unwrap());
+                (i.to_owned(), digest)
+            })
+            .collect();
+        assert_eq!(6, result.len());
+        assert_eq!(expected, result);
+    }
+
+    #[tokio::test]
+    async fn compute_digests_for_range_digests_are_the_leaves_of_the_merkle_tree() {
+        let cardano_db =
+            db_builder("compute_digests_for_range_digests_are_the_leaves_of_the_merkle_tree")
+                .with_immutables(&[1, 2, 3])
+                .append_immutable_trio()
+                .build();
+        let digester =
+            CardanoImmutableDigester::new("devnet".to_string(), None, TestLogger::stdout());
+
+        let digests = digester
+            .compute_digests_for_range(cardano_db.get_immutable_dir(), &(1..=3))
+            .await
+            .expect("compute_digests_for_range must not fail");
+        let merkle_tree = digester
+            .compute_merkle_tree(cardano_db.get_immutable_dir(), &CardanoDbBeacon::new(1, 3))
+            .await
+            .expect("compute_merkle_tree must not fail");
+
+        let expected_merkle_tree =
+            MKTree::<MKTreeStoreInMemory>::new(&digests.into_values().collect::<Vec<_>>()).unwrap();
+        assert_eq!(
+            expected_merkle_tree.compute_root().unwrap(),
+            merkle_tree.compute_root().unwrap()
+        );
+    }
+
     #[tokio::test]
     async fn computed_digest_with_cold_or_hot_or_without_any_cache_are_equals() {
         let cardano_db = DummyCardanoDbBuilder::new(
@@ -1,9 +1,9 @@
-use std::path::Path;
+use std::{collections::BTreeMap, ops::RangeInclusive, path::Path};
 
 use crate::{
     crypto_helper::{MKTree, MKTreeStoreInMemory},
-    digesters::{ImmutableDigester, ImmutableDigesterError},
-    entities::CardanoDbBeacon,
+    digesters::{ImmutableDigester, ImmutableDigesterError, ImmutableFile},
+    entities::{CardanoDbBeacon, HexEncodedDigest, ImmutableFileNumber},
 };
 use async_trait::async_trait;
 use tokio::sync::RwLock;
@@ -85,4 +85,36 @@ impl ImmutableDigester for DumbImmutableDigester {
             })
         }
     }
+
+    /// Return a chunk file for each immutable file number of the range, associated with the
+    /// leaves used to construct the merkle tree, in order.
+    async fn compute_digests_for_range(
+        &self,
+        dirpath: &Path,
+        range: &RangeInclusive<ImmutableFileNumber>,
+    ) -> Result<BTreeMap<ImmutableFile, HexEncodedDigest>, ImmutableDigesterError> {
+        if self.is_success {
+            let leaves = self.mktree_leaves.read().await;
+            Ok(range
+                .clone()
+                .zip(leaves.iter())
+                .map(|(number, digest)| {
+                    let filename = format!("{number:05}.chunk");
+                    let immutable_file = ImmutableFile {
+                        path: dirpath.join(&filename),
+                        number,
+                        filename,
+                    };
+
+                    (immutable_file, digest.clone())
+                })
+                .collect())
+        } else {
+            Err(ImmutableDigesterError::NotEnoughImmutable {
+                expected_number: *range.end(),
+                found_number: None,
+                db_dir: dirpath.to_owned(),
+            })
+        }
+    }
 }
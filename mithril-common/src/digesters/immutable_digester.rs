@@ -1,12 +1,14 @@
 use crate::{
     crypto_helper::{MKTree, MKTreeStoreInMemory},
-    digesters::ImmutableFileListingError,
-    entities::{CardanoDbBeacon, ImmutableFileNumber},
+    digesters::{ImmutableFile, ImmutableFileListingError},
+    entities::{CardanoDbBeacon, HexEncodedDigest, ImmutableFileNumber},
     StdError,
 };
 use async_trait::async_trait;
 use std::{
+    collections::BTreeMap,
     io,
+    ops::RangeInclusive,
     path::{Path, PathBuf},
 };
 use thiserror::Error;
@@ -17,11 +19,13 @@ use thiserror::Error;
 /// ```
 /// mod test {
 ///     use async_trait::async_trait;
-///     use mithril_common::digesters::{ImmutableDigester, ImmutableDigesterError};
-///     use mithril_common::entities::CardanoDbBeacon;
+///     use mithril_common::digesters::{ImmutableDigester, ImmutableDigesterError, ImmutableFile};
+///     use mithril_common::entities::{CardanoDbBeacon, HexEncodedDigest, ImmutableFileNumber};
 ///     use mithril_common::crypto_helper::{MKTree, MKTreeStoreInMemory};
 ///     use anyhow::anyhow;
 ///     use mockall::mock;
+///     use std::collections::BTreeMap;
+///     use std::ops::RangeInclusive;
 ///     use std::path::Path;
 ///
 ///     mock! {
@@ -40,6 +44,12 @@ use thiserror::Error;
 ///              dirpath: &Path,
 ///              beacon: &CardanoDbBeacon,
 ///           ) -> Result<MKTree<MKTreeStoreInMemory>, ImmutableDigesterError>;
+///
+///            async fn compute_digests_for_range(
+///               &self,
+///              dirpath: &Path,
+///              range: &RangeInclusive<ImmutableFileNumber>,
+///           ) -> Result<BTreeMap<ImmutableFile, HexEncodedDigest>, ImmutableDigesterError>;
 ///         }
 ///     }
 ///
@@ -74,6 +84,15 @@ pub trait ImmutableDigester: Sync + Send {
         dirpath: &Path,
         beacon: &CardanoDbBeacon,
     ) -> Result<MKTree<MKTreeStoreInMemory>, ImmutableDigesterError>;
+
+    /// Compute the digests of the immutable files of the given range found in the directory
+    ///
+    /// Unlike the other computations, the last immutable files trio of the directory is included.
+    async fn compute_digests_for_range(
+        &self,
+        dirpath: &Path,
+        range: &RangeInclusive<ImmutableFileNumber>,
+    ) -> Result<BTreeMap<ImmutableFile, HexEncodedDigest>, ImmutableDigesterError>;
 }
 
 /// [ImmutableDigester] related Errors.
@@ -128,13 +128,12 @@ impl ImmutableFile {
         Ok(hasher.finalize())
     }
 
-    /// List all [`ImmutableFile`] in a given directory.
+    /// List all [`ImmutableFile`] in a given directory, including the last chunk / primary /
+    /// secondary trio.
     ///
-    /// Important Note: It will skip the last chunk / primary / secondary trio since they're not yet
-    /// complete.
-    pub fn list_completed_in_dir(
-        dir: &Path,
-    ) -> Result<Vec<ImmutableFile>, ImmutableFileListingError> {
+    /// Use it when the immutable files come from a certified source, such as a restored Cardano
+    /// database, and are known to be complete.
+    pub fn list_all_in_dir(dir: &Path) -> Result<Vec<ImmutableFile>, ImmutableFileListingError> {
         let immutable_dir =
             find_immutables_dir(dir).ok_or(MissingImmutableFolder(dir.to_path_buf()))?;
         let mut files: Vec<ImmutableFile> = vec![];
@@ -151,6 +150,18 @@ impl ImmutableFile {
         }
         files.sort();
 
+        Ok(files)
+    }
+
+    /// List all [`ImmutableFile`] in a given directory.
+    ///
+    /// Important Note: It will skip the last chunk / primary / secondary trio since they're not yet
+    /// complete.
+    pub fn list_completed_in_dir(
+        dir: &Path,
+    ) -> Result<Vec<ImmutableFile>, ImmutableFileListingError> {
+        let files = Self::list_all_in_dir(dir)?;
+
         match files.last() {
             // empty list
             None => Ok(files),
@@ -257,6 +268,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn list_all_immutable_file_should_not_skip_last_number() {
+        let target_dir =
+            get_test_dir("list_all_immutable_file_should_not_skip_last_number/immutable");
+        let entries = vec![
+            "123.chunk",
+            "123.primary",
+            "123.secondary",
+            "0124.chunk",
+            "0124.primary",
+            "0124.secondary",
+            "125.chunk",
+            "125.primary",
+            "125.secondary",
+        ];
+        create_fake_files(&target_dir, &entries);
+        let result = ImmutableFile::list_all_in_dir(target_dir.parent().unwrap())
+            .expect("ImmutableFile::list_all_in_dir Failed");
+
+        assert_eq!(result.last().unwrap().number, 125);
+        assert_eq!(result.len(), entries.len());
+    }
+
     #[test]
     fn list_immutable_file_should_works_in_a_empty_folder() {
         let target_dir =
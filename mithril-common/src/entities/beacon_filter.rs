@@ -0,0 +1,183 @@
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{
+    BlockNumber, Epoch, ImmutableFileNumber, SignedEntityType, SignedEntityTypeDiscriminants,
+};
+use crate::StdResult;
+
+/// Filter on the fields of the beacon of a signed entity type
+///
+/// Only the fields that exist in the beacon of a signed entity type can be set, see
+/// [BeaconFilter::validate_for].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BeaconFilter {
+    /// Only select the beacons of this epoch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub epoch: Option<Epoch>,
+
+    /// Only select the beacons of this immutable file number
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub immutable_file_number: Option<ImmutableFileNumber>,
+
+    /// Only select the beacons of this block number
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_number: Option<BlockNumber>,
+}
+
+impl BeaconFilter {
+    /// Only select the beacons of the given epoch
+    pub fn epoch(mut self, epoch: Epoch) -> Self {
+        self.epoch = Some(epoch);
+        self
+    }
+
+    /// Only select the beacons of the given immutable file number
+    pub fn immutable_file_number(mut self, immutable_file_number: ImmutableFileNumber) -> Self {
+        self.immutable_file_number = Some(immutable_file_number);
+        self
+    }
+
+    /// Only select the beacons of the given block number
+    pub fn block_number(mut self, block_number: BlockNumber) -> Self {
+        self.block_number = Some(block_number);
+        self
+    }
+
+    /// Check if no filter is set
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Check that the set fields exist in the beacon of the given signed entity type
+    pub fn validate_for(&self, signed_entity_type: SignedEntityTypeDiscriminants) -> StdResult<()> {
+        let (has_immutable_file_number, has_block_number) = match signed_entity_type {
+            SignedEntityTypeDiscriminants::MithrilStakeDistribution
+            | SignedEntityTypeDiscriminants::CardanoStakeDistribution => (false, false),
+            SignedEntityTypeDiscriminants::CardanoImmutableFilesFull
+            | SignedEntityTypeDiscriminants::CardanoDatabase => (true, false),
+            SignedEntityTypeDiscriminants::CardanoTransactions
+            | SignedEntityTypeDiscriminants::CardanoBlocks => (false, true),
+        };
+
+        if self.immutable_file_number.is_some() && !has_immutable_file_number {
+            return Err(anyhow!(
+                "The beacon of '{signed_entity_type}' has no immutable file number"
+            ));
+        }
+        if self.block_number.is_some() && !has_block_number {
+            return Err(anyhow!(
+                "The beacon of '{signed_entity_type}' has no block number"
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Encode the filters as an URL query string, without the leading `?`
+    pub fn to_query_string(&self) -> String {
+        let mut parameters = vec![];
+        if let Some(epoch) = self.epoch {
+            parameters.push(format!("epoch={epoch}"));
+        }
+        if let Some(immutable_file_number) = self.immutable_file_number {
+            parameters.push(format!("immutable_file_number={immutable_file_number}"));
+        }
+        if let Some(block_number) = self.block_number {
+            parameters.push(format!("block_number={block_number}"));
+        }
+
+        parameters.join("&")
+    }
+}
+
+impl From<&SignedEntityType> for BeaconFilter {
+    fn from(signed_entity_type: &SignedEntityType) -> Self {
+        match signed_entity_type {
+            SignedEntityType::MithrilStakeDistribution(epoch)
+            | SignedEntityType::CardanoStakeDistribution(epoch) => Self::default().epoch(*epoch),
+            SignedEntityType::CardanoImmutableFilesFull(beacon)
+            | SignedEntityType::CardanoDatabase(beacon) => Self::default()
+                .epoch(beacon.epoch)
+                .immutable_file_number(beacon.immutable_file_number),
+            SignedEntityType::CardanoTransactions(epoch, block_number)
+            | SignedEntityType::CardanoBlocks(epoch, block_number) => {
+                Self::default().epoch(*epoch).block_number(*block_number)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entities::CardanoDbBeacon;
+
+    use super::*;
+
+    #[test]
+    fn filter_query_string_contains_all_set_parameters() {
+        assert_eq!("", BeaconFilter::default().to_query_string());
+        assert_eq!(
+            "epoch=4&immutable_file_number=1234",
+            BeaconFilter::default()
+                .epoch(Epoch(4))
+                .immutable_file_number(1234)
+                .to_query_string()
+        );
+        assert_eq!(
+            "block_number=99",
+            BeaconFilter::default()
+                .block_number(BlockNumber(99))
+                .to_query_string()
+        );
+    }
+
+    #[test]
+    fn filter_from_signed_entity_type_matches_its_whole_beacon() {
+        assert_eq!(
+            BeaconFilter::default().epoch(Epoch(5)),
+            BeaconFilter::from(&SignedEntityType::CardanoStakeDistribution(Epoch(5)))
+        );
+        assert_eq!(
+            BeaconFilter::default()
+                .epoch(Epoch(5))
+                .immutable_file_number(1234),
+            BeaconFilter::from(&SignedEntityType::CardanoImmutableFilesFull(
+                CardanoDbBeacon::new(5, 1234)
+            ))
+        );
+        assert_eq!(
+            BeaconFilter::default()
+                .epoch(Epoch(5))
+                .block_number(BlockNumber(99)),
+            BeaconFilter::from(&SignedEntityType::CardanoTransactions(
+                Epoch(5),
+                BlockNumber(99)
+            ))
+        );
+    }
+
+    #[test]
+    fn filter_is_only_valid_for_the_signed_entity_types_having_its_fields() {
+        let epoch_filter = BeaconFilter::default().epoch(Epoch(5));
+        for signed_entity_type in SignedEntityTypeDiscriminants::all() {
+            epoch_filter.validate_for(signed_entity_type).unwrap();
+        }
+
+        let immutable_filter = BeaconFilter::default().immutable_file_number(1234);
+        immutable_filter
+            .validate_for(SignedEntityTypeDiscriminants::CardanoImmutableFilesFull)
+            .unwrap();
+        immutable_filter
+            .validate_for(SignedEntityTypeDiscriminants::CardanoTransactions)
+            .expect_err("CardanoTransactions beacon has no immutable file number");
+
+        let block_filter = BeaconFilter::default().block_number(BlockNumber(99));
+        block_filter
+            .validate_for(SignedEntityTypeDiscriminants::CardanoTransactions)
+            .unwrap();
+        block_filter
+            .validate_for(SignedEntityTypeDiscriminants::MithrilStakeDistribution)
+            .expect_err("MithrilStakeDistribution beacon has no block number");
+    }
+}
@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    crypto_helper::MKTreeNode,
+    entities::{BlockHash, BlockNumber, SlotNumber},
+};
+
+/// Cardano block representation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CardanoBlock {
+    /// Hash of the block
+    pub block_hash: BlockHash,
+
+    /// Number of the block
+    pub block_number: BlockNumber,
+
+    /// Slot number of the block
+    pub slot_number: SlotNumber,
+}
+
+impl CardanoBlock {
+    /// CardanoBlock factory
+    pub fn new<T: Into<BlockHash>>(
+        block_hash: T,
+        block_number: BlockNumber,
+        slot_number: SlotNumber,
+    ) -> Self {
+        Self {
+            block_hash: block_hash.into(),
+            block_number,
+            slot_number,
+        }
+    }
+}
+
+impl From<CardanoBlock> for MKTreeNode {
+    fn from(other: CardanoBlock) -> Self {
+        (&other).into()
+    }
+}
+
+impl From<&CardanoBlock> for MKTreeNode {
+    /// The leaf of a block includes its number and slot number alongside its hash, so a proof of
+    /// membership also certifies the height of the block in the chain.
+    fn from(other: &CardanoBlock) -> Self {
+        MKTreeNode::new(
+            format!(
+                "{}:{}:{}",
+                other.block_number, other.slot_number, other.block_hash
+            )
+            .into_bytes(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_cardano_block_to_merkle_tree_node() {
+        let block = CardanoBlock::new("block-hash-123", BlockNumber(10), SlotNumber(4));
+
+        let computed_mktree_node: MKTreeNode = block.into();
+        let expected_mk_tree_node = MKTreeNode::new("10:4:block-hash-123".as_bytes().to_vec());
+
+        assert_eq!(expected_mk_tree_node, computed_mktree_node);
+    }
+
+    #[test]
+    fn merkle_tree_node_of_a_block_depends_on_its_number_and_slot_number() {
+        let block = CardanoBlock::new("block-hash-123", BlockNumber(10), SlotNumber(4));
+        let node: MKTreeNode = (&block).into();
+
+        assert_ne!(
+            node,
+            CardanoBlock {
+                block_number: BlockNumber(11),
+                ..block.clone()
+            }
+            .into()
+        );
+        assert_ne!(
+            node,
+            CardanoBlock {
+                slot_number: SlotNumber(5),
+                ..block.clone()
+            }
+            .into()
+        );
+        assert_ne!(
+            node,
+            CardanoBlock {
+                block_hash: "block-hash-456".to_string(),
+                ..block
+            }
+            .into()
+        );
+    }
+}
@@ -1,7 +1,9 @@
 use crate::crypto_helper::{
-    ProtocolAggregateVerificationKey, ProtocolGenesisSignature, ProtocolMultiSignature,
+    ProtocolAggregateVerificationKey, ProtocolGenesisSignature, ProtocolGenesisSignatureBundle,
+    ProtocolMultiSignature,
 };
 use crate::entities::{CertificateMetadata, Epoch, ProtocolMessage, SignedEntityType};
+use crate::StdResult;
 use std::fmt::{Debug, Formatter};
 
 use sha2::{Digest, Sha256};
@@ -13,11 +15,29 @@ pub enum CertificateSignature {
     /// aka GENESIS_SIG(AVK(-1))
     GenesisSignature(ProtocolGenesisSignature),
 
+    /// Threshold genesis signature created from the original stake distribution by at least `m`
+    /// of the `n` holders of the genesis keys
+    /// aka GENESIS_THRESHOLD_SIG(AVK(-1))
+    GenesisThresholdSignature(ProtocolGenesisSignatureBundle),
+
     /// STM multi signature created from a quorum of single signatures from the signers
     /// aka (BEACON(p,n), MULTI_SIG(H(MSG(p,n) || AVK(n-1))))
     MultiSignature(SignedEntityType, ProtocolMultiSignature),
 }
 
+impl CertificateSignature {
+    /// Decode a hex encoded genesis signature, either a bytes hex encoded single genesis signature
+    /// or a JSON hex encoded threshold genesis signature bundle
+    pub fn decode_genesis_signature(encoded: &str) -> StdResult<Self> {
+        match ProtocolGenesisSignature::from_bytes_hex(encoded) {
+            Ok(signature) => Ok(Self::GenesisSignature(signature)),
+            Err(error) => ProtocolGenesisSignatureBundle::from_json_hex(encoded)
+                .map(Self::GenesisThresholdSignature)
+                .map_err(|_| error),
+        }
+    }
+}
+
 /// Certificate represents a Mithril certificate embedding a Mithril STM multisignature
 #[derive(Clone)]
 pub struct Certificate {
@@ -99,6 +119,9 @@ impl Certificate {
             CertificateSignature::GenesisSignature(signature) => {
                 hasher.update(signature.to_bytes_hex());
             }
+            CertificateSignature::GenesisThresholdSignature(bundle) => {
+                hasher.update(bundle.to_json_hex().unwrap());
+            }
             CertificateSignature::MultiSignature(signed_entity_type, signature) => {
                 signed_entity_type.feed_hash(&mut hasher);
                 hasher.update(signature.to_json_hex().unwrap());
@@ -109,7 +132,11 @@ impl Certificate {
 
     /// Tell if the certificate is a genesis certificate
     pub fn is_genesis(&self) -> bool {
-        matches!(self.signature, CertificateSignature::GenesisSignature(_))
+        matches!(
+            self.signature,
+            CertificateSignature::GenesisSignature(_)
+                | CertificateSignature::GenesisThresholdSignature(_)
+        )
     }
 
     /// Return true if the certificate is chaining into itself (meaning that its hash and previous
@@ -126,7 +153,10 @@ impl Certificate {
     /// Get the certificate signed entity type.
     pub fn signed_entity_type(&self) -> SignedEntityType {
         match &self.signature {
-            CertificateSignature::GenesisSignature(_) => SignedEntityType::genesis(self.epoch),
+            CertificateSignature::GenesisSignature(_)
+            | CertificateSignature::GenesisThresholdSignature(_) => {
+                SignedEntityType::genesis(self.epoch)
+            }
             CertificateSignature::MultiSignature(entity_type, _) => entity_type.clone(),
         }
     }
@@ -169,6 +199,7 @@ impl Debug for Certificate {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::crypto_helper::ProtocolGenesisSigner;
     use crate::entities::SignedEntityType::CardanoStakeDistribution;
     use crate::{
         entities::{
@@ -366,4 +397,30 @@ mod tests {
             .compute_hash(),
         );
     }
+
+    #[test]
+    fn test_decode_genesis_signature() {
+        let genesis_signer = ProtocolGenesisSigner::create_deterministic_genesis_signer();
+        let genesis_signature = genesis_signer.sign(b"message");
+        let genesis_signature_bundle =
+            ProtocolGenesisSignatureBundle::new(vec![genesis_signer.sign_share(b"message")]);
+
+        let decoded =
+            CertificateSignature::decode_genesis_signature(&genesis_signature.to_bytes_hex())
+                .unwrap();
+        assert!(
+            matches!(decoded, CertificateSignature::GenesisSignature(signature) if signature == genesis_signature)
+        );
+
+        let decoded = CertificateSignature::decode_genesis_signature(
+            &genesis_signature_bundle.to_json_hex().unwrap(),
+        )
+        .unwrap();
+        assert!(
+            matches!(decoded, CertificateSignature::GenesisThresholdSignature(bundle) if bundle == genesis_signature_bundle)
+        );
+
+        CertificateSignature::decode_genesis_signature("invalid")
+            .expect_err("decoding an invalid genesis signature should fail");
+    }
 }
@@ -3,6 +3,7 @@ use std::collections::HashSet;
 use anyhow::anyhow;
 use serde::{Deserialize, Serialize};
 
+use crate::entities::ImmutableFileNumber;
 use crate::StdResult;
 
 /// FileUri represents a file URI used to identify the file's location
@@ -44,6 +45,19 @@ impl MultiFilesUri {
 
         Ok(templates.into_iter().next().map(TemplateUri))
     }
+
+    /// Expand the multi files uri to the file uri of the given immutable file number
+    pub fn expand_for_immutable_file_number(
+        &self,
+        immutable_file_number: ImmutableFileNumber,
+    ) -> FileUri {
+        match self {
+            MultiFilesUri::Template(template) => FileUri(template.0.replace(
+                "{immutable_file_number}",
+                &format!("{:05}", immutable_file_number),
+            )),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -89,4 +103,31 @@ mod tests {
                 "Should return an error when multiple templates are found in the file URIs",
             );
     }
+
+    #[test]
+    fn expand_for_immutable_file_number_pads_the_immutable_file_number() {
+        let multi_files_uri = MultiFilesUri::Template(TemplateUri(
+            "http://whatever/{immutable_file_number}.tar.gz".to_string(),
+        ));
+
+        assert_eq!(
+            FileUri("http://whatever/00012.tar.gz".to_string()),
+            multi_files_uri.expand_for_immutable_file_number(12)
+        );
+        assert_eq!(
+            FileUri("http://whatever/123456.tar.gz".to_string()),
+            multi_files_uri.expand_for_immutable_file_number(123456)
+        );
+    }
+
+    #[test]
+    fn expand_for_immutable_file_number_without_variable_in_template_returns_the_template() {
+        let multi_files_uri =
+            MultiFilesUri::Template(TemplateUri("http://whatever/file.tar.gz".to_string()));
+
+        assert_eq!(
+            FileUri("http://whatever/file.tar.gz".to_string()),
+            multi_files_uri.expand_for_immutable_file_number(12)
+        );
+    }
 }
@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{Epoch, SignedEntityTypeDiscriminants};
+
+/// Cursor and filters used to select a page of items of a list
+///
+/// Items are listed from the most recent to the oldest. The cursors are certificate hashes
+/// (or, for the artifacts lists, artifact hashes) of items of a previously retrieved page.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ListFilter {
+    /// Only select the items older than the item identified by this hash
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+
+    /// Only select the items more recent than the item identified by this hash
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+
+    /// Only select the items certified at this epoch or after
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_epoch: Option<Epoch>,
+
+    /// Only select the items certified at this epoch or before
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_epoch: Option<Epoch>,
+
+    /// Only select the items of this signed entity type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signed_entity_type: Option<SignedEntityTypeDiscriminants>,
+}
+
+impl ListFilter {
+    /// Only select the items older than the item identified by the given hash
+    pub fn before<T: Into<String>>(mut self, hash: T) -> Self {
+        self.before = Some(hash.into());
+        self
+    }
+
+    /// Only select the items more recent than the item identified by the given hash
+    pub fn after<T: Into<String>>(mut self, hash: T) -> Self {
+        self.after = Some(hash.into());
+        self
+    }
+
+    /// Only select the items certified at the given epoch or after
+    pub fn from_epoch(mut self, epoch: Epoch) -> Self {
+        self.from_epoch = Some(epoch);
+        self
+    }
+
+    /// Only select the items certified at the given epoch or before
+    pub fn to_epoch(mut self, epoch: Epoch) -> Self {
+        self.to_epoch = Some(epoch);
+        self
+    }
+
+    /// Only select the items of the given signed entity type
+    pub fn signed_entity_type(mut self, signed_entity_type: SignedEntityTypeDiscriminants) -> Self {
+        self.signed_entity_type = Some(signed_entity_type);
+        self
+    }
+
+    /// Check if no cursor nor filter is set
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Encode the cursor and filters as an URL query string, without the leading `?`
+    pub fn to_query_string(&self) -> String {
+        let mut parameters = vec![];
+        if let Some(before) = &self.before {
+            parameters.push(format!("before={before}"));
+        }
+        if let Some(after) = &self.after {
+            parameters.push(format!("after={after}"));
+        }
+        if let Some(from_epoch) = self.from_epoch {
+            parameters.push(format!("from_epoch={from_epoch}"));
+        }
+        if let Some(to_epoch) = self.to_epoch {
+            parameters.push(format!("to_epoch={to_epoch}"));
+        }
+        if let Some(signed_entity_type) = self.signed_entity_type {
+            parameters.push(format!("signed_entity_type={signed_entity_type}"));
+        }
+
+        parameters.join("&")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_filter_has_an_empty_query_string() {
+        let filter = ListFilter::default();
+
+        assert!(filter.is_empty());
+        assert_eq!("", filter.to_query_string());
+    }
+
+    #[test]
+    fn filter_query_string_contains_all_set_parameters() {
+        let filter = ListFilter::default()
+            .before("hash-123")
+            .from_epoch(Epoch(4))
+            .to_epoch(Epoch(12))
+            .signed_entity_type(SignedEntityTypeDiscriminants::CardanoTransactions);
+
+        assert!(!filter.is_empty());
+        assert_eq!(
+            "before=hash-123&from_epoch=4&to_epoch=12&signed_entity_type=CardanoTransactions",
+            filter.to_query_string()
+        );
+        assert_eq!(
+            "after=hash-456",
+            ListFilter::default().after("hash-456").to_query_string()
+        );
+    }
+}
@@ -1,8 +1,12 @@
 //! The entities used by, and exchanged between, the aggregator, signers and client.
 
 pub(crate) mod arithmetic_operation_wrapper;
+mod beacon_filter;
 mod block_number;
 mod block_range;
+mod cardano_block;
+mod cardano_blocks_set_proof;
+mod cardano_blocks_snapshot;
 mod cardano_chain_point;
 mod cardano_database;
 mod cardano_db_beacon;
@@ -18,6 +22,7 @@ mod compression_algorithm;
 mod epoch;
 mod file_uri;
 mod http_server_error;
+mod list_filter;
 mod mithril_stake_distribution;
 mod protocol_message;
 mod protocol_parameters;
@@ -30,8 +35,12 @@ mod snapshot;
 mod time_point;
 mod type_alias;
 
+pub use beacon_filter::BeaconFilter;
 pub use block_number::BlockNumber;
 pub use block_range::{BlockRange, BlockRangeLength, BlockRangesSequence};
+pub use cardano_block::CardanoBlock;
+pub use cardano_blocks_set_proof::CardanoBlocksSetProof;
+pub use cardano_blocks_snapshot::CardanoBlocksSnapshot;
 pub use cardano_chain_point::{BlockHash, ChainPoint};
 pub use cardano_database::{
     AncillaryLocation, ArtifactsLocations, CardanoDatabaseSnapshot, DigestLocation,
@@ -50,6 +59,7 @@ pub use compression_algorithm::*;
 pub use epoch::{Epoch, EpochError};
 pub use file_uri::{FileUri, MultiFilesUri, TemplateUri};
 pub use http_server_error::{ClientError, ServerError};
+pub use list_filter::ListFilter;
 pub use mithril_stake_distribution::MithrilStakeDistribution;
 pub use protocol_message::{ProtocolMessage, ProtocolMessagePartKey, ProtocolMessagePartValue};
 pub use protocol_parameters::ProtocolParameters;
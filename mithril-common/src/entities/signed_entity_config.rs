@@ -87,6 +87,11 @@ impl SignedEntityConfig {
             SignedEntityTypeDiscriminants::CardanoDatabase => SignedEntityType::CardanoDatabase(
                 CardanoDbBeacon::new(*time_point.epoch, time_point.immutable_file_number),
             ),
+            SignedEntityTypeDiscriminants::CardanoBlocks => SignedEntityType::CardanoBlocks(
+                time_point.epoch,
+                self.cardano_transactions_signing_config
+                    .compute_block_number_to_be_signed(time_point.chain_point.block_number),
+            ),
         };
 
         Ok(signed_entity_type)
@@ -252,6 +257,16 @@ mod tests {
                 )
                 .unwrap()
         );
+        // Cardano blocks are signed at the same block numbers than Cardano transactions
+        assert_eq!(
+            SignedEntityType::CardanoBlocks(Epoch(1), BlockNumber(14)),
+            config
+                .time_point_to_signed_entity(
+                    SignedEntityTypeDiscriminants::CardanoBlocks,
+                    &time_point
+                )
+                .unwrap()
+        );
     }
 
     #[test]
@@ -27,6 +27,9 @@ const ENTITY_TYPE_CARDANO_TRANSACTIONS: usize = 3;
 /// Database representation of the SignedEntityType::CardanoDatabase value
 const ENTITY_TYPE_CARDANO_DATABASE: usize = 4;
 
+/// Database representation of the SignedEntityType::CardanoBlocks value
+const ENTITY_TYPE_CARDANO_BLOCKS: usize = 5;
+
 /// The signed entity type that represents a type of data signed by the Mithril
 /// protocol Note: Each variant of this enum must be associated to an entry in
 /// the `signed_entity_type` table of the signer/aggregator nodes. The variant
@@ -61,6 +64,9 @@ pub enum SignedEntityType {
 
     /// Cardano Transactions
     CardanoTransactions(Epoch, BlockNumber),
+
+    /// Cardano Blocks
+    CardanoBlocks(Epoch, BlockNumber),
 }
 
 impl SignedEntityType {
@@ -80,7 +86,8 @@ impl SignedEntityType {
             Self::CardanoImmutableFilesFull(b) | Self::CardanoDatabase(b) => b.epoch,
             Self::CardanoStakeDistribution(e)
             | Self::MithrilStakeDistribution(e)
-            | Self::CardanoTransactions(e, _) => *e,
+            | Self::CardanoTransactions(e, _)
+            | Self::CardanoBlocks(e, _) => *e,
         }
     }
 
@@ -89,7 +96,9 @@ impl SignedEntityType {
         match self {
             Self::CardanoImmutableFilesFull(beacon) | Self::CardanoDatabase(beacon) => beacon.epoch,
             Self::CardanoStakeDistribution(epoch) => epoch.next(),
-            Self::MithrilStakeDistribution(epoch) | Self::CardanoTransactions(epoch, _) => *epoch,
+            Self::MithrilStakeDistribution(epoch)
+            | Self::CardanoTransactions(epoch, _)
+            | Self::CardanoBlocks(epoch, _) => *epoch,
         }
     }
 
@@ -101,6 +110,7 @@ impl SignedEntityType {
             Self::CardanoImmutableFilesFull(_) => ENTITY_TYPE_CARDANO_IMMUTABLE_FILES_FULL,
             Self::CardanoTransactions(_, _) => ENTITY_TYPE_CARDANO_TRANSACTIONS,
             Self::CardanoDatabase(_) => ENTITY_TYPE_CARDANO_DATABASE,
+            Self::CardanoBlocks(_, _) => ENTITY_TYPE_CARDANO_BLOCKS,
         }
     }
 
@@ -113,7 +123,8 @@ impl SignedEntityType {
             Self::CardanoStakeDistribution(value) | Self::MithrilStakeDistribution(value) => {
                 serde_json::to_string(value)?
             }
-            Self::CardanoTransactions(epoch, block_number) => {
+            Self::CardanoTransactions(epoch, block_number)
+            | Self::CardanoBlocks(epoch, block_number) => {
                 let json = serde_json::json!({
                     "epoch": epoch,
                     "block_number": block_number,
@@ -132,6 +143,7 @@ impl SignedEntityType {
             Self::CardanoStakeDistribution(_) => Some(Duration::from_secs(600)),
             Self::CardanoTransactions(_, _) => Some(Duration::from_secs(1800)),
             Self::CardanoDatabase(_) => Some(Duration::from_secs(1800)),
+            Self::CardanoBlocks(_, _) => Some(Duration::from_secs(1800)),
         }
     }
 
@@ -146,7 +158,8 @@ impl SignedEntityType {
                 hasher.update(&db_beacon.epoch.to_be_bytes());
                 hasher.update(&db_beacon.immutable_file_number.to_be_bytes());
             }
-            SignedEntityType::CardanoTransactions(epoch, block_number) => {
+            SignedEntityType::CardanoTransactions(epoch, block_number)
+            | SignedEntityType::CardanoBlocks(epoch, block_number) => {
                 hasher.update(&epoch.to_be_bytes());
                 hasher.update(&block_number.to_be_bytes())
             }
@@ -168,6 +181,7 @@ impl SignedEntityTypeDiscriminants {
             Self::CardanoImmutableFilesFull => ENTITY_TYPE_CARDANO_IMMUTABLE_FILES_FULL,
             Self::CardanoTransactions => ENTITY_TYPE_CARDANO_TRANSACTIONS,
             Self::CardanoDatabase => ENTITY_TYPE_CARDANO_DATABASE,
+            Self::CardanoBlocks => ENTITY_TYPE_CARDANO_BLOCKS,
         }
     }
 
@@ -179,6 +193,7 @@ impl SignedEntityTypeDiscriminants {
             ENTITY_TYPE_CARDANO_IMMUTABLE_FILES_FULL => Ok(Self::CardanoImmutableFilesFull),
             ENTITY_TYPE_CARDANO_TRANSACTIONS => Ok(Self::CardanoTransactions),
             ENTITY_TYPE_CARDANO_DATABASE => Ok(Self::CardanoDatabase),
+            ENTITY_TYPE_CARDANO_BLOCKS => Ok(Self::CardanoBlocks),
             index => Err(anyhow!("Invalid entity_type_id {index}.")),
         }
     }
@@ -294,6 +309,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_epoch_when_signed_entity_type_is_signed_for_cardano_blocks_return_epoch_stored_in_signed_entity_type(
+    ) {
+        let signed_entity_type = SignedEntityType::CardanoBlocks(Epoch(3), BlockNumber(77));
+        assert_eq!(
+            signed_entity_type.get_epoch_when_signed_entity_type_is_signed(),
+            Epoch(3)
+        );
+    }
+
     #[test]
     fn verify_signed_entity_type_properties_are_included_in_computed_hash() {
         fn hash(signed_entity_type: SignedEntityType) -> String {
@@ -364,6 +389,19 @@ mod tests {
                 12, 123
             )))
         );
+
+        let reference_hash = hash(SignedEntityType::CardanoBlocks(Epoch(35), BlockNumber(77)));
+        assert_ne!(
+            reference_hash,
+            hash(SignedEntityType::CardanoBlocks(Epoch(3), BlockNumber(77)))
+        );
+        assert_ne!(
+            reference_hash,
+            hash(SignedEntityType::CardanoBlocks(
+                Epoch(35),
+                BlockNumber(98765)
+            ))
+        );
     }
 
     #[test]
@@ -404,13 +442,19 @@ mod tests {
             r#"{"epoch":12,"immutable_file_number":987}"#,
             &cardano_database_full_json
         );
+
+        let cardano_blocks_json = SignedEntityType::CardanoBlocks(Epoch(35), BlockNumber(77))
+            .get_json_beacon()
+            .unwrap();
+        assert_same_json!(r#"{"epoch":35,"block_number":77}"#, &cardano_blocks_json);
     }
 
     // Expected ord:
-    // MithrilStakeDistribution < CardanoStakeDistribution < CardanoImmutableFilesFull < CardanoDatabase < CardanoTransactions
+    // MithrilStakeDistribution < CardanoStakeDistribution < CardanoImmutableFilesFull < CardanoDatabase < CardanoTransactions < CardanoBlocks
     #[test]
     fn ordering_discriminant() {
         let mut list = vec![
+            SignedEntityTypeDiscriminants::CardanoBlocks,
             SignedEntityTypeDiscriminants::CardanoStakeDistribution,
             SignedEntityTypeDiscriminants::CardanoDatabase,
             SignedEntityTypeDiscriminants::CardanoTransactions,
@@ -427,6 +471,7 @@ mod tests {
                 SignedEntityTypeDiscriminants::CardanoImmutableFilesFull,
                 SignedEntityTypeDiscriminants::CardanoDatabase,
                 SignedEntityTypeDiscriminants::CardanoTransactions,
+                SignedEntityTypeDiscriminants::CardanoBlocks,
             ]
         );
     }
@@ -51,6 +51,9 @@ pub type HexEncodedGenesisSecretKey = HexEncodedKey;
 /// Hex encoded Genesis Verification Key
 pub type HexEncodedGenesisVerificationKey = HexEncodedKey;
 
+/// Hex encoded Genesis Verification Key Set
+pub type HexEncodedGenesisVerificationKeySet = HexEncodedKey;
+
 /// Hex encoded Sha256 Digest
 pub type HexEncodedDigest = HexEncodedKey;
 
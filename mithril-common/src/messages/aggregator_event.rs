@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{Epoch, SignedEntityType};
+
+/// Message pushed by an aggregator on its events stream
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum AggregatorEventMessage {
+    /// A new certificate has been created
+    CertificateCreated {
+        /// Hash of the created certificate
+        certificate_hash: String,
+
+        /// Epoch at which the certificate has been created
+        epoch: Epoch,
+
+        /// Signed entity type certified by the certificate
+        signed_entity_type: SignedEntityType,
+    },
+
+    /// A new artifact has been created
+    ArtifactCreated {
+        /// Signed entity type of the created artifact
+        signed_entity_type: SignedEntityType,
+
+        /// Hash of the certificate that certifies the artifact
+        certificate_hash: String,
+    },
+
+    /// A new open message has been opened, signers can now send their signatures for it
+    OpenMessageOpened {
+        /// Signed entity type of the open message
+        signed_entity_type: SignedEntityType,
+
+        /// Epoch of the open message
+        epoch: Epoch,
+    },
+
+    /// The aggregator has transitioned to a new epoch
+    EpochChanged {
+        /// The new epoch
+        epoch: Epoch,
+    },
+}
+
+impl AggregatorEventMessage {
+    /// Name of the event, as sent in the `event` field of a server-sent event
+    pub fn event_name(&self) -> &'static str {
+        match self {
+            Self::CertificateCreated { .. } => "certificate_created",
+            Self::ArtifactCreated { .. } => "artifact_created",
+            Self::OpenMessageOpened { .. } => "open_message_opened",
+            Self::EpochChanged { .. } => "epoch_changed",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entities::CardanoDbBeacon;
+
+    use super::*;
+
+    const CERTIFICATE_CREATED_JSON: &str = r#"{
+        "type": "certificate_created",
+        "data": {
+            "certificate_hash": "certificate-hash-123",
+            "epoch": 7,
+            "signed_entity_type": { "CardanoImmutableFilesFull": { "epoch": 7, "immutable_file_number": 1234 } }
+        }
+    }"#;
+
+    const EPOCH_CHANGED_JSON: &str = r#"{
+        "type": "epoch_changed",
+        "data": { "epoch": 8 }
+    }"#;
+
+    #[test]
+    fn test_certificate_created_message_compatibility() {
+        let message: AggregatorEventMessage = serde_json::from_str(CERTIFICATE_CREATED_JSON)
+            .expect(
+                "This JSON is expected to be successfully parsed into an AggregatorEventMessage instance.",
+            );
+
+        assert_eq!(
+            AggregatorEventMessage::CertificateCreated {
+                certificate_hash: "certificate-hash-123".to_string(),
+                epoch: Epoch(7),
+                signed_entity_type: SignedEntityType::CardanoImmutableFilesFull(
+                    CardanoDbBeacon::new(7, 1234)
+                ),
+            },
+            message
+        );
+        assert_eq!("certificate_created", message.event_name());
+    }
+
+    #[test]
+    fn test_epoch_changed_message_compatibility() {
+        let message: AggregatorEventMessage = serde_json::from_str(EPOCH_CHANGED_JSON).expect(
+            "This JSON is expected to be successfully parsed into an AggregatorEventMessage instance.",
+        );
+
+        assert_eq!(
+            AggregatorEventMessage::EpochChanged { epoch: Epoch(8) },
+            message
+        );
+        assert_eq!("epoch_changed", message.event_name());
+    }
+}
@@ -0,0 +1,391 @@
+use crate::entities::{
+    BlockHash, BlockNumber, CardanoBlock, CardanoBlocksSetProof, ProtocolMessage,
+    ProtocolMessagePartKey,
+};
+use crate::messages::CardanoBlocksSetProofMessagePart;
+use crate::StdError;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A cryptographic proof for a set of Cardano blocks
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub struct CardanoBlocksProofsMessage {
+    /// Hash of the certificate that validate this proof merkle root
+    pub certificate_hash: String,
+
+    /// Blocks that have been certified
+    pub certified_blocks: Vec<CardanoBlocksSetProofMessagePart>,
+
+    /// Hashes of the blocks that could not be certified
+    pub non_certified_blocks: Vec<BlockHash>,
+
+    /// Latest block number that has been certified
+    pub latest_block_number: BlockNumber,
+}
+
+impl CardanoBlocksProofsMessage {
+    /// Hashes of the blocks that have been certified
+    pub fn blocks_hashes(&self) -> Vec<BlockHash> {
+        self.certified_blocks
+            .iter()
+            .flat_map(|cb| cb.blocks.iter().map(|block| block.block_hash.clone()))
+            .collect::<Vec<_>>()
+    }
+}
+
+/// Set of blocks verified by [CardanoBlocksProofsMessage::verify].
+///
+/// Can be used to reconstruct part of a [ProtocolMessage] in order to check that
+/// it is indeed signed by a certificate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifiedCardanoBlocks {
+    certificate_hash: String,
+    merkle_root: String,
+    certified_blocks: Vec<CardanoBlock>,
+    latest_block_number: BlockNumber,
+}
+
+impl VerifiedCardanoBlocks {
+    /// Hash of the certificate that signs this struct Merkle root.
+    pub fn certificate_hash(&self) -> &str {
+        &self.certificate_hash
+    }
+
+    /// Certified blocks, with their number and slot number
+    pub fn certified_blocks(&self) -> &[CardanoBlock] {
+        &self.certified_blocks
+    }
+
+    /// Fill the given [ProtocolMessage] with the data associated with this
+    /// verified blocks set.
+    pub fn fill_protocol_message(&self, message: &mut ProtocolMessage) {
+        message.set_message_part(
+            ProtocolMessagePartKey::CardanoBlocksMerkleRoot,
+            self.merkle_root.clone(),
+        );
+
+        message.set_message_part(
+            ProtocolMessagePartKey::LatestBlockNumber,
+            self.latest_block_number.to_string(),
+        );
+    }
+}
+
+/// Error encountered or produced by the [cardano blocks proof verification][CardanoBlocksProofsMessage::verify].
+#[derive(Error, Debug)]
+pub enum VerifyCardanoBlocksProofsError {
+    /// The verification of an individual [CardanoBlocksSetProofMessagePart] failed.
+    #[error("Invalid set proof for blocks hashes: {blocks_hashes:?}")]
+    InvalidSetProof {
+        /// Hashes of the invalid blocks
+        blocks_hashes: Vec<BlockHash>,
+        /// Error source
+        source: StdError,
+    },
+
+    /// No certified blocks set proof to verify
+    #[error("There's no certified block to verify")]
+    NoCertifiedBlock,
+
+    /// Not all certified blocks set proof have the same merkle root.
+    ///
+    /// This is problematic because all the set proof should be generated from the same
+    /// merkle tree which root is signed in the [certificate][crate::entities::Certificate].
+    #[error("All certified blocks set proofs must share the same Merkle root")]
+    NonMatchingMerkleRoot,
+
+    /// An individual [CardanoBlocksSetProofMessagePart] could not be converted to a
+    /// [CardanoBlocksSetProof] for verification.
+    #[error("Malformed data or unknown Cardano Set Proof format")]
+    MalformedData(#[source] StdError),
+}
+
+impl CardanoBlocksProofsMessage {
+    /// Create a new `CardanoBlocksProofsMessage`
+    pub fn new(
+        certificate_hash: &str,
+        certified_blocks: Vec<CardanoBlocksSetProofMessagePart>,
+        non_certified_blocks: Vec<BlockHash>,
+        latest_block_number: BlockNumber,
+    ) -> Self {
+        Self {
+            certificate_hash: certificate_hash.to_string(),
+            certified_blocks,
+            non_certified_blocks,
+            latest_block_number,
+        }
+    }
+
+    /// Verify that all the certified blocks proofs are valid
+    ///
+    /// The following checks will be executed:
+    ///
+    /// 1 - Check that each Merkle proof is valid
+    ///
+    /// 2 - Check that all proofs share the same Merkle root
+    ///
+    /// 3 - Assert that there's at least one certified block
+    ///
+    /// If every check is okay, the hex encoded Merkle root of the proof will be returned.
+    pub fn verify(&self) -> Result<VerifiedCardanoBlocks, VerifyCardanoBlocksProofsError> {
+        let mut merkle_root = None;
+
+        for certified_block in &self.certified_blocks {
+            let certified_block: CardanoBlocksSetProof = certified_block
+                .clone()
+                .try_into()
+                .map_err(VerifyCardanoBlocksProofsError::MalformedData)?;
+            certified_block.verify().map_err(|e| {
+                VerifyCardanoBlocksProofsError::InvalidSetProof {
+                    blocks_hashes: certified_block
+                        .blocks()
+                        .iter()
+                        .map(|block| block.block_hash.clone())
+                        .collect(),
+                    source: e,
+                }
+            })?;
+
+            let blocks_merkle_root = Some(certified_block.merkle_root());
+
+            if merkle_root.is_none() {
+                merkle_root = blocks_merkle_root;
+            } else if merkle_root != blocks_merkle_root {
+                return Err(VerifyCardanoBlocksProofsError::NonMatchingMerkleRoot);
+            }
+        }
+
+        Ok(VerifiedCardanoBlocks {
+            certificate_hash: self.certificate_hash.clone(),
+            merkle_root: merkle_root.ok_or(VerifyCardanoBlocksProofsError::NoCertifiedBlock)?,
+            certified_blocks: self
+                .certified_blocks
+                .iter()
+                .flat_map(|c| c.blocks.clone())
+                .collect(),
+            latest_block_number: self.latest_block_number,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::crypto_helper::MKProof;
+    use crate::entities::SlotNumber;
+
+    use super::*;
+
+    #[test]
+    fn verify_malformed_proofs_fail() {
+        let blocks_proofs = CardanoBlocksProofsMessage::new(
+            "whatever",
+            vec![CardanoBlocksSetProofMessagePart {
+                blocks: vec![],
+                proof: "invalid".to_string(),
+            }],
+            vec![],
+            BlockNumber(99999),
+        );
+
+        let error = blocks_proofs
+            .verify()
+            .expect_err("Malformed blocks proofs should fail to verify itself");
+        assert!(
+            matches!(error, VerifyCardanoBlocksProofsError::MalformedData(_)),
+            "Expected 'MalformedData' error but got '{:?}'",
+            error
+        );
+    }
+
+    #[test]
+    fn verify_no_certified_block_fail() {
+        let blocks_proofs =
+            CardanoBlocksProofsMessage::new("whatever", vec![], vec![], BlockNumber(99999));
+
+        let error = blocks_proofs
+            .verify()
+            .expect_err("Proofs without certified blocks should fail to verify itself");
+        assert!(
+            matches!(error, VerifyCardanoBlocksProofsError::NoCertifiedBlock),
+            "Expected 'NoCertifiedBlock' error but got '{:?}'",
+            error
+        );
+    }
+
+    #[test]
+    fn verify_valid_proofs() {
+        let set_proof = CardanoBlocksSetProof::dummy();
+        let expected = VerifiedCardanoBlocks {
+            certificate_hash: "whatever".to_string(),
+            merkle_root: set_proof.merkle_root(),
+            certified_blocks: set_proof.blocks().to_vec(),
+            latest_block_number: BlockNumber(99999),
+        };
+        let blocks_proofs = CardanoBlocksProofsMessage::new(
+            "whatever",
+            vec![set_proof.try_into().unwrap()],
+            vec![],
+            BlockNumber(99999),
+        );
+
+        let verified_blocks = blocks_proofs
+            .verify()
+            .expect("Valid blocks proofs should verify itself");
+
+        assert_eq!(expected, verified_blocks);
+    }
+
+    #[test]
+    fn verify_invalid_proofs() {
+        let set_proof = CardanoBlocksSetProof::new(
+            vec![CardanoBlock::new(
+                "invalid1",
+                BlockNumber(1),
+                SlotNumber(10),
+            )],
+            MKProof::from_leaves(&["invalid2"]).unwrap(),
+        );
+        let blocks_proofs = CardanoBlocksProofsMessage::new(
+            "whatever",
+            vec![set_proof.try_into().unwrap()],
+            vec![],
+            BlockNumber(99999),
+        );
+
+        let error = blocks_proofs
+            .verify()
+            .expect_err("Invalid blocks proofs should fail to verify itself");
+
+        assert!(
+            matches!(
+                error,
+                VerifyCardanoBlocksProofsError::InvalidSetProof { .. },
+            ),
+            "Expected 'InvalidSetProof' error but got '{:?}'",
+            error
+        );
+    }
+
+    #[test]
+    fn verify_valid_proof_with_different_merkle_root_fail() {
+        let block_1 = CardanoBlock::new("block-1", BlockNumber(1), SlotNumber(10));
+        let block_2 = CardanoBlock::new("block-2", BlockNumber(2), SlotNumber(20));
+        let set_proofs = vec![
+            CardanoBlocksSetProof::new(
+                vec![block_1.clone()],
+                MKProof::from_leaves(&[block_1]).unwrap(),
+            ),
+            CardanoBlocksSetProof::new(
+                vec![block_2.clone()],
+                MKProof::from_leaves(&[block_2]).unwrap(),
+            ),
+        ];
+        let blocks_proofs = CardanoBlocksProofsMessage::new(
+            "whatever",
+            set_proofs
+                .into_iter()
+                .map(|p| p.try_into().unwrap())
+                .collect(),
+            vec![],
+            BlockNumber(99999),
+        );
+
+        let error = blocks_proofs
+            .verify()
+            .expect_err("Blocks proofs with non matching merkle root should fail to verify itself");
+
+        assert!(
+            matches!(error, VerifyCardanoBlocksProofsError::NonMatchingMerkleRoot,),
+            "Expected 'NonMatchingMerkleRoot' error but got '{:?}'",
+            error
+        );
+    }
+
+    #[cfg(feature = "fs")]
+    mod fs_only {
+        use crate::crypto_helper::{MKMap, MKMapNode, MKTreeStoreInMemory};
+        use crate::entities::BlockRange;
+        use crate::signable_builder::{
+            CardanoBlocksSignableBuilder, MockBlockRangeRootRetriever, MockTransactionsImporter,
+            SignableBuilder,
+        };
+        use std::sync::Arc;
+
+        use super::*;
+
+        #[tokio::test]
+        async fn verify_hashes_from_verified_cardano_blocks_and_from_signable_builder_are_equals() {
+            let blocks = vec![
+                CardanoBlock::new("block-hash-10", BlockNumber(10), SlotNumber(1)),
+                CardanoBlock::new("block-hash-20", BlockNumber(20), SlotNumber(2)),
+            ];
+
+            assert_eq!(
+                from_verified_cardano_blocks(&blocks, 99999).compute_hash(),
+                from_signable_builder(&blocks, BlockNumber(99999))
+                    .await
+                    .compute_hash()
+            );
+
+            assert_ne!(
+                from_verified_cardano_blocks(&blocks, 99999).compute_hash(),
+                from_signable_builder(&blocks, BlockNumber(123456))
+                    .await
+                    .compute_hash()
+            );
+        }
+
+        fn from_verified_cardano_blocks(
+            blocks: &[CardanoBlock],
+            block_number: u64,
+        ) -> ProtocolMessage {
+            let set_proof =
+                CardanoBlocksSetProof::from_leaves::<MKTreeStoreInMemory>(blocks).unwrap();
+
+            let verified_blocks_fake = VerifiedCardanoBlocks {
+                certificate_hash: "whatever".to_string(),
+                merkle_root: set_proof.merkle_root(),
+                certified_blocks: set_proof.blocks().to_vec(),
+                latest_block_number: BlockNumber(block_number),
+            };
+
+            let mut message = ProtocolMessage::new();
+            verified_blocks_fake.fill_protocol_message(&mut message);
+
+            message
+        }
+
+        async fn from_signable_builder(
+            blocks: &[CardanoBlock],
+            block_number: BlockNumber,
+        ) -> ProtocolMessage {
+            let mut blocks_importer = MockTransactionsImporter::new();
+            blocks_importer.expect_import().return_once(move |_| Ok(()));
+            let mut block_hash_range_root_retriever = MockBlockRangeRootRetriever::new();
+
+            let blocks_imported = blocks.to_vec();
+            block_hash_range_root_retriever
+                .expect_compute_merkle_map_from_block_range_roots()
+                .return_once(move |_| {
+                    MKMap::<
+                        BlockRange,
+                        MKMapNode<BlockRange, MKTreeStoreInMemory>,
+                        MKTreeStoreInMemory,
+                    >::new_from_iter(blocks_imported.into_iter().map(|block| {
+                        (
+                            BlockRange::from_block_number(block.block_number),
+                            MKMapNode::TreeNode(block.into()),
+                        )
+                    }))
+                });
+            let cardano_blocks_signable_builder = CardanoBlocksSignableBuilder::new(
+                Arc::new(blocks_importer),
+                Arc::new(block_hash_range_root_retriever),
+            );
+            cardano_blocks_signable_builder
+                .compute_protocol_message(block_number)
+                .await
+                .unwrap()
+        }
+    }
+}
@@ -11,6 +11,9 @@ use crate::StdError;
 #[cfg(any(test, feature = "test_tools"))]
 use crate::{entities::ProtocolMessagePartKey, test_utils::fake_keys};
 
+/// Message structure of a segment of a certificate chain, from a certificate to its ancestors
+pub type CertificateChainMessage = Vec<CertificateMessage>;
+
 /// Message structure of a certificate
 #[derive(Clone, PartialEq, Serialize, Deserialize)]
 pub struct CertificateMessage {
@@ -164,14 +167,12 @@ impl TryFrom<CertificateMessage> for Certificate {
                         })?,
                 )
             } else {
-                CertificateSignature::GenesisSignature(
-                    certificate_message
-                        .genesis_signature
-                        .try_into()
-                        .with_context(|| {
-                            "Can not convert message to certificate: can not decode the genesis signature"
-                        })?,
+                CertificateSignature::decode_genesis_signature(
+                    &certificate_message.genesis_signature,
                 )
+                .with_context(|| {
+                    "Can not convert message to certificate: can not decode the genesis signature"
+                })?
             },
         };
 
@@ -197,6 +198,12 @@ impl TryFrom<Certificate> for CertificateMessage {
             CertificateSignature::GenesisSignature(signature) => {
                 (String::new(), signature.to_bytes_hex())
             }
+            CertificateSignature::GenesisThresholdSignature(bundle) => (
+                String::new(),
+                bundle.to_json_hex().with_context(|| {
+                    "Can not convert certificate to message: can not encode the genesis signature bundle"
+                })?,
+            ),
             CertificateSignature::MultiSignature(_, signature) => (
                 signature.to_json_hex().with_context(|| {
                     "Can not convert certificate to message: can not encode the multi-signature"
@@ -1,8 +1,10 @@
+mod cardano_blocks_set_proof;
 mod cardano_transactions_set_proof;
 mod certificate_metadata;
 mod signed_entity_type_message;
 mod signer;
 
+pub use cardano_blocks_set_proof::CardanoBlocksSetProofMessagePart;
 pub use cardano_transactions_set_proof::CardanoTransactionsSetProofMessagePart;
 pub use certificate_metadata::CertificateMetadataMessagePart;
 pub use signed_entity_type_message::{CardanoDbBeaconMessagePart, SignedEntityTypeMessagePart};
@@ -48,6 +48,9 @@ pub enum SignedEntityTypeMessagePart {
 
     /// Cardano Transactions
     CardanoTransactions(Epoch, BlockNumber),
+
+    /// Cardano Blocks
+    CardanoBlocks(Epoch, BlockNumber),
 }
 
 impl CardanoDbBeaconMessagePart {
@@ -114,6 +117,9 @@ impl From<SignedEntityTypeMessagePart> for SignedEntityType {
                 Self::CardanoTransactions(epoch, block_number)
             }
             SignedEntityTypeMessagePart::CardanoDatabase(beacon) => Self::CardanoDatabase(beacon),
+            SignedEntityTypeMessagePart::CardanoBlocks(epoch, block_number) => {
+                Self::CardanoBlocks(epoch, block_number)
+            }
         }
     }
 }
@@ -141,6 +147,10 @@ impl PartialEq<SignedEntityTypeMessagePart> for SignedEntityType {
                 &SignedEntityType::CardanoDatabase(left_beacon),
                 &SignedEntityTypeMessagePart::CardanoDatabase(right_beacon),
             ) => left_beacon == right_beacon,
+            (
+                &SignedEntityType::CardanoBlocks(left_epoch, left_block_number),
+                &SignedEntityTypeMessagePart::CardanoBlocks(right_epoch, right_block_number),
+            ) => left_epoch == right_epoch && left_block_number == right_block_number,
             _ => false,
         }
     }
@@ -172,6 +182,9 @@ impl<N: Into<String>> From<(SignedEntityType, N)> for SignedEntityTypeMessagePar
                 Self::CardanoTransactions(epoch, block_number)
             }
             SignedEntityType::CardanoDatabase(beacon) => Self::CardanoDatabase(beacon),
+            SignedEntityType::CardanoBlocks(epoch, block_number) => {
+                Self::CardanoBlocks(epoch, block_number)
+            }
         }
     }
 }
@@ -247,6 +260,13 @@ mod tests {
                 CardanoDbBeacon::new(98, 123)
             ))
         );
+        assert_eq!(
+            SignedEntityType::CardanoBlocks(Epoch(123), BlockNumber(4678)),
+            SignedEntityType::from(SignedEntityTypeMessagePart::CardanoBlocks(
+                Epoch(123),
+                BlockNumber(4678)
+            ))
+        );
     }
 
     #[test]
@@ -288,6 +308,13 @@ mod tests {
                 "unused"
             ))
         );
+        assert_eq!(
+            SignedEntityTypeMessagePart::CardanoBlocks(Epoch(123), BlockNumber(4678)),
+            SignedEntityTypeMessagePart::from((
+                SignedEntityType::CardanoBlocks(Epoch(123), BlockNumber(4678)),
+                "unused"
+            ))
+        );
     }
 
     #[test]
@@ -411,5 +438,19 @@ mod tests {
             SignedEntityType::CardanoDatabase(CardanoDbBeacon::new(*epoch, immutable + 3)),
             SignedEntityTypeMessagePart::CardanoDatabase(CardanoDbBeacon::new(*epoch, immutable)),
         );
+
+        // CardanoBlocks
+        assert_commutative_eq(
+            SignedEntityType::CardanoBlocks(epoch, block_number),
+            SignedEntityTypeMessagePart::CardanoBlocks(epoch, block_number),
+        );
+        assert_commutative_ne(
+            SignedEntityType::CardanoBlocks(epoch, block_number + 3),
+            SignedEntityTypeMessagePart::CardanoBlocks(epoch, block_number),
+        );
+        assert_commutative_ne(
+            SignedEntityType::CardanoTransactions(epoch, block_number),
+            SignedEntityTypeMessagePart::CardanoBlocks(epoch, block_number),
+        );
     }
 }
@@ -1,7 +1,9 @@
 //! Messages module
 //! This module aims at providing shared structures for API communications.
+mod aggregator_event;
 mod aggregator_features;
 mod aggregator_status;
+mod cardano_blocks_proof;
 mod cardano_database;
 mod cardano_database_digest_list;
 mod cardano_database_immutable_files_restored;
@@ -25,10 +27,14 @@ mod snapshot;
 mod snapshot_download;
 mod snapshot_list;
 
+pub use aggregator_event::AggregatorEventMessage;
 pub use aggregator_features::{
     AggregatorCapabilities, AggregatorFeaturesMessage, CardanoTransactionsProverCapabilities,
 };
 pub use aggregator_status::AggregatorStatusMessage;
+pub use cardano_blocks_proof::{
+    CardanoBlocksProofsMessage, VerifiedCardanoBlocks, VerifyCardanoBlocksProofsError,
+};
 pub use cardano_database::{ArtifactsLocationsMessagePart, CardanoDatabaseSnapshotMessage};
 pub use cardano_database_digest_list::{
     CardanoDatabaseDigestListItemMessage, CardanoDatabaseDigestListMessage,
@@ -49,7 +55,7 @@ pub use cardano_transactions_proof::{
     CardanoTransactionsProofsMessage, VerifiedCardanoTransactions,
     VerifyCardanoTransactionsProofsError,
 };
-pub use certificate::CertificateMessage;
+pub use certificate::{CertificateChainMessage, CertificateMessage};
 pub use certificate_list::{
     CertificateListItemMessage, CertificateListItemMessageMetadata, CertificateListMessage,
 };
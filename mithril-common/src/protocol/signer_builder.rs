@@ -1,12 +1,12 @@
 use anyhow::Context;
 use rand_chacha::ChaCha20Rng;
 use rand_core::{CryptoRng, RngCore, SeedableRng};
-use std::path::Path;
+use std::sync::Arc;
 use thiserror::Error;
 
 use crate::{
     crypto_helper::{
-        ProtocolAggregateVerificationKey, ProtocolClerk, ProtocolClosedKeyRegistration,
+        KesSigner, ProtocolAggregateVerificationKey, ProtocolClerk, ProtocolClosedKeyRegistration,
         ProtocolInitializer, ProtocolKeyRegistration, ProtocolStakeDistribution,
     },
     entities::{PartyId, ProtocolParameters, SignerWithStake},
@@ -90,12 +90,12 @@ impl SignerBuilder {
     fn build_single_signer_with_rng<R: RngCore + CryptoRng>(
         &self,
         signer_with_stake: SignerWithStake,
-        kes_secret_key_path: Option<&Path>,
+        kes_signer: Option<Arc<dyn KesSigner>>,
         rng: &mut R,
     ) -> StdResult<(SingleSigner, ProtocolInitializer)> {
         let protocol_initializer = ProtocolInitializer::setup(
             self.protocol_parameters.clone().into(),
-            kes_secret_key_path,
+            kes_signer,
             signer_with_stake.kes_period,
             signer_with_stake.stake,
             rng,
@@ -127,13 +127,9 @@ impl SignerBuilder {
     pub fn build_single_signer(
         &self,
         signer_with_stake: SignerWithStake,
-        kes_secret_key_path: Option<&Path>,
+        kes_signer: Option<Arc<dyn KesSigner>>,
     ) -> StdResult<(SingleSigner, ProtocolInitializer)> {
-        self.build_single_signer_with_rng(
-            signer_with_stake,
-            kes_secret_key_path,
-            &mut rand_core::OsRng,
-        )
+        self.build_single_signer_with_rng(signer_with_stake, kes_signer, &mut rand_core::OsRng)
     }
 
     /// Build deterministic [SingleSigner] and [ProtocolInitializer] based on the registered parties.
@@ -142,7 +138,7 @@ impl SignerBuilder {
     pub fn build_test_single_signer(
         &self,
         signer_with_stake: SignerWithStake,
-        kes_secret_key_path: Option<&Path>,
+        kes_signer: Option<Arc<dyn KesSigner>>,
     ) -> StdResult<(SingleSigner, ProtocolInitializer)> {
         let protocol_initializer_seed: [u8; 32] = signer_with_stake.party_id.as_bytes()[..32]
             .try_into()
@@ -150,7 +146,7 @@ impl SignerBuilder {
 
         self.build_single_signer_with_rng(
             signer_with_stake,
-            kes_secret_key_path,
+            kes_signer,
             &mut ChaCha20Rng::from_seed(protocol_initializer_seed),
         )
     }
@@ -262,7 +258,7 @@ mod test {
         .unwrap()
         .build_test_single_signer(
             non_registered_signer.signer_with_stake.clone(),
-            non_registered_signer.kes_secret_key_path(),
+            non_registered_signer.kes_signer(),
         )
         .expect_err(
             "We should not be able to construct a single signer from a not registered party",
@@ -291,10 +287,7 @@ mod test {
         .unwrap();
 
         builder
-            .build_test_single_signer(
-                signer.signer_with_stake.clone(),
-                signer.kes_secret_key_path(),
-            )
+            .build_test_single_signer(signer.signer_with_stake.clone(), signer.kes_signer())
             .expect("Should be able to build test single signer for a registered party");
     }
 
@@ -311,10 +304,7 @@ mod test {
         .unwrap();
 
         let (_, initializer) = first_builder
-            .build_test_single_signer(
-                signer.signer_with_stake.clone(),
-                signer.kes_secret_key_path(),
-            )
+            .build_test_single_signer(signer.signer_with_stake.clone(), signer.kes_signer())
             .unwrap();
 
         let second_builder = SignerBuilder::new(
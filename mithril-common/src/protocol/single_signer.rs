@@ -1,5 +1,7 @@
+use anyhow::Context;
+
 use crate::{
-    crypto_helper::ProtocolSigner,
+    crypto_helper::{ProtocolLotteryPreview, ProtocolSigner},
     entities::{PartyId, SingleSignatures},
     protocol::ToMessage,
     StdResult,
@@ -39,6 +41,16 @@ impl SingleSigner {
         }
     }
 
+    /// Preview the lottery of this signer for the stake distribution of its registration.
+    pub fn lottery_preview(&self) -> StdResult<ProtocolLotteryPreview> {
+        self.protocol_signer.lottery_preview().with_context(|| {
+            format!(
+                "Lottery preview of signer '{}' is unavailable without a closed registration",
+                self.party_id
+            )
+        })
+    }
+
     /// Return the partyId associated with this Signer.
     pub fn get_party_id(&self) -> PartyId {
         self.party_id.clone()
@@ -48,7 +60,8 @@ impl SingleSigner {
 #[cfg(test)]
 mod test {
     use crate::{
-        entities::ProtocolMessage, protocol::SignerBuilder, test_utils::MithrilFixtureBuilder,
+        crypto_helper::ProtocolParameters, entities::ProtocolMessage, protocol::SignerBuilder,
+        test_utils::MithrilFixtureBuilder,
     };
 
     #[test]
@@ -62,10 +75,7 @@ mod test {
             &fixture.protocol_parameters(),
         )
         .unwrap()
-        .build_test_single_signer(
-            signer.signer_with_stake.clone(),
-            signer.kes_secret_key_path(),
-        )
+        .build_test_single_signer(signer.signer_with_stake.clone(), signer.kes_signer())
         .unwrap();
 
         let signature = single_signer
@@ -74,4 +84,32 @@ mod test {
 
         assert!(signature.is_some());
     }
+
+    #[test]
+    fn single_signer_lottery_preview_is_computed_with_its_stake() {
+        let fixture = MithrilFixtureBuilder::default().with_signers(3).build();
+        let signers = fixture.signers_fixture();
+        let signer = signers.first().unwrap();
+        let total_stake = fixture
+            .signers_with_stake()
+            .iter()
+            .map(|s| s.stake)
+            .sum::<u64>();
+
+        let (single_signer, _) = SignerBuilder::new(
+            &fixture.signers_with_stake(),
+            &fixture.protocol_parameters(),
+        )
+        .unwrap()
+        .build_test_single_signer(signer.signer_with_stake.clone(), signer.kes_signer())
+        .unwrap();
+
+        let preview = single_signer.lottery_preview().unwrap();
+
+        assert_eq!(
+            ProtocolParameters::from(fixture.protocol_parameters())
+                .lottery_preview(signer.signer_with_stake.stake, total_stake),
+            preview
+        );
+    }
 }
@@ -0,0 +1,136 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    crypto_helper::MKTreeStorer,
+    entities::{BlockNumber, ProtocolMessage, ProtocolMessagePartKey},
+    signable_builder::{BlockRangeRootRetriever, SignableBuilder, TransactionsImporter},
+    StdResult,
+};
+
+/// A [CardanoBlocksSignableBuilder] builder
+///
+/// The blocks are imported alongside the transactions, by the same [TransactionsImporter].
+pub struct CardanoBlocksSignableBuilder<S: MKTreeStorer> {
+    blocks_importer: Arc<dyn TransactionsImporter>,
+    block_hash_range_root_retriever: Arc<dyn BlockRangeRootRetriever<S>>,
+}
+
+impl<S: MKTreeStorer> CardanoBlocksSignableBuilder<S> {
+    /// Constructor
+    pub fn new(
+        blocks_importer: Arc<dyn TransactionsImporter>,
+        block_hash_range_root_retriever: Arc<dyn BlockRangeRootRetriever<S>>,
+    ) -> Self {
+        Self {
+            blocks_importer,
+            block_hash_range_root_retriever,
+        }
+    }
+}
+
+#[async_trait]
+impl<S: MKTreeStorer> SignableBuilder<BlockNumber> for CardanoBlocksSignableBuilder<S> {
+    async fn compute_protocol_message(&self, beacon: BlockNumber) -> StdResult<ProtocolMessage> {
+        self.blocks_importer.import(beacon).await?;
+
+        let mk_root = self
+            .block_hash_range_root_retriever
+            .compute_merkle_map_from_block_range_roots(beacon)
+            .await?
+            .compute_root()?;
+
+        let mut protocol_message = ProtocolMessage::new();
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::CardanoBlocksMerkleRoot,
+            mk_root.to_hex(),
+        );
+        protocol_message.set_message_part(
+            ProtocolMessagePartKey::LatestBlockNumber,
+            beacon.to_string(),
+        );
+
+        Ok(protocol_message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::crypto_helper::{MKMap, MKMapNode, MKTreeStoreInMemory};
+    use crate::entities::{BlockRange, CardanoBlock, SlotNumber};
+    use crate::signable_builder::{MockBlockRangeRootRetriever, MockTransactionsImporter};
+
+    use super::*;
+
+    fn compute_mk_map_from_blocks(
+        blocks: Vec<CardanoBlock>,
+    ) -> MKMap<BlockRange, MKMapNode<BlockRange, MKTreeStoreInMemory>, MKTreeStoreInMemory> {
+        MKMap::new_from_iter(blocks.iter().map(|block| {
+            (
+                BlockRange::from_block_number(block.block_number),
+                MKMapNode::TreeNode(block.into()),
+            )
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_compute_signable() {
+        let block_number = BlockNumber(1453);
+        let blocks = vec![
+            CardanoBlock::new("block-hash-10", BlockNumber(10), SlotNumber(100)),
+            CardanoBlock::new("block-hash-20", BlockNumber(20), SlotNumber(200)),
+        ];
+        let mk_map = compute_mk_map_from_blocks(blocks.clone());
+        let mut blocks_importer = MockTransactionsImporter::new();
+        blocks_importer
+            .expect_import()
+            .withf(move |up_to| *up_to == block_number)
+            .return_once(|_| Ok(()));
+        let mut block_hash_range_root_retriever = MockBlockRangeRootRetriever::new();
+        block_hash_range_root_retriever
+            .expect_compute_merkle_map_from_block_range_roots()
+            .return_once(move |_| Ok(compute_mk_map_from_blocks(blocks)));
+        let cardano_blocks_signable_builder = CardanoBlocksSignableBuilder::new(
+            Arc::new(blocks_importer),
+            Arc::new(block_hash_range_root_retriever),
+        );
+
+        let signable = cardano_blocks_signable_builder
+            .compute_protocol_message(block_number)
+            .await
+            .unwrap();
+
+        let mut signable_expected = ProtocolMessage::new();
+        signable_expected.set_message_part(
+            ProtocolMessagePartKey::CardanoBlocksMerkleRoot,
+            mk_map.compute_root().unwrap().to_hex(),
+        );
+        signable_expected.set_message_part(
+            ProtocolMessagePartKey::LatestBlockNumber,
+            format!("{}", block_number),
+        );
+        assert_eq!(signable_expected, signable);
+    }
+
+    #[tokio::test]
+    async fn test_compute_signable_with_no_block_hash_range_root_return_error() {
+        let mut blocks_importer = MockTransactionsImporter::new();
+        blocks_importer.expect_import().return_once(|_| Ok(()));
+        let mut block_hash_range_root_retriever = MockBlockRangeRootRetriever::new();
+        block_hash_range_root_retriever
+            .expect_compute_merkle_map_from_block_range_roots()
+            .return_once(move |_| Ok(compute_mk_map_from_blocks(vec![])));
+        let cardano_blocks_signable_builder = CardanoBlocksSignableBuilder::new(
+            Arc::new(blocks_importer),
+            Arc::new(block_hash_range_root_retriever),
+        );
+
+        let result = cardano_blocks_signable_builder
+            .compute_protocol_message(BlockNumber(50))
+            .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -3,9 +3,9 @@ use std::fmt::Debug;
 
 use crate::{
     entities::{
-        BlockNumber, CardanoDatabaseSnapshot, CardanoDbBeacon, CardanoStakeDistribution,
-        CardanoTransactionsSnapshot, Epoch, MithrilStakeDistribution, ProtocolMessage,
-        ProtocolMessagePartValue, Snapshot,
+        BlockNumber, CardanoBlocksSnapshot, CardanoDatabaseSnapshot, CardanoDbBeacon,
+        CardanoStakeDistribution, CardanoTransactionsSnapshot, Epoch, MithrilStakeDistribution,
+        ProtocolMessage, ProtocolMessagePartValue, Snapshot,
     },
     StdResult,
 };
@@ -75,6 +75,13 @@ impl Artifact for CardanoTransactionsSnapshot {
     }
 }
 
+#[typetag::serde]
+impl Artifact for CardanoBlocksSnapshot {
+    fn get_id(&self) -> String {
+        self.hash.clone()
+    }
+}
+
 #[typetag::serde]
 impl Artifact for MithrilStakeDistribution {
     fn get_id(&self) -> String {
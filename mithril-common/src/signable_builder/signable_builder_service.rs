@@ -32,6 +32,7 @@ pub struct MithrilSignableBuilderService {
     cardano_transactions_signable_builder: Arc<dyn SignableBuilder<BlockNumber>>,
     cardano_stake_distribution_builder: Arc<dyn SignableBuilder<Epoch>>,
     cardano_database_signable_builder: Arc<dyn SignableBuilder<CardanoDbBeacon>>,
+    cardano_blocks_signable_builder: Arc<dyn SignableBuilder<BlockNumber>>,
     logger: Logger,
 }
 
@@ -42,6 +43,7 @@ pub struct SignableBuilderServiceDependencies {
     cardano_transactions_signable_builder: Arc<dyn SignableBuilder<BlockNumber>>,
     cardano_stake_distribution_builder: Arc<dyn SignableBuilder<Epoch>>,
     cardano_database_signable_builder: Arc<dyn SignableBuilder<CardanoDbBeacon>>,
+    cardano_blocks_signable_builder: Arc<dyn SignableBuilder<BlockNumber>>,
 }
 
 impl SignableBuilderServiceDependencies {
@@ -52,6 +54,7 @@ impl SignableBuilderServiceDependencies {
         cardano_transactions_signable_builder: Arc<dyn SignableBuilder<BlockNumber>>,
         cardano_stake_distribution_builder: Arc<dyn SignableBuilder<Epoch>>,
         cardano_database_signable_builder: Arc<dyn SignableBuilder<CardanoDbBeacon>>,
+        cardano_blocks_signable_builder: Arc<dyn SignableBuilder<BlockNumber>>,
     ) -> Self {
         Self {
             mithril_stake_distribution_builder,
@@ -59,6 +62,7 @@ impl SignableBuilderServiceDependencies {
             cardano_transactions_signable_builder,
             cardano_stake_distribution_builder,
             cardano_database_signable_builder,
+            cardano_blocks_signable_builder,
         }
     }
 }
@@ -78,6 +82,7 @@ impl MithrilSignableBuilderService {
                 .cardano_transactions_signable_builder,
             cardano_stake_distribution_builder: dependencies.cardano_stake_distribution_builder,
             cardano_database_signable_builder: dependencies.cardano_database_signable_builder,
+            cardano_blocks_signable_builder: dependencies.cardano_blocks_signable_builder,
             logger: logger.new_with_component_name::<Self>(),
         }
     }
@@ -126,6 +131,13 @@ impl MithrilSignableBuilderService {
                 .with_context(|| format!(
                     "Signable builder service can not compute protocol message for Cardano database with beacon: '{beacon}'"
                 ))?,
+            SignedEntityType::CardanoBlocks(_, block_number) => self
+                .cardano_blocks_signable_builder
+                .compute_protocol_message(block_number)
+                .await
+                .with_context(|| format!(
+                    "Signable builder service can not compute protocol message for Cardano blocks with block_number: '{block_number}'"
+                ))?,
         };
 
         Ok(protocol_message)
@@ -209,6 +221,7 @@ mod tests {
         mock_cardano_transactions_signable_builder: MockSignableBuilderImpl<BlockNumber>,
         mock_cardano_stake_distribution_signable_builder: MockSignableBuilderImpl<Epoch>,
         mock_cardano_database_signable_builder: MockSignableBuilderImpl<CardanoDbBeacon>,
+        mock_cardano_blocks_signable_builder: MockSignableBuilderImpl<BlockNumber>,
     }
 
     impl MockDependencyInjector {
@@ -220,6 +233,7 @@ mod tests {
                 mock_cardano_stake_distribution_signable_builder: MockSignableBuilderImpl::new(),
                 mock_cardano_transactions_signable_builder: MockSignableBuilderImpl::new(),
                 mock_cardano_database_signable_builder: MockSignableBuilderImpl::new(),
+                mock_cardano_blocks_signable_builder: MockSignableBuilderImpl::new(),
             }
         }
 
@@ -230,6 +244,7 @@ mod tests {
                 Arc::new(self.mock_cardano_transactions_signable_builder),
                 Arc::new(self.mock_cardano_stake_distribution_signable_builder),
                 Arc::new(self.mock_cardano_database_signable_builder),
+                Arc::new(self.mock_cardano_blocks_signable_builder),
             );
 
             MithrilSignableBuilderService::new(
@@ -348,4 +363,21 @@ mod tests {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn build_blocks_signable_when_given_cardano_blocks_entity_type() {
+        let mut mock_container = build_mock_container();
+        mock_container
+            .mock_cardano_blocks_signable_builder
+            .expect_compute_protocol_message()
+            .once()
+            .return_once(|_| Ok(ProtocolMessage::new()));
+        let signable_builder_service = mock_container.build_signable_builder_service();
+        let signed_entity_type = SignedEntityType::CardanoBlocks(Epoch(5), BlockNumber(1000));
+
+        signable_builder_service
+            .compute_protocol_message(signed_entity_type)
+            .await
+            .unwrap();
+    }
 }
@@ -2,8 +2,8 @@ use chrono::{DateTime, Utc};
 
 use super::Artifact;
 use crate::entities::{
-    CardanoStakeDistribution, CardanoTransactionsSnapshot, MithrilStakeDistribution,
-    SignedEntityType, Snapshot,
+    CardanoBlocksSnapshot, CardanoStakeDistribution, CardanoTransactionsSnapshot,
+    MithrilStakeDistribution, SignedEntityType, Snapshot,
 };
 #[cfg(any(test, feature = "test_tools"))]
 use crate::test_utils::fake_data;
@@ -85,6 +85,24 @@ impl SignedEntity<CardanoTransactionsSnapshot> {
     }
 }
 
+impl SignedEntity<CardanoBlocksSnapshot> {
+    cfg_test_tools! {
+        /// Create a dummy [SignedEntity] for [CardanoBlocksSnapshot] entity
+        pub fn dummy() -> Self {
+            let block_number = crate::entities::BlockNumber(50);
+            SignedEntity {
+                signed_entity_id: "blocks-snapshot-id-123".to_string(),
+                signed_entity_type: SignedEntityType::CardanoBlocks(Epoch(5), block_number),
+                certificate_id: "certificate-hash-123".to_string(),
+                artifact: CardanoBlocksSnapshot::new("mkroot123".to_string(), block_number),
+                created_at: DateTime::parse_from_rfc3339("2023-01-19T13:43:05.618857482Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            }
+        }
+    }
+}
+
 impl SignedEntity<CardanoStakeDistribution> {
     cfg_test_tools! {
         /// Create a dummy [SignedEntity] for [CardanoStakeDistribution] entity
@@ -9,9 +9,10 @@ use std::{
 use crate::{
     certificate_chain::CertificateGenesisProducer,
     crypto_helper::{
-        ProtocolAggregateVerificationKey, ProtocolClosedKeyRegistration, ProtocolGenesisSigner,
-        ProtocolInitializer, ProtocolOpCert, ProtocolSigner, ProtocolSignerVerificationKey,
-        ProtocolSignerVerificationKeySignature, ProtocolStakeDistribution,
+        FileKesSigner, KesSigner, ProtocolAggregateVerificationKey, ProtocolClosedKeyRegistration,
+        ProtocolGenesisSigner, ProtocolInitializer, ProtocolOpCert, ProtocolSigner,
+        ProtocolSignerVerificationKey, ProtocolSignerVerificationKeySignature,
+        ProtocolStakeDistribution,
     },
     entities::{
         Certificate, Epoch, HexEncodedAggregateVerificationKey, PartyId, ProtocolParameters,
@@ -297,4 +298,11 @@ impl SignerFixture {
     pub fn kes_secret_key_path(&self) -> Option<&Path> {
         self.kes_secret_key_path.as_deref()
     }
+
+    /// Get a [KesSigner] backed by this signer kes secret key, if any
+    pub fn kes_signer(&self) -> Option<Arc<dyn KesSigner>> {
+        self.kes_secret_key_path
+            .as_ref()
+            .map(|path| Arc::new(FileKesSigner::new(path)) as Arc<dyn KesSigner>)
+    }
 }
@@ -4,6 +4,7 @@
 //! * A Open Api Spec tester
 //! * Some precomputed fake data and keys
 //! * A builder of [MithrilFixture] to generate signers alongside a stake distribution
+//! * A generator of deterministic test vectors ([TestVectorsCorpus])
 //!
 
 #[cfg(feature = "apispec")]
@@ -18,7 +19,10 @@ mod certificate_chain_builder;
 mod fixture_builder;
 mod mithril_fixture;
 mod precomputed_kes_key;
+#[cfg(unix)]
+mod socket_kes_signer_server;
 mod temp_dir;
+mod test_vectors;
 
 #[cfg(feature = "test_http_server")]
 #[cfg_attr(docsrs, doc(cfg(feature = "test_http_server")))]
@@ -30,7 +34,13 @@ pub use certificate_chain_builder::{
 };
 pub use fixture_builder::{MithrilFixtureBuilder, StakeDistributionGenerationMethod};
 pub use mithril_fixture::{MithrilFixture, SignerFixture};
+#[cfg(unix)]
+pub use socket_kes_signer_server::SocketKesSignerServer;
 pub use temp_dir::*;
+pub use test_vectors::{
+    AggregateSignatureTestVector, CertificateChainTestVector, SingleSignatureTestVector,
+    TestVectorsCorpus, TEST_VECTORS_CORPUS_VERSION,
+};
 #[cfg(test)]
 pub(crate) use utils::*;
 
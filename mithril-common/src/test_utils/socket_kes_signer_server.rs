@@ -0,0 +1,88 @@
+use anyhow::Context;
+use kes_summed_ed25519::kes::Sum6KesSig;
+use std::fs::Permissions;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::os::unix::fs::PermissionsExt;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::crypto_helper::{KesSignRequest, KesSignResponse, KesSigner};
+use crate::StdResult;
+
+/// Maximum size in bytes of a signature request
+const MAX_REQUEST_SIZE: u64 = 64 * 1024;
+
+/// Local stand-in of the external KES signing process used by a
+/// [SocketKesSigner][crate::crypto_helper::SocketKesSigner].
+///
+/// It serves the signature requests on a Unix domain socket on top of another [KesSigner].
+pub struct SocketKesSignerServer {
+    kes_signer: Arc<dyn KesSigner>,
+    listener: UnixListener,
+    socket_path: PathBuf,
+}
+
+impl SocketKesSignerServer {
+    /// Bind the server to the given socket path, the socket being only accessible to its owner.
+    pub fn bind<P: AsRef<Path>>(kes_signer: Arc<dyn KesSigner>, socket_path: P) -> StdResult<Self> {
+        let socket_path = socket_path.as_ref().to_path_buf();
+        let listener = UnixListener::bind(&socket_path).with_context(|| {
+            format!(
+                "SocketKesSignerServer can not bind socket '{}'",
+                socket_path.display()
+            )
+        })?;
+        std::fs::set_permissions(&socket_path, Permissions::from_mode(0o600))?;
+
+        Ok(Self {
+            kes_signer,
+            listener,
+            socket_path,
+        })
+    }
+
+    /// Path of the socket the server is listening on.
+    pub fn socket_path(&self) -> &Path {
+        &self.socket_path
+    }
+
+    /// Serve incoming connections, one at a time, until the listener fails.
+    pub fn serve(&self) -> StdResult<()> {
+        for stream in self.listener.incoming() {
+            self.handle_connection(stream?)?;
+        }
+
+        Ok(())
+    }
+
+    fn handle_connection(&self, stream: UnixStream) -> StdResult<()> {
+        let mut writer = stream.try_clone()?;
+        let mut line = String::new();
+        BufReader::new(stream.take(MAX_REQUEST_SIZE)).read_line(&mut line)?;
+
+        let response = match self.sign_request(&line) {
+            Ok(signature) => KesSignResponse {
+                signature: Some(signature.into()),
+                error: None,
+            },
+            Err(error) => KesSignResponse {
+                signature: None,
+                error: Some(format!("{error:?}")),
+            },
+        };
+        writeln!(writer, "{}", serde_json::to_string(&response)?)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    fn sign_request(&self, line: &str) -> StdResult<Sum6KesSig> {
+        let request: KesSignRequest =
+            serde_json::from_str(line).with_context(|| "Invalid KES sign request")?;
+        let message = hex::decode(&request.message)
+            .with_context(|| "Invalid hex message in KES sign request")?;
+
+        self.kes_signer.sign(&message, request.kes_period)
+    }
+}
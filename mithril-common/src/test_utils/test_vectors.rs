@@ -0,0 +1,405 @@
+//! Deterministic test vectors of the Mithril protocol.
+//!
+//! They provide known answers to other implementations of the Mithril verification: keys and
+//! registrations of signers, single and aggregate signatures, certificate chains, and the expected
+//! result of their verification, including negative cases.
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use rand_chacha::ChaCha20Rng;
+use rand_core::SeedableRng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    crypto_helper::{
+        ProtocolAggregateVerificationKey, ProtocolClerk, ProtocolGenesisSigner,
+        ProtocolGenesisVerificationKey, ProtocolMultiSignature, ProtocolSingleSignature,
+    },
+    entities::{Certificate, PartyId, ProtocolParameters},
+    messages::{CertificateMessage, SignerWithStakeMessagePart},
+    test_utils::{CertificateChainBuilder, MithrilFixture, MithrilFixtureBuilder},
+    StdResult,
+};
+
+/// Version of the test vectors corpus.
+///
+/// It must be incremented each time the content or the layout of the corpus changes.
+pub const TEST_VECTORS_CORPUS_VERSION: u32 = 1;
+
+/// Message signed by the single and aggregate signatures of the corpus.
+const SIGNED_MESSAGE: &[u8] = b"mithril-test-vectors-message";
+
+/// A versioned corpus of deterministic test vectors. For tests only.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestVectorsCorpus {
+    /// Version of the corpus
+    pub version: u32,
+
+    /// Protocol parameters of the signers
+    pub protocol_parameters: ProtocolParameters,
+
+    /// Registered signers with their keys and stakes
+    pub signers: Vec<SignerWithStakeMessagePart>,
+
+    /// Aggregate verification key of the registered signers
+    pub aggregate_verification_key: ProtocolAggregateVerificationKey,
+
+    /// Single signatures issued by the registered signers
+    pub single_signatures: Vec<SingleSignatureTestVector>,
+
+    /// Aggregate signatures of the registered signers
+    pub aggregate_signatures: Vec<AggregateSignatureTestVector>,
+
+    /// Certificate chains
+    pub certificate_chains: Vec<CertificateChainTestVector>,
+}
+
+/// A single signature test vector. For tests only.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SingleSignatureTestVector {
+    /// Name of the test vector
+    pub name: String,
+
+    /// Party id of the registered signer to verify the signature against
+    pub party_id: PartyId,
+
+    /// Signed message (hex encoded)
+    pub message: String,
+
+    /// Single signature
+    pub signature: ProtocolSingleSignature,
+
+    /// Expected result of the verification
+    pub expected_valid: bool,
+}
+
+/// An aggregate signature test vector. For tests only.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggregateSignatureTestVector {
+    /// Name of the test vector
+    pub name: String,
+
+    /// Signed message (hex encoded)
+    pub message: String,
+
+    /// Aggregate verification key to verify the signature against
+    pub aggregate_verification_key: ProtocolAggregateVerificationKey,
+
+    /// Aggregate signature
+    pub multi_signature: ProtocolMultiSignature,
+
+    /// Expected result of the verification
+    pub expected_valid: bool,
+}
+
+/// A certificate chain test vector. For tests only.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CertificateChainTestVector {
+    /// Name of the test vector
+    pub name: String,
+
+    /// Genesis verification key to verify the chain against
+    pub genesis_verification_key: ProtocolGenesisVerificationKey,
+
+    /// Certificates of the chain, the latest first
+    pub certificates: Vec<CertificateMessage>,
+
+    /// Expected result of the verification of the chain from its latest certificate
+    pub expected_valid: bool,
+
+    /// Beginning of the message of the expected verification error, for information
+    pub expected_error: Option<String>,
+}
+
+impl TestVectorsCorpus {
+    /// Generate the corpus of test vectors.
+    ///
+    /// The generation is deterministic: all the keys and randomness are derived from fixed seeds.
+    pub fn generate() -> StdResult<Self> {
+        let protocol_parameters = ProtocolParameters::new(5, 100, 0.65);
+        let fixture = MithrilFixtureBuilder::default()
+            .with_protocol_parameters(protocol_parameters.clone())
+            .with_signers(5)
+            .build();
+        let other_fixture = MithrilFixtureBuilder::default()
+            .with_protocol_parameters(protocol_parameters.clone())
+            .with_signers(4)
+            .build();
+
+        Ok(Self {
+            version: TEST_VECTORS_CORPUS_VERSION,
+            protocol_parameters,
+            signers: SignerWithStakeMessagePart::from_signers(fixture.signers_with_stake()),
+            aggregate_verification_key: fixture.compute_avk(),
+            single_signatures: Self::generate_single_signatures(&fixture)?,
+            aggregate_signatures: Self::generate_aggregate_signatures(&fixture, &other_fixture)?,
+            certificate_chains: Self::generate_certificate_chains()?,
+        })
+    }
+
+    /// Serialize the corpus to pretty printed JSON, terminated by a new line.
+    pub fn to_json(&self) -> StdResult<String> {
+        let json = serde_json::to_string_pretty(self)
+            .with_context(|| "Could not serialize the test vectors corpus to JSON")?;
+
+        Ok(format!("{json}\n"))
+    }
+
+    fn generate_single_signatures(
+        fixture: &MithrilFixture,
+    ) -> StdResult<Vec<SingleSignatureTestVector>> {
+        let signers = fixture.signers_fixture();
+        let mut vectors = vec![];
+        for signer in &signers {
+            if let Some(signature) = signer.protocol_signer.sign(SIGNED_MESSAGE) {
+                vectors.push(SingleSignatureTestVector {
+                    name: format!("valid-signature-of-{}", signer.party_id()),
+                    party_id: signer.party_id(),
+                    message: hex::encode(SIGNED_MESSAGE),
+                    signature: signature.into(),
+                    expected_valid: true,
+                });
+            }
+        }
+        let reference = vectors
+            .first()
+            .cloned()
+            .with_context(|| "No signer won the lottery for the test vectors message")?;
+        let other_signer = signers
+            .iter()
+            .find(|s| s.party_id() != reference.party_id)
+            .with_context(|| "The test vectors need at least two signers")?;
+
+        vectors.push(SingleSignatureTestVector {
+            name: "invalid-tampered-message".to_string(),
+            message: hex::encode(b"tampered-message"),
+            expected_valid: false,
+            ..reference.clone()
+        });
+        vectors.push(SingleSignatureTestVector {
+            name: "invalid-signature-of-another-signer".to_string(),
+            party_id: other_signer.party_id(),
+            expected_valid: false,
+            ..reference
+        });
+
+        Ok(vectors)
+    }
+
+    fn generate_aggregate_signatures(
+        fixture: &MithrilFixture,
+        other_fixture: &MithrilFixture,
+    ) -> StdResult<Vec<AggregateSignatureTestVector>> {
+        let signers = fixture.signers_fixture();
+        let single_signatures = signers
+            .iter()
+            .filter_map(|s| s.protocol_signer.sign(SIGNED_MESSAGE))
+            .collect::<Vec<_>>();
+        let clerk = ProtocolClerk::from_signer(&signers[0].protocol_signer);
+        let multi_signature: ProtocolMultiSignature = clerk
+            .aggregate(&single_signatures, SIGNED_MESSAGE)
+            .with_context(|| "Could not aggregate the test vectors single signatures")?
+            .into();
+        let valid = AggregateSignatureTestVector {
+            name: "valid-aggregate-signature".to_string(),
+            message: hex::encode(SIGNED_MESSAGE),
+            aggregate_verification_key: fixture.compute_avk(),
+            multi_signature,
+            expected_valid: true,
+        };
+
+        Ok(vec![
+            AggregateSignatureTestVector {
+                name: "invalid-tampered-message".to_string(),
+                message: hex::encode(b"tampered-message"),
+                expected_valid: false,
+                ..valid.clone()
+            },
+            AggregateSignatureTestVector {
+                name: "invalid-aggregate-verification-key".to_string(),
+                aggregate_verification_key: other_fixture.compute_avk(),
+                expected_valid: false,
+                ..valid.clone()
+            },
+            valid,
+        ])
+    }
+
+    fn generate_certificate_chains() -> StdResult<Vec<CertificateChainTestVector>> {
+        let (certificates, genesis_verifier) = CertificateChainBuilder::new()
+            .with_total_certificates(5)
+            .with_certificates_per_epoch(2)
+            .with_genesis_certificate_processor(&|certificate, _, _| {
+                // The genesis certificate is sealed at the time of its creation
+                let mut certificate = certificate;
+                let sealed_at = DateTime::parse_from_rfc3339("2006-01-02T15:04:05Z")
+                    .unwrap()
+                    .with_timezone(&Utc);
+                certificate.metadata.initiated_at = sealed_at;
+                certificate.metadata.sealed_at = sealed_at;
+
+                certificate
+            })
+            .build();
+        let genesis_verification_key = genesis_verifier.to_verification_key();
+        let other_genesis_verification_key =
+            ProtocolGenesisSigner::create_test_genesis_signer(ChaCha20Rng::from_seed([1u8; 32]))
+                .verification_key();
+
+        let mut tampered_hash_certificates = certificates.clone();
+        tampered_hash_certificates[1].signed_message = "tampered-message".to_string();
+
+        let mut invalid_multi_signature_certificates = certificates.clone();
+        invalid_multi_signature_certificates[0].signature =
+            invalid_multi_signature_certificates[1].signature.clone();
+        invalid_multi_signature_certificates[0].hash =
+            invalid_multi_signature_certificates[0].compute_hash();
+
+        let chain = |name: &str,
+                     certificates: Vec<Certificate>,
+                     genesis_verification_key: ProtocolGenesisVerificationKey,
+                     expected_error: Option<&str>|
+         -> StdResult<CertificateChainTestVector> {
+            Ok(CertificateChainTestVector {
+                name: name.to_string(),
+                genesis_verification_key,
+                certificates: certificates
+                    .into_iter()
+                    .map(CertificateMessage::try_from)
+                    .collect::<StdResult<Vec<_>>>()?,
+                expected_valid: expected_error.is_none(),
+                expected_error: expected_error.map(|e| e.to_string()),
+            })
+        };
+
+        Ok(vec![
+            chain(
+                "valid-certificate-chain",
+                certificates.clone(),
+                genesis_verification_key,
+                None,
+            )?,
+            chain(
+                "invalid-certificate-hash",
+                tampered_hash_certificates,
+                genesis_verification_key,
+                Some("certificate hash unmatch error"),
+            )?,
+            chain(
+                "invalid-multi-signature",
+                invalid_multi_signature_certificates,
+                genesis_verification_key,
+                Some("multi signature verification failed"),
+            )?,
+            chain(
+                "invalid-genesis-verification-key",
+                certificates,
+                other_genesis_verification_key,
+                Some("Certificate verifier failed verifying a genesis certificate"),
+            )?,
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::certificate_chain::{
+        CertificateVerifier, FakeCertificaterRetriever, MithrilCertificateVerifier,
+    };
+    use crate::test_utils::TestLogger;
+
+    use super::*;
+
+    const CORPUS_JSON: &str = include_str!("../../test_data/test_vectors_v1.json");
+
+    #[test]
+    fn corpus_is_byte_stable() {
+        let corpus = TestVectorsCorpus::generate().unwrap();
+
+        assert_eq!(TEST_VECTORS_CORPUS_VERSION, corpus.version);
+        assert!(
+            corpus.to_json().unwrap() == CORPUS_JSON,
+            "The generated test vectors differ from the committed corpus: if the change is \
+            intended, increment TEST_VECTORS_CORPUS_VERSION, regenerate the corpus with \
+            `make test-vectors` and load the new corpus file in this test"
+        );
+    }
+
+    #[test]
+    fn single_signatures_match_expected_verification_results() {
+        let corpus = TestVectorsCorpus::generate().unwrap();
+        let avk = &corpus.aggregate_verification_key;
+
+        for vector in &corpus.single_signatures {
+            let signer = corpus
+                .signers
+                .iter()
+                .find(|s| s.party_id == vector.party_id)
+                .unwrap();
+            let verification_key: crate::crypto_helper::ProtocolSignerVerificationKey =
+                signer.verification_key.as_str().try_into().unwrap();
+            let is_valid = vector
+                .signature
+                .verify(
+                    &corpus.protocol_parameters.clone().into(),
+                    &verification_key.vk,
+                    &signer.stake,
+                    avk,
+                    &hex::decode(&vector.message).unwrap(),
+                )
+                .is_ok();
+
+            assert_eq!(vector.expected_valid, is_valid, "{}", vector.name);
+        }
+    }
+
+    #[test]
+    fn aggregate_signatures_match_expected_verification_results() {
+        let corpus = TestVectorsCorpus::generate().unwrap();
+
+        for vector in &corpus.aggregate_signatures {
+            let is_valid = vector
+                .multi_signature
+                .verify(
+                    &hex::decode(&vector.message).unwrap(),
+                    &vector.aggregate_verification_key,
+                    &corpus.protocol_parameters.clone().into(),
+                )
+                .is_ok();
+
+            assert_eq!(vector.expected_valid, is_valid, "{}", vector.name);
+        }
+    }
+
+    #[tokio::test]
+    async fn certificate_chains_match_expected_verification_results() {
+        let corpus = TestVectorsCorpus::generate().unwrap();
+
+        for vector in corpus.certificate_chains {
+            let certificates = vector
+                .certificates
+                .into_iter()
+                .map(Certificate::try_from)
+                .collect::<StdResult<Vec<_>>>()
+                .unwrap();
+            let verifier = MithrilCertificateVerifier::new(
+                TestLogger::stdout(),
+                Arc::new(FakeCertificaterRetriever::from_certificates(&certificates)),
+            );
+
+            let result = verifier
+                .verify_certificate_chain(certificates[0].clone(), &vector.genesis_verification_key)
+                .await;
+
+            assert_eq!(vector.expected_valid, result.is_ok(), "{}", vector.name);
+            if let (Some(expected_error), Err(error)) = (&vector.expected_error, result) {
+                assert!(
+                    error.to_string().starts_with(expected_error),
+                    "{}: unexpected error {error:?}",
+                    vector.name
+                );
+            }
+        }
+    }
+}
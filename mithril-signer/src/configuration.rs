@@ -1,4 +1,4 @@
-use anyhow::Context;
+use anyhow::{anyhow, Context};
 use config::{ConfigError, Map, Source, Value, ValueKind};
 use mithril_doc::{Documenter, DocumenterDefault, StructDoc};
 use serde::{Deserialize, Serialize};
@@ -6,7 +6,7 @@ use std::{path::PathBuf, sync::Arc};
 
 use mithril_common::{
     chain_observer::ChainObserver,
-    crypto_helper::tests_setup,
+    crypto_helper::{tests_setup, FileKesSigner, KesSigner},
     entities::{BlockNumber, PartyId},
     era::{
         adapters::{EraReaderAdapterBuilder, EraReaderAdapterType},
@@ -74,6 +74,12 @@ pub struct Configuration {
     /// File path to the KES secret key of the pool
     pub kes_secret_key_path: Option<PathBuf>,
 
+    /// Unix domain socket path of an external KES signing process to use instead of the KES secret key file
+    ///
+    /// Can not be set in conjunction with `kes_secret_key_path`.
+    #[example = "`/run/kes-signer/kes-signer.sock`"]
+    pub kes_signer_socket_path: Option<PathBuf>,
+
     /// File path to the operational certificate of the pool
     pub operational_certificate_path: Option<PathBuf>,
 
@@ -143,6 +149,7 @@ impl Configuration {
             data_stores_directory: PathBuf::new(),
             store_retention_limit: None,
             kes_secret_key_path: signer_temp_dir.as_ref().map(|dir| dir.join("kes.sk")),
+            kes_signer_socket_path: None,
             operational_certificate_path: signer_temp_dir
                 .as_ref()
                 .map(|dir| dir.join("opcert.cert")),
@@ -188,6 +195,30 @@ impl Configuration {
         Ok(self.data_stores_directory.join(sqlite_file_name))
     }
 
+    /// Create the KES signer used to certify the signer registration, if any.
+    ///
+    /// The KES signature is delegated to an external process if `kes_signer_socket_path` is set,
+    /// otherwise it is computed from the KES secret key file at `kes_secret_key_path`.
+    pub fn build_kes_signer(&self) -> StdResult<Option<Arc<dyn KesSigner>>> {
+        match (&self.kes_secret_key_path, &self.kes_signer_socket_path) {
+            (Some(_), Some(_)) => Err(anyhow!(
+                "Configuration: 'kes_secret_key_path' and 'kes_signer_socket_path' can not be set at the same time."
+            )),
+            (Some(kes_secret_key_path), None) => {
+                Ok(Some(Arc::new(FileKesSigner::new(kes_secret_key_path))))
+            }
+            #[cfg(unix)]
+            (None, Some(kes_signer_socket_path)) => Ok(Some(Arc::new(
+                mithril_common::crypto_helper::SocketKesSigner::new(kes_signer_socket_path),
+            ))),
+            #[cfg(not(unix))]
+            (None, Some(_)) => Err(anyhow!(
+                "Configuration: 'kes_signer_socket_path' is only supported on Unix platforms."
+            )),
+            (None, None) => Ok(None),
+        }
+    }
+
     /// Create era reader adapter from configuration settings.
     pub fn build_era_reader_adapter(
         &self,
@@ -292,3 +323,56 @@ impl Source for DefaultConfiguration {
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_kes_signer_returns_none_without_kes_configuration() {
+        let config = Configuration {
+            kes_secret_key_path: None,
+            kes_signer_socket_path: None,
+            ..Configuration::new_sample("party-123456")
+        };
+
+        assert!(config.build_kes_signer().unwrap().is_none());
+    }
+
+    #[test]
+    fn build_kes_signer_with_kes_secret_key_path() {
+        let config = Configuration {
+            kes_secret_key_path: Some(PathBuf::from("kes.sk")),
+            kes_signer_socket_path: None,
+            ..Configuration::new_sample("party-123456")
+        };
+
+        assert!(config.build_kes_signer().unwrap().is_some());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn build_kes_signer_with_kes_signer_socket_path() {
+        let config = Configuration {
+            kes_secret_key_path: None,
+            kes_signer_socket_path: Some(PathBuf::from("kes-signer.sock")),
+            ..Configuration::new_sample("party-123456")
+        };
+
+        assert!(config.build_kes_signer().unwrap().is_some());
+    }
+
+    #[test]
+    fn build_kes_signer_fails_if_both_kes_secret_key_path_and_kes_signer_socket_path_are_set() {
+        let config = Configuration {
+            kes_secret_key_path: Some(PathBuf::from("kes.sk")),
+            kes_signer_socket_path: Some(PathBuf::from("kes-signer.sock")),
+            ..Configuration::new_sample("party-123456")
+        };
+
+        assert!(
+            config.build_kes_signer().is_err(),
+            "Building a KES signer with both a file and a socket should fail"
+        );
+    }
+}
@@ -126,6 +126,15 @@ alter table new_protocol_initializer rename to protocol_initializer;
             r#"
 insert into signed_entity_type (signed_entity_type_id, name) 
     values  (4, 'Cardano Database');
+"#,
+        ),
+        // Migration 8
+        // Add the `signed_entity_type` record for 'CardanoBlocks'
+        SqlMigration::new(
+            8,
+            r#"
+insert into signed_entity_type (signed_entity_type_id, name) 
+    values  (5, 'Cardano Blocks');
 "#,
         ),
     ]
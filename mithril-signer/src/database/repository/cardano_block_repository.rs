@@ -0,0 +1,59 @@
+use std::ops::Range;
+
+use async_trait::async_trait;
+
+use mithril_common::crypto_helper::MKTreeNode;
+use mithril_common::entities::{BlockNumber, BlockRange, CardanoBlock, ChainPoint, SlotNumber};
+use mithril_common::StdResult;
+use mithril_persistence::database::repository::CardanoBlockRepository;
+
+use crate::services::{BlockStore, TransactionPruner};
+
+#[async_trait]
+impl BlockStore for CardanoBlockRepository {
+    async fn get_highest_beacon(&self) -> StdResult<Option<ChainPoint>> {
+        self.get_block_highest_chain_point().await
+    }
+
+    async fn get_highest_block_range(&self) -> StdResult<Option<BlockRange>> {
+        let record = self.retrieve_highest_block_hash_range_root().await?;
+        Ok(record.map(|record| record.range))
+    }
+
+    async fn store_blocks(&self, blocks: Vec<CardanoBlock>) -> StdResult<()> {
+        self.store_blocks(blocks).await
+    }
+
+    async fn get_blocks_in_range(&self, range: Range<BlockNumber>) -> StdResult<Vec<CardanoBlock>> {
+        self.get_blocks_in_range_blocks(range).await.map(|v| {
+            v.into_iter()
+                .map(|record| record.into())
+                .collect::<Vec<CardanoBlock>>()
+        })
+    }
+
+    async fn store_block_range_roots(
+        &self,
+        block_ranges: Vec<(BlockRange, MKTreeNode)>,
+    ) -> StdResult<()> {
+        if !block_ranges.is_empty() {
+            self.create_block_hash_range_roots(block_ranges).await?;
+        }
+        Ok(())
+    }
+
+    async fn remove_rolled_back_blocks_and_block_range(
+        &self,
+        slot_number: SlotNumber,
+    ) -> StdResult<()> {
+        self.remove_rolled_back_blocks_and_block_range_by_slot_number(slot_number)
+            .await
+    }
+}
+
+#[async_trait]
+impl TransactionPruner for CardanoBlockRepository {
+    async fn prune(&self, number_of_blocks_to_keep: BlockNumber) -> StdResult<()> {
+        self.prune_blocks(number_of_blocks_to_keep).await
+    }
+}
@@ -1,5 +1,6 @@
 //! Signer related database repositories
 
+mod cardano_block_repository;
 mod cardano_transaction_repository;
 mod protocol_initializer_repository;
 mod signed_beacon_repository;
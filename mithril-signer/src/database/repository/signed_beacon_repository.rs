@@ -97,7 +97,9 @@ mod tests {
 
     use crate::database::query::GetSignedBeaconQuery;
     use crate::database::record::SignedBeaconRecord;
-    use crate::database::test_helper::{insert_signed_beacons, main_db_connection};
+    use crate::database::test_helper::{
+        insert_signed_beacons, main_db_connection, main_db_connection_with_foreign_keys,
+    };
 
     use super::*;
 
@@ -288,6 +290,29 @@ mod tests {
         assert_eq!(beacon_to_sign, signed_beacon);
     }
 
+    #[tokio::test]
+    async fn mark_cardano_blocks_beacon_as_signed_with_foreign_keys_enabled() {
+        let connection = Arc::new(main_db_connection_with_foreign_keys().unwrap());
+        let repository = SignedBeaconRepository::new(connection.clone(), None);
+
+        let beacon_to_sign = BeaconToSign {
+            epoch: Epoch(13),
+            signed_entity_type: SignedEntityType::CardanoBlocks(Epoch(13), BlockNumber(120)),
+            initiated_at: Utc::now(),
+        };
+
+        repository
+            .mark_beacon_as_signed(&beacon_to_sign)
+            .await
+            .unwrap();
+
+        let signed_beacon = connection
+            .fetch_first(GetSignedBeaconQuery::all())
+            .unwrap()
+            .expect("A signed beacon should have been inserted");
+        assert_eq!(beacon_to_sign, signed_beacon);
+    }
+
     #[tokio::test]
     async fn test_dont_execute_pruning_tasks_if_no_retention_limit_set() {
         let connection = Arc::new(main_db_connection().unwrap());
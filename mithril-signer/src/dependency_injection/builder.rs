@@ -21,16 +21,18 @@ use mithril_common::digesters::{
 };
 use mithril_common::era::{EraChecker, EraReader};
 use mithril_common::signable_builder::{
-    CardanoDatabaseSignableBuilder, CardanoImmutableFilesFullSignableBuilder,
-    CardanoStakeDistributionSignableBuilder, CardanoTransactionsSignableBuilder,
-    MithrilSignableBuilderService, MithrilStakeDistributionSignableBuilder,
-    SignableBuilderServiceDependencies,
+    CardanoBlocksSignableBuilder, CardanoDatabaseSignableBuilder,
+    CardanoImmutableFilesFullSignableBuilder, CardanoStakeDistributionSignableBuilder,
+    CardanoTransactionsSignableBuilder, MithrilSignableBuilderService,
+    MithrilStakeDistributionSignableBuilder, SignableBuilderServiceDependencies,
 };
 use mithril_common::{MithrilTickerService, StdResult, TickerService};
 use mithril_signed_entity_lock::SignedEntityTypeLock;
 use mithril_signed_entity_preloader::CardanoTransactionsPreloader;
 
-use mithril_persistence::database::repository::CardanoTransactionRepository;
+use mithril_persistence::database::repository::{
+    CardanoBlockRepository, CardanoTransactionRepository,
+};
 use mithril_persistence::database::{ApplicationNodeType, SqlMigration};
 use mithril_persistence::sqlite::{ConnectionBuilder, SqliteConnection, SqliteConnectionPool};
 
@@ -274,6 +276,9 @@ impl<'a> DependenciesBuilder<'a> {
         let transaction_store = Arc::new(CardanoTransactionRepository::new(
             sqlite_connection_cardano_transaction_pool.clone(),
         ));
+        let block_store = Arc::new(CardanoBlockRepository::new(
+            sqlite_connection_cardano_transaction_pool.clone(),
+        ));
         let chain_block_reader = PallasChainReader::new(
             &self.config.cardano_node_socket_path,
             network,
@@ -285,17 +290,29 @@ impl<'a> DependenciesBuilder<'a> {
                 .cardano_transactions_block_streamer_max_roll_forwards_per_poll,
             self.root_logger(),
         ));
-        let transactions_importer = Arc::new(CardanoTransactionsImporter::new(
-            block_scanner,
+        let transactions_importer = Arc::new(
+            CardanoTransactionsImporter::new(
+                block_scanner,
+                transaction_store.clone(),
+                self.root_logger(),
+            )
+            .with_block_store(block_store.clone()),
+        );
+        // Wrap the transaction importer with decorators to prune the transactions and the blocks
+        // after import
+        let transactions_importer = Arc::new(TransactionsImporterWithPruner::new(
+            self.config
+                .enable_transaction_pruning
+                .then_some(self.config.network_security_parameter),
             transaction_store.clone(),
+            transactions_importer,
             self.root_logger(),
         ));
-        // Wrap the transaction importer with decorator to prune the transactions after import
         let transactions_importer = Arc::new(TransactionsImporterWithPruner::new(
             self.config
                 .enable_transaction_pruning
                 .then_some(self.config.network_security_parameter),
-            transaction_store.clone(),
+            block_store.clone(),
             transactions_importer,
             self.root_logger(),
         ));
@@ -323,9 +340,14 @@ impl<'a> DependenciesBuilder<'a> {
         let cardano_transactions_builder = Arc::new(CardanoTransactionsSignableBuilder::<
             MKTreeStoreSqlite,
         >::new(
-            state_machine_transactions_importer,
+            state_machine_transactions_importer.clone(),
             block_range_root_retriever,
         ));
+        let cardano_blocks_builder =
+            Arc::new(CardanoBlocksSignableBuilder::<MKTreeStoreSqlite>::new(
+                state_machine_transactions_importer.clone(),
+                block_store,
+            ));
         let cardano_stake_distribution_signable_builder = Arc::new(
             CardanoStakeDistributionSignableBuilder::new(stake_store.clone()),
         );
@@ -354,6 +376,7 @@ impl<'a> DependenciesBuilder<'a> {
             cardano_transactions_builder,
             cardano_stake_distribution_signable_builder,
             cardano_database_signable_builder,
+            cardano_blocks_builder,
         );
         let signable_builder_service = Arc::new(MithrilSignableBuilderService::new(
             signable_seed_builder_service,
@@ -395,6 +418,8 @@ impl<'a> DependenciesBuilder<'a> {
             self.root_logger(),
         ));
 
+        let kes_signer = self.config.build_kes_signer()?;
+
         let services = SignerDependencyContainer {
             ticker_service,
             certificate_handler: aggregator_client,
@@ -413,6 +438,7 @@ impl<'a> DependenciesBuilder<'a> {
             upkeep_service,
             epoch_service,
             certifier,
+            kes_signer,
         };
 
         Ok(services)
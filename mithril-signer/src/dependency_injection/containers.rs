@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use mithril_common::api_version::APIVersionProvider;
 use mithril_common::chain_observer::ChainObserver;
+use mithril_common::crypto_helper::KesSigner;
 use mithril_common::digesters::ImmutableDigester;
 use mithril_common::era::{EraChecker, EraReader};
 use mithril_common::signable_builder::SignableBuilderService;
@@ -81,4 +82,7 @@ pub struct SignerDependencyContainer {
 
     /// Certifier service
     pub certifier: Arc<dyn CertifierService>,
+
+    /// KES signer used to certify the signer registration, if any
+    pub kes_signer: Option<Arc<dyn KesSigner>>,
 }
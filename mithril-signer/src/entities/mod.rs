@@ -4,6 +4,10 @@
 
 mod beacon_to_sign;
 mod signer_epoch_settings;
+mod signer_kes_period_status;
+mod signer_lottery_preview;
 
 pub use beacon_to_sign::*;
 pub use signer_epoch_settings::*;
+pub use signer_kes_period_status::*;
+pub use signer_lottery_preview::*;
@@ -0,0 +1,249 @@
+use serde::Serialize;
+use thiserror::Error;
+
+use mithril_common::crypto_helper::{KESPeriod, OpCert};
+
+/// Maximum number of evolutions of the KES key certified by an operational certificate
+/// (`maxKESEvolutions` of the Shelley genesis, identical on all the Cardano networks)
+pub const MAX_KES_EVOLUTIONS: KESPeriod = 62;
+
+/// Number of remaining KES periods under which the expiry of the KES key is a warning
+pub const KES_EXPIRY_WARNING_REMAINING_PERIODS: KESPeriod = 10;
+
+/// Number of remaining KES periods under which the expiry of the KES key is critical
+pub const KES_EXPIRY_CRITICAL_REMAINING_PERIODS: KESPeriod = 2;
+
+/// Errors preventing a signer from registering with its operational certificate
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SignerRegistrationError {
+    /// The operational certificate is not signed by the cold key of the pool
+    #[error("The operational certificate is invalid: its signature by the pool cold key can not be verified.")]
+    OperationalCertificateInvalid,
+
+    /// The operational certificate starts after the current KES period
+    #[error("The operational certificate is not valid yet: its start KES period {start_kes_period} is after the current KES period {current_kes_period}.")]
+    OperationalCertificateNotYetValid {
+        /// Start KES period of the operational certificate
+        start_kes_period: KESPeriod,
+        /// Current KES period of the Cardano chain
+        current_kes_period: KESPeriod,
+    },
+
+    /// The KES key certified by the operational certificate has expired
+    #[error("The KES key has expired: the operational certificate starting at KES period {start_kes_period} is valid for {max_kes_evolutions} KES periods but the current KES period is {current_kes_period}. A new KES key and operational certificate must be issued.")]
+    KesKeyExpired {
+        /// Start KES period of the operational certificate
+        start_kes_period: KESPeriod,
+        /// Current KES period of the Cardano chain
+        current_kes_period: KESPeriod,
+        /// Maximum number of evolutions of the KES key
+        max_kes_evolutions: KESPeriod,
+    },
+
+    /// The counter of the operational certificate does not match the node protocol state
+    #[error("The operational certificate counter {opcert_counter} is invalid: it must be equal to the node protocol state counter {node_state_counter} or be one more.")]
+    OperationalCertificateCounterInvalid {
+        /// Counter of the operational certificate
+        opcert_counter: u64,
+        /// Counter of the pool known by the node protocol state
+        node_state_counter: u64,
+    },
+}
+
+/// Alert level of the expiry of the KES key of a signer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub enum KesExpiryAlertLevel {
+    /// The KES key is far from its expiry
+    Ok,
+    /// The KES key will expire soon
+    Warning,
+    /// The KES key is about to expire
+    Critical,
+    /// The KES key has expired
+    Expired,
+}
+
+/// SignerKesPeriodStatus represents the validity of the KES key and operational certificate of a signer
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct SignerKesPeriodStatus {
+    /// Current KES period of the Cardano chain
+    pub current_kes_period: KESPeriod,
+
+    /// Start KES period of the operational certificate
+    pub start_kes_period: KESPeriod,
+
+    /// Maximum number of evolutions of the KES key
+    pub max_kes_evolutions: KESPeriod,
+
+    /// Counter of the operational certificate
+    pub opcert_counter: u64,
+
+    /// Counter of the pool known by the node protocol state, if available
+    pub node_state_opcert_counter: Option<u64>,
+}
+
+impl SignerKesPeriodStatus {
+    /// Compute the KES period status of an operational certificate
+    pub fn compute(
+        opcert: &OpCert,
+        current_kes_period: KESPeriod,
+        node_state_opcert_counter: Option<u64>,
+    ) -> Self {
+        Self {
+            current_kes_period,
+            start_kes_period: opcert.start_kes_period as KESPeriod,
+            max_kes_evolutions: MAX_KES_EVOLUTIONS,
+            opcert_counter: opcert.issue_number(),
+            node_state_opcert_counter,
+        }
+    }
+
+    /// Number of evolutions of the KES key to reach the current KES period
+    ///
+    /// None if the operational certificate is not valid yet.
+    pub fn kes_evolutions(&self) -> Option<KESPeriod> {
+        self.current_kes_period.checked_sub(self.start_kes_period)
+    }
+
+    /// Number of KES periods remaining before the expiry of the KES key
+    pub fn remaining_kes_periods(&self) -> KESPeriod {
+        (self.start_kes_period + self.max_kes_evolutions).saturating_sub(self.current_kes_period)
+    }
+
+    /// Alert level of the expiry of the KES key
+    pub fn alert_level(&self) -> KesExpiryAlertLevel {
+        match self.remaining_kes_periods() {
+            0 => KesExpiryAlertLevel::Expired,
+            remaining if remaining <= KES_EXPIRY_CRITICAL_REMAINING_PERIODS => {
+                KesExpiryAlertLevel::Critical
+            }
+            remaining if remaining <= KES_EXPIRY_WARNING_REMAINING_PERIODS => {
+                KesExpiryAlertLevel::Warning
+            }
+            _ => KesExpiryAlertLevel::Ok,
+        }
+    }
+
+    /// Check that the signer can register with this operational certificate and return the
+    /// KES period to use to sign its registration, i.e. the number of evolutions of the KES key.
+    pub fn check_can_register(&self) -> Result<KESPeriod, SignerRegistrationError> {
+        if let Some(node_state_counter) = self.node_state_opcert_counter {
+            if self.opcert_counter < node_state_counter
+                || self.opcert_counter > node_state_counter + 1
+            {
+                return Err(
+                    SignerRegistrationError::OperationalCertificateCounterInvalid {
+                        opcert_counter: self.opcert_counter,
+                        node_state_counter,
+                    },
+                );
+            }
+        }
+
+        let kes_evolutions = self.kes_evolutions().ok_or(
+            SignerRegistrationError::OperationalCertificateNotYetValid {
+                start_kes_period: self.start_kes_period,
+                current_kes_period: self.current_kes_period,
+            },
+        )?;
+        if self.alert_level() == KesExpiryAlertLevel::Expired {
+            return Err(SignerRegistrationError::KesKeyExpired {
+                start_kes_period: self.start_kes_period,
+                current_kes_period: self.current_kes_period,
+                max_kes_evolutions: self.max_kes_evolutions,
+            });
+        }
+
+        Ok(kes_evolutions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(
+        start_kes_period: KESPeriod,
+        current_kes_period: KESPeriod,
+        opcert_counter: u64,
+        node_state_opcert_counter: Option<u64>,
+    ) -> SignerKesPeriodStatus {
+        SignerKesPeriodStatus {
+            current_kes_period,
+            start_kes_period,
+            max_kes_evolutions: MAX_KES_EVOLUTIONS,
+            opcert_counter,
+            node_state_opcert_counter,
+        }
+    }
+
+    #[test]
+    fn compute_remaining_kes_periods_and_alert_level() {
+        let cases = [
+            (100, 100, 62, KesExpiryAlertLevel::Ok),
+            (100, 151, 11, KesExpiryAlertLevel::Ok),
+            (100, 152, 10, KesExpiryAlertLevel::Warning),
+            (100, 159, 3, KesExpiryAlertLevel::Warning),
+            (100, 160, 2, KesExpiryAlertLevel::Critical),
+            (100, 161, 1, KesExpiryAlertLevel::Critical),
+            (100, 162, 0, KesExpiryAlertLevel::Expired),
+            (100, 200, 0, KesExpiryAlertLevel::Expired),
+        ];
+
+        for (start, current, expected_remaining, expected_alert_level) in cases {
+            let status = status(start, current, 1, None);
+
+            assert_eq!(
+                (expected_remaining, expected_alert_level),
+                (status.remaining_kes_periods(), status.alert_level()),
+                "start: {start}, current: {current}"
+            );
+        }
+    }
+
+    #[test]
+    fn check_can_register_returns_kes_evolutions() {
+        assert_eq!(Ok(0), status(100, 100, 1, None).check_can_register());
+        assert_eq!(Ok(61), status(100, 161, 1, None).check_can_register());
+        assert_eq!(Ok(20), status(100, 120, 3, Some(3)).check_can_register());
+        assert_eq!(Ok(20), status(100, 120, 4, Some(3)).check_can_register());
+    }
+
+    #[test]
+    fn check_can_register_fails_if_opcert_is_not_valid_yet() {
+        assert_eq!(
+            Err(SignerRegistrationError::OperationalCertificateNotYetValid {
+                start_kes_period: 100,
+                current_kes_period: 99,
+            }),
+            status(100, 99, 1, None).check_can_register()
+        );
+    }
+
+    #[test]
+    fn check_can_register_fails_if_kes_key_has_expired() {
+        assert_eq!(
+            Err(SignerRegistrationError::KesKeyExpired {
+                start_kes_period: 100,
+                current_kes_period: 162,
+                max_kes_evolutions: MAX_KES_EVOLUTIONS,
+            }),
+            status(100, 162, 1, None).check_can_register()
+        );
+    }
+
+    #[test]
+    fn check_can_register_fails_if_opcert_counter_does_not_match_node_state() {
+        for (opcert_counter, node_state_counter) in [(2, 3), (5, 3)] {
+            assert_eq!(
+                Err(
+                    SignerRegistrationError::OperationalCertificateCounterInvalid {
+                        opcert_counter,
+                        node_state_counter,
+                    }
+                ),
+                status(100, 120, opcert_counter, Some(node_state_counter)).check_can_register()
+            );
+        }
+    }
+}
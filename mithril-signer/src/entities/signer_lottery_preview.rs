@@ -0,0 +1,95 @@
+use serde::Serialize;
+
+use mithril_common::crypto_helper::ProtocolParameters as ProtocolStmParameters;
+use mithril_common::entities::{Epoch, PartyId, ProtocolParameters, Stake};
+
+/// SignerLotteryPreview represents the expected outcome of the lottery of a signer
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SignerLotteryPreview {
+    /// Epoch of the stake distribution used for the preview
+    pub epoch: Epoch,
+
+    /// Party id of the signer
+    pub party_id: PartyId,
+
+    /// Stake of the signer
+    pub stake: Stake,
+
+    /// Total stake of the signers the lottery is played against
+    pub total_stake: Stake,
+
+    /// Protocol parameters used for the preview
+    pub protocol_parameters: ProtocolParameters,
+
+    /// Probability to win at least one lottery index, i.e. to issue a signature for a message
+    pub win_probability: f64,
+
+    /// Expected number of won lottery indices for a message
+    pub expected_won_indices: f64,
+
+    /// Expected share of the quorum brought by the won lottery indices of the signer
+    pub expected_quorum_contribution: f64,
+}
+
+impl SignerLotteryPreview {
+    /// Compute the lottery preview of a signer
+    pub fn compute(
+        epoch: Epoch,
+        party_id: PartyId,
+        stake: Stake,
+        total_stake: Stake,
+        protocol_parameters: ProtocolParameters,
+    ) -> Self {
+        let preview = ProtocolStmParameters::from(protocol_parameters.clone())
+            .lottery_preview(stake, total_stake);
+
+        Self {
+            epoch,
+            party_id,
+            stake,
+            total_stake,
+            protocol_parameters,
+            win_probability: preview.win_probability,
+            expected_won_indices: preview.expected_won_indices,
+            expected_quorum_contribution: preview.expected_quorum_contribution,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compute_lottery_preview_for_the_signer_stake() {
+        let protocol_parameters = ProtocolParameters::new(20, 100, 0.2);
+
+        let preview = SignerLotteryPreview::compute(
+            Epoch(10),
+            "pool1".to_string(),
+            250,
+            1_000,
+            protocol_parameters.clone(),
+        );
+
+        let phi = 1.0 - 0.8f64.powf(0.25);
+        assert!((preview.win_probability - (1.0 - (1.0 - phi).powi(100))).abs() < 1e-9);
+        assert!((preview.expected_won_indices - 100.0 * phi).abs() < 1e-9);
+        assert!((preview.expected_quorum_contribution - 100.0 * phi / 20.0).abs() < 1e-9);
+        assert_eq!(protocol_parameters, preview.protocol_parameters);
+    }
+
+    #[test]
+    fn compute_lottery_preview_without_stake() {
+        let preview = SignerLotteryPreview::compute(
+            Epoch(10),
+            "pool1".to_string(),
+            0,
+            1_000,
+            ProtocolParameters::new(20, 100, 0.2),
+        );
+
+        assert_eq!(0.0, preview.win_probability);
+        assert_eq!(0.0, preview.expected_won_indices);
+    }
+}
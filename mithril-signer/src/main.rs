@@ -17,7 +17,7 @@ use mithril_doc::{Documenter, DocumenterDefault, GenerateDocCommands, StructDoc}
 use mithril_metric::MetricsServer;
 use mithril_signer::dependency_injection::DependenciesBuilder;
 use mithril_signer::{
-    Configuration, DefaultConfiguration, SignerRunner, SignerState, StateMachine,
+    Configuration, DefaultConfiguration, Runner, SignerRunner, SignerState, StateMachine,
 };
 
 /// CLI args
@@ -115,6 +115,12 @@ fn build_logger(min_level: Level) -> Logger {
 
 #[derive(Subcommand, Debug, Clone)]
 enum SignerCommands {
+    /// Preview the lottery of the signer with the current stake distribution of the Cardano chain
+    ///
+    /// Displays the probability to win at least one lottery index, i.e. to issue a signature,
+    /// and the expected number of won indices for a message.
+    LotteryPreview,
+
     #[clap(alias("doc"), hide(true))]
     GenerateDoc(GenerateDocCommands),
 }
@@ -175,6 +181,17 @@ async fn main() -> StdResult<()> {
         .await
         .with_context(|| "services initialization error")?;
 
+    if let Some(SignerCommands::LotteryPreview) = &args.command {
+        let runner = SignerRunner::new(config.clone(), services, root_logger.clone());
+        let lottery_preview = runner
+            .compute_lottery_preview()
+            .await
+            .with_context(|| "lottery preview error")?;
+        println!("{}", serde_json::to_string_pretty(&lottery_preview)?);
+
+        return Ok(());
+    }
+
     let metrics_service = services.metrics_service.clone();
     let cardano_transaction_preloader = services.cardano_transactions_preloader.clone();
 
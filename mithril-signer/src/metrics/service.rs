@@ -35,6 +35,22 @@ build_metrics_service!(
     runtime_cycle_total_since_startup_counter:MetricCounter(
         "mithril_signer_runtime_cycle_total_since_startup",
         "Number of runtime cycles since startup on a Mithril signer node"
+    ),
+    signer_lottery_win_probability_gauge:MetricGauge(
+        "mithril_signer_lottery_win_probability",
+        "Probability to win at least one lottery index for a message in the current epoch on a Mithril signer node"
+    ),
+    signer_lottery_expected_won_indices_gauge:MetricGauge(
+        "mithril_signer_lottery_expected_won_indices",
+        "Expected number of won lottery indices for a message in the current epoch on a Mithril signer node"
+    ),
+    signer_kes_current_period_gauge:MetricGauge(
+        "mithril_signer_kes_current_period",
+        "Current KES period of the Cardano chain on a Mithril signer node"
+    ),
+    signer_kes_remaining_periods_gauge:MetricGauge(
+        "mithril_signer_kes_remaining_periods",
+        "Number of KES periods remaining before the KES key expiry on a Mithril signer node"
     )
 
 );
@@ -4,15 +4,18 @@ use slog::{debug, warn, Logger};
 use thiserror::Error;
 use tokio::sync::RwLockReadGuard;
 
-use mithril_common::crypto_helper::{KESPeriod, OpCert, ProtocolOpCert, SerDeShelleyFileFormat};
+use mithril_common::crypto_helper::{OpCert, ProtocolOpCert, SerDeShelleyFileFormat};
 use mithril_common::entities::{
-    Epoch, PartyId, ProtocolMessage, SignedEntityType, Signer, TimePoint,
+    Epoch, PartyId, ProtocolMessage, SignedEntityType, Signer, Stake, TimePoint,
 };
 use mithril_common::logging::LoggerExtensions;
 use mithril_common::StdResult;
 
 use crate::dependency_injection::SignerDependencyContainer;
-use crate::entities::{BeaconToSign, SignerEpochSettings};
+use crate::entities::{
+    BeaconToSign, SignerEpochSettings, SignerKesPeriodStatus, SignerLotteryPreview,
+    SignerRegistrationError,
+};
 use crate::services::{EpochService, MithrilProtocolInitializerBuilder};
 use crate::Configuration;
 
@@ -37,6 +40,16 @@ pub trait Runner: Send + Sync {
     /// Check if the signer can sign the current epoch.
     async fn can_sign_current_epoch(&self) -> StdResult<bool>;
 
+    /// Preview the lottery of the signer with the current stake distribution of the Cardano chain
+    /// and the registration protocol parameters of the aggregator.
+    ///
+    /// The lottery is played against the stake of the signers registered for the next epoch,
+    /// including this signer.
+    async fn compute_lottery_preview(&self) -> StdResult<SignerLotteryPreview>;
+
+    /// Compute the KES period status of the operational certificate of the signer, if any.
+    async fn compute_kes_period_status(&self) -> StdResult<Option<SignerKesPeriodStatus>>;
+
     /// Register epoch information
     async fn inform_epoch_settings(&self, epoch_settings: SignerEpochSettings) -> StdResult<()>;
 
@@ -97,6 +110,48 @@ impl SignerRunner {
     async fn epoch_service_read(&self) -> RwLockReadGuard<'_, dyn EpochService> {
         self.services.epoch_service.read().await
     }
+
+    fn read_operational_certificate(&self) -> StdResult<Option<OpCert>> {
+        match &self.config.operational_certificate_path {
+            Some(operational_certificate_path) => {
+                let opcert: OpCert = OpCert::from_file(operational_certificate_path)
+                    .map_err(|_| RunnerError::FileParse("operational_certificate_path".to_string()))
+                    .with_context(|| "Runner can not decode OpCert from file")?;
+                Ok(Some(opcert))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn compute_kes_period_status_of(
+        &self,
+        operational_certificate: &OpCert,
+    ) -> StdResult<SignerKesPeriodStatus> {
+        let current_kes_period = self
+            .services
+            .chain_observer
+            .get_current_kes_period(operational_certificate)
+            .await?
+            .ok_or_else(|| RunnerError::NoValueError("current KES period".to_string()))?;
+        let node_state_opcert_counter = self
+            .services
+            .chain_observer
+            .get_current_operational_certificate_counter(operational_certificate)
+            .await?;
+        if node_state_opcert_counter.is_none() {
+            warn!(
+                self.logger,
+                "Operational certificate counter unknown: the chain observer could not retrieve it from the node protocol state, the counter check is skipped";
+                "operational_certificate_issue_number" => operational_certificate.issue_number()
+            );
+        }
+
+        Ok(SignerKesPeriodStatus::compute(
+            operational_certificate,
+            current_kes_period,
+            node_state_opcert_counter,
+        ))
+    }
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -156,38 +211,41 @@ impl Runner for SignerRunner {
         let stake = stake_distribution
             .get(&self.services.single_signer.get_party_id())
             .ok_or_else(RunnerError::NoStakeForSelf)?;
-        let (operational_certificate, protocol_operational_certificate) = match &self
-            .config
-            .operational_certificate_path
+        let (operational_certificate, protocol_operational_certificate) = match self
+            .read_operational_certificate()
+            .with_context(|| "register_signer_to_aggregator can not read OpCert")?
         {
-            Some(operational_certificate_path) => {
-                let opcert: OpCert = OpCert::from_file(operational_certificate_path)
-                    .map_err(|_| RunnerError::FileParse("operational_certificate_path".to_string()))
-                    .with_context(|| {
-                        "register_signer_to_aggregator can not decode OpCert from file"
-                    })?;
-                (Some(opcert.clone()), Some(ProtocolOpCert::new(opcert)))
-            }
-            _ => (None, None),
+            Some(opcert) => (Some(opcert.clone()), Some(ProtocolOpCert::new(opcert))),
+            None => (None, None),
         };
 
         let kes_period = match operational_certificate {
-            Some(operational_certificate) => Some(
-                self.services
-                    .chain_observer
-                    .get_current_kes_period(&operational_certificate)
-                    .await?
-                    .unwrap_or_default()
-                    - operational_certificate.start_kes_period as KESPeriod,
-            ),
+            Some(operational_certificate) => {
+                operational_certificate
+                    .validate()
+                    .map_err(|_| SignerRegistrationError::OperationalCertificateInvalid)?;
+                let kes_period_status = self
+                    .compute_kes_period_status_of(&operational_certificate)
+                    .await?;
+
+                Some(kes_period_status.check_can_register()?)
+            }
             None => None,
         };
-        let protocol_initializer = MithrilProtocolInitializerBuilder::build(
-            stake,
-            &protocol_parameters,
-            self.config.kes_secret_key_path.clone(),
-            kes_period,
-        )?;
+        // The KES signer may delegate to an external process, build the protocol initializer in
+        // another thread to avoid blocking the tokio runtime
+        let stake = *stake;
+        let kes_signer = self.services.kes_signer.clone();
+        let protocol_initializer = tokio::task::spawn_blocking(move || {
+            MithrilProtocolInitializerBuilder::build(
+                &stake,
+                &protocol_parameters,
+                kes_signer,
+                kes_period,
+            )
+        })
+        .await
+        .with_context(|| "Protocol initializer build thread crashed")??;
         let signer = Signer::new(
             self.services.single_signer.get_party_id(),
             protocol_initializer.verification_key().into(),
@@ -240,6 +298,53 @@ impl Runner for SignerRunner {
         epoch_service.can_signer_sign_current_epoch(self.services.single_signer.get_party_id())
     }
 
+    async fn compute_lottery_preview(&self) -> StdResult<SignerLotteryPreview> {
+        debug!(self.logger, ">> compute_lottery_preview");
+        let epoch_settings = self
+            .services
+            .certificate_handler
+            .retrieve_epoch_settings()
+            .await?
+            .ok_or_else(|| RunnerError::NoValueError("epoch settings".to_string()))?;
+        let stake_distribution = self
+            .services
+            .chain_observer
+            .get_current_stake_distribution()
+            .await?
+            .ok_or_else(|| RunnerError::NoValueError("current stake distribution".to_string()))?;
+        let party_id = self.services.single_signer.get_party_id();
+        let stake = *stake_distribution
+            .get(&party_id)
+            .ok_or_else(RunnerError::NoStakeForSelf)?;
+        let total_stake = epoch_settings
+            .next_signers
+            .iter()
+            .filter(|signer| signer.party_id != party_id)
+            .filter_map(|signer| stake_distribution.get(&signer.party_id))
+            .sum::<Stake>()
+            + stake;
+
+        Ok(SignerLotteryPreview::compute(
+            epoch_settings.epoch,
+            party_id,
+            stake,
+            total_stake,
+            epoch_settings.registration_protocol_parameters,
+        ))
+    }
+
+    async fn compute_kes_period_status(&self) -> StdResult<Option<SignerKesPeriodStatus>> {
+        debug!(self.logger, ">> compute_kes_period_status");
+
+        match self.read_operational_certificate()? {
+            Some(operational_certificate) => Ok(Some(
+                self.compute_kes_period_status_of(&operational_certificate)
+                    .await?,
+            )),
+            None => Ok(None),
+        }
+    }
+
     async fn inform_epoch_settings(&self, epoch_settings: SignerEpochSettings) -> StdResult<()> {
         debug!(
             self.logger,
@@ -328,7 +433,8 @@ impl Runner for SignerRunner {
 #[cfg(test)]
 mod tests {
     use mithril_common::signable_builder::{
-        CardanoDatabaseSignableBuilder, SignableBuilderServiceDependencies,
+        CardanoBlocksSignableBuilder, CardanoDatabaseSignableBuilder,
+        SignableBuilderServiceDependencies,
     };
     use mockall::mock;
     use mockall::predicate::eq;
@@ -362,6 +468,7 @@ mod tests {
         ProtocolInitializerRepository, SignedBeaconRepository, StakePoolStore,
     };
     use crate::database::test_helper::main_db_connection;
+    use crate::entities::MAX_KES_EVOLUTIONS;
     use crate::metrics::MetricsService;
     use crate::services::{
         CardanoTransactionsImporter, DumbAggregatorClient, MithrilEpochService,
@@ -445,6 +552,10 @@ mod tests {
             transactions_importer.clone(),
             block_range_root_retriever,
         ));
+        let cardano_blocks_builder = Arc::new(CardanoBlocksSignableBuilder::new(
+            transactions_importer.clone(),
+            Arc::new(MockBlockRangeRootRetrieverImpl::<MKTreeStoreInMemory>::new()),
+        ));
         let stake_store = Arc::new(StakePoolStore::new(sqlite_connection.clone(), None));
         let cardano_stake_distribution_builder = Arc::new(
             CardanoStakeDistributionSignableBuilder::new(stake_store.clone()),
@@ -478,6 +589,7 @@ mod tests {
             cardano_transactions_builder,
             cardano_stake_distribution_builder,
             cardano_database_signable_builder,
+            cardano_blocks_builder,
         );
         let signable_builder_service = Arc::new(MithrilSignableBuilderService::new(
             signable_seed_builder_service,
@@ -524,6 +636,7 @@ mod tests {
             upkeep_service,
             epoch_service,
             certifier,
+            kes_signer: None,
         }
     }
 
@@ -640,6 +753,153 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_compute_kes_period_status_without_operational_certificate() {
+        let config = Configuration {
+            operational_certificate_path: None,
+            ..Configuration::new_sample("1")
+        };
+        let runner = init_runner(None, Some(config)).await;
+
+        let kes_period_status = runner.compute_kes_period_status().await.unwrap();
+
+        assert_eq!(None, kes_period_status);
+    }
+
+    #[tokio::test]
+    async fn test_compute_kes_period_status_with_operational_certificate() {
+        let fixture = MithrilFixtureBuilder::default().with_signers(1).build();
+        let signer = fixture.signers_fixture()[0].clone();
+        let config = Configuration::new_sample(signer.party_id());
+        let runner = init_runner(None, Some(config)).await;
+
+        let kes_period_status = runner
+            .compute_kes_period_status()
+            .await
+            .unwrap()
+            .expect("A KES period status should be computed for a certified signer");
+
+        assert_eq!(0, kes_period_status.current_kes_period);
+        assert_eq!(0, kes_period_status.start_kes_period);
+        assert_eq!(
+            MAX_KES_EVOLUTIONS,
+            kes_period_status.remaining_kes_periods()
+        );
+        assert_eq!(Ok(0), kes_period_status.check_can_register());
+    }
+
+    #[tokio::test]
+    async fn test_compute_kes_period_status_with_the_operational_certificate_counter_of_the_node() {
+        let fixture = MithrilFixtureBuilder::default().with_signers(1).build();
+        let signer = fixture.signers_fixture()[0].clone();
+        let config = Configuration::new_sample(signer.party_id());
+        let chain_observer = Arc::new(FakeObserver::default());
+        chain_observer
+            .set_operational_certificate_counter(Some(0))
+            .await;
+        let mut services = init_services().await;
+        services.chain_observer = chain_observer;
+        let runner = init_runner(Some(services), Some(config)).await;
+
+        let kes_period_status = runner
+            .compute_kes_period_status()
+            .await
+            .unwrap()
+            .expect("A KES period status should be computed for a certified signer");
+
+        assert_eq!(Some(0), kes_period_status.node_state_opcert_counter);
+        assert_eq!(Ok(0), kes_period_status.check_can_register());
+    }
+
+    #[tokio::test]
+    async fn test_register_signer_to_aggregator_fails_if_the_operational_certificate_counter_is_outdated(
+    ) {
+        let fixture = MithrilFixtureBuilder::default().with_signers(1).build();
+        let signer = fixture.signers_fixture()[0].clone();
+        let config = Configuration::new_sample(signer.party_id());
+        let chain_observer = Arc::new(FakeObserver::default());
+        chain_observer
+            .set_operational_certificate_counter(Some(5))
+            .await;
+        let mut services = init_services().await;
+        services.chain_observer = chain_observer;
+        let current_epoch = services.ticker_service.get_current_epoch().await.unwrap();
+        let stakes = services
+            .chain_observer
+            .get_current_stake_distribution()
+            .await
+            .unwrap()
+            .unwrap();
+        services
+            .stake_store
+            .save_stakes(current_epoch.offset_to_recording_epoch(), stakes)
+            .await
+            .unwrap();
+        let runner = init_runner(Some(services), Some(config)).await;
+        runner
+            .inform_epoch_settings(SignerEpochSettings {
+                epoch: current_epoch,
+                current_signers: fixture.signers(),
+                next_signers: fixture.signers(),
+                ..SignerEpochSettings::dummy().clone()
+            })
+            .await
+            .unwrap();
+
+        let error = runner
+            .register_signer_to_aggregator()
+            .await
+            .expect_err("registering with an outdated operational certificate should fail");
+
+        assert!(
+            matches!(
+                error.downcast_ref::<SignerRegistrationError>(),
+                Some(
+                    SignerRegistrationError::OperationalCertificateCounterInvalid {
+                        node_state_counter: 5,
+                        ..
+                    }
+                )
+            ),
+            "unexpected error: {error:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_compute_lottery_preview() {
+        let services = init_services().await;
+        let party_id = services.single_signer.get_party_id();
+        let stake_distribution = services
+            .chain_observer
+            .get_current_stake_distribution()
+            .await
+            .unwrap()
+            .unwrap();
+        let epoch_settings = SignerEpochSettings::dummy();
+        let runner = init_runner(Some(services), None).await;
+
+        let preview = runner
+            .compute_lottery_preview()
+            .await
+            .expect("previewing the lottery should not fail");
+
+        let stake = stake_distribution[&party_id];
+        assert_eq!(party_id, preview.party_id);
+        assert_eq!(stake, preview.stake);
+        assert!(preview.total_stake >= stake);
+        assert!(preview.total_stake <= stake_distribution.values().sum::<Stake>());
+        assert_eq!(
+            SignerLotteryPreview::compute(
+                epoch_settings.epoch,
+                party_id,
+                stake,
+                preview.total_stake,
+                epoch_settings.registration_protocol_parameters,
+            ),
+            preview
+        );
+    }
+
     #[tokio::test]
     async fn test_update_era_checker() {
         let services = init_services().await;
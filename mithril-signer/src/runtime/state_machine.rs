@@ -1,4 +1,4 @@
-use slog::{debug, info, Logger};
+use slog::{crit, debug, info, warn, Logger};
 use std::{fmt::Display, ops::Deref, sync::Arc, time::Duration};
 use tokio::{sync::Mutex, time::sleep};
 
@@ -8,7 +8,7 @@ use mithril_common::{
     logging::LoggerExtensions,
 };
 
-use crate::entities::{BeaconToSign, SignerEpochSettings};
+use crate::entities::{BeaconToSign, KesExpiryAlertLevel, SignerEpochSettings};
 use crate::MetricsService;
 
 use super::{Runner, RuntimeError};
@@ -314,6 +314,8 @@ impl StateMachine {
                 nested_error: Some(e),
             })?;
 
+        self.record_kes_period_status_metrics(epoch).await;
+
         self.runner.register_signer_to_aggregator()
             .await.map_err(|e| {
             if e.downcast_ref::<ProtocolInitializerError>().is_some() {
@@ -347,11 +349,88 @@ impl StateMachine {
                 message: "Failed to check if signer can sign in the current epoch in 'unregistered → ?' phase".to_string(),
                 nested_error: Some(e),
             })? {
-            true => Ok(SignerState::ReadyToSign { epoch }),
+            true => {
+                self.record_lottery_preview_metrics(epoch).await;
+                Ok(SignerState::ReadyToSign { epoch })
+            }
             false => Ok(SignerState::RegisteredNotAbleToSign { epoch }),
         }
     }
 
+    /// Record the lottery preview of the signer for the epoch, a failure being only logged
+    /// since it must not prevent the signer from signing.
+    async fn record_lottery_preview_metrics(&self, epoch: Epoch) {
+        match self.runner.compute_lottery_preview().await {
+            Ok(preview) => {
+                info!(
+                    self.logger, "Lottery preview for the epoch";
+                    "epoch" => ?epoch,
+                    "win_probability" => preview.win_probability,
+                    "expected_won_indices" => preview.expected_won_indices,
+                    "expected_quorum_contribution" => preview.expected_quorum_contribution,
+                );
+                self.metrics_service
+                    .get_signer_lottery_win_probability_gauge()
+                    .record(preview.win_probability);
+                self.metrics_service
+                    .get_signer_lottery_expected_won_indices_gauge()
+                    .record(preview.expected_won_indices);
+            }
+            Err(error) => {
+                warn!(
+                    self.logger, "Could not compute the lottery preview";
+                    "epoch" => ?epoch,
+                    "error" => ?error,
+                );
+            }
+        }
+    }
+
+    /// Record the KES period status of the signer for the epoch and log a warning escalating
+    /// with the proximity of the expiry of its KES key, a failure being only logged since the
+    /// registration will fail with a detailed error if the KES period is invalid.
+    async fn record_kes_period_status_metrics(&self, epoch: Epoch) {
+        match self.runner.compute_kes_period_status().await {
+            Ok(Some(status)) => {
+                self.metrics_service
+                    .get_signer_kes_current_period_gauge()
+                    .record(status.current_kes_period);
+                self.metrics_service
+                    .get_signer_kes_remaining_periods_gauge()
+                    .record(status.remaining_kes_periods());
+
+                let logger = self.logger.new(slog::o!(
+                    "epoch" => *epoch,
+                    "current_kes_period" => status.current_kes_period,
+                    "start_kes_period" => status.start_kes_period,
+                    "remaining_kes_periods" => status.remaining_kes_periods(),
+                ));
+                match status.alert_level() {
+                    KesExpiryAlertLevel::Ok => {
+                        info!(logger, "KES period status for the epoch");
+                    }
+                    KesExpiryAlertLevel::Warning => {
+                        warn!(logger, "The KES key will expire soon, a new KES key and operational certificate must be issued");
+                    }
+                    KesExpiryAlertLevel::Critical => {
+                        crit!(logger, "The KES key is about to expire, a new KES key and operational certificate must be issued now");
+                    }
+                    KesExpiryAlertLevel::Expired => {
+                        crit!(logger, "The KES key has expired, the signer can not register until a new KES key and operational certificate are issued");
+                    }
+                }
+            }
+            Ok(None) => {}
+            Err(error) => {
+                warn!(
+                    self.logger, "Could not compute the KES period status";
+                    "epoch" => ?epoch,
+                    "error" => ?error,
+                );
+            }
+        }
+    }
+
     async fn transition_from_registered_not_able_to_sign_to_unregistered(
         &self,
         epoch: Epoch,
@@ -458,6 +537,7 @@ mod tests {
     use mithril_common::entities::{ChainPoint, Epoch, ProtocolMessage, SignedEntityType};
     use mithril_common::test_utils::fake_data;
 
+    use crate::entities::{SignerKesPeriodStatus, SignerLotteryPreview, MAX_KES_EVOLUTIONS};
     use crate::runtime::runner::MockSignerRunner;
     use crate::test_tools::TestLogger;
 
@@ -563,6 +643,10 @@ mod tests {
             .expect_update_stake_distribution()
             .once()
             .returning(|_| Ok(()));
+        runner
+            .expect_compute_kes_period_status()
+            .once()
+            .returning(|| Ok(None));
         runner
             .expect_register_signer_to_aggregator()
             .once()
@@ -617,6 +701,18 @@ mod tests {
             .expect_update_stake_distribution()
             .once()
             .returning(|_| Ok(()));
+        runner
+            .expect_compute_kes_period_status()
+            .once()
+            .returning(|| {
+                Ok(Some(SignerKesPeriodStatus {
+                    current_kes_period: 112,
+                    start_kes_period: 100,
+                    max_kes_evolutions: MAX_KES_EVOLUTIONS,
+                    opcert_counter: 1,
+                    node_state_opcert_counter: Some(1),
+                }))
+            });
         runner
             .expect_register_signer_to_aggregator()
             .once()
@@ -627,6 +723,109 @@ mod tests {
             .once()
             .returning(|| Ok(true));
 
+        runner
+            .expect_compute_lottery_preview()
+            .once()
+            .returning(|| {
+                Ok(SignerLotteryPreview {
+                    win_probability: 0.75,
+                    expected_won_indices: 2.5,
+                    expected_quorum_contribution: 0.5,
+                    ..SignerLotteryPreview::compute(
+                        Epoch(1),
+                        "party".to_string(),
+                        10,
+                        100,
+                        fake_data::protocol_parameters(),
+                    )
+                })
+            });
+
+        let state_machine = init_state_machine(
+            SignerState::Unregistered {
+                epoch: TimePoint::dummy().epoch,
+            },
+            runner,
+        );
+
+        state_machine
+            .cycle()
+            .await
+            .expect("Cycling the state machine should not fail");
+
+        assert_eq!(
+            SignerState::ReadyToSign {
+                epoch: TimePoint::dummy().epoch,
+            },
+            state_machine.get_state().await
+        );
+        assert_eq!(
+            0.75,
+            state_machine
+                .metrics_service
+                .get_signer_lottery_win_probability_gauge()
+                .get()
+        );
+        assert_eq!(
+            2.5,
+            state_machine
+                .metrics_service
+                .get_signer_lottery_expected_won_indices_gauge()
+                .get()
+        );
+        assert_eq!(
+            112.0,
+            state_machine
+                .metrics_service
+                .get_signer_kes_current_period_gauge()
+                .get()
+        );
+        assert_eq!(
+            50.0,
+            state_machine
+                .metrics_service
+                .get_signer_kes_remaining_periods_gauge()
+                .get()
+        );
+    }
+
+    #[tokio::test]
+    async fn unregistered_to_ready_to_sign_even_if_lottery_preview_fails() {
+        let mut runner = MockSignerRunner::new();
+        runner.expect_upkeep().returning(|_| Ok(())).once();
+        runner
+            .expect_get_epoch_settings()
+            .once()
+            .returning(|| Ok(Some(SignerEpochSettings::dummy())));
+        runner
+            .expect_inform_epoch_settings()
+            .once()
+            .returning(|_| Ok(()));
+        runner
+            .expect_get_current_time_point()
+            .times(2)
+            .returning(|| Ok(TimePoint::dummy()));
+        runner
+            .expect_update_stake_distribution()
+            .once()
+            .returning(|_| Ok(()));
+        runner
+            .expect_compute_kes_period_status()
+            .once()
+            .returning(|| Ok(None));
+        runner
+            .expect_register_signer_to_aggregator()
+            .once()
+            .returning(|| Ok(()));
+        runner
+            .expect_can_sign_current_epoch()
+            .once()
+            .returning(|| Ok(true));
+        runner
+            .expect_compute_lottery_preview()
+            .once()
+            .returning(|| Err(anyhow::anyhow!("no protocol initializer")));
+
         let state_machine = init_state_machine(
             SignerState::Unregistered {
                 epoch: TimePoint::dummy().epoch,
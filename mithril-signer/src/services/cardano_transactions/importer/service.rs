@@ -10,7 +10,7 @@ use tokio::{runtime::Handle, sync::Mutex, task};
 use mithril_common::cardano_block_scanner::{BlockScanner, ChainScannedBlocks, RawCardanoPoint};
 use mithril_common::crypto_helper::{MKTree, MKTreeNode, MKTreeStoreInMemory};
 use mithril_common::entities::{
-    BlockNumber, BlockRange, CardanoTransaction, ChainPoint, SlotNumber,
+    BlockNumber, BlockRange, CardanoBlock, CardanoTransaction, ChainPoint, SlotNumber,
 };
 use mithril_common::logging::LoggerExtensions;
 use mithril_common::signable_builder::TransactionsImporter;
@@ -51,11 +51,48 @@ pub trait TransactionStore: Send + Sync {
     ) -> StdResult<()>;
 }
 
+/// Cardano blocks store
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait BlockStore: Send + Sync {
+    /// Get the highest known block beacon
+    async fn get_highest_beacon(&self) -> StdResult<Option<ChainPoint>>;
+
+    /// Get the highest stored block hashes range root bounds
+    async fn get_highest_block_range(&self) -> StdResult<Option<BlockRange>>;
+
+    /// Store list of blocks
+    async fn store_blocks(&self, blocks: Vec<CardanoBlock>) -> StdResult<()>;
+
+    /// Get blocks in an interval of blocks
+    async fn get_blocks_in_range(&self, range: Range<BlockNumber>) -> StdResult<Vec<CardanoBlock>>;
+
+    /// Store list of block ranges with the merkle root of their block hashes
+    async fn store_block_range_roots(
+        &self,
+        block_ranges: Vec<(BlockRange, MKTreeNode)>,
+    ) -> StdResult<()>;
+
+    /// Remove blocks and block hashes range roots that are in a rolled-back fork
+    ///
+    /// * Remove blocks with slot number strictly greater than the given slot number
+    /// * Remove block hashes range roots that have lower bound range strictly above the given slot number
+    async fn remove_rolled_back_blocks_and_block_range(
+        &self,
+        slot_number: SlotNumber,
+    ) -> StdResult<()>;
+}
+
 /// Import and store [CardanoTransaction].
+///
+/// If a [BlockStore] is set, the scanned blocks are imported and stored alongside the
+/// transactions. The blocks that precede the highest stored transaction, stored before the
+/// [BlockStore] was set, are backfilled from the highest stored block.
 #[derive(Clone)]
 pub struct CardanoTransactionsImporter {
     block_scanner: Arc<dyn BlockScanner>,
     transaction_store: Arc<dyn TransactionStore>,
+    block_store: Option<Arc<dyn BlockStore>>,
     last_polled_point: Arc<Mutex<Option<RawCardanoPoint>>>,
     logger: Logger,
 }
@@ -70,11 +107,18 @@ impl CardanoTransactionsImporter {
         Self {
             block_scanner,
             transaction_store,
+            block_store: None,
             last_polled_point: Arc::new(Mutex::new(None)),
             logger: logger.new_with_component_name::<Self>(),
         }
     }
 
+    /// Set the store used to import the scanned blocks
+    pub fn with_block_store(mut self, block_store: Arc<dyn BlockStore>) -> Self {
+        self.block_store = Some(block_store);
+        self
+    }
+
     async fn start_point(
         &self,
         highest_stored_chain_point: &Option<ChainPoint>,
@@ -128,6 +172,14 @@ impl CardanoTransactionsImporter {
         while let Some(blocks) = streamer.poll_next().await? {
             match blocks {
                 ChainScannedBlocks::RollForwards(forward_blocks) => {
+                    if let Some(block_store) = &self.block_store {
+                        let parsed_blocks: Vec<CardanoBlock> = forward_blocks
+                            .iter()
+                            .map(|b| b.to_cardano_block())
+                            .collect();
+                        block_store.store_blocks(parsed_blocks).await?;
+                    }
+
                     let parsed_transactions: Vec<CardanoTransaction> = forward_blocks
                         .into_iter()
                         .flat_map(|b| b.into_transactions())
@@ -141,6 +193,11 @@ impl CardanoTransactionsImporter {
                     self.transaction_store
                         .remove_rolled_back_transactions_and_block_range(slot_number)
                         .await?;
+                    if let Some(block_store) = &self.block_store {
+                        block_store
+                            .remove_rolled_back_blocks_and_block_range(slot_number)
+                            .await?;
+                    }
                 }
             }
         }
@@ -152,6 +209,59 @@ impl CardanoTransactionsImporter {
         Ok(())
     }
 
+    async fn backfill_blocks(&self) -> StdResult<()> {
+        let Some(block_store) = &self.block_store else {
+            return Ok(());
+        };
+        let Some(highest_stored_transaction_beacon) =
+            self.transaction_store.get_highest_beacon().await?
+        else {
+            return Ok(());
+        };
+        let highest_stored_block_beacon = block_store.get_highest_beacon().await?;
+
+        if highest_stored_block_beacon
+            .as_ref()
+            .is_some_and(|b| b.block_number >= highest_stored_transaction_beacon.block_number)
+        {
+            return Ok(());
+        }
+
+        debug!(
+            self.logger, "Backfilling Cardano blocks until block numbered '{}'", highest_stored_transaction_beacon.block_number;
+            "highest_stored_block_number" => ?highest_stored_block_beacon.as_ref().map(|c| c.block_number),
+        );
+
+        let mut streamer = self
+            .block_scanner
+            .scan(
+                highest_stored_block_beacon
+                    .as_ref()
+                    .map(RawCardanoPoint::from),
+                highest_stored_transaction_beacon.block_number,
+            )
+            .await?;
+
+        while let Some(blocks) = streamer.poll_next().await? {
+            match blocks {
+                ChainScannedBlocks::RollForwards(forward_blocks) => {
+                    let parsed_blocks: Vec<CardanoBlock> = forward_blocks
+                        .iter()
+                        .map(|b| b.to_cardano_block())
+                        .collect();
+                    block_store.store_blocks(parsed_blocks).await?;
+                }
+                ChainScannedBlocks::RollBackward(slot_number) => {
+                    block_store
+                        .remove_rolled_back_blocks_and_block_range(slot_number)
+                        .await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     async fn import_block_ranges(&self, until: BlockNumber) -> StdResult<()> {
         let block_ranges = match self.transaction_store.get_highest_block_range().await?.map(
             |highest_stored_block_range| {
@@ -199,6 +309,58 @@ impl CardanoTransactionsImporter {
             .store_block_range_roots(block_ranges_with_merkle_root)
             .await
     }
+
+    async fn import_block_hash_ranges(&self, until: BlockNumber) -> StdResult<()> {
+        let Some(block_store) = &self.block_store else {
+            return Ok(());
+        };
+        let block_ranges =
+            match block_store
+                .get_highest_block_range()
+                .await?
+                .map(|highest_stored_block_range| {
+                    BlockRange::all_block_ranges_in(
+                        BlockRange::start(highest_stored_block_range.end)..=(until),
+                    )
+                }) {
+                // No block hashes range root stored yet, start from the beginning
+                None => BlockRange::all_block_ranges_in(BlockNumber(0)..=(until)),
+                // Not enough block to form at least one block range
+                Some(ranges) if ranges.is_empty() => return Ok(()),
+                Some(ranges) => ranges,
+            };
+
+        debug!(
+            self.logger, "Computing Block Hashes Range Roots";
+            "start_block" => *block_ranges.start(), "end_block" => *block_ranges.end(),
+        );
+
+        let mut block_ranges_with_merkle_root: Vec<(BlockRange, MKTreeNode)> = vec![];
+        for block_range in block_ranges {
+            let blocks = block_store
+                .get_blocks_in_range(block_range.start..block_range.end)
+                .await?;
+
+            if blocks.is_empty() {
+                continue;
+            }
+
+            let merkle_root = MKTree::<MKTreeStoreInMemory>::new(&blocks)?.compute_root()?;
+            block_ranges_with_merkle_root.push((block_range, merkle_root));
+
+            if block_ranges_with_merkle_root.len() >= 100 {
+                let block_ranges_with_merkle_root_save =
+                    mem::take(&mut block_ranges_with_merkle_root);
+                block_store
+                    .store_block_range_roots(block_ranges_with_merkle_root_save)
+                    .await?;
+            }
+        }
+
+        block_store
+            .store_block_range_roots(block_ranges_with_merkle_root)
+            .await
+    }
 }
 
 #[async_trait]
@@ -207,8 +369,10 @@ impl TransactionsImporter for CardanoTransactionsImporter {
         let importer = self.clone();
         task::spawn_blocking(move || {
             Handle::current().block_on(async move {
+                importer.backfill_blocks().await?;
                 importer.import_transactions(up_to_beacon).await?;
                 importer.import_block_ranges(up_to_beacon).await?;
+                importer.import_block_hash_ranges(up_to_beacon).await?;
                 Ok(())
             })
         })
@@ -223,13 +387,16 @@ mod tests {
     use std::time::Duration;
 
     use mockall::mock;
+    use mockall::predicate::eq;
 
     use mithril_common::cardano_block_scanner::{
         BlockStreamer, DumbBlockScanner, DumbBlockStreamer, ScannedBlock,
     };
     use mithril_common::crypto_helper::MKTree;
     use mithril_common::entities::{BlockNumber, BlockRangesSequence};
-    use mithril_persistence::database::repository::CardanoTransactionRepository;
+    use mithril_persistence::database::repository::{
+        CardanoBlockRepository, CardanoTransactionRepository,
+    };
     use mithril_persistence::sqlite::SqliteConnectionPool;
 
     use crate::database::test_helper::cardano_tx_db_connection;
@@ -1123,6 +1290,160 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn with_a_block_store_parse_and_store_all_blocks_and_their_block_ranges() {
+        let connection_pool = Arc::new(SqliteConnectionPool::build_from_connection(
+            cardano_tx_db_connection().unwrap(),
+        ));
+        let transaction_repository =
+            Arc::new(CardanoTransactionRepository::new(connection_pool.clone()));
+        let block_repository = Arc::new(CardanoBlockRepository::new(connection_pool));
+
+        let up_to_block_number = BlockRange::LENGTH * 2;
+        let blocks = build_blocks(BlockNumber(0), up_to_block_number + 1);
+        let expected_blocks: Vec<CardanoBlock> =
+            blocks.iter().map(|b| b.to_cardano_block()).collect();
+        let expected_block_range_roots: Vec<(BlockRange, MKTreeNode)> = [0, 1]
+            .into_iter()
+            .map(|multiple| {
+                let block_range = BlockRange::from_block_number(BlockRange::LENGTH * multiple);
+                let blocks_in_range: Vec<&CardanoBlock> = expected_blocks
+                    .iter()
+                    .filter(|b| (block_range.start..block_range.end).contains(&b.block_number))
+                    .collect();
+                let merkle_root = MKTree::<MKTreeStoreInMemory>::new(&blocks_in_range)
+                    .unwrap()
+                    .compute_root()
+                    .unwrap();
+                (block_range, merkle_root)
+            })
+            .collect();
+
+        let importer = CardanoTransactionsImporter::new_for_test(
+            Arc::new(DumbBlockScanner::new().forwards(vec![blocks])),
+            transaction_repository,
+        )
+        .with_block_store(block_repository.clone());
+
+        importer
+            .import(up_to_block_number)
+            .await
+            .expect("Transactions Importer should succeed");
+
+        assert_eq!(expected_blocks, block_repository.get_all().await.unwrap());
+        let block_range_roots: Vec<(BlockRange, MKTreeNode)> = block_repository
+            .get_all_block_hash_range_root()
+            .unwrap()
+            .into_iter()
+            .map(|r| r.into())
+            .collect();
+        assert_eq!(expected_block_range_roots, block_range_roots);
+    }
+
+    #[tokio::test]
+    async fn with_a_block_store_backfill_the_blocks_of_the_already_stored_transactions() {
+        let connection_pool = Arc::new(SqliteConnectionPool::build_from_connection(
+            cardano_tx_db_connection().unwrap(),
+        ));
+        let transaction_repository =
+            Arc::new(CardanoTransactionRepository::new(connection_pool.clone()));
+        let block_repository = Arc::new(CardanoBlockRepository::new(connection_pool));
+
+        let up_to_block_number = BlockRange::LENGTH * 2;
+        let blocks = build_blocks(BlockNumber(0), up_to_block_number + 1);
+        transaction_repository
+            .store_transactions(into_transactions(&blocks))
+            .await
+            .unwrap();
+        let expected_blocks: Vec<CardanoBlock> =
+            blocks.iter().map(|b| b.to_cardano_block()).collect();
+
+        let importer = CardanoTransactionsImporter::new_for_test(
+            Arc::new(DumbBlockScanner::new().forwards(vec![blocks])),
+            transaction_repository.clone(),
+        )
+        .with_block_store(block_repository.clone());
+
+        importer
+            .import(up_to_block_number)
+            .await
+            .expect("Transactions Importer should succeed");
+
+        assert_eq!(expected_blocks, block_repository.get_all().await.unwrap());
+        assert_eq!(
+            2,
+            block_repository
+                .get_all_block_hash_range_root()
+                .unwrap()
+                .len()
+        );
+        assert_eq!(
+            into_transactions(&build_blocks(BlockNumber(0), up_to_block_number + 1)),
+            transaction_repository.get_all().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn with_a_block_store_dont_backfill_blocks_if_the_block_store_is_up_to_date() {
+        let mut block_store = MockBlockStore::new();
+        block_store.expect_get_highest_beacon().returning(|| {
+            Ok(Some(ChainPoint::new(
+                SlotNumber(100),
+                BlockNumber(10),
+                "block_hash-10",
+            )))
+        });
+        block_store.expect_store_blocks().never();
+
+        let mut transaction_store = MockTransactionStore::new();
+        transaction_store.expect_get_highest_beacon().returning(|| {
+            Ok(Some(ChainPoint::new(
+                SlotNumber(100),
+                BlockNumber(10),
+                "block_hash-10",
+            )))
+        });
+
+        let importer = CardanoTransactionsImporter::new_for_test(
+            Arc::new(
+                DumbBlockScanner::new()
+                    .forwards(vec![build_blocks(BlockNumber(0), BlockNumber(11))]),
+            ),
+            Arc::new(transaction_store),
+        )
+        .with_block_store(Arc::new(block_store));
+
+        importer
+            .backfill_blocks()
+            .await
+            .expect("Blocks backfill should succeed");
+    }
+
+    #[tokio::test]
+    async fn with_a_block_store_when_rollbackward_should_remove_blocks() {
+        let mut block_store = MockBlockStore::new();
+        block_store
+            .expect_remove_rolled_back_blocks_and_block_range()
+            .with(eq(SlotNumber(5)))
+            .return_once(|_| Ok(()))
+            .once();
+
+        let connection = cardano_tx_db_connection().unwrap();
+        let repository = Arc::new(CardanoTransactionRepository::new(Arc::new(
+            SqliteConnectionPool::build_from_connection(connection),
+        )));
+        let chain_point = ChainPoint::new(SlotNumber(5), BlockNumber(130), "block_hash-130");
+        let scanner = DumbBlockScanner::new().backward(chain_point);
+
+        let importer = CardanoTransactionsImporter::new_for_test(Arc::new(scanner), repository)
+            .with_block_store(Arc::new(block_store));
+
+        importer
+            .import_transactions(BlockNumber(3000))
+            .await
+            .expect("Transactions Importer should succeed");
+    }
+
     #[tokio::test]
     async fn test_import_is_non_blocking() {
         static COUNTER: AtomicUsize = AtomicUsize::new(0);
@@ -25,15 +25,16 @@ use mithril_common::{
     },
     era::{adapters::EraReaderDummyAdapter, EraChecker, EraMarker, EraReader, SupportedEra},
     signable_builder::{
-        CardanoDatabaseSignableBuilder, CardanoImmutableFilesFullSignableBuilder,
-        CardanoStakeDistributionSignableBuilder, CardanoTransactionsSignableBuilder,
-        MithrilSignableBuilderService, MithrilStakeDistributionSignableBuilder,
-        SignableBuilderServiceDependencies,
+        CardanoBlocksSignableBuilder, CardanoDatabaseSignableBuilder,
+        CardanoImmutableFilesFullSignableBuilder, CardanoStakeDistributionSignableBuilder,
+        CardanoTransactionsSignableBuilder, MithrilSignableBuilderService,
+        MithrilStakeDistributionSignableBuilder, SignableBuilderServiceDependencies,
     },
     MithrilTickerService, StdError, TickerService,
 };
 use mithril_persistence::{
-    database::repository::CardanoTransactionRepository, sqlite::SqliteConnectionPool,
+    database::repository::{CardanoBlockRepository, CardanoTransactionRepository},
+    sqlite::SqliteConnectionPool,
     store::StakeStorer,
 };
 use mithril_signed_entity_lock::SignedEntityTypeLock;
@@ -44,6 +45,7 @@ use mithril_signed_entity_preloader::{
 use mithril_signer::{
     database::repository::{ProtocolInitializerRepository, SignedBeaconRepository, StakePoolStore},
     dependency_injection::{DependenciesBuilder, SignerDependencyContainer},
+    entities::MAX_KES_EVOLUTIONS,
     services::{
         AggregatorClient, CardanoTransactionsImporter, MithrilEpochService, MithrilSingleSigner,
         SignerCertifierService, SignerSignableSeedBuilder, SignerSignedEntityConfigProvider,
@@ -197,11 +199,17 @@ impl StateMachineTester {
         let transaction_store = Arc::new(CardanoTransactionRepository::new(
             sqlite_connection_cardano_transaction_pool.clone(),
         ));
-        let transactions_importer = Arc::new(CardanoTransactionsImporter::new(
-            block_scanner.clone(),
-            transaction_store.clone(),
-            logger.clone(),
+        let block_store = Arc::new(CardanoBlockRepository::new(
+            sqlite_connection_cardano_transaction_pool.clone(),
         ));
+        let transactions_importer = Arc::new(
+            CardanoTransactionsImporter::new(
+                block_scanner.clone(),
+                transaction_store.clone(),
+                logger.clone(),
+            )
+            .with_block_store(block_store.clone()),
+        );
         let block_range_root_retriever = transaction_store.clone();
         let cardano_transactions_builder = Arc::new(CardanoTransactionsSignableBuilder::<
             MKTreeStoreSqlite,
@@ -209,6 +217,11 @@ impl StateMachineTester {
             transactions_importer.clone(),
             block_range_root_retriever,
         ));
+        let cardano_blocks_builder =
+            Arc::new(CardanoBlocksSignableBuilder::<MKTreeStoreSqlite>::new(
+                transactions_importer.clone(),
+                block_store,
+            ));
         let cardano_stake_distribution_builder = Arc::new(
             CardanoStakeDistributionSignableBuilder::new(stake_store.clone()),
         );
@@ -237,6 +250,7 @@ impl StateMachineTester {
             cardano_transactions_builder,
             cardano_stake_distribution_builder,
             cardano_database_signable_builder,
+            cardano_blocks_builder,
         );
         let signable_builder_service = Arc::new(MithrilSignableBuilderService::new(
             signable_seed_builder_service,
@@ -291,6 +305,7 @@ impl StateMachineTester {
             upkeep_service,
             epoch_service,
             certifier,
+            kes_signer: config.build_kes_signer().unwrap(),
         };
         // set up stake distribution
         chain_observer
@@ -735,6 +750,42 @@ impl StateMachineTester {
                 .name(),
             Value::Counter(total_signer_registrations_expected as f64),
         );
+        let lottery_win_probability = self
+            .metrics_service
+            .get_signer_lottery_win_probability_gauge()
+            .get();
+        self.assert(
+            lottery_win_probability > 0.0 && lottery_win_probability <= 1.0,
+            format!("Lottery win probability should be in ]0, 1], got {lottery_win_probability}"),
+        )?;
+        expected_metrics.insert(
+            self.metrics_service
+                .get_signer_lottery_win_probability_gauge()
+                .name(),
+            Value::Gauge(lottery_win_probability),
+        );
+        expected_metrics.insert(
+            self.metrics_service
+                .get_signer_lottery_expected_won_indices_gauge()
+                .name(),
+            Value::Gauge(
+                self.metrics_service
+                    .get_signer_lottery_expected_won_indices_gauge()
+                    .get(),
+            ),
+        );
+        expected_metrics.insert(
+            self.metrics_service
+                .get_signer_kes_current_period_gauge()
+                .name(),
+            Value::Gauge(0.0),
+        );
+        expected_metrics.insert(
+            self.metrics_service
+                .get_signer_kes_remaining_periods_gauge()
+                .name(),
+            Value::Gauge(MAX_KES_EVOLUTIONS as f64),
+        );
         self.assert(
             expected_metrics == metrics,
             format!("Metrics service should export expected metrics: given {metrics:?}, expected {expected_metrics:?}"),
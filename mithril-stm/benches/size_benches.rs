@@ -54,12 +54,16 @@ where
     // Aggregate with random parties
     let aggr = clerk.aggregate(&sigs, &msg).unwrap();
 
+    let legacy_size = aggr.to_bytes().len();
+    let compact_size = aggr.to_compact_bytes().len();
     println!(
-        "k = {} | m = {} | nr parties = {}; {} bytes",
+        "k = {} | m = {} | nr parties = {}; {} bytes (compact: {} bytes, {:.2}% smaller)",
         k,
         m,
         nparties,
-        aggr.to_bytes().len(),
+        legacy_size,
+        compact_size,
+        100.0 * (legacy_size - compact_size) as f64 / legacy_size as f64,
     );
 }
 
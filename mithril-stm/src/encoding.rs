@@ -0,0 +1,124 @@
+//! Variable length encoding of integers, used by the compact byte representations.
+//!
+//! Integers are encoded in LEB128: 7 bits per byte, the most significant bit telling if another
+//! byte follows. Sequences of integers are encoded as the zigzag encoded difference with the
+//! previous value, so that sorted sequences of close values take a single byte per value.
+
+/// Append the variable length encoding of `value` to `out`.
+pub(crate) fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Append the variable length encoding of the difference between `value` and `previous`.
+pub(crate) fn write_delta(out: &mut Vec<u8>, previous: u64, value: u64) {
+    let delta = value.wrapping_sub(previous) as i64;
+    write_varint(out, ((delta << 1) ^ (delta >> 63)) as u64);
+}
+
+/// Sequential reader of a byte slice.
+pub(crate) struct ByteReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, offset: 0 }
+    }
+
+    /// Read the next `len` bytes, `None` if there are not enough bytes left.
+    pub(crate) fn read_bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        let end = self.offset.checked_add(len)?;
+        let bytes = self.bytes.get(self.offset..end)?;
+        self.offset = end;
+        Some(bytes)
+    }
+
+    /// Read a variable length encoded integer, `None` if it is truncated or overflows a `u64`.
+    pub(crate) fn read_varint(&mut self) -> Option<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = *self.read_bytes(1)?.first()?;
+            let bits = u64::from(byte & 0x7f);
+            if shift == 63 && bits > 1 {
+                return None;
+            }
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    /// Read a variable length encoded integer as a `usize`.
+    pub(crate) fn read_varint_usize(&mut self) -> Option<usize> {
+        usize::try_from(self.read_varint()?).ok()
+    }
+
+    /// Read a value encoded with [write_delta] from the `previous` value.
+    pub(crate) fn read_delta(&mut self, previous: u64) -> Option<u64> {
+        let zigzag = self.read_varint()?;
+        let delta = ((zigzag >> 1) as i64) ^ -((zigzag & 1) as i64);
+        Some(previous.wrapping_add(delta as u64))
+    }
+
+    /// Returns `true` if all the bytes have been read.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.offset == self.bytes.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    #[test]
+    fn small_values_take_a_single_byte() {
+        let mut out = Vec::new();
+        write_varint(&mut out, 127);
+        write_delta(&mut out, 1_000, 1_063);
+        write_delta(&mut out, 1_063, 1_000);
+
+        assert_eq!(vec![127, 126, 125], out);
+    }
+
+    #[test]
+    fn read_varint_rejects_truncated_or_overflowing_values() {
+        assert_eq!(None, ByteReader::new(&[0x80]).read_varint());
+        assert_eq!(None, ByteReader::new(&[0xff; 10]).read_varint());
+        assert_eq!(None, ByteReader::new(&[0x80; 11]).read_varint());
+    }
+
+    proptest! {
+        #[test]
+        fn varint_and_delta_round_trip(values in vec(any::<u64>(), 0..20)) {
+            let mut out = Vec::new();
+            for value in &values {
+                write_varint(&mut out, *value);
+            }
+            let mut previous = 0;
+            for value in &values {
+                write_delta(&mut out, previous, *value);
+                previous = *value;
+            }
+
+            let mut reader = ByteReader::new(&out);
+            for value in &values {
+                assert_eq!(Some(*value), reader.read_varint());
+            }
+            let mut previous = 0;
+            for value in &values {
+                previous = reader.read_delta(previous).unwrap();
+                assert_eq!(*value, previous);
+            }
+            assert!(reader.is_empty());
+        }
+    }
+}
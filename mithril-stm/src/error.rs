@@ -57,6 +57,10 @@ pub enum StmSignatureError {
     /// This error occurs when the the serialization of the raw bytes failed
     #[error("Invalid bytes")]
     SerializationError,
+
+    /// The signer index of the signature is not part of the registration
+    #[error("Signer index {0} is not registered.")]
+    UnknownSigner(u64),
 }
 
 /// Errors which can be output by Mithril aggregate verification.
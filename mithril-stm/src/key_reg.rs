@@ -4,6 +4,7 @@ use crate::error::RegisterError;
 use crate::merkle_tree::{MTLeaf, MerkleTree};
 use crate::multi_sig::{VerificationKey, VerificationKeyPoP};
 use blake2::digest::{Digest, FixedOutput};
+use serde::{Deserialize, Serialize};
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -21,7 +22,16 @@ pub struct KeyReg {
 
 /// Structure generated out of a closed registration containing the registered parties, total stake, and the merkle tree.
 /// One can only get a global `avk` out of a closed key registration.
-#[derive(Clone, Debug, PartialEq, Eq)]
+///
+/// When deserialized, the registration is checked the same way as by [ClosedKeyReg::from_bytes].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    bound(
+        serialize = "MerkleTree<D>: Serialize",
+        deserialize = "D: Clone + FixedOutput, MerkleTree<D>: Deserialize<'de>"
+    ),
+    try_from = "UncheckedClosedKeyReg<D>"
+)]
 pub struct ClosedKeyReg<D: Digest> {
     /// Ordered list of registered parties.
     pub reg_parties: Vec<RegParty>,
@@ -31,6 +41,15 @@ pub struct ClosedKeyReg<D: Digest> {
     pub merkle_tree: Arc<MerkleTree<D>>,
 }
 
+/// A deserialized `ClosedKeyReg` that has not been checked yet.
+#[derive(Deserialize)]
+#[serde(bound(deserialize = "MerkleTree<D>: Deserialize<'de>"))]
+struct UncheckedClosedKeyReg<D: Digest> {
+    reg_parties: Vec<RegParty>,
+    total_stake: Stake,
+    merkle_tree: Arc<MerkleTree<D>>,
+}
+
 impl KeyReg {
     /// Initialize an empty `KeyReg`.
     pub fn init() -> Self {
@@ -81,6 +100,103 @@ impl KeyReg {
     }
 }
 
+impl<D: Clone + Digest + FixedOutput> ClosedKeyReg<D> {
+    /// Convert a `ClosedKeyReg` into bytes, the merkle tree of the registered parties being
+    /// persisted so that it is not rebuilt when restored.
+    /// # Layout
+    /// * Total stake (as u64)
+    /// * Number of registered parties, `n` (as u64)
+    /// * Registered parties, `n` times a verification key (96 bytes) and its stake (as u64)
+    /// * Merkle tree of the registered parties
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let merkle_tree = self.merkle_tree.to_bytes();
+        let mut out = Vec::with_capacity(16 + self.reg_parties.len() * 104 + merkle_tree.len());
+        out.extend_from_slice(&self.total_stake.to_be_bytes());
+        out.extend_from_slice(&u64::try_from(self.reg_parties.len()).unwrap().to_be_bytes());
+        for reg_party in &self.reg_parties {
+            out.extend_from_slice(&reg_party.to_bytes());
+        }
+        out.extend_from_slice(&merkle_tree);
+        out
+    }
+
+    /// Try to convert bytes into a `ClosedKeyReg`.
+    ///
+    /// The persisted merkle tree is not rebuilt, it is only spot checked: the leaves of the first
+    /// and last registered parties must match them and their paths must lead to its root. Its
+    /// other nodes are not verified, so the bytes must come from a trusted storage, or the root
+    /// of the tree must be compared with a trusted aggregate verification key.
+    /// # Error
+    /// The function fails if the bytes are not well formed, if the registered parties are not
+    /// sorted, or if they do not match the total stake or the spot check of the merkle tree.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, RegisterError> {
+        let mut u64_bytes = [0u8; 8];
+        u64_bytes.copy_from_slice(bytes.get(..8).ok_or(RegisterError::SerializationError)?);
+        let total_stake = Stake::from_be_bytes(u64_bytes);
+        u64_bytes.copy_from_slice(bytes.get(8..16).ok_or(RegisterError::SerializationError)?);
+        let nr_parties = usize::try_from(u64::from_be_bytes(u64_bytes))
+            .map_err(|_| RegisterError::SerializationError)?;
+        let merkle_tree_offset = nr_parties
+            .checked_mul(104)
+            .and_then(|size| size.checked_add(16))
+            .filter(|offset| *offset <= bytes.len())
+            .ok_or(RegisterError::SerializationError)?;
+
+        let reg_parties = bytes[16..merkle_tree_offset]
+            .chunks_exact(104)
+            .map(|reg_party| {
+                RegParty::from_bytes(reg_party).map_err(|_| RegisterError::SerializationError)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let merkle_tree = MerkleTree::from_bytes(&bytes[merkle_tree_offset..])
+            .map_err(|_| RegisterError::SerializationError)?;
+
+        UncheckedClosedKeyReg {
+            reg_parties,
+            total_stake,
+            merkle_tree: Arc::new(merkle_tree),
+        }
+        .try_into()
+    }
+}
+
+impl<D: Clone + Digest + FixedOutput> TryFrom<UncheckedClosedKeyReg<D>> for ClosedKeyReg<D> {
+    type Error = RegisterError;
+
+    fn try_from(unchecked: UncheckedClosedKeyReg<D>) -> Result<Self, Self::Error> {
+        let UncheckedClosedKeyReg {
+            reg_parties,
+            total_stake,
+            merkle_tree,
+        } = unchecked;
+
+        let parties_stake = reg_parties.iter().try_fold(0 as Stake, |total, reg_party| {
+            total.checked_add(reg_party.1)
+        });
+        if parties_stake != Some(total_stake)
+            || !reg_parties.windows(2).all(|pair| pair[0] <= pair[1])
+            || reg_parties.is_empty()
+            || merkle_tree.nr_leaves() != reg_parties.len()
+            || !merkle_tree.has_consistent_shape()
+        {
+            return Err(RegisterError::SerializationError);
+        }
+
+        let last_index = reg_parties.len() - 1;
+        if !merkle_tree.check_leaf(0, &reg_parties[0])
+            || !merkle_tree.check_leaf(last_index, &reg_parties[last_index])
+        {
+            return Err(RegisterError::SerializationError);
+        }
+
+        Ok(Self {
+            reg_parties,
+            total_stake,
+            merkle_tree,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -144,5 +260,71 @@ mod tests {
                 assert!(retrieved_keys == keys);
             }
         }
+
+        #[test]
+        fn test_closed_key_reg_serialize_deserialize(stake in vec(1..1u64 << 60, 1..=10),
+                                                     seed in any::<[u8;32]>()) {
+            let mut rng = ChaCha20Rng::from_seed(seed);
+            let mut kr = KeyReg::init();
+            for stake in stake {
+                let sk = SigningKey::gen(&mut rng);
+                kr.register(stake, VerificationKeyPoP::from(&sk)).unwrap();
+            }
+            let closed = kr.close::<Blake2b<U32>>();
+
+            let bytes = closed.to_bytes();
+            let decoded = ClosedKeyReg::<Blake2b<U32>>::from_bytes(&bytes).unwrap();
+            assert_eq!(closed.reg_parties, decoded.reg_parties);
+            assert_eq!(closed.total_stake, decoded.total_stake);
+            assert_eq!(closed.merkle_tree.to_bytes(), decoded.merkle_tree.to_bytes());
+
+            let encoded = bincode::serialize(&closed).unwrap();
+            let decoded: ClosedKeyReg<Blake2b<U32>> = bincode::deserialize(&encoded).unwrap();
+            assert_eq!(bytes, decoded.to_bytes());
+
+            assert!(ClosedKeyReg::<Blake2b<U32>>::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+            let mut wrong_total_stake = bytes.clone();
+            wrong_total_stake[7] ^= 1;
+            assert!(ClosedKeyReg::<Blake2b<U32>>::from_bytes(&wrong_total_stake).is_err());
+            let mut wrong_merkle_tree = bytes.clone();
+            let last_byte = wrong_merkle_tree.len() - 1;
+            wrong_merkle_tree[last_byte] ^= 1;
+            assert!(ClosedKeyReg::<Blake2b<U32>>::from_bytes(&wrong_merkle_tree).is_err());
+        }
+
+        #[test]
+        fn test_closed_key_reg_deserialize_rejects_tampered_payload(stake in vec(1..1u64 << 60, 2..=10),
+                                                                    seed in any::<[u8;32]>()) {
+            let mut rng = ChaCha20Rng::from_seed(seed);
+            let mut kr = KeyReg::init();
+            for stake in stake {
+                let sk = SigningKey::gen(&mut rng);
+                kr.register(stake, VerificationKeyPoP::from(&sk)).unwrap();
+            }
+            let closed = kr.close::<Blake2b<U32>>();
+            let deserialize = |closed_reg: &ClosedKeyReg<Blake2b<U32>>| {
+                let encoded = bincode::serialize(closed_reg).unwrap();
+                bincode::deserialize::<ClosedKeyReg<Blake2b<U32>>>(&encoded)
+            };
+            assert!(deserialize(&closed).is_ok());
+
+            let mut wrong_total_stake = closed.clone();
+            wrong_total_stake.total_stake += 1;
+            assert!(deserialize(&wrong_total_stake).is_err());
+
+            let mut unordered_parties = closed.clone();
+            unordered_parties.reg_parties.reverse();
+            assert!(deserialize(&unordered_parties).is_err());
+
+            let mut wrong_last_party = closed.clone();
+            wrong_last_party.reg_parties.last_mut().unwrap().1 += 1;
+            wrong_last_party.total_stake += 1;
+            assert!(deserialize(&wrong_last_party).is_err());
+
+            let mut missing_party = closed.clone();
+            let removed_party = missing_party.reg_parties.pop().unwrap();
+            missing_party.total_stake -= removed_party.1;
+            assert!(deserialize(&missing_party).is_err());
+        }
     }
 }
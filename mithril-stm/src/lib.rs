@@ -5,9 +5,11 @@
 extern crate core;
 
 mod eligibility_check;
+mod encoding;
 mod error;
 pub mod key_reg;
 mod merkle_tree;
+pub mod simulation;
 pub mod stm;
 
 pub use crate::error::{
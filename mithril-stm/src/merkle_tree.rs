@@ -196,30 +196,39 @@ impl<D: Digest + FixedOutput> BatchPath<D> {
     }
 
     /// Try to convert a byte string into a `BatchPath`.
-    // todo: We should not panic if the size of the slice is invalid (I believe `bytes[offset + i * 8..offset + (i + 1) * 8]` will panic if bytes is not large enough.
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, MerkleTreeError<D>> {
         let mut u64_bytes = [0u8; 8];
-        u64_bytes.copy_from_slice(&bytes[..8]);
+        u64_bytes.copy_from_slice(bytes.get(..8).ok_or(MerkleTreeError::SerializationError)?);
         let len_v = usize::try_from(u64::from_be_bytes(u64_bytes))
             .map_err(|_| MerkleTreeError::SerializationError)?;
 
-        u64_bytes.copy_from_slice(&bytes[8..16]);
+        u64_bytes.copy_from_slice(
+            bytes
+                .get(8..16)
+                .ok_or(MerkleTreeError::SerializationError)?,
+        );
         let len_i = usize::try_from(u64::from_be_bytes(u64_bytes))
             .map_err(|_| MerkleTreeError::SerializationError)?;
 
-        let mut values = Vec::with_capacity(len_v);
-        for i in 0..len_v {
-            values.push(
-                bytes[16 + i * <D as Digest>::output_size()
-                    ..16 + (i + 1) * <D as Digest>::output_size()]
-                    .to_vec(),
-            );
-        }
-        let offset = 16 + len_v * <D as Digest>::output_size();
-
-        let mut indices = Vec::with_capacity(len_v);
-        for i in 0..len_i {
-            u64_bytes.copy_from_slice(&bytes[offset + i * 8..offset + (i + 1) * 8]);
+        let value_size = <D as Digest>::output_size();
+        let offset = len_v
+            .checked_mul(value_size)
+            .and_then(|values_size| values_size.checked_add(16))
+            .ok_or(MerkleTreeError::SerializationError)?;
+        let end = len_i
+            .checked_mul(8)
+            .and_then(|indices_size| indices_size.checked_add(offset))
+            .filter(|end| *end <= bytes.len())
+            .ok_or(MerkleTreeError::SerializationError)?;
+
+        let values = bytes[16..offset]
+            .chunks_exact(value_size)
+            .map(|value| value.to_vec())
+            .collect::<Vec<_>>();
+
+        let mut indices = Vec::with_capacity(len_i);
+        for index_bytes in bytes[offset..end].chunks_exact(8) {
+            u64_bytes.copy_from_slice(index_bytes);
             indices.push(
                 usize::try_from(u64::from_be_bytes(u64_bytes))
                     .map_err(|_| MerkleTreeError::SerializationError)?,
@@ -451,6 +460,11 @@ impl<D: Digest + FixedOutput> MerkleTree<D> {
         &self.nodes[0]
     }
 
+    /// Get the number of leaves committed in the tree.
+    pub fn nr_leaves(&self) -> usize {
+        self.n
+    }
+
     /// Get a path (hashes of siblings of the path to the root node)
     /// for the `i`th value stored in the tree.
     /// Requires `i < self.n`
@@ -551,12 +565,34 @@ impl<D: Digest + FixedOutput> MerkleTree<D> {
         }
     }
 
+    /// Check that the number of nodes and the offset of the leaves match the number of leaves,
+    /// which is not enforced when the tree is deserialized with serde.
+    pub(crate) fn has_consistent_shape(&self) -> bool {
+        self.n
+            .checked_next_power_of_two()
+            .and_then(|power| power.checked_add(self.n.checked_sub(1)?))
+            .is_some_and(|num_nodes| {
+                self.nodes.len() == num_nodes && self.leaf_off == num_nodes - self.n
+            })
+    }
+
+    /// Check that the `i`th leaf stored in the tree is the hash of `leaf` and that its path
+    /// leads to the root of the tree, without rebuilding the tree.
+    pub(crate) fn check_leaf(&self, i: usize, leaf: &MTLeaf) -> bool
+    where
+        D: Clone,
+    {
+        i < self.n
+            && self.nodes[self.idx_of_leaf(i)] == D::digest(leaf.to_bytes()).to_vec()
+            && self.to_commitment().check(leaf, &self.get_path(i)).is_ok()
+    }
+
     /// Return the index of the leaf.
     fn idx_of_leaf(&self, i: usize) -> usize {
         self.leaf_off + i
     }
 
-    /// Convert a `MerkleTree` into a byte string, containing $4 + n * S$ bytes where $n$ is the
+    /// Convert a `MerkleTree` into a byte string, containing $8 + n * S$ bytes where $n$ is the
     /// number of nodes and $S$ the output size of the hash function.
     /// # Layout
     /// * Number of leaves committed in the Merkle Tree (as u64)
@@ -572,21 +608,28 @@ impl<D: Digest + FixedOutput> MerkleTree<D> {
 
     /// Try to convert a byte string into a `MerkleTree`.
     /// # Error
-    /// It returns error if conversion fails.
+    /// It returns error if conversion fails, or if the number of bytes does not match the number
+    /// of leaves.
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, MerkleTreeError<D>> {
+        let node_size = <D as Digest>::output_size();
         let mut u64_bytes = [0u8; 8];
-        u64_bytes.copy_from_slice(&bytes[..8]);
+        u64_bytes.copy_from_slice(bytes.get(..8).ok_or(MerkleTreeError::SerializationError)?);
         let n = usize::try_from(u64::from_be_bytes(u64_bytes))
             .map_err(|_| MerkleTreeError::SerializationError)?;
-        let num_nodes = n + n.next_power_of_two() - 1;
-        let mut nodes = Vec::with_capacity(num_nodes);
-        for i in 0..num_nodes {
-            nodes.push(
-                bytes[8 + i * <D as Digest>::output_size()
-                    ..8 + (i + 1) * <D as Digest>::output_size()]
-                    .to_vec(),
-            );
-        }
+        if n == 0 {
+            return Err(MerkleTreeError::SerializationError);
+        }
+        let num_nodes = n
+            .checked_next_power_of_two()
+            .and_then(|power| power.checked_add(n - 1))
+            .ok_or(MerkleTreeError::SerializationError)?;
+        if num_nodes.checked_mul(node_size) != Some(bytes.len() - 8) {
+            return Err(MerkleTreeError::SerializationError);
+        }
+        let nodes = bytes[8..]
+            .chunks_exact(node_size)
+            .map(|node| node.to_vec())
+            .collect();
         Ok(Self {
             nodes,
             leaf_off: num_nodes - n,
@@ -692,6 +735,15 @@ mod tests {
             assert_eq!(tree.nodes, decoded.nodes);
         }
 
+        #[test]
+        fn test_bytes_tree_invalid_length((t, _values) in arb_tree(5), truncated in 1..32usize) {
+            let bytes = t.to_bytes();
+            assert!(MerkleTree::<Blake2b<U32>>::from_bytes(&bytes[..bytes.len() - truncated]).is_err());
+            assert!(MerkleTree::<Blake2b<U32>>::from_bytes(&[bytes.as_slice(), &[0u8]].concat()).is_err());
+            assert!(MerkleTree::<Blake2b<U32>>::from_bytes(&bytes[..4]).is_err());
+            assert!(MerkleTree::<Blake2b<U32>>::from_bytes(&0u64.to_be_bytes()).is_err());
+        }
+
         #[test]
         fn test_bytes_tree_commitment_batch_compat((t, values) in arb_tree(5)) {
             let encoded = bincode::serialize(&t.to_commitment_batch_compat()).unwrap();
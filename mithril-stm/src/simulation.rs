@@ -0,0 +1,376 @@
+//! Simulation of the protocol parameters against a stake distribution.
+//!
+//! The [ParametersSimulator] helps choosing the `StmParameters` of a network by estimating,
+//! for a given stake distribution:
+//! - the probability to reach the quorum for a participation rate of the signers, with Monte Carlo
+//!   simulations of the lottery,
+//! - the expected size of an aggregate signature,
+//! - the maximum adversarial stake for which the adversary alone can not reach the quorum, except
+//!   with a negligible probability.
+use blake2::digest::Digest;
+use rand_core::RngCore;
+use serde::{Deserialize, Serialize};
+
+use crate::eligibility_check::ev_lt_phi;
+use crate::stm::{Stake, StmParameters};
+
+/// Size in bytes of a serialized `StmSigRegParty` without its indexes: the verification key and
+/// stake of the party, the number of indexes, the signature and the signer index.
+const SIG_REG_PARTY_BASE_SIZE: usize = 96 + 8 + 8 + 48 + 8;
+
+/// Estimation of the behavior of the protocol parameters for a participation rate of the signers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParticipationSimulation {
+    /// Probability for each signer to take part in the signature
+    pub participation_rate: f64,
+    /// Ratio of the Monte Carlo runs that reached the quorum
+    pub quorum_success_probability: f64,
+    /// Expected size in bytes of the aggregate signature
+    pub expected_aggregate_signature_size: usize,
+}
+
+/// Result of the simulation of protocol parameters against a stake distribution.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParametersSimulationReport {
+    /// Simulated protocol parameters
+    pub parameters: StmParameters,
+    /// Number of signers with a non zero stake
+    pub total_signers: usize,
+    /// Total stake of the distribution
+    pub total_stake: Stake,
+    /// Number of Monte Carlo runs for each participation rate
+    pub runs: usize,
+    /// Simulations for each participation rate
+    pub participations: Vec<ParticipationSimulation>,
+    /// Security level used to compute the adversarial stake threshold, in bits
+    pub security_bits: u32,
+    /// Maximum ratio of the total stake that an adversary can hold without being able to reach the
+    /// quorum alone, except with a probability lower than `2^-security_bits`
+    pub adversarial_stake_threshold: f64,
+}
+
+/// Simulator of the protocol parameters against a stake distribution.
+#[derive(Debug, Clone)]
+pub struct ParametersSimulator {
+    params: StmParameters,
+    stakes: Vec<Stake>,
+    total_stake: Stake,
+}
+
+impl ParametersSimulator {
+    /// Create a simulator for the given parameters and stakes of the signers.
+    ///
+    /// Returns `None` if the total stake is zero or overflows.
+    pub fn new(params: StmParameters, stakes: &[Stake]) -> Option<Self> {
+        let stakes: Vec<Stake> = stakes.iter().copied().filter(|s| *s > 0).collect();
+        let total_stake = stakes
+            .iter()
+            .try_fold(0 as Stake, |total, stake| total.checked_add(*stake))?;
+        if total_stake == 0 {
+            return None;
+        }
+
+        Some(Self {
+            params,
+            stakes,
+            total_stake,
+        })
+    }
+
+    /// Estimate the probability to reach the quorum when each signer takes part in the signature
+    /// with the given probability.
+    ///
+    /// Each run draws the participating signers, then plays the lottery of the `m` indexes with
+    /// their stake. The union of the lotteries of several signers is the lottery of their summed
+    /// stake, since `1 - phi(w1 + w2) = (1 - phi(w1)) * (1 - phi(w2))`.
+    pub fn quorum_success_probability<R: RngCore>(
+        &self,
+        participation_rate: f64,
+        runs: usize,
+        rng: &mut R,
+    ) -> f64 {
+        if runs == 0 {
+            return 0.0;
+        }
+
+        let successful_runs = (0..runs)
+            .filter(|_| {
+                let participating_stake = self
+                    .stakes
+                    .iter()
+                    .filter(|_| random_bool(rng, participation_rate))
+                    .sum::<Stake>();
+                self.play_lottery(participating_stake, rng)
+            })
+            .count();
+
+        successful_runs as f64 / runs as f64
+    }
+
+    /// Estimate the size in bytes of an aggregate signature when each signer takes part in the
+    /// signature with the given probability, for a merkle tree digest `D`.
+    pub fn expected_aggregate_signature_size<D: Digest>(&self, participation_rate: f64) -> usize {
+        let m = self.params.m as f64;
+        let k = self.params.k as f64;
+        let expected_winners: f64 = self
+            .stakes
+            .iter()
+            .map(|stake| {
+                participation_rate
+                    * self
+                        .params
+                        .lottery_preview(*stake, self.total_stake)
+                        .win_probability
+            })
+            .sum();
+        let expected_unique_indexes = m * self.phi_ratio(participation_rate);
+        if expected_winners == 0.0 || expected_unique_indexes == 0.0 {
+            return 0;
+        }
+
+        // Only the signatures needed to reach the quorum are aggregated
+        let expected_signatures = (expected_winners * (k / expected_unique_indexes).min(1.0))
+            .ceil()
+            .max(1.0);
+        let tree_depth = (self.stakes.len() as f64).log2().ceil();
+        let batch_path_values =
+            expected_signatures * (tree_depth - expected_signatures.log2().floor()).max(0.0);
+
+        let signatures_size = expected_signatures * SIG_REG_PARTY_BASE_SIZE as f64 + k * 8.0 + 16.0;
+        let batch_path_size = batch_path_values * <D as Digest>::output_size() as f64
+            + expected_signatures * 8.0
+            + 16.0;
+
+        (signatures_size + batch_path_size).ceil() as usize
+    }
+
+    /// Probability that signers holding the given ratio of the total stake win at least `k`
+    /// indexes.
+    pub fn quorum_probability_for_stake_ratio(&self, stake_ratio: f64) -> f64 {
+        binomial_tail(self.params.m, self.params.k, self.phi_ratio(stake_ratio))
+    }
+
+    /// Maximum ratio of the total stake that an adversary can hold, such that the probability
+    /// that it reaches the quorum alone is lower than `2^-security_bits`.
+    pub fn adversarial_stake_threshold(&self, security_bits: u32) -> f64 {
+        let max_probability = 2f64.powi(-(security_bits as i32));
+        let (mut secure_ratio, mut insecure_ratio) = (0.0, 1.0);
+        if self.quorum_probability_for_stake_ratio(insecure_ratio) <= max_probability {
+            return insecure_ratio;
+        }
+        for _ in 0..64 {
+            let ratio = (secure_ratio + insecure_ratio) / 2.0;
+            if self.quorum_probability_for_stake_ratio(ratio) <= max_probability {
+                secure_ratio = ratio;
+            } else {
+                insecure_ratio = ratio;
+            }
+        }
+
+        secure_ratio
+    }
+
+    /// Run all the simulations for the given participation rates.
+    pub fn simulate<D: Digest, R: RngCore>(
+        &self,
+        participation_rates: &[f64],
+        runs: usize,
+        security_bits: u32,
+        rng: &mut R,
+    ) -> ParametersSimulationReport {
+        let participations = participation_rates
+            .iter()
+            .map(|&participation_rate| ParticipationSimulation {
+                participation_rate,
+                quorum_success_probability: self.quorum_success_probability(
+                    participation_rate,
+                    runs,
+                    rng,
+                ),
+                expected_aggregate_signature_size: self
+                    .expected_aggregate_signature_size::<D>(participation_rate),
+            })
+            .collect();
+
+        ParametersSimulationReport {
+            parameters: self.params,
+            total_signers: self.stakes.len(),
+            total_stake: self.total_stake,
+            runs,
+            participations,
+            security_bits,
+            adversarial_stake_threshold: self.adversarial_stake_threshold(security_bits),
+        }
+    }
+
+    /// Play the lottery of the `m` indexes with the given stake and return `true` if at least
+    /// `k` of them are won.
+    fn play_lottery<R: RngCore>(&self, stake: Stake, rng: &mut R) -> bool {
+        if stake == 0 {
+            return self.params.k == 0;
+        }
+
+        let mut won_indexes = 0;
+        for played_indexes in 0..self.params.m {
+            if won_indexes >= self.params.k {
+                return true;
+            }
+            if won_indexes + (self.params.m - played_indexes) < self.params.k {
+                return false;
+            }
+            let mut ev = [0u8; 64];
+            rng.fill_bytes(&mut ev);
+            if ev_lt_phi(self.params.phi_f, ev, stake, self.total_stake) {
+                won_indexes += 1;
+            }
+        }
+
+        won_indexes >= self.params.k
+    }
+
+    fn phi_ratio(&self, stake_ratio: f64) -> f64 {
+        1.0 - (1.0 - self.params.phi_f).powf(stake_ratio)
+    }
+}
+
+fn random_bool<R: RngCore>(rng: &mut R, probability: f64) -> bool {
+    (rng.next_u64() as f64 / u64::MAX as f64) < probability
+}
+
+/// Probability that a binomial variable of `n` trials with a success probability `p` is at least
+/// `k`, computed in the log space to support a large `n`.
+fn binomial_tail(n: u64, k: u64, p: f64) -> f64 {
+    if k == 0 {
+        return 1.0;
+    }
+    if k > n || p <= 0.0 {
+        return 0.0;
+    }
+    if p >= 1.0 {
+        return 1.0;
+    }
+
+    let mut ln_factorials = Vec::with_capacity(n as usize + 1);
+    ln_factorials.push(0.0);
+    for i in 1..=n {
+        ln_factorials.push(ln_factorials[i as usize - 1] + (i as f64).ln());
+    }
+    let ln_pmf = |i: u64| {
+        ln_factorials[n as usize] - ln_factorials[i as usize] - ln_factorials[(n - i) as usize]
+            + i as f64 * p.ln()
+            + (n - i) as f64 * (-p).ln_1p()
+    };
+
+    let ln_terms: Vec<f64> = (k..=n).map(ln_pmf).collect();
+    let max_ln_term = ln_terms.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let sum: f64 = ln_terms.iter().map(|t| (t - max_ln_term).exp()).sum();
+
+    (max_ln_term + sum.ln()).exp().min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use blake2::{digest::consts::U32, Blake2b};
+    use rand_chacha::ChaCha20Rng;
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    fn simulator(m: u64, k: u64, phi_f: f64, stakes: &[Stake]) -> ParametersSimulator {
+        ParametersSimulator::new(StmParameters { m, k, phi_f }, stakes).unwrap()
+    }
+
+    #[test]
+    fn new_fails_without_stake() {
+        let params = StmParameters {
+            m: 10,
+            k: 5,
+            phi_f: 0.2,
+        };
+
+        assert!(ParametersSimulator::new(params, &[]).is_none());
+        assert!(ParametersSimulator::new(params, &[0, 0]).is_none());
+        assert!(ParametersSimulator::new(params, &[u64::MAX, 1]).is_none());
+    }
+
+    #[test]
+    fn binomial_tail_matches_known_values() {
+        assert_eq!(1.0, binomial_tail(10, 0, 0.5));
+        assert_eq!(0.0, binomial_tail(10, 11, 0.5));
+        assert!((binomial_tail(10, 10, 0.5) - 0.5f64.powi(10)).abs() < 1e-12);
+        // P(X >= 5) for X ~ B(10, 0.5) is 638 / 1024
+        assert!((binomial_tail(10, 5, 0.5) - 638.0 / 1024.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn quorum_is_always_reached_with_full_participation_and_phi_f_one() {
+        let simulator = simulator(20, 10, 1.0, &[10, 20, 30]);
+        let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+
+        assert_eq!(1.0, simulator.quorum_success_probability(1.0, 10, &mut rng));
+        assert_eq!(0.0, simulator.quorum_success_probability(0.0, 10, &mut rng));
+    }
+
+    #[test]
+    fn quorum_success_probability_matches_the_analytic_probability() {
+        let simulator = simulator(100, 20, 0.2, &[100; 10]);
+        let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+
+        let simulated = simulator.quorum_success_probability(1.0, 200, &mut rng);
+        let analytic = simulator.quorum_probability_for_stake_ratio(1.0);
+
+        assert!(
+            (simulated - analytic).abs() < 0.1,
+            "simulated: {simulated}, analytic: {analytic}"
+        );
+    }
+
+    #[test]
+    fn adversarial_stake_threshold_decreases_with_the_security_level() {
+        let simulator = simulator(2000, 400, 0.2, &[100; 100]);
+
+        let threshold_64_bits = simulator.adversarial_stake_threshold(64);
+        let threshold_128_bits = simulator.adversarial_stake_threshold(128);
+
+        assert!(threshold_64_bits > 0.0 && threshold_64_bits < 1.0);
+        assert!(threshold_128_bits < threshold_64_bits);
+        assert!(
+            simulator.quorum_probability_for_stake_ratio(threshold_128_bits) <= 2f64.powi(-128)
+        );
+    }
+
+    #[test]
+    fn expected_aggregate_signature_size_grows_with_the_quorum() {
+        let small_quorum = simulator(100, 5, 0.2, &[100; 50]);
+        let large_quorum = simulator(100, 20, 0.2, &[100; 50]);
+
+        let small_size = small_quorum.expected_aggregate_signature_size::<Blake2b<U32>>(1.0);
+        let large_size = large_quorum.expected_aggregate_signature_size::<Blake2b<U32>>(1.0);
+
+        assert!(small_size > 0);
+        assert!(large_size > small_size);
+        assert_eq!(
+            0,
+            small_quorum.expected_aggregate_signature_size::<Blake2b<U32>>(0.0)
+        );
+    }
+
+    #[test]
+    fn simulate_report_each_participation_rate() {
+        let simulator = simulator(50, 10, 0.2, &[100; 10]);
+        let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+
+        let report = simulator.simulate::<Blake2b<U32>, _>(&[0.5, 1.0], 20, 64, &mut rng);
+
+        assert_eq!(10, report.total_signers);
+        assert_eq!(1000, report.total_stake);
+        assert_eq!(
+            vec![0.5, 1.0],
+            report
+                .participations
+                .iter()
+                .map(|p| p.participation_rate)
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -107,6 +107,7 @@
 //! ```
 
 use crate::eligibility_check::ev_lt_phi;
+use crate::encoding::{write_delta, write_varint, ByteReader};
 use crate::error::{
     AggregationError, CoreVerifierError, RegisterError, StmAggregateSignatureError,
     StmSignatureError,
@@ -130,6 +131,15 @@ pub type Stake = u64;
 /// An aggregate signature (`StmMultiSig`) must have at least `k` unique indices.
 pub type Index = u64;
 
+/// First byte of the compact representation of an aggregate signature.
+///
+/// The legacy representation starts with the number of signatures as a big endian u64, so its
+/// first byte is always `0` in practice.
+const COMPACT_AGGR_SIG_TAG: u8 = 0xFF;
+
+/// Version of the compact representation of an aggregate signature.
+const COMPACT_AGGR_SIG_VERSION: u8 = 1;
+
 /// Wrapper of the MultiSignature Verification key with proof of possession
 pub type StmVerificationKeyPoP = VerificationKeyPoP;
 
@@ -153,6 +163,17 @@ pub struct StmParameters {
     pub phi_f: f64,
 }
 
+/// Preview of the lottery of a party, computed from its stake before signing any message.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StmLotteryPreview {
+    /// Probability to win at least one index, i.e. to issue a signature for a message.
+    pub win_probability: f64,
+    /// Expected number of won indices for a message.
+    pub expected_won_indices: f64,
+    /// Expected share of the `k` indices of the quorum brought by the won indices of the party.
+    pub expected_quorum_contribution: f64,
+}
+
 /// Initializer for `StmSigner`.
 /// This is the data that is used during the key registration procedure.
 /// Once the latter is finished, this instance is consumed into an `StmSigner`.
@@ -194,6 +215,19 @@ pub struct StmClerk<D: Clone + Digest> {
     pub(crate) params: StmParameters,
 }
 
+/// Stateful aggregation of `StmSig`s for a message, created by [StmClerk::start_aggregation].
+///
+/// The signatures are verified when they are added and only the signature with the smallest
+/// scalar is kept for each index, so that [StmAggregationSession::finalize] only has to build the
+/// batch proof of the winning signatures.
+#[derive(Debug, Clone)]
+pub struct StmAggregationSession<'a, D: Clone + Digest + FixedOutput> {
+    clerk: &'a StmClerk<D>,
+    msgp: Vec<u8>,
+    signatures: Vec<StmSigRegParty>,
+    sig_position_by_index: BTreeMap<Index, usize>,
+}
+
 /// Signature created by a single party who has won the lottery.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StmSig {
@@ -300,6 +334,37 @@ impl StmParameters {
 
         Ok(Self { m, k, phi_f })
     }
+
+    /// Preview the lottery of a party holding `stake` out of `total_stake`.
+    ///
+    /// Each of the `m` indices is won with probability `phi(stake / total_stake)`, so the party
+    /// wins at least one index with probability `1 - (1 - phi_f)^(m * stake / total_stake)`.
+    pub fn lottery_preview(&self, stake: Stake, total_stake: Stake) -> StmLotteryPreview {
+        if stake == 0 || total_stake == 0 {
+            return StmLotteryPreview {
+                win_probability: 0.0,
+                expected_won_indices: 0.0,
+                expected_quorum_contribution: 0.0,
+            };
+        }
+
+        let ln_one_minus_phi_f = (-self.phi_f).ln_1p();
+        let stake_ratio = stake as f64 / total_stake as f64;
+        let phi = -(stake_ratio * ln_one_minus_phi_f).exp_m1();
+        let win_probability = -(self.m as f64 * stake_ratio * ln_one_minus_phi_f).exp_m1();
+        let expected_won_indices = self.m as f64 * phi;
+        let expected_quorum_contribution = if self.k == 0 {
+            0.0
+        } else {
+            expected_won_indices / self.k as f64
+        };
+
+        StmLotteryPreview {
+            win_probability,
+            expected_won_indices,
+            expected_quorum_contribution,
+        }
+    }
 }
 
 impl StmInitializer {
@@ -456,6 +521,19 @@ impl<D: Clone + Digest + FixedOutput> StmSigner<D> {
         self.stake
     }
 
+    /// Preview the lottery of the signer with its stake and the total stake of the closed
+    /// registration, see [StmParameters::lottery_preview].
+    ///
+    /// Returns `None` for a core signer, which has no closed registration.
+    pub fn lottery_preview(&self) -> Option<StmLotteryPreview> {
+        let closed_reg = self.closed_reg.as_ref()?;
+
+        Some(
+            self.params
+                .lottery_preview(self.stake, closed_reg.total_stake),
+        )
+    }
+
     /// A core signature generated without closed registration.
     /// The core signature can be verified by core verifier.
     /// Once the signature is produced, this function checks whether any index in `[0,..,self.params.m]`
@@ -558,6 +636,21 @@ impl<D: Digest + Clone + FixedOutput> StmClerk<D> {
         })
     }
 
+    /// Start a stateful aggregation of the signatures of the given message.
+    ///
+    /// Unlike [StmClerk::aggregate], the signatures are added one at a time to the returned
+    /// session, which is useful when they are received over time.
+    pub fn start_aggregation(&self, msg: &[u8]) -> StmAggregationSession<'_, D> {
+        let msgp = self.compute_avk().mt_commitment.concat_with_msg(msg);
+
+        StmAggregationSession {
+            clerk: self,
+            msgp,
+            signatures: Vec::new(),
+            sig_position_by_index: BTreeMap::new(),
+        }
+    }
+
     /// Compute the `StmAggrVerificationKey` related to the used registration.
     pub fn compute_avk(&self) -> StmAggrVerificationKey<D> {
         StmAggrVerificationKey::from(&self.closed_reg)
@@ -570,6 +663,142 @@ impl<D: Digest + Clone + FixedOutput> StmClerk<D> {
             .get(*party_index as usize)
             .map(|&r| r.into())
     }
+
+    /// Convert the `StmClerk` into bytes, so that it can be restored from a persisted
+    /// registration without recomputing the merkle tree of the registered parties.
+    /// # Layout
+    /// * Protocol parameters (24 bytes)
+    /// * Closed key registration
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = self.params.to_bytes().to_vec();
+        out.extend_from_slice(&self.closed_reg.to_bytes());
+        out
+    }
+
+    /// Try to convert bytes into a `StmClerk`.
+    /// # Error
+    /// The function fails if the parameters or the closed key registration are not well formed.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, RegisterError> {
+        let params =
+            StmParameters::from_bytes(bytes.get(..24).ok_or(RegisterError::SerializationError)?)?;
+        let closed_reg = ClosedKeyReg::from_bytes(&bytes[24..])?;
+
+        Ok(Self { closed_reg, params })
+    }
+}
+
+impl<D: Clone + Digest + FixedOutput> StmAggregationSession<'_, D> {
+    /// Verify the given signature and add it to the session.
+    ///
+    /// For each of its indexes, the signature replaces the previously added one if its scalar is
+    /// smaller. A signature that was already added is ignored.
+    ///
+    /// Returns `true` if the quorum is reached.
+    pub fn add_signature(&mut self, sig: &StmSig) -> Result<bool, StmSignatureError> {
+        let reg_party = *self
+            .clerk
+            .closed_reg
+            .reg_parties
+            .get(sig.signer_index as usize)
+            .ok_or(StmSignatureError::UnknownSigner(sig.signer_index))?;
+        if self.signatures.iter().any(|sig_reg| &sig_reg.sig == sig) {
+            return Ok(self.has_quorum());
+        }
+        sig.verify_core(
+            &self.clerk.params,
+            &reg_party.0,
+            &reg_party.1,
+            &self.msgp,
+            &self.clerk.closed_reg.total_stake,
+        )?;
+
+        let position = self.signatures.len();
+        for index in &sig.indexes {
+            let is_better_sig = match self.sig_position_by_index.get(index) {
+                Some(&previous_position) => {
+                    sig.sigma < self.signatures[previous_position].sig.sigma
+                }
+                None => true,
+            };
+            if is_better_sig {
+                self.sig_position_by_index.insert(*index, position);
+            }
+        }
+        self.signatures.push(StmSigRegParty {
+            sig: sig.clone(),
+            reg_party,
+        });
+
+        Ok(self.has_quorum())
+    }
+
+    /// Number of unique indexes won by the signatures added so far.
+    pub fn unique_indices_count(&self) -> u64 {
+        self.sig_position_by_index.len() as u64
+    }
+
+    /// Whether the signatures added so far cover at least `k` unique indexes.
+    pub fn has_quorum(&self) -> bool {
+        self.unique_indices_count() >= self.clerk.params.k
+    }
+
+    /// Aggregate the signatures added to the session.
+    ///
+    /// The result is the same as [StmClerk::aggregate] called with all the added signatures:
+    /// taking indexes in ascending order, signatures are selected with the indexes they won until
+    /// at least `k` indexes are covered.
+    pub fn finalize(&self) -> Result<StmAggrSig<D>, AggregationError> {
+        let mut won_indexes_by_position: BTreeMap<usize, Vec<Index>> = BTreeMap::new();
+        for (&index, &position) in &self.sig_position_by_index {
+            won_indexes_by_position
+                .entry(position)
+                .or_default()
+                .push(index);
+        }
+
+        let mut selected_positions = HashSet::new();
+        let mut unique_sigs = Vec::new();
+        let mut count: u64 = 0;
+        for &position in self.sig_position_by_index.values() {
+            if count >= self.clerk.params.k {
+                break;
+            }
+            if !selected_positions.insert(position) {
+                continue;
+            }
+            let mut sig_reg = self.signatures[position].clone();
+            let won_indexes = &won_indexes_by_position[&position];
+            sig_reg
+                .sig
+                .indexes
+                .retain(|index| won_indexes.contains(index));
+            count += sig_reg.sig.indexes.len() as u64;
+            unique_sigs.push(sig_reg);
+        }
+
+        if count < self.clerk.params.k {
+            return Err(AggregationError::NotEnoughSignatures(
+                count,
+                self.clerk.params.k,
+            ));
+        }
+
+        unique_sigs.sort_unstable();
+        let mt_index_list = unique_sigs
+            .iter()
+            .map(|sig_reg| sig_reg.sig.signer_index as usize)
+            .collect::<Vec<usize>>();
+        let batch_proof = self
+            .clerk
+            .closed_reg
+            .merkle_tree
+            .get_batched_path(mt_index_list);
+
+        Ok(StmAggrSig {
+            signatures: unique_sigs,
+            batch_proof,
+        })
+    }
 }
 
 impl StmSig {
@@ -640,16 +869,26 @@ impl StmSig {
     ) -> Result<StmSig, StmSignatureError> {
         let mut u64_bytes = [0u8; 8];
 
-        u64_bytes.copy_from_slice(&bytes[0..8]);
-        let nr_indexes = u64::from_be_bytes(u64_bytes) as usize;
-
-        let mut indexes = Vec::new();
-        for i in 0..nr_indexes {
-            u64_bytes.copy_from_slice(&bytes[8 + i * 8..16 + i * 8]);
+        u64_bytes.copy_from_slice(
+            bytes
+                .get(0..8)
+                .ok_or(StmSignatureError::SerializationError)?,
+        );
+        let nr_indexes = usize::try_from(u64::from_be_bytes(u64_bytes))
+            .map_err(|_| StmSignatureError::SerializationError)?;
+
+        let offset = nr_indexes
+            .checked_mul(8)
+            .and_then(|indexes_size| indexes_size.checked_add(8))
+            .filter(|offset| offset.saturating_add(56) <= bytes.len())
+            .ok_or(StmSignatureError::SerializationError)?;
+
+        let mut indexes = Vec::with_capacity(nr_indexes);
+        for index_bytes in bytes[8..offset].chunks_exact(8) {
+            u64_bytes.copy_from_slice(index_bytes);
             indexes.push(u64::from_be_bytes(u64_bytes));
         }
 
-        let offset = 8 + nr_indexes * 8;
         let sigma = Signature::from_bytes(&bytes[offset..offset + 48])?;
 
         u64_bytes.copy_from_slice(&bytes[offset + 48..offset + 56]);
@@ -735,7 +974,11 @@ impl StmSigRegParty {
     pub fn from_bytes<D: Digest + Clone + FixedOutput>(
         bytes: &[u8],
     ) -> Result<StmSigRegParty, StmSignatureError> {
-        let reg_party = RegParty::from_bytes(&bytes[0..104])?;
+        let reg_party = RegParty::from_bytes(
+            bytes
+                .get(0..104)
+                .ok_or(StmSignatureError::SerializationError)?,
+        )?;
         let sig = StmSig::from_bytes::<D>(&bytes[104..])?;
 
         Ok(StmSigRegParty { sig, reg_party })
@@ -867,17 +1110,38 @@ impl<D: Clone + Digest + FixedOutput + Send + Sync> StmAggrSig<D> {
     }
 
     ///Extract a `StmAggrSig` from a byte slice.
+    ///
+    /// The compact representation (see [StmAggrSig::to_compact_bytes]) is rejected, as it can
+    /// only be decoded with the key registration of the signers.
     pub fn from_bytes(bytes: &[u8]) -> Result<StmAggrSig<D>, StmAggregateSignatureError<D>> {
+        if bytes.first() == Some(&COMPACT_AGGR_SIG_TAG) {
+            return Err(StmAggregateSignatureError::SerializationError);
+        }
+
         let mut u64_bytes = [0u8; 8];
 
-        u64_bytes.copy_from_slice(&bytes[..8]);
+        u64_bytes.copy_from_slice(
+            bytes
+                .get(..8)
+                .ok_or(StmAggregateSignatureError::SerializationError)?,
+        );
         let size = usize::try_from(u64::from_be_bytes(u64_bytes))
             .map_err(|_| StmAggregateSignatureError::SerializationError)?;
 
-        u64_bytes.copy_from_slice(&bytes[8..16]);
+        u64_bytes.copy_from_slice(
+            bytes
+                .get(8..16)
+                .ok_or(StmAggregateSignatureError::SerializationError)?,
+        );
         let sig_reg_size = usize::try_from(u64::from_be_bytes(u64_bytes))
             .map_err(|_| StmAggregateSignatureError::SerializationError)?;
 
+        let offset = sig_reg_size
+            .checked_mul(size)
+            .and_then(|sig_reg_list_size| sig_reg_list_size.checked_add(16))
+            .filter(|offset| *offset <= bytes.len())
+            .ok_or(StmAggregateSignatureError::SerializationError)?;
+
         let mut sig_reg_list = Vec::with_capacity(size);
         for i in 0..size {
             let sig_reg = StmSigRegParty::from_bytes::<D>(
@@ -886,7 +1150,6 @@ impl<D: Clone + Digest + FixedOutput + Send + Sync> StmAggrSig<D> {
             sig_reg_list.push(sig_reg);
         }
 
-        let offset = 16 + sig_reg_size * size;
         let batch_proof = BatchPath::from_bytes(&bytes[offset..])?;
 
         Ok(StmAggrSig {
@@ -894,6 +1157,154 @@ impl<D: Clone + Digest + FixedOutput + Send + Sync> StmAggrSig<D> {
             batch_proof,
         })
     }
+
+    /// Convert multi signature to its compact bytes representation.
+    ///
+    /// The verification key and the stake of the signers are not written, they are rebuilt
+    /// from their merkle index in the key registration when decoding (see
+    /// [StmAggrSig::from_compact_bytes]). The merkle index of each signer is written once, as it
+    /// is shared by the signature and the batch proof, and integers are variable length encoded.
+    /// # Layout
+    /// * Compact encoding tag (`0xFF`), which can not be the first byte of the legacy encoding
+    /// * Compact encoding version (`1`)
+    /// * Number of signatures (as varint)
+    /// * Number of values of the batch proof (as varint)
+    /// * Values of the batch proof
+    /// * Merkle indices of the signers (as deltas from the previous one)
+    /// * For each signature:
+    ///     * Signature
+    ///     * Number of won indexes (as varint)
+    ///     * Won indexes (as deltas from the previous one)
+    pub fn to_compact_bytes(&self) -> Vec<u8> {
+        let mut out = vec![COMPACT_AGGR_SIG_TAG, COMPACT_AGGR_SIG_VERSION];
+        write_varint(&mut out, self.signatures.len() as u64);
+        write_varint(&mut out, self.batch_proof.values.len() as u64);
+        for value in &self.batch_proof.values {
+            out.extend_from_slice(value);
+        }
+
+        let mut previous = 0;
+        for sig_reg in &self.signatures {
+            write_delta(&mut out, previous, sig_reg.sig.signer_index);
+            previous = sig_reg.sig.signer_index;
+        }
+
+        for sig_reg in &self.signatures {
+            out.extend_from_slice(&sig_reg.sig.sigma.to_bytes());
+            write_varint(&mut out, sig_reg.sig.indexes.len() as u64);
+            let mut previous = 0;
+            for index in &sig_reg.sig.indexes {
+                write_delta(&mut out, previous, *index);
+                previous = *index;
+            }
+        }
+
+        out
+    }
+
+    /// Extract a `StmAggrSig` from its compact bytes representation, the verification key and
+    /// the stake of each signer being taken from the given key registration at its merkle index.
+    /// # Error
+    /// The function fails if the bytes are not well formed or if the batch proof does not match
+    /// the registered parties found at the merkle indices of the signers.
+    pub fn from_compact_bytes(
+        bytes: &[u8],
+        key_registration: &ClosedKeyReg<D>,
+    ) -> Result<StmAggrSig<D>, StmAggregateSignatureError<D>> {
+        let mut reader = ByteReader::new(bytes);
+        let tag_and_version = reader
+            .read_bytes(2)
+            .ok_or(StmAggregateSignatureError::SerializationError)?;
+        if tag_and_version != [COMPACT_AGGR_SIG_TAG, COMPACT_AGGR_SIG_VERSION] {
+            return Err(StmAggregateSignatureError::SerializationError);
+        }
+
+        let nr_signatures = reader
+            .read_varint_usize()
+            .ok_or(StmAggregateSignatureError::SerializationError)?;
+        let nr_values = reader
+            .read_varint_usize()
+            .ok_or(StmAggregateSignatureError::SerializationError)?;
+        let value_size = <D as Digest>::output_size();
+        let mut values = Vec::new();
+        for _ in 0..nr_values {
+            let value = reader
+                .read_bytes(value_size)
+                .ok_or(StmAggregateSignatureError::SerializationError)?;
+            values.push(value.to_vec());
+        }
+
+        let mut signer_indices = Vec::new();
+        let mut previous = 0;
+        for _ in 0..nr_signatures {
+            previous = reader
+                .read_delta(previous)
+                .ok_or(StmAggregateSignatureError::SerializationError)?;
+            signer_indices.push(previous);
+        }
+
+        let mut signatures = Vec::new();
+        for signer_index in &signer_indices {
+            let reg_party = usize::try_from(*signer_index)
+                .ok()
+                .and_then(|index| key_registration.reg_parties.get(index))
+                .ok_or(StmAggregateSignatureError::SerializationError)?;
+            let sigma = reader
+                .read_bytes(48)
+                .and_then(|bytes| Signature::from_bytes(bytes).ok())
+                .ok_or(StmAggregateSignatureError::SerializationError)?;
+            let nr_indexes = reader
+                .read_varint_usize()
+                .ok_or(StmAggregateSignatureError::SerializationError)?;
+            let mut indexes = Vec::new();
+            let mut previous = 0;
+            for _ in 0..nr_indexes {
+                previous = reader
+                    .read_delta(previous)
+                    .ok_or(StmAggregateSignatureError::SerializationError)?;
+                indexes.push(previous);
+            }
+
+            signatures.push(StmSigRegParty {
+                sig: StmSig {
+                    sigma,
+                    indexes,
+                    signer_index: *signer_index,
+                },
+                reg_party: *reg_party,
+            });
+        }
+
+        if !reader.is_empty() {
+            return Err(StmAggregateSignatureError::SerializationError);
+        }
+
+        let indices = signer_indices
+            .into_iter()
+            .map(usize::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| StmAggregateSignatureError::SerializationError)?;
+        let batch_proof = BatchPath {
+            values,
+            indices,
+            hasher: Default::default(),
+        };
+
+        let leaves = signatures
+            .iter()
+            .map(|sig_reg| sig_reg.reg_party)
+            .collect::<Vec<RegParty>>();
+        key_registration
+            .merkle_tree
+            .to_commitment_batch_compat()
+            .check(&leaves, &batch_proof)
+            .map_err(|_| StmAggregateSignatureError::SerializationError)?;
+
+        Ok(StmAggrSig {
+            signatures,
+            batch_proof,
+        })
+    }
 }
 
 impl CoreVerifier {
@@ -1200,6 +1611,101 @@ mod tests {
         sigs
     }
 
+    #[test]
+    fn aggregation_session_reports_quorum_once_k_unique_indices_are_collected() {
+        let params = StmParameters {
+            m: 10,
+            k: 3,
+            phi_f: 1.0,
+        };
+        let ps = setup_equal_parties(params, 3);
+        let clerk = StmClerk::from_signer(&ps[0]);
+        let msg = [0u8; 16];
+        let sigs = find_signatures(&msg, &ps, &[0, 1, 2]);
+        let mut session = clerk.start_aggregation(&msg);
+
+        assert_eq!(0, session.unique_indices_count());
+        session
+            .finalize()
+            .expect_err("Finalize should fail without signature");
+
+        let mut quorum_reached = false;
+        for sig in &sigs {
+            quorum_reached = session.add_signature(sig).unwrap();
+        }
+
+        assert!(quorum_reached);
+        assert!(session.unique_indices_count() >= params.k);
+        let aggr = session.finalize().unwrap();
+        aggr.verify(&msg, &clerk.compute_avk(), &params).unwrap();
+    }
+
+    #[test]
+    fn aggregation_session_rejects_invalid_signatures() {
+        let params = StmParameters {
+            m: 10,
+            k: 3,
+            phi_f: 1.0,
+        };
+        let ps = setup_equal_parties(params, 2);
+        let clerk = StmClerk::from_signer(&ps[0]);
+        let msg = [0u8; 16];
+        let sig_of_other_msg = ps[0].sign(&[1u8; 16]).unwrap();
+        let mut session = clerk.start_aggregation(&msg);
+
+        session
+            .add_signature(&sig_of_other_msg)
+            .expect_err("A signature of another message should be rejected");
+
+        let unknown_signer_sig = StmSig {
+            signer_index: 10,
+            ..ps[0].sign(&msg).unwrap()
+        };
+        assert!(matches!(
+            session.add_signature(&unknown_signer_sig),
+            Err(StmSignatureError::UnknownSigner(10))
+        ));
+        assert_eq!(0, session.unique_indices_count());
+    }
+
+    #[test]
+    fn lottery_preview_of_a_party() {
+        let params = StmParameters {
+            m: 100,
+            k: 20,
+            phi_f: 0.2,
+        };
+
+        let full_stake = params.lottery_preview(10, 10);
+        assert!((full_stake.win_probability - (1.0 - 0.8f64.powi(100))).abs() < 1e-12);
+        assert!((full_stake.expected_won_indices - 20.0).abs() < 1e-9);
+        assert!((full_stake.expected_quorum_contribution - 1.0).abs() < 1e-9);
+
+        let small_stake = params.lottery_preview(1, 1_000);
+        let phi = 1.0 - 0.8f64.powf(0.001);
+        assert!((small_stake.win_probability - (1.0 - (1.0 - phi).powi(100))).abs() < 1e-12);
+        assert!((small_stake.expected_won_indices - 100.0 * phi).abs() < 1e-12);
+
+        let no_stake = params.lottery_preview(0, 1_000);
+        assert_eq!(0.0, no_stake.win_probability);
+        assert_eq!(0.0, no_stake.expected_won_indices);
+    }
+
+    #[test]
+    fn signer_lottery_preview_uses_the_closed_registration_total_stake() {
+        let params = StmParameters {
+            m: 100,
+            k: 20,
+            phi_f: 0.2,
+        };
+        let ps = setup_parties(params, vec![10, 30, 60]);
+
+        assert_eq!(
+            Some(params.lottery_preview(30, 100)),
+            ps[1].lottery_preview()
+        );
+    }
+
     proptest! {
         #![proptest_config(ProptestConfig::with_cases(50))]
 
@@ -1274,6 +1780,38 @@ mod tests {
             }
         }
 
+        #[test]
+        /// Test that an aggregation session gives the same aggregate signature as `aggregate`,
+        /// whatever the order in which the signatures are added.
+        fn test_aggregation_session_matches_aggregate(nparties in 2_usize..30,
+                              m in 10_u64..20,
+                              k in 1_u64..5,
+                              msg in any::<[u8;16]>()) {
+            let params = StmParameters { m, k, phi_f: 0.2 };
+            let ps = setup_equal_parties(params, nparties);
+            let clerk = StmClerk::from_signer(&ps[0]);
+
+            let all_ps: Vec<usize> = (0..nparties).collect();
+            let sigs = find_signatures(&msg, &ps, &all_ps);
+            let mut session = clerk.start_aggregation(&msg);
+            for sig in sigs.iter().rev() {
+                session.add_signature(sig).unwrap();
+            }
+
+            match (clerk.aggregate(&sigs, &msg), session.finalize()) {
+                (Ok(aggr), Ok(session_aggr)) => {
+                    assert!(session.has_quorum());
+                    assert_eq!(aggr.to_bytes(), session_aggr.to_bytes());
+                }
+                (Err(AggregationError::NotEnoughSignatures(..)), Err(AggregationError::NotEnoughSignatures(..))) => {
+                    assert!(!session.has_quorum());
+                }
+                (aggr, session_aggr) => {
+                    panic!("Aggregate and session results differ: {aggr:?} vs {session_aggr:?}");
+                }
+            }
+        }
+
         #[test]
         /// Test that batch verification of certificates works
         fn batch_verify(nparties in 2_usize..30,
@@ -1401,12 +1939,69 @@ mod tests {
                     let bytes: Vec<u8> = aggr.to_bytes();
                     let aggr2 = StmAggrSig::from_bytes(&bytes).unwrap();
                     assert!(aggr2.verify(&msg, &clerk.compute_avk(), &params).is_ok());
+                    for len in 0..bytes.len() {
+                        assert!(StmAggrSig::<D>::from_bytes(&bytes[..len]).is_err());
+                    }
 
                     let encoded = bincode::serialize(&aggr).unwrap();
                     let decoded: StmAggrSig::<D> = bincode::deserialize(&encoded).unwrap();
                     assert!(decoded.verify(&msg, &clerk.compute_avk(), &params).is_ok());
             }
         }
+
+        #[test]
+        fn test_multisig_compact_serialize_deserialize(nparties in 2_usize..10,
+                                                       msg in any::<[u8;16]>()) {
+            let params = StmParameters { m: 10, k: 5, phi_f: 1.0 };
+            let ps = setup_equal_parties(params, nparties);
+            let clerk = StmClerk::from_signer(&ps[0]);
+
+            let all_ps: Vec<usize> = (0..nparties).collect();
+            let sigs = find_signatures(&msg, &ps, &all_ps);
+            let msig = clerk.aggregate(&sigs, &msg);
+            if let Ok(aggr) = msig {
+                    let bytes: Vec<u8> = aggr.to_compact_bytes();
+                    assert!(bytes.len() < aggr.to_bytes().len());
+
+                    let aggr2 = StmAggrSig::<D>::from_compact_bytes(&bytes, &clerk.closed_reg).unwrap();
+                    assert!(aggr2.verify(&msg, &clerk.compute_avk(), &params).is_ok());
+                    assert_eq!(aggr.to_bytes(), aggr2.to_bytes());
+
+                    assert!(StmAggrSig::<D>::from_bytes(&bytes).is_err());
+                    for len in 1..bytes.len() {
+                        assert!(StmAggrSig::<D>::from_compact_bytes(&bytes[..len], &clerk.closed_reg).is_err());
+                    }
+                    let mut unknown_version = bytes.clone();
+                    unknown_version[1] = COMPACT_AGGR_SIG_VERSION + 1;
+                    assert!(StmAggrSig::<D>::from_compact_bytes(&unknown_version, &clerk.closed_reg).is_err());
+
+                    let other_ps = setup_parties(params, vec![2; nparties]);
+                    let other_closed_reg = StmClerk::from_signer(&other_ps[0]).closed_reg;
+                    assert!(StmAggrSig::<D>::from_compact_bytes(&bytes, &other_closed_reg).is_err());
+            }
+        }
+
+        #[test]
+        fn test_clerk_serialize_deserialize(nparties in 2_usize..10,
+                                            msg in any::<[u8;16]>()) {
+            let params = StmParameters { m: 10, k: 5, phi_f: 1.0 };
+            let ps = setup_equal_parties(params, nparties);
+            let clerk = StmClerk::from_signer(&ps[0]);
+
+            let bytes = clerk.to_bytes();
+            let restored_clerk = StmClerk::<D>::from_bytes(&bytes).unwrap();
+            assert_eq!(clerk.params, restored_clerk.params);
+            assert_eq!(clerk.closed_reg.to_bytes(), restored_clerk.closed_reg.to_bytes());
+            assert_eq!(clerk.compute_avk(), restored_clerk.compute_avk());
+
+            let all_ps: Vec<usize> = (0..nparties).collect();
+            let sigs = find_signatures(&msg, &ps, &all_ps);
+            if let Ok(aggr) = restored_clerk.aggregate(&sigs, &msg) {
+                assert!(aggr.verify(&msg, &clerk.compute_avk(), &params).is_ok());
+            }
+
+            assert!(StmClerk::<D>::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        }
     }
 
     /// Pick N between min and max, and then
@@ -513,6 +513,15 @@ pub async fn assert_client_can_verify_cardano_database(
         .await?;
     info!("Client list & show the cardano database snapshot"; "hash" => &hash);
 
+    client
+        .run(ClientCommand::CardanoDbV2(CardanoDbV2Command::Download {
+            hash: hash.to_string(),
+            start: Some(1),
+            end: Some(1),
+        }))
+        .await?;
+    info!("Client downloaded a range of immutable files of the cardano database snapshot"; "hash" => &hash);
+
     Ok(())
 }
 
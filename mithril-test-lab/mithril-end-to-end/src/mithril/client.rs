@@ -1,6 +1,9 @@
 use crate::utils::MithrilCommand;
 use anyhow::{anyhow, Context};
-use mithril_common::{entities::TransactionHash, StdResult};
+use mithril_common::{
+    entities::{ImmutableFileNumber, TransactionHash},
+    StdResult,
+};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
@@ -43,7 +46,14 @@ impl CardanoDbCommand {
 #[derive(Debug)]
 pub enum CardanoDbV2Command {
     List,
-    Show { hash: String },
+    Show {
+        hash: String,
+    },
+    Download {
+        hash: String,
+        start: Option<ImmutableFileNumber>,
+        end: Option<ImmutableFileNumber>,
+    },
 }
 
 impl CardanoDbV2Command {
@@ -51,6 +61,7 @@ impl CardanoDbV2Command {
         match self {
             CardanoDbV2Command::List => "list".to_string(),
             CardanoDbV2Command::Show { hash } => format!("show-{hash}"),
+            CardanoDbV2Command::Download { hash, .. } => format!("download-{hash}"),
         }
     }
 
@@ -62,6 +73,17 @@ impl CardanoDbV2Command {
             CardanoDbV2Command::Show { hash } => {
                 vec!["snapshot".to_string(), "show".to_string(), hash.clone()]
             }
+            CardanoDbV2Command::Download { hash, start, end } => {
+                let mut args = vec!["download".to_string(), hash.clone()];
+                if let Some(start) = start {
+                    args.extend(["--start".to_string(), start.to_string()]);
+                }
+                if let Some(end) = end {
+                    args.extend(["--end".to_string(), end.to_string()]);
+                }
+
+                args
+            }
         }
     }
 }